@@ -134,6 +134,17 @@ pub enum AuthEvent {
     },
     /// Callback received from browser.
     CallbackReceived,
+    /// Device code issued; the user must enter the code on another device.
+    DeviceCodeIssued {
+        /// Code the user enters at the verification URI.
+        user_code: String,
+        /// Where the user enters the code.
+        verification_uri: String,
+        /// Verification URI with the code already filled in, if provided.
+        verification_uri_complete: Option<String>,
+        /// Seconds until the device code expires.
+        expires_in: u64,
+    },
     /// Token exchange started.
     ExchangingToken,
     /// Token obtained successfully.
@@ -85,6 +85,12 @@ impl TokenStore {
         tokens.keys().cloned().collect()
     }
 
+    /// Get a copy of all stored tokens, keyed like the store.
+    pub async fn snapshot(&self) -> HashMap<String, OAuth2Token> {
+        let tokens = self.tokens.read().await;
+        tokens.clone()
+    }
+
     /// Get token status for UI display.
     pub async fn get_status(&self, key: &str) -> TokenStatus {
         let tokens = self.tokens.read().await;
@@ -275,6 +281,7 @@ mod tests {
             .await;
 
         assert_eq!(store.count().await, 2);
+        assert_eq!(store.snapshot().await["key2"].access_token, "b");
 
         store.clear().await;
         assert_eq!(store.count().await, 0);
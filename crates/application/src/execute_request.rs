@@ -6,9 +6,10 @@
 use std::sync::Arc;
 
 use thiserror::Error;
+use tokio::sync::mpsc;
 use vortex_domain::{RequestErrorKind, RequestState, request::RequestSpec, response::ResponseSpec};
 
-use crate::ports::{CancellationReceiver, HttpClient, HttpClientError};
+use crate::ports::{CancellationReceiver, HttpClient, HttpClientError, ResponseStreamEvent};
 
 /// Result type for request execution.
 pub type ExecuteResult = Result<ResponseSpec, ExecuteRequestError>;
@@ -120,6 +121,30 @@ impl<C: HttpClient> ExecuteRequest<C> {
         }
     }
 
+    /// Executes the request, reporting the response while it arrives.
+    ///
+    /// Server-Sent Events and NDJSON bodies are reported event by event as
+    /// they arrive; see [`HttpClient::execute_streaming`]. Cancelling once the
+    /// headers arrived ends the stream and returns the response so far, with
+    /// its events collected for assertions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExecuteRequestError` on validation or HTTP failures, or if
+    /// cancelled before the response headers arrived.
+    pub async fn execute_streaming(
+        &self,
+        request: &RequestSpec,
+        cancel: CancellationReceiver,
+        updates: mpsc::UnboundedSender<ResponseStreamEvent>,
+    ) -> ExecuteResult {
+        self.validate(request)?;
+        Ok(self
+            .client
+            .execute_streaming(request, updates, cancel)
+            .await?)
+    }
+
     /// Validates the request before execution.
     #[allow(clippy::unused_self)]
     fn validate(&self, request: &RequestSpec) -> Result<(), ExecuteRequestError> {
@@ -251,6 +276,40 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_execute_streaming_reports_events() {
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "text/event-stream".to_string());
+        let client = Arc::new(MockHttpClient {
+            response: Ok(ResponseSpec::new(
+                200u16,
+                headers,
+                b"data: one\n\nevent: end\ndata: two\n\n".to_vec(),
+                Duration::from_millis(50),
+            )),
+        });
+        let use_case = ExecuteRequest::new(client);
+        let (_token, cancel) = crate::ports::CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let request = RequestSpec::get("https://api.example.com/events");
+        let response = use_case
+            .execute_streaming(&request, cancel, tx)
+            .await
+            .unwrap();
+        assert_eq!(response.events.len(), 2);
+        assert_eq!(response.events[1].event_type(), "end");
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(ResponseStreamEvent::Started { status: 200, .. })
+        ));
+        let Some(ResponseStreamEvent::Event(event)) = rx.recv().await else {
+            panic!("expected an event");
+        };
+        assert_eq!(event.data, "one");
+    }
+
     #[tokio::test]
     async fn test_result_to_request_state() {
         let success_result: ExecuteResult = Ok(ResponseSpec::new(
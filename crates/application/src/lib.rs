@@ -18,13 +18,19 @@ pub use auth::{AuthEvent, AuthProvider, AuthorizationState, TokenStatus, TokenSt
 pub use error::{ApplicationError, ApplicationResult};
 pub use execute_request::{ExecuteRequest, ExecuteRequestError, ExecuteResult, ExecuteResultExt};
 pub use ports::{
-    CancellationReceiver, CancellationToken, EnvironmentError, EnvironmentRepository, HttpClient,
-    HttpClientError, SecretsError, SecretsRepository,
+    CancellationReceiver, CancellationToken, CookieError, CookieRepository, EnvironmentError,
+    EnvironmentRepository, GraphqlSchemaError, GraphqlSchemaRepository, GrpcClient, GrpcEvent,
+    GrpcSender, GrpcSession, HttpClient, HttpClientError, ResponseStreamEvent, SecretCommandError,
+    SecretCommandRunner, SecretsError, SecretsRepository, WebSocketClient, WebSocketEvent,
+    WebSocketSender, WebSocketSession,
 };
 pub use use_cases::{
     CreateRequest, CreateRequestInput, CreateRequestOutput, CreateWorkspace, CreateWorkspaceInput,
-    ListEnvironments, ListEnvironmentsOutput, LoadCollection, LoadCollectionInput, LoadEnvironment,
-    LoadEnvironmentError, LoadEnvironmentOutput, ResolveVariables, ResolveVariablesOutput,
+    FetchGraphqlSchema, FetchGraphqlSchemaError, FetchGraphqlSchemaOutput, GraphqlSubscription,
+    GraphqlSubscriptionError, GraphqlSubscriptionEvent, GraphqlSubscriptionInput,
+    GraphqlSubscriptionSession, ListEnvironments, ListEnvironmentsOutput, LoadCollection,
+    LoadCollectionInput, LoadEnvironment, LoadEnvironmentError, LoadEnvironmentOutput,
+    ResolveSecretCommands, ResolveSecretCommandsError, ResolveVariables, ResolveVariablesOutput,
     SaveCollection, SaveCollectionInput, SaveEnvironment, SaveEnvironmentError, SwitchEnvironment,
     SwitchEnvironmentError, SwitchEnvironmentOutput, UpdateRequest, UpdateRequestInput,
     UpdateRequestOutput,
@@ -0,0 +1,58 @@
+//! Cookie repository port
+//!
+//! Defines the interface for cookie jar persistence. Each workspace has one
+//! shared jar and may keep a separate jar per environment.
+
+use async_trait::async_trait;
+use std::path::Path;
+
+use vortex_domain::cookie::CookieJar;
+
+/// Errors that can occur during cookie jar operations.
+#[derive(Debug, thiserror::Error)]
+pub enum CookieError {
+    /// I/O error.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Serialization/deserialization error.
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+}
+
+/// Repository trait for cookie jar persistence.
+#[async_trait]
+pub trait CookieRepository: Send + Sync {
+    /// Loads a cookie jar from the workspace.
+    ///
+    /// # Arguments
+    /// * `workspace` - Path to the workspace root
+    /// * `environment` - Environment name for a per-environment jar, or `None`
+    ///   for the workspace jar
+    ///
+    /// # Returns
+    /// The cookie jar without expired cookies. Returns an empty jar if the
+    /// file doesn't exist.
+    async fn load(
+        &self,
+        workspace: &Path,
+        environment: Option<&str>,
+    ) -> Result<CookieJar, CookieError>;
+
+    /// Saves a cookie jar to the workspace.
+    ///
+    /// # Arguments
+    /// * `workspace` - Path to the workspace root
+    /// * `environment` - Environment name for a per-environment jar, or `None`
+    ///   for the workspace jar
+    /// * `jar` - The cookie jar to save
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    async fn save(
+        &self,
+        workspace: &Path,
+        environment: Option<&str>,
+        jar: &CookieJar,
+    ) -> Result<(), CookieError>;
+}
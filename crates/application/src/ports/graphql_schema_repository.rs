@@ -0,0 +1,56 @@
+//! GraphQL schema repository port
+//!
+//! Defines the interface for caching GraphQL schemas. Schemas are downloaded
+//! with the introspection query and cached per workspace and endpoint, so
+//! queries can be validated without asking the server again.
+
+use async_trait::async_trait;
+use std::path::Path;
+
+use vortex_domain::graphql::GraphqlSchema;
+
+/// Errors that can occur during GraphQL schema cache operations.
+#[derive(Debug, thiserror::Error)]
+pub enum GraphqlSchemaError {
+    /// I/O error.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Serialization/deserialization error.
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+}
+
+/// Repository trait for cached GraphQL schemas.
+#[async_trait]
+pub trait GraphqlSchemaRepository: Send + Sync {
+    /// Loads the cached schema of an endpoint.
+    ///
+    /// # Arguments
+    /// * `workspace` - Path to the workspace root
+    /// * `endpoint` - URL of the GraphQL endpoint
+    ///
+    /// # Returns
+    /// The schema, or `None` if it was never fetched.
+    async fn load(
+        &self,
+        workspace: &Path,
+        endpoint: &str,
+    ) -> Result<Option<GraphqlSchema>, GraphqlSchemaError>;
+
+    /// Caches the schema of an endpoint.
+    ///
+    /// # Arguments
+    /// * `workspace` - Path to the workspace root
+    /// * `endpoint` - URL of the GraphQL endpoint
+    /// * `introspection` - The introspection response as received
+    ///
+    /// # Errors
+    /// Returns an error if the response is not JSON or the file cannot be written.
+    async fn save(
+        &self,
+        workspace: &Path,
+        endpoint: &str,
+        introspection: &str,
+    ) -> Result<(), GraphqlSchemaError>;
+}
@@ -0,0 +1,146 @@
+//! gRPC client port
+//!
+//! Defines the interface for gRPC calls. Service definitions come from server
+//! reflection or local `.proto` files, and messages are composed as JSON. A
+//! call is represented by a [`GrpcSession`]: a [`GrpcSender`] for further
+//! request messages and a stream of [`GrpcEvent`]s for the responses.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use vortex_domain::grpc::{GrpcCall, GrpcError, GrpcSchemaSource, GrpcService, GrpcStatus};
+
+/// Event emitted by a gRPC call.
+#[derive(Debug, Clone)]
+pub enum GrpcEvent {
+    /// The server sent its response metadata.
+    Headers(Vec<(String, String)>),
+    /// The server sent a response message.
+    Message {
+        /// The message as pretty-printed JSON.
+        content: String,
+        /// When the message arrived.
+        received_at: DateTime<Utc>,
+    },
+    /// The call ended. This is always the last event.
+    Finished {
+        /// The final status.
+        status: GrpcStatus,
+        /// The trailing metadata.
+        trailers: Vec<(String, String)>,
+    },
+}
+
+/// Handle for streaming request messages into a call.
+///
+/// Cheap to clone. The request stream of a client or bidi streaming call ends
+/// once every sender is dropped.
+#[derive(Debug, Clone)]
+pub struct GrpcSender {
+    tx: mpsc::UnboundedSender<String>,
+}
+
+impl GrpcSender {
+    /// Creates a sender that forwards JSON messages to the given channel.
+    #[must_use]
+    pub const fn new(tx: mpsc::UnboundedSender<String>) -> Self {
+        Self { tx }
+    }
+
+    /// Queues a JSON message for sending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GrpcError::ConnectionFailed` if the request stream has ended.
+    pub fn send(&self, message: impl Into<String>) -> Result<(), GrpcError> {
+        self.tx
+            .send(message.into())
+            .map_err(|_| GrpcError::ConnectionFailed("request stream has ended".to_string()))
+    }
+}
+
+/// A running gRPC call.
+#[derive(Debug)]
+pub struct GrpcSession {
+    sender: Option<GrpcSender>,
+    events: mpsc::UnboundedReceiver<GrpcEvent>,
+}
+
+impl GrpcSession {
+    /// Creates a session from a sender and an event channel.
+    #[must_use]
+    pub const fn new(sender: GrpcSender, events: mpsc::UnboundedReceiver<GrpcEvent>) -> Self {
+        Self {
+            sender: Some(sender),
+            events,
+        }
+    }
+
+    /// Returns the sender for this call, unless sending was closed.
+    #[must_use]
+    pub const fn sender(&self) -> Option<&GrpcSender> {
+        self.sender.as_ref()
+    }
+
+    /// Stops sending messages.
+    ///
+    /// The request stream ends once clones of the sender are dropped as well.
+    pub fn close_send(&mut self) {
+        self.sender = None;
+    }
+
+    /// Waits for the next event.
+    ///
+    /// Returns `None` once the call has finished.
+    pub async fn next_event(&mut self) -> Option<GrpcEvent> {
+        self.events.recv().await
+    }
+}
+
+/// Port for gRPC calls.
+pub trait GrpcClient: Send + Sync {
+    /// Lists the services of an endpoint.
+    ///
+    /// The metadata is sent with server reflection requests. With
+    /// [`GrpcSchemaSource::ProtoFiles`] the endpoint is not contacted.
+    fn list_services(
+        &self,
+        endpoint: &str,
+        source: &GrpcSchemaSource,
+        metadata: &[(String, String)],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GrpcService>, GrpcError>> + Send + '_>>;
+
+    /// Starts a call.
+    ///
+    /// Resolves once the messages of the call are validated against the
+    /// method's input type and the connection is open. The call runs in the
+    /// background and reports through the session's events; a failure status
+    /// from the server is a [`GrpcEvent::Finished`] event, not an error.
+    fn invoke(
+        &self,
+        call: &GrpcCall,
+    ) -> Pin<Box<dyn Future<Output = Result<GrpcSession, GrpcError>> + Send + '_>>;
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sender_forwards_messages() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sender = GrpcSender::new(tx);
+
+        sender.send(r#"{"n": 1}"#).unwrap();
+        assert_eq!(rx.recv().await.unwrap(), r#"{"n": 1}"#);
+
+        drop(rx);
+        assert!(matches!(
+            sender.send("{}"),
+            Err(GrpcError::ConnectionFailed(_))
+        ));
+    }
+}
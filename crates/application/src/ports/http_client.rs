@@ -2,11 +2,18 @@
 //!
 //! Defines the interface for HTTP operations and detailed error types.
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
 
+use chrono::Utc;
 use thiserror::Error;
-use vortex_domain::{RequestErrorKind, request::RequestSpec, response::ResponseSpec};
+use tokio::sync::mpsc;
+use vortex_domain::{
+    ProxyError, RequestErrorKind,
+    request::RequestSpec,
+    response::{DownloadProgress, ResponseSpec, StreamEvent, StreamParser},
+};
 
 /// Error type for HTTP client operations.
 ///
@@ -62,6 +69,10 @@ pub enum HttpClientError {
         max: u32,
     },
 
+    /// Proxy misconfiguration, connection or authentication failure.
+    #[error("{0}")]
+    ProxyError(#[from] ProxyError),
+
     /// Request was cancelled.
     #[error("Request cancelled")]
     Cancelled,
@@ -84,6 +95,7 @@ impl HttpClientError {
             Self::TlsError(_) => RequestErrorKind::TlsError,
             Self::InvalidBody(_) => RequestErrorKind::InvalidBody,
             Self::TooManyRedirects { .. } => RequestErrorKind::TooManyRedirects,
+            Self::ProxyError(_) => RequestErrorKind::ProxyError,
             Self::Cancelled => RequestErrorKind::Cancelled,
             Self::Other(_) => RequestErrorKind::Unknown,
         }
@@ -119,6 +131,65 @@ pub trait HttpClient: Send + Sync {
         &self,
         request: &RequestSpec,
     ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>>;
+
+    /// Executes an HTTP request, reporting the response while it arrives.
+    ///
+    /// Server-Sent Events and NDJSON bodies are reported event by event,
+    /// other bodies as text chunks. The timeout only covers waiting for the
+    /// response headers, so streams can stay open until they end or are
+    /// cancelled. Cancelling after the headers arrived ends the stream and
+    /// returns the response received so far.
+    ///
+    /// The default implementation buffers the response with
+    /// [`execute`](Self::execute) and reports it at once.
+    fn execute_streaming(
+        &self,
+        request: &RequestSpec,
+        updates: mpsc::UnboundedSender<ResponseStreamEvent>,
+        mut cancel: CancellationReceiver,
+    ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>> {
+        let execute = self.execute(request);
+        Box::pin(async move {
+            let mut response = tokio::select! {
+                result = execute => result?,
+                () = cancel.cancelled() => return Err(HttpClientError::Cancelled),
+            };
+            let _ = updates.send(ResponseStreamEvent::Started {
+                status: response.status,
+                headers: response.headers_map.clone(),
+            });
+            if let Some(format) = response.stream_format() {
+                if response.events.is_empty() {
+                    response.events =
+                        StreamParser::parse_all(format, response.body.as_bytes(), Utc::now());
+                }
+                for event in &response.events {
+                    let _ = updates.send(ResponseStreamEvent::Event(event.clone()));
+                }
+            } else if !response.body.is_empty() {
+                let _ = updates.send(ResponseStreamEvent::Chunk(response.body.clone()));
+            }
+            Ok(response)
+        })
+    }
+}
+
+/// Progress of a response executed with [`HttpClient::execute_streaming`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResponseStreamEvent {
+    /// The status and headers arrived.
+    Started {
+        /// HTTP status code.
+        status: u16,
+        /// Response headers.
+        headers: HashMap<String, String>,
+    },
+    /// A Server-Sent Event or NDJSON line arrived.
+    Event(StreamEvent),
+    /// Body text arrived, for bodies that are not split into events.
+    Chunk(String),
+    /// Part of a body arrived that is being saved to a file.
+    Progress(DownloadProgress),
 }
 
 /// A cancellation token for aborting in-flight requests.
@@ -219,6 +290,10 @@ mod tests {
             HttpClientError::Timeout { timeout_ms: 5000 }.to_error_kind(),
             RequestErrorKind::Timeout
         );
+        assert_eq!(
+            HttpClientError::from(ProxyError::AuthenticationFailed).to_error_kind(),
+            RequestErrorKind::ProxyError
+        );
         assert_eq!(
             HttpClientError::Cancelled.to_error_kind(),
             RequestErrorKind::Cancelled
@@ -5,20 +5,34 @@
 
 mod clock;
 mod collection_repository;
+mod cookie_repository;
 mod environment_repository;
 mod file_system;
+mod graphql_schema_repository;
+mod grpc_client;
 mod http_client;
+mod secret_command;
 mod secrets_repository;
 mod storage;
+mod websocket_client;
 mod workspace_repository;
 
 pub use clock::Clock;
 pub use collection_repository::{
     CollectionError, CollectionRepository, CollectionTree, FolderTree, slugify,
 };
+pub use cookie_repository::{CookieError, CookieRepository};
 pub use environment_repository::{EnvironmentError, EnvironmentRepository};
 pub use file_system::{FileSystem, FileSystemError};
-pub use http_client::{CancellationReceiver, CancellationToken, HttpClient, HttpClientError};
+pub use graphql_schema_repository::{GraphqlSchemaError, GraphqlSchemaRepository};
+pub use grpc_client::{GrpcClient, GrpcEvent, GrpcSender, GrpcSession};
+pub use http_client::{
+    CancellationReceiver, CancellationToken, HttpClient, HttpClientError, ResponseStreamEvent,
+};
+pub use secret_command::{SecretCommandError, SecretCommandRunner};
 pub use secrets_repository::{SecretsError, SecretsRepository};
 pub use storage::{CollectionStorage, EnvironmentStorage};
+pub use websocket_client::{
+    WebSocketClient, WebSocketCommand, WebSocketEvent, WebSocketSender, WebSocketSession,
+};
 pub use workspace_repository::{WorkspaceError, WorkspaceRepository};
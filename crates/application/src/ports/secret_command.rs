@@ -0,0 +1,60 @@
+//! Secret command port
+//!
+//! Defines the interface for obtaining secret values from external tools
+//! such as `pass` or the `vault` CLI.
+
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Errors that can occur when running a secret command.
+#[derive(Debug, thiserror::Error)]
+pub enum SecretCommandError {
+    /// The command could not be started.
+    #[error("Failed to run `{command}`: {message}")]
+    Spawn {
+        /// The command line.
+        command: String,
+        /// Error description.
+        message: String,
+    },
+
+    /// The command exited with a failure status.
+    #[error("`{command}` failed ({status}): {stderr}")]
+    Failed {
+        /// The command line.
+        command: String,
+        /// Exit status description.
+        status: String,
+        /// Standard error output of the command.
+        stderr: String,
+    },
+
+    /// The command did not finish in time.
+    #[error("`{command}` timed out after {timeout:?}")]
+    Timeout {
+        /// The command line.
+        command: String,
+        /// The time the command was given.
+        timeout: Duration,
+    },
+
+    /// The command printed something that is not UTF-8.
+    #[error("`{command}` printed a value that is not valid UTF-8")]
+    InvalidOutput {
+        /// The command line.
+        command: String,
+    },
+}
+
+/// Runs the command of a command variable and returns the secret it prints.
+#[async_trait]
+pub trait SecretCommandRunner: Send + Sync {
+    /// Runs a shell command and returns its standard output.
+    ///
+    /// # Arguments
+    /// * `command` - The command line, e.g. `pass show api/prod`
+    ///
+    /// # Errors
+    /// Returns an error if the command cannot be run or does not succeed.
+    async fn run(&self, command: &str) -> Result<String, SecretCommandError>;
+}
@@ -17,6 +17,18 @@ pub enum SecretsError {
     /// Serialization/deserialization error.
     #[error("Serialization error: {0}")]
     Serialization(String),
+
+    /// The secrets file is (or is to be) encrypted and no passphrase was given.
+    #[error("A passphrase is required for the encrypted secrets file")]
+    PassphraseRequired,
+
+    /// The passphrase does not decrypt the secrets file.
+    #[error("Wrong passphrase for the secrets file, or the file is corrupted")]
+    InvalidPassphrase,
+
+    /// The secrets could not be encrypted or decrypted.
+    #[error("Encryption error: {0}")]
+    Encryption(String),
 }
 
 /// Repository trait for secrets persistence.
@@ -0,0 +1,191 @@
+//! WebSocket client port
+//!
+//! Defines the interface for long-lived WebSocket connections. A connection is
+//! represented by a [`WebSocketSession`]: a [`WebSocketSender`] for outgoing
+//! messages and a stream of [`WebSocketEvent`]s for everything else.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use tokio::sync::mpsc;
+use vortex_domain::websocket::{ConnectionInfo, WebSocketConfig, WebSocketError, WebSocketMessage};
+
+/// Event emitted by an open WebSocket session.
+#[derive(Debug, Clone)]
+pub enum WebSocketEvent {
+    /// The connection state or statistics changed.
+    StateChanged(ConnectionInfo),
+    /// A message was sent, received, or a control frame was handled.
+    Message(WebSocketMessage),
+    /// A non-fatal error occurred (e.g. a failed send or reconnect attempt).
+    Error(WebSocketError),
+}
+
+/// Command sent from a [`WebSocketSender`] to the connection task.
+#[derive(Debug, Clone)]
+pub enum WebSocketCommand {
+    /// Send a text or binary message.
+    Send(WebSocketMessage),
+    /// Close the connection without reconnecting.
+    Close,
+}
+
+/// Handle for sending messages over a WebSocket session.
+///
+/// Cheap to clone. The connection is closed once every sender is dropped.
+#[derive(Debug, Clone)]
+pub struct WebSocketSender {
+    tx: mpsc::UnboundedSender<WebSocketCommand>,
+}
+
+impl WebSocketSender {
+    /// Creates a sender that forwards commands to the given channel.
+    #[must_use]
+    pub const fn new(tx: mpsc::UnboundedSender<WebSocketCommand>) -> Self {
+        Self { tx }
+    }
+
+    /// Queues a message for sending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebSocketError::NotConnected` if the session has ended.
+    pub fn send(&self, message: WebSocketMessage) -> Result<(), WebSocketError> {
+        self.command(WebSocketCommand::Send(message))
+    }
+
+    /// Queues a text message for sending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebSocketError::NotConnected` if the session has ended.
+    pub fn send_text(&self, content: impl Into<String>) -> Result<(), WebSocketError> {
+        self.send(WebSocketMessage::text(content))
+    }
+
+    /// Queues a binary message for sending.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebSocketError::NotConnected` if the session has ended.
+    pub fn send_binary(&self, data: Vec<u8>) -> Result<(), WebSocketError> {
+        self.send(WebSocketMessage::binary(data))
+    }
+
+    /// Asks the connection to close gracefully.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebSocketError::NotConnected` if the session has ended.
+    pub fn close(&self) -> Result<(), WebSocketError> {
+        self.command(WebSocketCommand::Close)
+    }
+
+    /// Returns true if the connection task is still running.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        !self.tx.is_closed()
+    }
+
+    fn command(&self, command: WebSocketCommand) -> Result<(), WebSocketError> {
+        self.tx
+            .send(command)
+            .map_err(|_| WebSocketError::NotConnected)
+    }
+}
+
+/// An open WebSocket session.
+#[derive(Debug)]
+pub struct WebSocketSession {
+    sender: WebSocketSender,
+    events: mpsc::UnboundedReceiver<WebSocketEvent>,
+}
+
+impl WebSocketSession {
+    /// Creates a session from a sender and an event channel.
+    #[must_use]
+    pub const fn new(
+        sender: WebSocketSender,
+        events: mpsc::UnboundedReceiver<WebSocketEvent>,
+    ) -> Self {
+        Self { sender, events }
+    }
+
+    /// Returns the sender for this session.
+    #[must_use]
+    pub const fn sender(&self) -> &WebSocketSender {
+        &self.sender
+    }
+
+    /// Waits for the next event.
+    ///
+    /// Returns `None` once the connection task has finished.
+    pub async fn next_event(&mut self) -> Option<WebSocketEvent> {
+        self.events.recv().await
+    }
+
+    /// Splits the session into its sender and event receiver.
+    #[must_use]
+    pub fn into_parts(self) -> (WebSocketSender, mpsc::UnboundedReceiver<WebSocketEvent>) {
+        (self.sender, self.events)
+    }
+}
+
+/// Port for WebSocket connections.
+///
+/// Implementations are responsible for the handshake, control frames and
+/// reconnection as described by the [`WebSocketConfig`].
+pub trait WebSocketClient: Send + Sync {
+    /// Opens a connection.
+    ///
+    /// Resolves once the initial handshake has completed. Later disconnects
+    /// and reconnects are reported through the session's events.
+    fn connect(
+        &self,
+        config: &WebSocketConfig,
+    ) -> Pin<Box<dyn Future<Output = Result<WebSocketSession, WebSocketError>> + Send + '_>>;
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use vortex_domain::websocket::MessageType;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sender_forwards_commands() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sender = WebSocketSender::new(tx);
+
+        sender.send_text("hello").unwrap();
+        sender.send_binary(vec![1, 2]).unwrap();
+        sender.close().unwrap();
+
+        match rx.recv().await.unwrap() {
+            WebSocketCommand::Send(message) => assert_eq!(message.content, "hello"),
+            WebSocketCommand::Close => panic!("expected a message"),
+        }
+        match rx.recv().await.unwrap() {
+            WebSocketCommand::Send(message) => {
+                assert_eq!(message.message_type, MessageType::Binary);
+                assert_eq!(message.binary, vec![1, 2]);
+            }
+            WebSocketCommand::Close => panic!("expected a message"),
+        }
+        assert!(matches!(rx.recv().await, Some(WebSocketCommand::Close)));
+    }
+
+    #[test]
+    fn test_sender_reports_closed_session() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let sender = WebSocketSender::new(tx);
+        drop(rx);
+
+        assert!(!sender.is_open());
+        assert!(matches!(
+            sender.send_text("late"),
+            Err(WebSocketError::NotConnected)
+        ));
+    }
+}
@@ -2,12 +2,14 @@
 
 mod list_environments;
 mod load_environment;
+mod resolve_secret_commands;
 mod resolve_variables;
 mod save_environment;
 mod switch_environment;
 
 pub use list_environments::{ListEnvironments, ListEnvironmentsOutput};
 pub use load_environment::{LoadEnvironment, LoadEnvironmentError, LoadEnvironmentOutput};
+pub use resolve_secret_commands::{ResolveSecretCommands, ResolveSecretCommandsError};
 pub use resolve_variables::{ResolveVariables, ResolveVariablesOutput};
 pub use save_environment::{SaveEnvironment, SaveEnvironmentError};
 pub use switch_environment::{SwitchEnvironment, SwitchEnvironmentError, SwitchEnvironmentOutput};
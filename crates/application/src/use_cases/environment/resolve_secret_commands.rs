@@ -0,0 +1,124 @@
+//! Resolve secret commands use case
+
+use vortex_domain::environment::ResolutionContext;
+
+use crate::ports::{SecretCommandError, SecretCommandRunner};
+
+/// Errors that can occur when resolving command variables.
+#[derive(Debug, thiserror::Error)]
+pub enum ResolveSecretCommandsError {
+    /// The command of a variable failed.
+    #[error("Could not get the value of `{name}`: {source}")]
+    Command {
+        /// Variable name.
+        name: String,
+        /// The command error.
+        #[source]
+        source: SecretCommandError,
+    },
+}
+
+/// Runs the commands of command variables and adds their output to the
+/// secret scope of a resolution context.
+///
+/// The values only live in the context, so they are masked like any other
+/// secret and never written back to the workspace.
+pub struct ResolveSecretCommands<R> {
+    runner: R,
+}
+
+impl<R: SecretCommandRunner> ResolveSecretCommands<R> {
+    /// Creates a new `ResolveSecretCommands` use case.
+    pub const fn new(runner: R) -> Self {
+        Self { runner }
+    }
+
+    /// Executes the use case.
+    ///
+    /// # Arguments
+    /// * `context` - The context whose command variables to resolve
+    ///
+    /// # Returns
+    /// The number of variables resolved.
+    ///
+    /// # Errors
+    /// Returns an error naming the variable if a command fails.
+    pub async fn execute(
+        &self,
+        context: &mut ResolutionContext,
+    ) -> Result<usize, ResolveSecretCommandsError> {
+        let commands = context.secret_commands();
+        for (name, command) in &commands {
+            let value = self.runner.run(command).await.map_err(|source| {
+                ResolveSecretCommandsError::Command {
+                    name: name.clone(),
+                    source,
+                }
+            })?;
+            context.secrets.insert(name.clone(), value);
+        }
+        Ok(commands.len())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use vortex_domain::environment::{Environment, VariableScope};
+
+    struct MockRunner;
+
+    #[async_trait]
+    impl SecretCommandRunner for MockRunner {
+        async fn run(&self, command: &str) -> Result<String, SecretCommandError> {
+            command
+                .strip_prefix("pass show ")
+                .map(|path| format!("secret-for-{path}"))
+                .ok_or_else(|| SecretCommandError::Failed {
+                    command: command.to_string(),
+                    status: "exit status: 1".to_string(),
+                    stderr: "not found".to_string(),
+                })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_secret_commands() {
+        let mut env = Environment::new("production");
+        env.add_secret_command("token", "pass show api/prod");
+        env.add_variable("base_url", "https://api.example.com");
+        let mut context = ResolutionContext::new().with_environment(&env);
+
+        let count = ResolveSecretCommands::new(MockRunner)
+            .execute(&mut context)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 1);
+        let token = context.resolve("token").unwrap();
+        assert_eq!(token.value, "secret-for-api/prod");
+        assert_eq!(token.scope, VariableScope::Secret);
+        assert_eq!(context.secret_values(), ["secret-for-api/prod"]);
+        assert!(context.environment["token"].value.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_secret_commands_failure() {
+        let mut env = Environment::new("production");
+        env.add_secret_command("token", "vault read secret/api");
+        let mut context = ResolutionContext::new().with_environment(&env);
+
+        let err = ResolveSecretCommands::new(MockRunner)
+            .execute(&mut context)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            &err,
+            ResolveSecretCommandsError::Command { name, .. } if name == "token"
+        ));
+        assert!(err.to_string().contains("not found"));
+    }
+}
@@ -1,7 +1,7 @@
 //! Resolve variables use case
 
 use vortex_domain::environment::ResolutionContext;
-use vortex_domain::request::RequestSpec;
+use vortex_domain::request::{RequestBodyKind, RequestSpec};
 
 use crate::variable_resolver::{ResolutionResult, VariableResolver};
 
@@ -111,8 +111,23 @@ impl ResolveVariables {
             let body_result = self.resolver.resolve(&request.body.content);
             all_unresolved.extend(body_result.unresolved);
 
+            let kind = match &request.body.kind {
+                RequestBodyKind::Graphql {
+                    variables,
+                    operation_name,
+                } => {
+                    let variables_result = self.resolver.resolve(variables);
+                    all_unresolved.extend(variables_result.unresolved);
+                    RequestBodyKind::Graphql {
+                        variables: variables_result.resolved,
+                        operation_name: operation_name.clone(),
+                    }
+                }
+                kind => kind.clone(),
+            };
+
             resolved_request.body = vortex_domain::request::RequestBody {
-                kind: request.body.kind.clone(),
+                kind,
                 content: body_result.resolved,
             };
         }
@@ -134,8 +149,9 @@ impl ResolveVariables {
         }
     }
 
-    /// Resolves variables in auth configuration.
-    fn resolve_auth(
+    /// Resolves variables in auth configuration, collecting unresolved
+    /// variable names.
+    pub fn resolve_auth(
         &mut self,
         auth: &vortex_domain::auth::AuthConfig,
         unresolved: &mut Vec<String>,
@@ -148,50 +164,85 @@ impl ResolveVariables {
                 key,
                 name,
                 location,
-            } => {
-                let key_result = self.resolver.resolve(key);
-                let name_result = self.resolver.resolve(name);
-                unresolved.extend(key_result.unresolved);
-                unresolved.extend(name_result.unresolved);
-
-                AuthConfig::ApiKey {
-                    key: key_result.resolved,
-                    name: name_result.resolved,
-                    location: *location,
-                }
-            }
-            AuthConfig::Bearer { token, prefix } => {
-                let token_result = self.resolver.resolve(token);
-                unresolved.extend(token_result.unresolved);
+            } => AuthConfig::ApiKey {
+                key: self.resolve_field(key, unresolved),
+                name: self.resolve_field(name, unresolved),
+                location: *location,
+            },
+            AuthConfig::Bearer { token, prefix } => AuthConfig::Bearer {
+                token: self.resolve_field(token, unresolved),
+                prefix: prefix.clone(),
+            },
+            AuthConfig::OAuth2ClientCredentials { .. }
+            | AuthConfig::OAuth2AuthorizationCode { .. }
+            | AuthConfig::OAuth2Password { .. }
+            | AuthConfig::OAuth2DeviceCode { .. }
+            | AuthConfig::OAuth2JwtBearer { .. } => self.resolve_oauth2(auth, unresolved),
+            AuthConfig::AwsSigV4 {
+                access_key,
+                secret_key,
+                session_token,
+                region,
+                service,
+            } => AuthConfig::AwsSigV4 {
+                access_key: self.resolve_field(access_key, unresolved),
+                secret_key: self.resolve_field(secret_key, unresolved),
+                session_token: session_token
+                    .as_ref()
+                    .map(|token| self.resolve_field(token, unresolved)),
+                region: self.resolve_field(region, unresolved),
+                service: self.resolve_field(service, unresolved),
+            },
+            AuthConfig::Basic { username, password } => AuthConfig::Basic {
+                username: self.resolve_field(username, unresolved),
+                password: self.resolve_field(password, unresolved),
+            },
+            AuthConfig::Digest { username, password } => AuthConfig::Digest {
+                username: self.resolve_field(username, unresolved),
+                password: self.resolve_field(password, unresolved),
+            },
+            AuthConfig::Jwt {
+                algorithm,
+                secret,
+                claims,
+                key_id,
+                location,
+                prefix,
+                query_param,
+            } => AuthConfig::Jwt {
+                algorithm: *algorithm,
+                secret: self.resolve_field(secret, unresolved),
+                claims: self.resolve_field(claims, unresolved),
+                key_id: key_id.as_ref().map(|id| self.resolve_field(id, unresolved)),
+                location: *location,
+                prefix: prefix.clone(),
+                query_param: self.resolve_field(query_param, unresolved),
+            },
+        }
+    }
 
-                AuthConfig::Bearer {
-                    token: token_result.resolved,
-                    prefix: prefix.clone(),
-                }
-            }
-            // OAuth2 configs - variables in URLs and secrets should be resolved
+    /// Resolves variables in `OAuth2` configuration: URLs and secrets.
+    fn resolve_oauth2(
+        &mut self,
+        auth: &vortex_domain::auth::AuthConfig,
+        unresolved: &mut Vec<String>,
+    ) -> vortex_domain::auth::AuthConfig {
+        use vortex_domain::auth::AuthConfig;
+
+        match auth {
             AuthConfig::OAuth2ClientCredentials {
                 token_url,
                 client_id,
                 client_secret,
                 scope,
                 extra_params,
-            } => {
-                let token_url_result = self.resolver.resolve(token_url);
-                let client_id_result = self.resolver.resolve(client_id);
-                let client_secret_result = self.resolver.resolve(client_secret);
-                unresolved.extend(token_url_result.unresolved);
-                unresolved.extend(client_id_result.unresolved);
-                unresolved.extend(client_secret_result.unresolved);
-
-                AuthConfig::OAuth2ClientCredentials {
-                    token_url: token_url_result.resolved,
-                    client_id: client_id_result.resolved,
-                    client_secret: client_secret_result.resolved,
-                    scope: scope.clone(),
-                    extra_params: extra_params.clone(),
-                }
-            }
+            } => AuthConfig::OAuth2ClientCredentials {
+                token_url: self.resolve_field(token_url, unresolved),
+                client_id: self.resolve_field(client_id, unresolved),
+                client_secret: self.resolve_field(client_secret, unresolved),
+                scope: scope.clone(),
+                extra_params: extra_params.clone(),
+            },
             AuthConfig::OAuth2AuthorizationCode {
                 auth_url,
                 token_url,
@@ -200,42 +251,73 @@ impl ResolveVariables {
                 redirect_uri,
                 scope,
                 extra_params,
-            } => {
-                let auth_url_result = self.resolver.resolve(auth_url);
-                let token_url_result = self.resolver.resolve(token_url);
-                let client_id_result = self.resolver.resolve(client_id);
-                let client_secret_result = self.resolver.resolve(client_secret);
-                let redirect_uri_result = self.resolver.resolve(redirect_uri);
-                unresolved.extend(auth_url_result.unresolved);
-                unresolved.extend(token_url_result.unresolved);
-                unresolved.extend(client_id_result.unresolved);
-                unresolved.extend(client_secret_result.unresolved);
-                unresolved.extend(redirect_uri_result.unresolved);
-
-                AuthConfig::OAuth2AuthorizationCode {
-                    auth_url: auth_url_result.resolved,
-                    token_url: token_url_result.resolved,
-                    client_id: client_id_result.resolved,
-                    client_secret: client_secret_result.resolved,
-                    redirect_uri: redirect_uri_result.resolved,
-                    scope: scope.clone(),
-                    extra_params: extra_params.clone(),
-                }
-            }
-            AuthConfig::Basic { username, password } => {
-                let user_result = self.resolver.resolve(username);
-                let pass_result = self.resolver.resolve(password);
-                unresolved.extend(user_result.unresolved);
-                unresolved.extend(pass_result.unresolved);
-
-                AuthConfig::Basic {
-                    username: user_result.resolved,
-                    password: pass_result.resolved,
-                }
-            }
+            } => AuthConfig::OAuth2AuthorizationCode {
+                auth_url: self.resolve_field(auth_url, unresolved),
+                token_url: self.resolve_field(token_url, unresolved),
+                client_id: self.resolve_field(client_id, unresolved),
+                client_secret: self.resolve_field(client_secret, unresolved),
+                redirect_uri: self.resolve_field(redirect_uri, unresolved),
+                scope: scope.clone(),
+                extra_params: extra_params.clone(),
+            },
+            AuthConfig::OAuth2Password {
+                token_url,
+                client_id,
+                client_secret,
+                username,
+                password,
+                scope,
+                extra_params,
+            } => AuthConfig::OAuth2Password {
+                token_url: self.resolve_field(token_url, unresolved),
+                client_id: self.resolve_field(client_id, unresolved),
+                client_secret: self.resolve_field(client_secret, unresolved),
+                username: self.resolve_field(username, unresolved),
+                password: self.resolve_field(password, unresolved),
+                scope: scope.clone(),
+                extra_params: extra_params.clone(),
+            },
+            AuthConfig::OAuth2DeviceCode {
+                device_authorization_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                extra_params,
+            } => AuthConfig::OAuth2DeviceCode {
+                device_authorization_url: self.resolve_field(device_authorization_url, unresolved),
+                token_url: self.resolve_field(token_url, unresolved),
+                client_id: self.resolve_field(client_id, unresolved),
+                client_secret: self.resolve_field(client_secret, unresolved),
+                scope: scope.clone(),
+                extra_params: extra_params.clone(),
+            },
+            AuthConfig::OAuth2JwtBearer {
+                token_url,
+                assertion,
+                client_id,
+                client_secret,
+                scope,
+                extra_params,
+            } => AuthConfig::OAuth2JwtBearer {
+                token_url: self.resolve_field(token_url, unresolved),
+                assertion: self.resolve_field(assertion, unresolved),
+                client_id: self.resolve_field(client_id, unresolved),
+                client_secret: self.resolve_field(client_secret, unresolved),
+                scope: scope.clone(),
+                extra_params: extra_params.clone(),
+            },
+            other => other.clone(),
         }
     }
 
+    /// Resolves a single auth field, collecting unresolved variable names.
+    fn resolve_field(&mut self, value: &str, unresolved: &mut Vec<String>) -> String {
+        let result = self.resolver.resolve(value);
+        unresolved.extend(result.unresolved);
+        result.resolved
+    }
+
     /// Preview resolution for the URL only (for UI display).
     #[must_use]
     pub fn preview_url(&self, url: &str) -> ResolutionResult {
@@ -381,6 +463,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_graphql_body() {
+        let context = create_test_context();
+        let mut use_case = ResolveVariables::new(context);
+
+        let mut request = RequestSpec::post("{{base_url}}/graphql");
+        request.body = RequestBody::graphql(
+            "query App($key: String!) { app(key: $key) { name } }",
+            r#"{"key": "{{api_key}}", "user": "{{missing}}"}"#,
+            Some("App".to_string()),
+        );
+
+        let output = use_case.execute(&request);
+
+        assert_eq!(output.all_unresolved, ["missing"]);
+        assert_eq!(
+            output.resolved_request.body.kind,
+            RequestBodyKind::Graphql {
+                variables: r#"{"key": "sk-secret-123", "user": "{{missing}}"}"#.to_string(),
+                operation_name: Some("App".to_string()),
+            }
+        );
+    }
+
     #[test]
     fn test_resolve_bearer_auth() {
         let context = create_test_context();
@@ -422,6 +528,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_resolve_oauth2_password_auth() {
+        let mut env = Environment::new("test");
+        env.add_variable("idp", "https://idp.example.com");
+        env.add_variable("password", "hunter2");
+
+        let context = ResolutionContext::from_environment(&env, &SecretsStore::new());
+        let mut use_case = ResolveVariables::new(context);
+
+        let mut request = RequestSpec::get("http://localhost/api");
+        request.auth = AuthConfig::OAuth2Password {
+            token_url: "{{idp}}/token".to_string(),
+            client_id: "cli".to_string(),
+            client_secret: String::new(),
+            username: "alice".to_string(),
+            password: "{{password}}".to_string(),
+            scope: None,
+            extra_params: std::collections::BTreeMap::new(),
+        };
+
+        let output = use_case.execute(&request);
+
+        assert!(output.is_complete);
+        match output.resolved_request.auth {
+            AuthConfig::OAuth2Password {
+                token_url,
+                password,
+                ..
+            } => {
+                assert_eq!(token_url, "https://idp.example.com/token");
+                assert_eq!(password, "hunter2");
+            }
+            _ => panic!("Expected OAuth2 password auth"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_jwt_auth() {
+        let mut env = Environment::new("test");
+        env.add_variable("service", "billing");
+        let mut secrets = SecretsStore::new();
+        secrets.set_secret("test", "jwt_secret", "s3cr3t");
+
+        let context = ResolutionContext::from_environment(&env, &secrets);
+        let mut use_case = ResolveVariables::new(context);
+
+        let mut request = RequestSpec::get("http://localhost/api");
+        request.auth = AuthConfig::jwt(
+            vortex_domain::auth::JwtAlgorithm::Hs256,
+            "{{jwt_secret}}",
+            r#"{"sub":"{{service}}","iat":{{$timestamp}}}"#,
+        );
+
+        let output = use_case.execute(&request);
+
+        assert!(output.is_complete);
+        match output.resolved_request.auth {
+            AuthConfig::Jwt { secret, claims, .. } => {
+                assert_eq!(secret, "s3cr3t");
+                assert!(claims.starts_with(r#"{"sub":"billing","iat":"#));
+                assert!(!claims.contains("{{"));
+            }
+            _ => panic!("Expected JWT auth"),
+        }
+    }
+
     #[test]
     fn test_builtin_variables() {
         let mut use_case = ResolveVariables::empty();
@@ -0,0 +1,198 @@
+//! Fetch GraphQL schema use case.
+
+use std::sync::Arc;
+
+use vortex_domain::graphql::{GraphqlError, GraphqlSchema, INTROSPECTION_QUERY};
+use vortex_domain::request::{HttpMethod, RequestBody, RequestSpec};
+
+use crate::ports::{HttpClient, HttpClientError};
+
+/// Errors that can occur when fetching a GraphQL schema.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FetchGraphqlSchemaError {
+    /// The request failed.
+    #[error("{0}")]
+    Http(#[from] HttpClientError),
+
+    /// The server answered with an error status.
+    #[error("The server answered {status} {status_text}")]
+    Status {
+        /// HTTP status code.
+        status: u16,
+        /// Status text.
+        status_text: String,
+    },
+
+    /// The response is not JSON.
+    #[error("The response is not JSON")]
+    NotJson,
+
+    /// The response does not contain a usable schema.
+    #[error("{0}")]
+    Schema(#[from] GraphqlError),
+}
+
+/// Output of fetching a GraphQL schema.
+#[derive(Debug, Clone)]
+pub struct FetchGraphqlSchemaOutput {
+    /// The parsed schema.
+    pub schema: GraphqlSchema,
+    /// The introspection response as received, for caching.
+    pub introspection: String,
+}
+
+/// Use case for downloading the schema of a GraphQL endpoint with the
+/// introspection query.
+pub struct FetchGraphqlSchema<C: HttpClient> {
+    client: Arc<C>,
+}
+
+impl<C: HttpClient> FetchGraphqlSchema<C> {
+    /// Creates a new `FetchGraphqlSchema` use case.
+    pub const fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    /// Sends the introspection query to the endpoint of `request`.
+    ///
+    /// The URL, headers, auth and connection settings of `request` are kept;
+    /// the method and body are replaced by the introspection query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the server answers with an
+    /// error status, or the response is not an introspection result.
+    pub async fn execute(
+        &self,
+        request: &RequestSpec,
+    ) -> Result<FetchGraphqlSchemaOutput, FetchGraphqlSchemaError> {
+        let mut request = request.clone();
+        request.method = HttpMethod::Post;
+        request.body = RequestBody::graphql(INTROSPECTION_QUERY, "", None);
+
+        let response = self.client.execute(&request).await?;
+        let json = response.body_as_json();
+        // GraphQL servers may answer errors with a 4xx status and a JSON body
+        // whose message is more useful than the status
+        if !response.is_success()
+            && json
+                .as_ref()
+                .is_none_or(|json| json.pointer("/errors/0/message").is_none())
+        {
+            return Err(FetchGraphqlSchemaError::Status {
+                status: response.status,
+                status_text: response.status_text,
+            });
+        }
+        let json = json.ok_or(FetchGraphqlSchemaError::NotJson)?;
+        let schema = GraphqlSchema::from_introspection(&json)?;
+
+        Ok(FetchGraphqlSchemaOutput {
+            schema,
+            introspection: response.body,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use vortex_domain::request::RequestBodyKind;
+    use vortex_domain::response::ResponseSpec;
+
+    use super::*;
+
+    struct MockHttpClient {
+        status: u16,
+        body: &'static str,
+        sent: Mutex<Vec<RequestSpec>>,
+    }
+
+    impl MockHttpClient {
+        fn new(status: u16, body: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                status,
+                body,
+                sent: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn execute(
+            &self,
+            request: &RequestSpec,
+        ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>>
+        {
+            self.sent.lock().unwrap().push(request.clone());
+            let response = ResponseSpec::new(
+                self.status,
+                HashMap::new(),
+                self.body.as_bytes().to_vec(),
+                Duration::from_millis(5),
+            );
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    const SCHEMA: &str = r#"{"data": {"__schema": {
+        "queryType": {"name": "Query"},
+        "types": [
+            {"kind": "OBJECT", "name": "Query", "fields": [
+                {"name": "me", "args": [], "type": {"kind": "SCALAR", "name": "String"}}
+            ]},
+            {"kind": "SCALAR", "name": "String"}
+        ]
+    }}}"#;
+
+    #[tokio::test]
+    async fn test_fetch_schema() {
+        let client = MockHttpClient::new(200, SCHEMA);
+        let use_case = FetchGraphqlSchema::new(Arc::clone(&client));
+        let request =
+            RequestSpec::get("https://api.example.com/graphql").with_header("X-Token", "abc");
+
+        let output = use_case.execute(&request).await.unwrap();
+        assert_eq!(output.schema.type_count(), 2);
+        assert_eq!(output.introspection, SCHEMA);
+
+        let sent = client.sent.lock().unwrap().remove(0);
+        assert_eq!(sent.method, HttpMethod::Post);
+        assert_eq!(sent.url, "https://api.example.com/graphql");
+        assert!(sent.headers.enabled().any(|h| h.name == "X-Token"));
+        assert!(matches!(sent.body.kind, RequestBodyKind::Graphql { .. }));
+        assert_eq!(sent.body.content, INTROSPECTION_QUERY);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_schema_errors() {
+        let use_case = FetchGraphqlSchema::new(MockHttpClient::new(404, "Not Found"));
+        let request = RequestSpec::get("https://api.example.com/graphql");
+        assert!(matches!(
+            use_case.execute(&request).await,
+            Err(FetchGraphqlSchemaError::Status { status: 404, .. })
+        ));
+
+        let use_case = FetchGraphqlSchema::new(MockHttpClient::new(
+            400,
+            r#"{"errors": [{"message": "Introspection is disabled"}]}"#,
+        ));
+        let error = use_case.execute(&request).await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid introspection result: Introspection is disabled"
+        );
+
+        let use_case = FetchGraphqlSchema::new(MockHttpClient::new(200, "<html>"));
+        assert!(matches!(
+            use_case.execute(&request).await,
+            Err(FetchGraphqlSchemaError::NotJson)
+        ));
+    }
+}
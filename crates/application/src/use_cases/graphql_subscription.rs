@@ -0,0 +1,401 @@
+//! GraphQL subscription use case.
+//!
+//! Runs a subscription over the `graphql-transport-ws` protocol on top of the
+//! WebSocket port: the connection is opened with the connection params, the
+//! operation is subscribed once the server acknowledges, and each `next`
+//! message becomes an event.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+use vortex_domain::graphql::{ClientMessage, GRAPHQL_TRANSPORT_WS, GraphqlError, ServerMessage};
+use vortex_domain::websocket::{
+    ConnectionInfo, MessageDirection, MessageType, WebSocketConfig, WebSocketError,
+    WebSocketMessage,
+};
+
+use crate::ports::{WebSocketClient, WebSocketEvent, WebSocketSender};
+
+/// Id of the operation; a session runs a single subscription.
+const OPERATION_ID: &str = "1";
+
+/// Errors that can occur when starting a GraphQL subscription.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GraphqlSubscriptionError {
+    /// The query, variables or connection params are invalid.
+    #[error("{0}")]
+    Graphql(#[from] GraphqlError),
+
+    /// The connection failed or was closed before it was acknowledged.
+    #[error("{0}")]
+    WebSocket(#[from] WebSocketError),
+}
+
+/// Input for starting a GraphQL subscription.
+#[derive(Debug, Clone, Default)]
+pub struct GraphqlSubscriptionInput {
+    /// Connection settings. The `graphql-transport-ws` subprotocol is added.
+    pub config: WebSocketConfig,
+    /// The subscription document.
+    pub query: String,
+    /// Variables as JSON text; may be empty.
+    pub variables: String,
+    /// The operation to run when the document has several.
+    pub operation_name: Option<String>,
+    /// Connection params as JSON text, sent with `connection_init`; may be empty.
+    pub connection_params: String,
+}
+
+/// Event of a running subscription.
+#[derive(Debug, Clone)]
+pub enum GraphqlSubscriptionEvent {
+    /// The connection state or statistics changed.
+    StateChanged(ConnectionInfo),
+    /// The server sent a result.
+    Next {
+        /// The execution result (`data`, `errors`).
+        payload: JsonValue,
+        /// When the result was received.
+        received_at: DateTime<Utc>,
+    },
+    /// The operation failed; holds the error messages.
+    Error(Vec<String>),
+    /// The server ended the operation.
+    Complete,
+    /// A connection error, or a message that breaks the protocol.
+    Warning(String),
+}
+
+/// A running GraphQL subscription.
+#[derive(Debug)]
+pub struct GraphqlSubscriptionSession {
+    sender: WebSocketSender,
+    pending: VecDeque<WebSocketEvent>,
+    events: mpsc::UnboundedReceiver<WebSocketEvent>,
+    finished: bool,
+}
+
+impl GraphqlSubscriptionSession {
+    /// Waits for the next event.
+    ///
+    /// Server pings are answered here. The connection is closed once the
+    /// operation completes or fails; `None` is returned after it has ended.
+    pub async fn next_event(&mut self) -> Option<GraphqlSubscriptionEvent> {
+        loop {
+            let event = match self.pending.pop_front() {
+                Some(event) => event,
+                None => self.events.recv().await?,
+            };
+            match event {
+                WebSocketEvent::StateChanged(info) => {
+                    return Some(GraphqlSubscriptionEvent::StateChanged(info));
+                }
+                WebSocketEvent::Error(error) => {
+                    return Some(GraphqlSubscriptionEvent::Warning(error.to_string()));
+                }
+                WebSocketEvent::Message(message) => {
+                    if let Some(event) = self.received(&message) {
+                        return Some(event);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stops the subscription and closes the connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WebSocketError::NotConnected` if the connection has ended.
+    pub fn stop(&self) -> Result<(), WebSocketError> {
+        if !self.finished {
+            let complete = ClientMessage::Complete {
+                id: OPERATION_ID.to_string(),
+            };
+            self.sender.send_text(complete.to_json())?;
+        }
+        self.sender.close()
+    }
+
+    /// Returns the sender of the underlying connection.
+    #[must_use]
+    pub const fn sender(&self) -> &WebSocketSender {
+        &self.sender
+    }
+
+    /// Handles a message of the underlying connection.
+    fn received(&mut self, message: &WebSocketMessage) -> Option<GraphqlSubscriptionEvent> {
+        if message.direction != MessageDirection::Received
+            || message.message_type != MessageType::Text
+        {
+            return None;
+        }
+
+        let event = match ServerMessage::parse(&message.content) {
+            Ok(ServerMessage::Next { id, payload }) if id == OPERATION_ID => {
+                GraphqlSubscriptionEvent::Next {
+                    payload,
+                    received_at: message.timestamp,
+                }
+            }
+            Ok(error @ ServerMessage::Error { .. }) => {
+                self.finish();
+                GraphqlSubscriptionEvent::Error(error.error_messages())
+            }
+            Ok(ServerMessage::Complete { id }) if id == OPERATION_ID => {
+                self.finish();
+                GraphqlSubscriptionEvent::Complete
+            }
+            Ok(ServerMessage::Ping { .. }) => {
+                let pong = ClientMessage::Pong { payload: None };
+                let _ = self.sender.send_text(pong.to_json());
+                return None;
+            }
+            Ok(_) => return None,
+            Err(error) => GraphqlSubscriptionEvent::Warning(error.to_string()),
+        };
+        Some(event)
+    }
+
+    /// Closes the connection after the server ended the operation.
+    fn finish(&mut self) {
+        self.finished = true;
+        let _ = self.sender.close();
+    }
+}
+
+/// Use case for running a GraphQL subscription.
+pub struct GraphqlSubscription<C: WebSocketClient + ?Sized> {
+    client: Arc<C>,
+}
+
+impl<C: WebSocketClient + ?Sized> GraphqlSubscription<C> {
+    /// Creates a new `GraphqlSubscription` use case.
+    pub const fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+
+    /// Connects, waits for the server to acknowledge the connection and
+    /// subscribes to the operation.
+    ///
+    /// Reconnection is turned off: a new connection would need a new
+    /// subscription.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is invalid, the connection fails, or
+    /// the server closes it or does not acknowledge it within the connect
+    /// timeout.
+    pub async fn execute(
+        &self,
+        input: GraphqlSubscriptionInput,
+    ) -> Result<GraphqlSubscriptionSession, GraphqlSubscriptionError> {
+        let init = ClientMessage::connection_init(&input.connection_params)?;
+        let subscribe = ClientMessage::subscribe(
+            OPERATION_ID,
+            &input.query,
+            &input.variables,
+            input.operation_name.as_deref(),
+        )?;
+
+        let mut config = input.config;
+        config.auto_reconnect = false;
+        config.subprotocols = vec![GRAPHQL_TRANSPORT_WS.to_string()];
+        let timeout = config.connect_timeout();
+
+        let (sender, mut events) = self.client.connect(&config).await?.into_parts();
+        sender.send_text(init.to_json())?;
+
+        // Events before the acknowledgement are kept for the session
+        let mut pending = VecDeque::new();
+        let acknowledged = tokio::time::timeout(timeout, async {
+            while let Some(event) = events.recv().await {
+                match &event {
+                    WebSocketEvent::Message(message)
+                        if message.direction == MessageDirection::Received =>
+                    {
+                        match ServerMessage::parse(&message.content) {
+                            Ok(ServerMessage::ConnectionAck { .. }) => return Ok(()),
+                            Ok(ServerMessage::Ping { .. }) => {
+                                let pong = ClientMessage::Pong { payload: None };
+                                sender.send_text(pong.to_json())?;
+                                continue;
+                            }
+                            _ => {}
+                        }
+                    }
+                    WebSocketEvent::Error(error) => return Err(error.clone()),
+                    _ => {}
+                }
+                pending.push_back(event);
+            }
+            Err(WebSocketError::ConnectionClosed(
+                "closed before the connection was acknowledged".to_string(),
+            ))
+        })
+        .await;
+
+        if let Err(error) = acknowledged
+            .map_err(|_| WebSocketError::Timeout)
+            .and_then(|result| result)
+        {
+            let _ = sender.close();
+            return Err(error.into());
+        }
+
+        sender.send_text(subscribe.to_json())?;
+
+        Ok(GraphqlSubscriptionSession {
+            sender,
+            pending,
+            events,
+            finished: false,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use vortex_domain::websocket::ConnectionState;
+
+    use super::*;
+    use crate::ports::{WebSocketCommand, WebSocketSession};
+
+    /// Plays a subscription server: answers `connection_init` with `reply`,
+    /// and `subscribe` with two results and `complete`.
+    struct MockWebSocketClient {
+        reply: &'static str,
+        sent: Arc<Mutex<Vec<String>>>,
+        configs: Mutex<Vec<WebSocketConfig>>,
+    }
+
+    impl MockWebSocketClient {
+        fn new(reply: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                reply,
+                sent: Arc::default(),
+                configs: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    impl WebSocketClient for MockWebSocketClient {
+        fn connect(
+            &self,
+            config: &WebSocketConfig,
+        ) -> Pin<Box<dyn Future<Output = Result<WebSocketSession, WebSocketError>> + Send + '_>>
+        {
+            self.configs.lock().unwrap().push(config.clone());
+            let (command_tx, mut commands) = mpsc::unbounded_channel();
+            let (events, event_rx) = mpsc::unbounded_channel();
+            let (reply, sent) = (self.reply, Arc::clone(&self.sent));
+
+            tokio::spawn(async move {
+                let mut info = ConnectionInfo::new("ws://localhost");
+                info.connected(Some(GRAPHQL_TRANSPORT_WS.to_string()));
+                let _ = events.send(WebSocketEvent::StateChanged(info.clone()));
+
+                let receive =
+                    |text: &str| WebSocketEvent::Message(WebSocketMessage::received_text(text));
+                while let Some(WebSocketCommand::Send(message)) = commands.recv().await {
+                    sent.lock().unwrap().push(message.content.clone());
+                    let _ = events.send(WebSocketEvent::Message(message.clone()));
+                    if message.content.contains("connection_init") {
+                        let _ = events.send(receive(r#"{"type": "ping"}"#));
+                        if !reply.is_empty() {
+                            let _ = events.send(receive(reply));
+                        }
+                    } else if message.content.contains("subscribe") {
+                        for n in 1..=2 {
+                            let next = format!(
+                                r#"{{"type": "next", "id": "1", "payload": {{"data": {{"n": {n}}}}}}}"#
+                            );
+                            let _ = events.send(receive(&next));
+                        }
+                        let _ = events.send(receive(r#"{"type": "complete", "id": "1"}"#));
+                    }
+                }
+                info.state = ConnectionState::Disconnected;
+                let _ = events.send(WebSocketEvent::StateChanged(info));
+            });
+
+            let session = WebSocketSession::new(WebSocketSender::new(command_tx), event_rx);
+            Box::pin(async move { Ok(session) })
+        }
+    }
+
+    fn input() -> GraphqlSubscriptionInput {
+        GraphqlSubscriptionInput {
+            config: WebSocketConfig::new("ws://localhost/graphql").with_auto_reconnect(true),
+            query: "subscription { n }".to_string(),
+            connection_params: r#"{"authToken": "abc"}"#.to_string(),
+            ..GraphqlSubscriptionInput::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_receives_results() {
+        let client = MockWebSocketClient::new(r#"{"type": "connection_ack"}"#);
+        let use_case = GraphqlSubscription::new(Arc::clone(&client));
+
+        let mut session = use_case.execute(input()).await.unwrap();
+
+        let mut results = Vec::new();
+        while let Some(event) = session.next_event().await {
+            match event {
+                GraphqlSubscriptionEvent::Next { payload, .. } => {
+                    results.push(payload["data"]["n"].clone());
+                }
+                GraphqlSubscriptionEvent::Complete => break,
+                GraphqlSubscriptionEvent::StateChanged(_) => {}
+                other => panic!("unexpected event: {other:?}"),
+            }
+        }
+        assert_eq!(results, [1, 2]);
+
+        let config = client.configs.lock().unwrap().remove(0);
+        assert_eq!(config.subprotocols, [GRAPHQL_TRANSPORT_WS]);
+        assert!(!config.auto_reconnect);
+        let sent = client.sent.lock().unwrap().clone();
+        assert_eq!(
+            sent,
+            [
+                r#"{"type":"connection_init","payload":{"authToken":"abc"}}"#,
+                r#"{"type":"pong"}"#,
+                r#"{"type":"subscribe","id":"1","payload":{"query":"subscription { n }"}}"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscription_requires_acknowledgement() {
+        let client = MockWebSocketClient::new("");
+        let use_case = GraphqlSubscription::new(client);
+        let mut input = input();
+        input.config.connect_timeout_secs = 1;
+
+        let started = tokio::time::Instant::now();
+        assert!(matches!(
+            use_case.execute(input).await,
+            Err(GraphqlSubscriptionError::WebSocket(WebSocketError::Timeout))
+        ));
+        assert!(started.elapsed() < Duration::from_secs(3));
+
+        let use_case = GraphqlSubscription::new(MockWebSocketClient::new(""));
+        let mut input = self::input();
+        input.connection_params = "[]".to_string();
+        assert!(matches!(
+            use_case.execute(input).await,
+            Err(GraphqlSubscriptionError::Graphql(GraphqlError::Protocol(_)))
+        ));
+    }
+}
@@ -3,6 +3,8 @@
 mod create_request;
 mod create_workspace;
 pub mod environment;
+mod fetch_graphql_schema;
+mod graphql_subscription;
 mod load_collection;
 mod save_collection;
 mod update_request;
@@ -11,9 +13,12 @@ pub use create_request::*;
 pub use create_workspace::*;
 pub use environment::{
     ListEnvironments, ListEnvironmentsOutput, LoadEnvironment, LoadEnvironmentError,
-    LoadEnvironmentOutput, ResolveVariables, ResolveVariablesOutput, SaveEnvironment,
-    SaveEnvironmentError, SwitchEnvironment, SwitchEnvironmentError, SwitchEnvironmentOutput,
+    LoadEnvironmentOutput, ResolveSecretCommands, ResolveSecretCommandsError, ResolveVariables,
+    ResolveVariablesOutput, SaveEnvironment, SaveEnvironmentError, SwitchEnvironment,
+    SwitchEnvironmentError, SwitchEnvironmentOutput,
 };
+pub use fetch_graphql_schema::*;
+pub use graphql_subscription::*;
 pub use load_collection::*;
 pub use save_collection::*;
 pub use update_request::*;
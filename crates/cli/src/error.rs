@@ -0,0 +1,74 @@
+//! CLI error types.
+
+use vortex_application::ports::{CollectionError, SecretsError, WorkspaceError};
+use vortex_application::{HttpClientError, ResolveSecretCommandsError, SwitchEnvironmentError};
+use vortex_domain::ProxyError;
+use vortex_infrastructure::{DataFileError, ReportError, TokenCacheError};
+
+/// Errors that abort a CLI run before or while loading the workspace.
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    /// The workspace could not be loaded.
+    #[error("Failed to load workspace: {0}")]
+    Workspace(#[from] WorkspaceError),
+
+    /// A collection could not be loaded.
+    #[error("Failed to load collection: {0}")]
+    Collection(#[from] CollectionError),
+
+    /// The selected environment could not be activated.
+    #[error("Failed to load environment: {0}")]
+    Environment(#[from] SwitchEnvironmentError),
+
+    /// A command variable could not be resolved.
+    #[error("Failed to resolve variable: {0}")]
+    SecretCommand(#[from] ResolveSecretCommandsError),
+
+    /// The HTTP client could not be created.
+    #[error("Failed to create HTTP client: {0}")]
+    HttpClient(#[from] HttpClientError),
+
+    /// The proxy configuration is invalid.
+    #[error("Invalid proxy configuration: {0}")]
+    Proxy(#[from] ProxyError),
+
+    /// The `--data` file could not be loaded.
+    #[error("Failed to load data file: {0}")]
+    DataFile(#[from] DataFileError),
+
+    /// The `--data` file has no rows.
+    #[error("Data file has no rows")]
+    EmptyDataFile,
+
+    /// A `--report` file could not be written.
+    #[error("Failed to write report: {0}")]
+    Report(#[from] ReportError),
+
+    /// No collection matched the `--collection` filter.
+    #[error("No collection matches: {0}")]
+    CollectionNotFound(String),
+
+    /// The filters did not select any request.
+    #[error("No requests matched the given filters")]
+    NoRequests,
+
+    /// The token cache could not be read or written.
+    #[error("Token cache error: {0}")]
+    TokenCache(#[from] TokenCacheError),
+
+    /// A token command needs the cache passphrase.
+    #[error("Set VORTEX_TOKEN_PASSPHRASE to unlock the token cache")]
+    MissingTokenPassphrase,
+
+    /// `tokens revoke` was given a key that is not cached.
+    #[error("No cached token with key: {0}")]
+    TokenNotFound(String),
+
+    /// The secrets file could not be read or written.
+    #[error("Secrets error: {0}")]
+    Secrets(#[from] SecretsError),
+
+    /// A secrets command needs the secrets passphrase.
+    #[error("Set VORTEX_SECRETS_PASSPHRASE to encrypt the secrets file")]
+    MissingSecretsPassphrase,
+}
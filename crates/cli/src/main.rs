@@ -0,0 +1,44 @@
+//! Vortex CLI - Headless collection runner
+//!
+//! Runs the saved requests of a workspace without the desktop UI, so
+//! collections can be used in CI. The process exits with:
+//! - `0` when every request succeeded and all tests passed
+//! - `1` when a request failed or a test did not pass
+//! - `2` when the workspace, collections or environment could not be loaded
+//!
+//! When `VORTEX_TOKEN_PASSPHRASE` is set, `OAuth2` tokens are kept in an
+//! encrypted cache between runs; `vortex-cli tokens` manages it. Encrypted
+//! workspace secrets are unlocked with `VORTEX_SECRETS_PASSPHRASE`.
+
+mod args;
+mod error;
+mod output;
+mod run;
+mod secrets;
+mod tokens;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use args::{Cli, Command};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run(args) => run::execute(args).await,
+        Command::Tokens(args) => tokens::execute(args).await,
+        Command::Secrets(args) => secrets::execute(args).await,
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,214 @@
+//! Console output for runs, token and secrets commands.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use vortex_application::TokenStatus;
+use vortex_domain::report::{ReportFormat, RequestRunResult, RunReport, mask_secrets};
+use vortex_domain::tls::TlsSecurityWarning;
+
+/// Prints the header shown before a collection starts running.
+pub fn print_collection_header(name: &str, environment: Option<&str>) {
+    match environment {
+        Some(env) => println!("\n{name} (environment: {env})"),
+        None => println!("\n{name}"),
+    }
+}
+
+/// Prints the header shown before a data iteration starts.
+pub fn print_iteration_header(iteration: Option<usize>, total: usize) {
+    if let Some(index) = iteration {
+        println!("\n  Iteration {}/{total}", index + 1);
+    }
+}
+
+/// Prints one request result and its assertions, masking `secrets`.
+pub fn print_result(result: &RequestRunResult, secrets: &[String]) {
+    print!("{}", mask_secrets(&format_result(result), secrets));
+}
+
+/// Formats one request result and its assertions.
+fn format_result(result: &RequestRunResult) -> String {
+    let mark = if result.is_skipped() {
+        "-"
+    } else if result.passed() {
+        "✓"
+    } else {
+        "✗"
+    };
+    let status = result
+        .response
+        .as_ref()
+        .map_or_else(String::new, |response| {
+            format!(" {} {}", response.status, response.status_text)
+        });
+
+    let mut text = String::new();
+    let _ = writeln!(
+        text,
+        "  {mark} {} [{} {}]{status} ({} ms)",
+        result.display_path(),
+        result.method,
+        result.url,
+        result.duration_ms
+    );
+
+    if let Some(error) = &result.error {
+        let _ = writeln!(text, "      error: {error}");
+    }
+    if let Some(reason) = &result.skipped {
+        let _ = writeln!(text, "      skipped: {reason}");
+    }
+
+    for assertion in &result.test_results.results {
+        let mark = if assertion.passed { "✓" } else { "✗" };
+        let _ = writeln!(text, "      {mark} {}", assertion.assertion.description());
+        if !assertion.passed {
+            if let Some(error) = &assertion.error {
+                let _ = writeln!(text, "          {error}");
+            }
+            if let Some(actual) = &assertion.actual {
+                let _ = writeln!(text, "          actual: {actual}");
+            }
+        }
+    }
+    text
+}
+
+/// Prints one line per data iteration of a finished run.
+pub fn print_iterations(report: &RunReport) {
+    if report.iterations.is_empty() {
+        return;
+    }
+
+    println!();
+    for iteration in &report.iterations {
+        let mark = if iteration.passed() { "✓" } else { "✗" };
+        let test_results = &iteration.test_results;
+        println!(
+            "  {mark} Iteration {}: {}/{} requests passed, {}/{} assertions passed",
+            iteration.index + 1,
+            iteration.summary.passed_requests,
+            iteration.summary.total_requests,
+            test_results.passed,
+            test_results.total
+        );
+    }
+}
+
+/// Prints the summary of a finished run.
+pub fn print_summary(report: &RunReport) {
+    let summary = &report.summary;
+    println!(
+        "\n  Requests:   {} total, {} passed, {} failed, {} errored, {} skipped",
+        summary.total_requests,
+        summary.passed_requests,
+        summary.failed_requests,
+        summary.errored_requests,
+        summary.skipped_requests
+    );
+    println!(
+        "  Assertions: {} total, {} passed, {} failed",
+        summary.total_assertions, summary.passed_assertions, summary.failed_assertions
+    );
+    println!("  Duration:   {} ms", summary.duration_ms);
+}
+
+/// Prints where a report file was written.
+pub fn print_report_written(format: ReportFormat, path: &Path) {
+    println!(
+        "  {} report written to {}",
+        format.display_name(),
+        path.display()
+    );
+}
+
+/// Prints a warning for each way the TLS configuration weakens verification.
+pub fn print_tls_warnings(warnings: &[TlsSecurityWarning]) {
+    for warning in warnings {
+        eprintln!("warning: {}", warning.message());
+    }
+}
+
+/// Prints the instructions for approving a device code sign-in.
+pub fn print_device_code(user_code: &str, verification_uri: &str, complete_uri: Option<&str>) {
+    eprintln!("To sign in, open {verification_uri} and enter the code {user_code}");
+    if let Some(uri) = complete_uri {
+        eprintln!("  or open {uri}");
+    }
+}
+
+/// Prints the cached tokens with their status.
+pub fn print_tokens(tokens: &[(String, TokenStatus)]) {
+    if tokens.is_empty() {
+        println!("No cached tokens");
+        return;
+    }
+    for (key, status) in tokens {
+        println!("  {key}  {}", status.display_message());
+    }
+}
+
+/// Prints that a token was removed from the cache.
+pub fn print_token_revoked(key: &str) {
+    println!("Revoked {key}");
+}
+
+/// Prints that the token cache was deleted.
+pub fn print_token_cache_cleared(path: &Path) {
+    println!("Deleted {}", path.display());
+}
+
+/// Prints the outcome of `secrets encrypt`.
+pub fn print_secrets_encrypted(encrypted: bool) {
+    if encrypted {
+        println!("Encrypted .vortex/secrets.json");
+    } else {
+        println!("No plaintext secrets file to encrypt");
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use vortex_domain::testing::{Assertion, AssertionResult, TestResults};
+
+    use super::*;
+
+    #[test]
+    fn test_print_result_masks_secrets() {
+        let secret = "s3cr3t-t0ken";
+        let assertion = Assertion::BodyContains {
+            text: "ok".to_string(),
+            ignore_case: false,
+        };
+        let result = RequestRunResult {
+            request_id: "login".to_string(),
+            request_name: "Login".to_string(),
+            folder_path: Vec::new(),
+            iteration: None,
+            method: "GET".to_string(),
+            url: format!("https://api.test/login?key={secret}"),
+            request: None,
+            response: None,
+            test_results: TestResults::new(
+                "Login",
+                vec![AssertionResult::fail_with_value(
+                    assertion,
+                    format!("{{\"token\":\"{secret}\"}}"),
+                    "Text not found",
+                )],
+                1,
+            ),
+            error: None,
+            skipped: None,
+            duration_ms: 5,
+        };
+
+        let text = mask_secrets(&format_result(&result), &[secret.to_string()]);
+
+        assert!(!text.contains(secret));
+        assert!(text.contains("[GET https://api.test/login?key=********]"));
+        assert!(text.contains("actual: {\"token\":\"********\"}"));
+    }
+}
@@ -0,0 +1,244 @@
+//! The `run` command.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use vortex_application::ports::{CollectionRepository, CollectionTree, WorkspaceRepository};
+use vortex_application::{AuthEvent, ResolveSecretCommands, SwitchEnvironment, TokenStore};
+use vortex_domain::environment::{Globals, ResolutionContext};
+use vortex_domain::persistence::{RequestSettings, WorkspaceManifest};
+use vortex_domain::proxy::{GlobalProxySettings, ProxyConfig};
+use vortex_domain::report::RunReport;
+use vortex_infrastructure::{
+    CollectionRunner, FileEnvironmentRepository, FileSystemCollectionRepository,
+    FileSystemWorkspaceRepository, OAuth2Provider, ProxyResolver, ReqwestHttpClient, RunnerConfig,
+    SharedCookieJar, ShellSecretCommandRunner, TokioFileSystem, load_data_file, plan_requests,
+    save_report,
+};
+
+use crate::args::RunArgs;
+use crate::error::CliError;
+use crate::output;
+use crate::secrets;
+use crate::tokens;
+
+/// Runs the selected collections and returns whether everything passed.
+pub async fn execute(args: RunArgs) -> Result<bool, CliError> {
+    let fs = TokioFileSystem::new();
+    let manifest = FileSystemWorkspaceRepository::new(fs.clone())
+        .load(&args.workspace)
+        .await?;
+    let environment = args
+        .env
+        .clone()
+        .or_else(|| manifest.default_environment.clone());
+
+    let config = RunnerConfig {
+        stop_on_failure: args.bail,
+        delay_between_ms: args.delay,
+        folders: args.folders.clone(),
+        requests: args.requests.clone(),
+    };
+
+    let rows = match &args.data {
+        Some(path) => {
+            let rows = load_data_file(path).await?;
+            if rows.is_empty() {
+                return Err(CliError::EmptyDataFile);
+            }
+            Some(rows)
+        }
+        None => None,
+    };
+
+    let trees = load_collections(&args, &manifest).await?;
+    let planned: usize = trees
+        .iter()
+        .map(|tree| plan_requests(tree, &config).len())
+        .sum();
+    if planned == 0 {
+        return Err(CliError::NoRequests);
+    }
+
+    // The workspace TLS configuration applies to requests without their own
+    let tls = manifest
+        .settings
+        .as_ref()
+        .and_then(RequestSettings::tls_config)
+        .unwrap_or_default();
+    output::print_tls_warnings(&tls.security_warnings());
+
+    // Cookies live for the duration of the run so login flows work unattended
+    let client = ReqwestHttpClient::with_cookie_jar(SharedCookieJar::default())?
+        .with_proxy_resolver(ProxyResolver::new(proxy_settings(&args, &manifest)?))
+        .with_tls_config(tls);
+    // Tokens from earlier runs are reused while the cache is unlocked
+    let token_store = Arc::new(TokenStore::new());
+    let token_cache = tokens::token_cache()?;
+    if let Some(cache) = &token_cache {
+        cache.load_into(&token_store).await?;
+    }
+    let runner = CollectionRunner::new(
+        Arc::new(client),
+        auth_provider(Arc::clone(&token_store)),
+        config,
+    );
+
+    // Command variables run once per run, whatever collections use them
+    let secret_commands = ResolveSecretCommands::new(ShellSecretCommandRunner::new());
+
+    let mut reports = Vec::new();
+    for tree in &trees {
+        if plan_requests(tree, runner.config()).is_empty() {
+            continue;
+        }
+
+        let mut context = resolution_context(&args.workspace, environment.as_deref(), tree).await?;
+        secret_commands.execute(&mut context).await?;
+        let secrets = context.secret_values();
+        output::print_collection_header(&tree.collection.name, environment.as_deref());
+        let report = match &rows {
+            Some(rows) => {
+                let mut current = None;
+                let report = runner
+                    .run_iterations(tree, context, rows, |result| {
+                        if current != result.iteration {
+                            current = result.iteration;
+                            output::print_iteration_header(result.iteration, rows.len());
+                        }
+                        output::print_result(result, &secrets);
+                    })
+                    .await;
+                output::print_iterations(&report);
+                report
+            }
+            None => {
+                runner
+                    .run(tree, context, |r| output::print_result(r, &secrets))
+                    .await
+            }
+        };
+        output::print_summary(&report);
+
+        let passed = report.all_passed();
+        reports.push(report);
+        if !passed && args.bail {
+            break;
+        }
+    }
+
+    if let Some(cache) = &token_cache {
+        cache.save_from(&token_store).await?;
+    }
+
+    for target in &args.reports {
+        save_report(&reports, target.format, &target.path).await?;
+        output::print_report_written(target.format, &target.path);
+    }
+
+    Ok(reports.iter().all(RunReport::all_passed))
+}
+
+/// Creates the auth provider, printing device codes the user must approve.
+fn auth_provider(token_store: Arc<TokenStore>) -> OAuth2Provider {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            if let AuthEvent::DeviceCodeIssued {
+                user_code,
+                verification_uri,
+                verification_uri_complete,
+                ..
+            } = event
+            {
+                output::print_device_code(
+                    &user_code,
+                    &verification_uri,
+                    verification_uri_complete.as_deref(),
+                );
+            }
+        }
+    });
+    OAuth2Provider::with_token_store(token_store).with_event_sender(tx)
+}
+
+/// Builds the global proxy settings for the run.
+///
+/// `--proxy` wins over the workspace proxy, which wins over the proxy
+/// environment variables. `--no-proxy` disables all three.
+fn proxy_settings(
+    args: &RunArgs,
+    manifest: &WorkspaceManifest,
+) -> Result<GlobalProxySettings, CliError> {
+    let proxy = if args.no_proxy {
+        ProxyConfig::new()
+    } else if let Some(url) = &args.proxy {
+        ProxyConfig::with_url(url)
+    } else {
+        manifest
+            .settings
+            .as_ref()
+            .and_then(|settings| settings.proxy.clone())
+            .unwrap_or_else(ProxyConfig::system)
+    };
+    proxy.validate()?;
+
+    Ok(GlobalProxySettings {
+        http_proxy: proxy,
+        ..GlobalProxySettings::default()
+    })
+}
+
+/// Loads the workspace collections, keeping only those selected by `--collection`.
+async fn load_collections(
+    args: &RunArgs,
+    manifest: &WorkspaceManifest,
+) -> Result<Vec<CollectionTree>, CliError> {
+    let repo = FileSystemCollectionRepository::new(TokioFileSystem::new());
+    let mut trees = Vec::new();
+
+    for path in &manifest.collections {
+        let tree = repo.load_collection(&args.workspace.join(path)).await?;
+        let selected = args.collections.is_empty()
+            || args.collections.iter().any(|filter| {
+                filter.eq_ignore_ascii_case(&tree.collection.name)
+                    || filter.trim_end_matches('/') == path.trim_end_matches('/')
+            });
+        if selected {
+            trees.push(tree);
+        }
+    }
+
+    if trees.is_empty() && !args.collections.is_empty() {
+        return Err(CliError::CollectionNotFound(args.collections.join(", ")));
+    }
+
+    Ok(trees)
+}
+
+/// Builds the variable context for a collection, activating the environment if one is set.
+async fn resolution_context(
+    workspace: &Path,
+    environment: Option<&str>,
+    tree: &CollectionTree,
+) -> Result<ResolutionContext, CliError> {
+    let collection_variables = tree.collection.variable_map();
+
+    let Some(name) = environment else {
+        let mut context = ResolutionContext::new();
+        context.collection = collection_variables;
+        return Ok(context);
+    };
+
+    let fs = TokioFileSystem::new();
+    let switch = SwitchEnvironment::new(
+        FileEnvironmentRepository::new(fs.clone()),
+        secrets::repository(fs),
+    );
+    let output = switch
+        .execute(workspace, name, &Globals::new(), &collection_variables)
+        .await?;
+
+    Ok(output.resolution_context)
+}
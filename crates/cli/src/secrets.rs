@@ -0,0 +1,43 @@
+//! The `secrets` command and the secrets repository used by `run`.
+
+use std::env;
+
+use vortex_infrastructure::{FileSecretsRepository, TokioFileSystem};
+
+use crate::args::{SecretsAction, SecretsArgs};
+use crate::error::CliError;
+use crate::output;
+
+/// Environment variable holding the secrets file passphrase.
+const PASSPHRASE_VAR: &str = "VORTEX_SECRETS_PASSPHRASE";
+
+/// Runs a secrets file action.
+pub async fn execute(args: SecretsArgs) -> Result<bool, CliError> {
+    match args.action {
+        SecretsAction::Encrypt { workspace } => {
+            if passphrase().is_none() {
+                return Err(CliError::MissingSecretsPassphrase);
+            }
+            let encrypted = repository(TokioFileSystem::new())
+                .migrate(&workspace)
+                .await?;
+            output::print_secrets_encrypted(encrypted);
+        }
+    }
+    Ok(true)
+}
+
+/// Creates the secrets repository, unlocked if a passphrase is set.
+pub fn repository(fs: TokioFileSystem) -> FileSecretsRepository<TokioFileSystem> {
+    let repository = FileSecretsRepository::new(fs);
+    match passphrase() {
+        Some(passphrase) => repository.with_passphrase(passphrase),
+        None => repository,
+    }
+}
+
+fn passphrase() -> Option<String> {
+    env::var(PASSPHRASE_VAR)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+}
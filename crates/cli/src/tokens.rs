@@ -0,0 +1,56 @@
+//! The `tokens` command and the token cache used by `run`.
+
+use std::env;
+
+use vortex_application::TokenStore;
+use vortex_infrastructure::TokenCacheRepository;
+
+use crate::args::{TokensAction, TokensArgs};
+use crate::error::CliError;
+use crate::output;
+
+/// Environment variable holding the token cache passphrase.
+const PASSPHRASE_VAR: &str = "VORTEX_TOKEN_PASSPHRASE";
+
+/// Runs a token cache action.
+pub async fn execute(args: TokensArgs) -> Result<bool, CliError> {
+    match args.action {
+        TokensAction::List => {
+            let cache = token_cache()?.ok_or(CliError::MissingTokenPassphrase)?;
+            let store = TokenStore::new();
+            cache.load_into(&store).await?;
+
+            let mut keys = store.keys().await;
+            keys.sort();
+            let mut tokens = Vec::with_capacity(keys.len());
+            for key in keys {
+                let status = store.get_status(&key).await;
+                tokens.push((key, status));
+            }
+            output::print_tokens(&tokens);
+        }
+        TokensAction::Revoke { key } => {
+            let cache = token_cache()?.ok_or(CliError::MissingTokenPassphrase)?;
+            if cache.revoke(&key).await?.is_none() {
+                return Err(CliError::TokenNotFound(key));
+            }
+            output::print_token_revoked(&key);
+        }
+        TokensAction::Clear => {
+            let cache = TokenCacheRepository::new(String::new())?;
+            cache.clear().await?;
+            output::print_token_cache_cleared(cache.path());
+        }
+    }
+    Ok(true)
+}
+
+/// Opens the token cache if a passphrase is set.
+pub fn token_cache() -> Result<Option<TokenCacheRepository>, CliError> {
+    match env::var(PASSPHRASE_VAR) {
+        Ok(passphrase) if !passphrase.is_empty() => {
+            Ok(Some(TokenCacheRepository::new(passphrase)?))
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,264 @@
+//! Integration tests for the `vortex-cli run` command.
+//!
+//! These tests build a workspace on disk, serve requests from a local
+//! HTTP stub and check the exit code and output of the binary.
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::{Command, Output};
+use std::thread;
+
+use tempfile::tempdir;
+
+use vortex_application::EnvironmentRepository;
+use vortex_application::ports::{CollectionRepository, WorkspaceRepository};
+use vortex_domain::environment::{Environment, Variable};
+use vortex_domain::generate_id;
+use vortex_domain::persistence::{
+    PersistenceCollection, PersistenceFolder, PersistenceHttpMethod, SavedRequest, TestAssertion,
+};
+use vortex_infrastructure::{
+    FileEnvironmentRepository, FileSystemCollectionRepository, FileSystemWorkspaceRepository,
+    TokioFileSystem,
+};
+
+/// Starts an HTTP stub that answers every request with `200 {"ok":true}`.
+fn start_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut buffer = [0_u8; 4096];
+            let _ = stream.read(&mut buffer);
+            let body = r#"{"ok":true}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    format!("http://{address}")
+}
+
+/// Creates a workspace with one collection, an `Auth` folder and a `local` environment.
+async fn create_workspace(workspace: &Path, base_url: &str, expected_status: u16) {
+    let fs = TokioFileSystem::new();
+    let workspace_repo = FileSystemWorkspaceRepository::new(fs.clone());
+    let mut manifest = workspace_repo.create(workspace, "CI").await.unwrap();
+    manifest.add_collection("collections/api");
+    workspace_repo.save(workspace, &manifest).await.unwrap();
+
+    let collection_dir = workspace.join("collections/api");
+    let collection_repo = FileSystemCollectionRepository::new(fs.clone());
+    collection_repo
+        .create_collection(
+            &collection_dir,
+            &PersistenceCollection::new(generate_id(), "API"),
+        )
+        .await
+        .unwrap();
+
+    let health = SavedRequest::new(
+        generate_id(),
+        "Health",
+        PersistenceHttpMethod::Get,
+        "{{base_url}}/health",
+    )
+    .with_test(TestAssertion::status("is up", expected_status));
+    collection_repo
+        .create_request(&collection_dir, None, &health)
+        .await
+        .unwrap();
+
+    let folder_path = collection_repo
+        .create_folder(
+            &collection_dir,
+            None,
+            &PersistenceFolder::new(generate_id(), "Auth"),
+        )
+        .await
+        .unwrap();
+    let login = SavedRequest::new(
+        generate_id(),
+        "Login",
+        PersistenceHttpMethod::Post,
+        "{{base_url}}/login",
+    )
+    .with_test(TestAssertion::body_contains("says ok", "ok"));
+    collection_repo
+        .create_request(&collection_dir, Some(&folder_path), &login)
+        .await
+        .unwrap();
+
+    let mut environment = Environment::new("local");
+    environment.set_variable("base_url", Variable::new(base_url));
+    FileEnvironmentRepository::new(fs)
+        .save(workspace, &environment)
+        .await
+        .unwrap();
+}
+
+fn run_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_vortex-cli"))
+        .arg("run")
+        .args(args)
+        .env_remove("VORTEX_TOKEN_PASSPHRASE")
+        .output()
+        .expect("Failed to run vortex-cli")
+}
+
+#[tokio::test]
+async fn test_run_passes_with_environment() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    create_workspace(&workspace, &start_server(), 200).await;
+
+    let output = run_cli(&[workspace.to_str().unwrap(), "--env", "local"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(stdout.contains("Health"));
+    assert!(stdout.contains("Auth/Login"));
+    assert!(stdout.contains("2 total, 2 passed"));
+}
+
+#[tokio::test]
+async fn test_run_fails_when_assertion_fails() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    create_workspace(&workspace, &start_server(), 201).await;
+
+    let output = run_cli(&[workspace.to_str().unwrap(), "--env", "local"]);
+
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[tokio::test]
+async fn test_run_filters_by_folder() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    create_workspace(&workspace, &start_server(), 201).await;
+
+    let output = run_cli(&[
+        workspace.to_str().unwrap(),
+        "--env",
+        "local",
+        "--folder",
+        "auth",
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(!stdout.contains("Health"));
+    assert!(stdout.contains("1 total, 1 passed"));
+}
+
+#[tokio::test]
+async fn test_run_errors_when_nothing_matches() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    create_workspace(&workspace, &start_server(), 200).await;
+
+    let output = run_cli(&[workspace.to_str().unwrap(), "--request", "Missing"]);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No requests matched"));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_run_resolves_command_variables() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    let base_url = start_server();
+    create_workspace(&workspace, &base_url, 200).await;
+
+    // A script stands in for a password manager like `pass`
+    let script = temp_dir.path().join("fake-pass");
+    std::fs::write(&script, format!("#!/bin/sh\necho '{base_url}'\n")).unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut environment = Environment::new("local");
+    environment.add_secret_command("base_url", format!("'{}' show api/local", script.display()));
+    FileEnvironmentRepository::new(TokioFileSystem::new())
+        .save(&workspace, &environment)
+        .await
+        .unwrap();
+
+    let output = run_cli(&[workspace.to_str().unwrap(), "--env", "local"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(stdout.contains("2 total, 2 passed"));
+
+    let saved = std::fs::read_to_string(workspace.join("environments/local.json")).unwrap();
+    assert!(saved.contains("show api/local"));
+    assert!(!saved.contains(&base_url));
+
+    // A failing command aborts the run before any request is sent
+    std::fs::write(&script, "#!/bin/sh\necho 'not in the store' >&2\nexit 1\n").unwrap();
+    let output = run_cli(&[workspace.to_str().unwrap(), "--env", "local"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not in the store"));
+}
+
+#[tokio::test]
+async fn test_run_iterates_over_data_file() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    // The environment points nowhere; each data row overrides `base_url`.
+    create_workspace(&workspace, "http://127.0.0.1:1", 200).await;
+    let server = start_server();
+    let data_file = temp_dir.path().join("rows.csv");
+    std::fs::write(
+        &data_file,
+        format!("base_url,user\n{server},alice\n{server},bob\n"),
+    )
+    .unwrap();
+
+    let output = run_cli(&[
+        workspace.to_str().unwrap(),
+        "--env",
+        "local",
+        "--data",
+        data_file.to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(stdout.contains("Iteration 2/2"));
+    assert!(stdout.contains("Iteration 2: 2/2 requests passed"));
+    assert!(stdout.contains("4 total, 4 passed"));
+}
+
+#[tokio::test]
+async fn test_run_writes_reports() {
+    let temp_dir = tempdir().unwrap();
+    let workspace = temp_dir.path().join("ws");
+    create_workspace(&workspace, &start_server(), 201).await;
+    let junit = temp_dir.path().join("reports/junit.xml");
+    let json = temp_dir.path().join("reports/run.json");
+
+    let output = run_cli(&[
+        workspace.to_str().unwrap(),
+        "--env",
+        "local",
+        "--report",
+        &format!("junit:{}", junit.display()),
+        "--report",
+        &format!("json:{}", json.display()),
+    ]);
+
+    assert_eq!(output.status.code(), Some(1));
+    let xml = std::fs::read_to_string(junit).unwrap();
+    assert!(xml.contains("failures=\"1\""));
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(json).unwrap()).unwrap();
+    assert_eq!(json["summary"]["failed_requests"], 1);
+}
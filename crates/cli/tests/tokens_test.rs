@@ -0,0 +1,86 @@
+//! Integration tests for the `vortex-cli tokens` command.
+//!
+//! These tests point the config directory at a temporary directory, write a
+//! token cache and manage it through the binary.
+#![cfg(target_os = "linux")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use std::collections::BTreeMap;
+use std::num::NonZeroU32;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use tempfile::tempdir;
+
+use vortex_domain::OAuth2Token;
+use vortex_infrastructure::TokenCacheRepository;
+
+const PASSPHRASE: &str = "correct horse battery staple";
+
+fn tokens_cli(config_dir: &Path, passphrase: Option<&str>, args: &[&str]) -> Output {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_vortex-cli"));
+    command
+        .arg("tokens")
+        .args(args)
+        .env("XDG_CONFIG_HOME", config_dir)
+        .env_remove("VORTEX_TOKEN_PASSPHRASE");
+    if let Some(passphrase) = passphrase {
+        command.env("VORTEX_TOKEN_PASSPHRASE", passphrase);
+    }
+    command.output().expect("Failed to run vortex-cli")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[tokio::test]
+async fn test_list_and_revoke_cached_tokens() {
+    let temp_dir = tempdir().unwrap();
+    let cache = TokenCacheRepository::at(temp_dir.path().join("vortex/tokens.json"), PASSPHRASE)
+        .with_iterations(NonZeroU32::new(1_000).unwrap());
+
+    let mut tokens = BTreeMap::new();
+    for key in [
+        "cc:https://idp/token:cli:",
+        "pw:https://idp/token:cli:alice:",
+    ] {
+        let token = OAuth2Token::new(
+            "access".to_string(),
+            "Bearer".to_string(),
+            Some(7200),
+            None,
+            vec![],
+        );
+        tokens.insert(key.to_string(), token);
+    }
+    cache.save(&tokens).await.unwrap();
+
+    let output = tokens_cli(temp_dir.path(), None, &["list"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("VORTEX_TOKEN_PASSPHRASE"));
+
+    let output = tokens_cli(temp_dir.path(), Some("wrong"), &["list"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = tokens_cli(temp_dir.path(), Some(PASSPHRASE), &["list"]);
+    assert_eq!(output.status.code(), Some(0));
+    let listed = stdout(&output);
+    assert!(listed.contains("cc:https://idp/token:cli:  Valid for"));
+    assert!(listed.contains("pw:https://idp/token:cli:alice:"));
+
+    let key = "pw:https://idp/token:cli:alice:";
+    let output = tokens_cli(temp_dir.path(), Some(PASSPHRASE), &["revoke", key]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        cache.load().await.unwrap().keys().collect::<Vec<_>>(),
+        ["cc:https://idp/token:cli:"]
+    );
+
+    let output = tokens_cli(temp_dir.path(), Some(PASSPHRASE), &["revoke", key]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = tokens_cli(temp_dir.path(), None, &["clear"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(!cache.path().exists());
+}
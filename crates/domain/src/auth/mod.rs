@@ -2,4 +2,6 @@
 
 mod types;
 
-pub use types::{ApiKeyLocation, AuthConfig, AuthError, AuthResolution, OAuth2Token};
+pub(crate) use types::default_jwt_query_param;
+
+pub use types::{ApiKeyLocation, AuthConfig, AuthError, AuthResolution, JwtAlgorithm, OAuth2Token};
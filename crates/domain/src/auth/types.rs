@@ -35,6 +35,16 @@ pub enum AuthConfig {
         /// Password (may contain variables)
         password: String,
     },
+    /// HTTP Digest authentication (RFC 7616)
+    ///
+    /// The HTTP client answers the server's 401 challenge and reuses it for
+    /// later requests to the same origin.
+    Digest {
+        /// Username (may contain variables)
+        username: String,
+        /// Password (may contain variables)
+        password: String,
+    },
     /// `OAuth2` Client Credentials flow
     #[serde(rename = "oauth2_client_credentials")]
     OAuth2ClientCredentials {
@@ -71,12 +81,146 @@ pub enum AuthConfig {
         #[serde(default)]
         extra_params: BTreeMap<String, String>,
     },
+    /// `OAuth2` Resource Owner Password Credentials flow
+    #[serde(rename = "oauth2_password")]
+    OAuth2Password {
+        /// Token endpoint URL
+        token_url: String,
+        /// Client ID
+        client_id: String,
+        /// Client secret (may be empty for public clients)
+        #[serde(default)]
+        client_secret: String,
+        /// Resource owner username
+        username: String,
+        /// Resource owner password
+        password: String,
+        /// Space-separated scopes
+        #[serde(default)]
+        scope: Option<String>,
+        /// Additional parameters to send with token request
+        #[serde(default)]
+        extra_params: BTreeMap<String, String>,
+    },
+    /// `OAuth2` Device Authorization Grant (RFC 8628)
+    #[serde(rename = "oauth2_device_code")]
+    OAuth2DeviceCode {
+        /// Device authorization endpoint URL
+        device_authorization_url: String,
+        /// Token endpoint URL
+        token_url: String,
+        /// Client ID
+        client_id: String,
+        /// Client secret (may be empty for public clients)
+        #[serde(default)]
+        client_secret: String,
+        /// Space-separated scopes
+        #[serde(default)]
+        scope: Option<String>,
+        /// Additional parameters for the device authorization request
+        #[serde(default)]
+        extra_params: BTreeMap<String, String>,
+    },
+    /// `OAuth2` JWT Bearer assertion grant (RFC 7523)
+    #[serde(rename = "oauth2_jwt_bearer")]
+    OAuth2JwtBearer {
+        /// Token endpoint URL
+        token_url: String,
+        /// Signed JWT assertion (may contain variables)
+        assertion: String,
+        /// Client ID (optional for this grant)
+        #[serde(default)]
+        client_id: String,
+        /// Client secret (optional for this grant)
+        #[serde(default)]
+        client_secret: String,
+        /// Space-separated scopes
+        #[serde(default)]
+        scope: Option<String>,
+        /// Additional parameters to send with token request
+        #[serde(default)]
+        extra_params: BTreeMap<String, String>,
+    },
+    /// AWS Signature Version 4 request signing
+    ///
+    /// The request is signed by the HTTP client when it is sent, after all
+    /// headers, query parameters and the body are final.
+    #[serde(rename = "aws_sigv4")]
+    AwsSigV4 {
+        /// Access key ID
+        access_key: String,
+        /// Secret access key
+        secret_key: String,
+        /// Session token for temporary credentials
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_token: Option<String>,
+        /// AWS region (e.g., "us-east-1")
+        region: String,
+        /// Service name (e.g., "execute-api", "s3")
+        service: String,
+    },
+    /// JSON Web Token generated from a claims template
+    ///
+    /// The token is signed again for every request, so builtins such as
+    /// `{{$timestamp}}` in the claims yield fresh values each time.
+    Jwt {
+        /// Signing algorithm
+        algorithm: JwtAlgorithm,
+        /// Shared secret for HS256, or a PEM private key for RS256 and
+        /// ES256 (usually a secret variable like `{{jwt_signing_key}}`)
+        secret: String,
+        /// JSON object with the claims (may contain variables)
+        claims: String,
+        /// Key ID sent in the `kid` header
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key_id: Option<String>,
+        /// Where to send the token
+        #[serde(default)]
+        location: ApiKeyLocation,
+        /// Prefix for the Authorization header, defaults to "Bearer"
+        #[serde(default = "default_bearer_prefix")]
+        prefix: String,
+        /// Query parameter name, defaults to `access_token`
+        #[serde(default = "default_jwt_query_param")]
+        query_param: String,
+    },
 }
 
 fn default_bearer_prefix() -> String {
     "Bearer".to_string()
 }
 
+pub fn default_jwt_query_param() -> String {
+    "access_token".to_string()
+}
+
+/// Algorithm used to sign a generated JWT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum JwtAlgorithm {
+    /// HMAC with SHA-256
+    #[default]
+    #[serde(rename = "HS256")]
+    Hs256,
+    /// RSASSA-PKCS1-v1_5 with SHA-256
+    #[serde(rename = "RS256")]
+    Rs256,
+    /// ECDSA with the P-256 curve and SHA-256
+    #[serde(rename = "ES256")]
+    Es256,
+}
+
+impl JwtAlgorithm {
+    /// Returns the `alg` header value.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Hs256 => "HS256",
+            Self::Rs256 => "RS256",
+            Self::Es256 => "ES256",
+        }
+    }
+}
+
 /// Location for API key authentication.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -100,7 +244,11 @@ impl AuthConfig {
     pub const fn is_oauth2(&self) -> bool {
         matches!(
             self,
-            Self::OAuth2ClientCredentials { .. } | Self::OAuth2AuthorizationCode { .. }
+            Self::OAuth2ClientCredentials { .. }
+                | Self::OAuth2AuthorizationCode { .. }
+                | Self::OAuth2Password { .. }
+                | Self::OAuth2DeviceCode { .. }
+                | Self::OAuth2JwtBearer { .. }
         )
     }
 
@@ -122,6 +270,40 @@ impl AuthConfig {
         }
     }
 
+    /// Returns true if the request must be signed as a whole when sent,
+    /// rather than carrying a precomputed credential.
+    #[must_use]
+    pub const fn signs_request(&self) -> bool {
+        matches!(self, Self::AwsSigV4 { .. } | Self::Digest { .. })
+    }
+
+    /// Creates a JWT authentication sent as a Bearer header.
+    #[must_use]
+    pub fn jwt(
+        algorithm: JwtAlgorithm,
+        secret: impl Into<String>,
+        claims: impl Into<String>,
+    ) -> Self {
+        Self::Jwt {
+            algorithm,
+            secret: secret.into(),
+            claims: claims.into(),
+            key_id: None,
+            location: ApiKeyLocation::Header,
+            prefix: default_bearer_prefix(),
+            query_param: default_jwt_query_param(),
+        }
+    }
+
+    /// Creates a digest authentication.
+    #[must_use]
+    pub fn digest(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::Digest {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
     /// Creates an API key authentication in header.
     #[must_use]
     pub fn api_key_header(name: impl Into<String>, key: impl Into<String>) -> Self {
@@ -155,11 +337,56 @@ impl AuthConfig {
                 let scope_part = scope.as_deref().unwrap_or("");
                 Some(format!("ac:{auth_url}:{client_id}:{scope_part}"))
             }
+            Self::OAuth2Password {
+                token_url,
+                client_id,
+                username,
+                scope,
+                ..
+            } => {
+                let scope_part = scope.as_deref().unwrap_or("");
+                Some(format!(
+                    "pw:{token_url}:{client_id}:{username}:{scope_part}"
+                ))
+            }
+            Self::OAuth2DeviceCode {
+                device_authorization_url,
+                client_id,
+                scope,
+                ..
+            } => {
+                let scope_part = scope.as_deref().unwrap_or("");
+                Some(format!(
+                    "dc:{device_authorization_url}:{client_id}:{scope_part}"
+                ))
+            }
+            Self::OAuth2JwtBearer {
+                token_url,
+                assertion,
+                client_id,
+                scope,
+                ..
+            } => {
+                // Different assertions yield different tokens, but the
+                // assertion itself is a credential and stays out of the key.
+                let scope_part = scope.as_deref().unwrap_or("");
+                let assertion_hash = fnv1a(assertion.as_bytes());
+                Some(format!(
+                    "jwt:{token_url}:{client_id}:{scope_part}:{assertion_hash:016x}"
+                ))
+            }
             _ => None,
         }
     }
 }
 
+/// 64-bit FNV-1a hash, stable across runs and platforms.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 /// `OAuth2` token with metadata for expiry tracking.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OAuth2Token {
@@ -320,6 +547,7 @@ impl std::fmt::Display for AuthError {
 impl std::error::Error for AuthError {}
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -356,6 +584,108 @@ mod tests {
         assert!(auth.cache_key().is_some());
     }
 
+    #[test]
+    fn test_oauth2_password_and_device_code_cache_keys() {
+        let password = AuthConfig::OAuth2Password {
+            token_url: "https://auth.example.com/token".to_string(),
+            client_id: "cli".to_string(),
+            client_secret: String::new(),
+            username: "alice".to_string(),
+            password: "secret".to_string(),
+            scope: Some("read".to_string()),
+            extra_params: BTreeMap::new(),
+        };
+        assert!(password.is_oauth2());
+        assert_eq!(
+            password.cache_key().as_deref(),
+            Some("pw:https://auth.example.com/token:cli:alice:read")
+        );
+
+        let device = AuthConfig::OAuth2DeviceCode {
+            device_authorization_url: "https://auth.example.com/device".to_string(),
+            token_url: "https://auth.example.com/token".to_string(),
+            client_id: "cli".to_string(),
+            client_secret: String::new(),
+            scope: None,
+            extra_params: BTreeMap::new(),
+        };
+        assert!(device.is_oauth2());
+        assert_eq!(
+            device.cache_key().as_deref(),
+            Some("dc:https://auth.example.com/device:cli:")
+        );
+    }
+
+    #[test]
+    fn test_oauth2_jwt_bearer_cache_key_hides_assertion() {
+        let jwt_bearer = |assertion: &str| AuthConfig::OAuth2JwtBearer {
+            token_url: "https://auth.example.com/token".to_string(),
+            assertion: assertion.to_string(),
+            client_id: String::new(),
+            client_secret: String::new(),
+            scope: None,
+            extra_params: BTreeMap::new(),
+        };
+        let first = jwt_bearer("eyJhbGciOi.first.sig").cache_key().unwrap();
+        let second = jwt_bearer("eyJhbGciOi.second.sig").cache_key().unwrap();
+        assert!(first.starts_with("jwt:https://auth.example.com/token:"));
+        assert!(!first.contains("eyJ"));
+        assert_ne!(first, second);
+        assert_eq!(
+            first,
+            jwt_bearer("eyJhbGciOi.first.sig").cache_key().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_oauth2_grant_serialization() {
+        let json = r#"{"type":"oauth2_jwt_bearer","token_url":"https://t","assertion":"a.b.c"}"#;
+        let auth: AuthConfig = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            auth,
+            AuthConfig::OAuth2JwtBearer { ref client_id, .. } if client_id.is_empty()
+        ));
+    }
+
+    #[test]
+    fn test_digest_auth() {
+        let auth = AuthConfig::digest("admin", "secret");
+        assert!(auth.is_configured());
+        assert!(auth.signs_request());
+        assert_eq!(
+            serde_json::to_string(&auth).unwrap(),
+            r#"{"type":"digest","username":"admin","password":"secret"}"#
+        );
+    }
+
+    #[test]
+    fn test_aws_sigv4_serialization() {
+        let json = r#"{"type":"aws_sigv4","access_key":"AKID","secret_key":"secret","region":"us-east-1","service":"s3"}"#;
+        let auth: AuthConfig = serde_json::from_str(json).unwrap();
+        assert!(auth.signs_request());
+        assert!(!auth.is_oauth2());
+        assert!(auth.cache_key().is_none());
+        assert_eq!(serde_json::to_string(&auth).unwrap(), json);
+    }
+
+    #[test]
+    fn test_jwt_serialization() {
+        let json = r#"{"type":"jwt","algorithm":"RS256","secret":"{{jwt_key}}","claims":"{\"sub\":\"svc\"}"}"#;
+        let auth: AuthConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            auth,
+            AuthConfig::jwt(JwtAlgorithm::Rs256, "{{jwt_key}}", r#"{"sub":"svc"}"#)
+        );
+        assert!(!auth.signs_request());
+        assert!(!auth.is_oauth2());
+        assert!(auth.cache_key().is_none());
+
+        let round_trip: AuthConfig =
+            serde_json::from_str(&serde_json::to_string(&auth).unwrap()).unwrap();
+        assert_eq!(round_trip, auth);
+        assert_eq!(JwtAlgorithm::Es256.as_str(), "ES256");
+    }
+
     #[test]
     fn test_oauth2_token_expiry() {
         let token = OAuth2Token::new(
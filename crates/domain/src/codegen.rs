@@ -78,6 +78,24 @@ impl CodeLanguage {
         }
     }
 
+    /// Get the prefix that starts a line comment in the language.
+    #[must_use]
+    pub const fn line_comment(&self) -> &'static str {
+        match self {
+            Self::Curl | Self::Python | Self::Ruby => "#",
+            Self::JavaScript
+            | Self::JavaScriptAxios
+            | Self::TypeScript
+            | Self::Rust
+            | Self::Go
+            | Self::Java
+            | Self::CSharp
+            | Self::Php
+            | Self::Swift
+            | Self::Kotlin => "//",
+        }
+    }
+
     /// Get all available languages.
     #[must_use]
     pub const fn all() -> &'static [Self] {
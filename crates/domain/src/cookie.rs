@@ -158,7 +158,18 @@ impl Cookie {
                 let attr = attr.trim().to_lowercase();
                 let val = val.trim();
                 match attr.as_str() {
-                    "domain" => cookie.domain = val.trim_start_matches('.').to_string(),
+                    "domain" => {
+                        let domain = val.trim_start_matches('.').to_lowercase();
+                        // A server may only set cookies for its own domain or a parent of it,
+                        // and never for a public suffix shared with other sites
+                        if !domain_matches(&domain, request_domain)
+                            || (is_public_suffix(&domain)
+                                && !domain.eq_ignore_ascii_case(request_domain))
+                        {
+                            return None;
+                        }
+                        cookie.domain = domain;
+                    }
                     "path" => cookie.path = val.to_string(),
                     "expires" => {
                         if let Ok(exp) = DateTime::parse_from_rfc2822(val) {
@@ -220,7 +231,7 @@ impl SameSite {
 }
 
 /// Cookie jar for storing cookies.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CookieJar {
     /// Stored cookies by domain.
     #[serde(default)]
@@ -234,6 +245,12 @@ const fn default_enabled() -> bool {
     true
 }
 
+impl Default for CookieJar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CookieJar {
     /// Create a new empty cookie jar.
     #[must_use]
@@ -245,8 +262,12 @@ impl CookieJar {
     }
 
     /// Add a cookie to the jar.
+    ///
+    /// An already expired cookie deletes any stored cookie with the same
+    /// name, domain and path, which is how servers clear cookies.
     pub fn add(&mut self, cookie: Cookie) {
         if cookie.is_expired() {
+            self.remove_cookie(&cookie);
             return;
         }
 
@@ -264,6 +285,15 @@ impl CookieJar {
         if let Some(cookies) = self.cookies.get_mut(domain) {
             cookies.retain(|c| c.name != name);
         }
+        self.cookies.retain(|_, cookies| !cookies.is_empty());
+    }
+
+    /// Remove the cookie with the same name, domain and path as the given one.
+    pub fn remove_cookie(&mut self, cookie: &Cookie) {
+        if let Some(cookies) = self.cookies.get_mut(&cookie.domain) {
+            cookies.retain(|c| c.name != cookie.name || c.path != cookie.path);
+        }
+        self.cookies.retain(|_, cookies| !cookies.is_empty());
     }
 
     /// Get all cookies for a URL.
@@ -286,6 +316,14 @@ impl CookieJar {
         self.cookies.values().flatten().collect()
     }
 
+    /// Get all cookies sorted by domain, path and name.
+    #[must_use]
+    pub fn sorted(&self) -> Vec<&Cookie> {
+        let mut cookies = self.all();
+        cookies.sort_by(|a, b| (&a.domain, &a.path, &a.name).cmp(&(&b.domain, &b.path, &b.name)));
+        cookies
+    }
+
     /// Get all non-expired cookies.
     #[must_use]
     pub fn all_valid(&self) -> Vec<&Cookie> {
@@ -378,6 +416,62 @@ fn extract_path(url: &str) -> Option<String> {
     Some(path.to_string())
 }
 
+/// Multi-label public suffixes under which anyone can register a name.
+///
+/// An excerpt of the Public Suffix List covering common second-level
+/// registries and shared hosting platforms. Top-level domains are
+/// recognized by having a single label.
+const PUBLIC_SUFFIXES: &[&str] = &[
+    "ac.uk",
+    "co.uk",
+    "gov.uk",
+    "ltd.uk",
+    "me.uk",
+    "net.uk",
+    "org.uk",
+    "plc.uk",
+    "com.au",
+    "edu.au",
+    "gov.au",
+    "net.au",
+    "org.au",
+    "co.nz",
+    "net.nz",
+    "org.nz",
+    "co.jp",
+    "ne.jp",
+    "or.jp",
+    "co.kr",
+    "co.in",
+    "co.za",
+    "com.br",
+    "com.cn",
+    "com.hk",
+    "com.mx",
+    "com.sg",
+    "com.tr",
+    "com.tw",
+    "appspot.com",
+    "azurewebsites.net",
+    "blogspot.com",
+    "cloudfront.net",
+    "firebaseapp.com",
+    "github.io",
+    "gitlab.io",
+    "herokuapp.com",
+    "netlify.app",
+    "pages.dev",
+    "vercel.app",
+    "web.app",
+    "workers.dev",
+];
+
+/// Check if a cookie domain is a public suffix, such as `com` or `co.uk`.
+fn is_public_suffix(domain: &str) -> bool {
+    let domain = domain.to_lowercase();
+    !domain.contains('.') || PUBLIC_SUFFIXES.contains(&domain.as_str())
+}
+
 /// Check if a cookie domain matches a request host.
 fn domain_matches(cookie_domain: &str, request_host: &str) -> bool {
     let cookie_domain = cookie_domain.to_lowercase();
@@ -465,6 +559,81 @@ mod tests {
         assert_eq!(cookie.same_site, SameSite::Strict);
     }
 
+    #[test]
+    fn test_cookie_from_set_cookie_domain() {
+        let cookie =
+            Cookie::from_set_cookie("id=1; Domain=.Example.com", "api.example.com").unwrap();
+        assert_eq!(cookie.domain, "example.com");
+
+        // Servers cannot set cookies for unrelated domains
+        assert!(Cookie::from_set_cookie("id=1; Domain=evil.com", "example.com").is_none());
+        assert!(Cookie::from_set_cookie("id=1; Domain=api.example.com", "example.com").is_none());
+    }
+
+    #[test]
+    fn test_cookie_from_set_cookie_rejects_public_suffix() {
+        assert!(Cookie::from_set_cookie("id=1; Domain=co.uk", "shop.co.uk").is_none());
+        assert!(Cookie::from_set_cookie("id=1; Domain=.github.io", "alice.github.io").is_none());
+        assert!(Cookie::from_set_cookie("id=1; Domain=com", "example.com").is_none());
+
+        // A host that is itself listed may still set cookies for itself
+        let cookie = Cookie::from_set_cookie("id=1; Domain=localhost", "localhost").unwrap();
+        assert_eq!(cookie.domain, "localhost");
+        assert!(is_public_suffix("CO.UK"));
+        assert!(!is_public_suffix("example.co.uk"));
+    }
+
+    #[test]
+    fn test_cookie_jar_expired_cookie_deletes() {
+        let mut jar = CookieJar::new();
+        jar.process_set_cookies(
+            &[("Set-Cookie".to_string(), "session=abc; Path=/".to_string())],
+            "example.com",
+        );
+        assert_eq!(jar.len(), 1);
+
+        jar.process_set_cookies(
+            &[(
+                "set-cookie".to_string(),
+                "session=; Path=/; Max-Age=0".to_string(),
+            )],
+            "example.com",
+        );
+        assert!(jar.is_empty());
+    }
+
+    #[test]
+    fn test_cookie_jar_remove_cookie_and_sorted() {
+        let mut jar = CookieJar::new();
+        jar.add(Cookie::new("b", "2", "example.com"));
+        jar.add(Cookie::new("a", "1", "example.com").with_path("/api"));
+        jar.add(Cookie::new("a", "1", "example.com"));
+        jar.add(Cookie::new("z", "3", "alpha.com"));
+
+        let names: Vec<_> = jar
+            .sorted()
+            .iter()
+            .map(|c| format!("{}{}{}", c.domain, c.path, c.name))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "alpha.com/z",
+                "example.com/a",
+                "example.com/b",
+                "example.com/apia"
+            ]
+        );
+
+        jar.remove_cookie(&Cookie::new("a", "", "example.com").with_path("/api"));
+        assert_eq!(jar.len(), 3);
+
+        jar.remove("z", "alpha.com");
+        jar.remove("a", "example.com");
+        jar.remove("b", "example.com");
+        assert!(jar.is_empty());
+    }
+
     #[test]
     fn test_cookie_jar_add_get() {
         let mut jar = CookieJar::new();
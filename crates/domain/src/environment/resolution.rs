@@ -2,7 +2,7 @@
 //!
 //! Holds all variable sources for resolution with proper precedence.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::globals::Globals;
 use super::secrets::SecretsStore;
@@ -12,9 +12,10 @@ use super::variable::{Environment, ResolvedVariable, VariableMap, VariableScope}
 /// Variables are resolved in order of precedence (highest wins):
 /// 1. Built-in ($uuid, $timestamp, etc.) - handled by resolver engine
 /// 2. Secrets (.vortex/secrets.json)
-/// 3. Environment (environments/*.json)
-/// 4. Collection (collection.json variables)
-/// 5. Global (globals.json)
+/// 3. Data (current row of an iteration data file)
+/// 4. Environment (environments/*.json)
+/// 5. Collection (collection.json variables)
+/// 6. Global (globals.json)
 #[derive(Debug, Clone, Default)]
 pub struct ResolutionContext {
     /// Global variables (lowest precedence for user variables).
@@ -29,6 +30,9 @@ pub struct ResolutionContext {
     /// Active environment name (for display purposes).
     pub environment_name: String,
 
+    /// Values of the current data file row during iteration runs.
+    pub data: HashMap<String, String>,
+
     /// Secrets for the active environment (highest precedence for user variables).
     pub secrets: HashMap<String, String>,
 }
@@ -58,6 +62,7 @@ impl ResolutionContext {
             collection: collection_variables.clone(),
             environment: environment.variables.clone(),
             environment_name: environment.name.clone(),
+            data: HashMap::new(),
             secrets: env_secrets,
         }
     }
@@ -75,6 +80,7 @@ impl ResolutionContext {
             collection: VariableMap::new(),
             environment: environment.variables.clone(),
             environment_name: environment.name.clone(),
+            data: HashMap::new(),
             secrets: env_secrets,
         }
     }
@@ -93,9 +99,19 @@ impl ResolutionContext {
             });
         }
 
+        // Data file row values
+        if let Some(value) = self.data.get(name) {
+            return Some(ResolvedVariable {
+                name: name.to_string(),
+                value: value.clone(),
+                scope: VariableScope::Data,
+            });
+        }
+
         // Environment variables
         if let Some(var) = self.environment.get(name)
             && var.enabled
+            && var.command.is_none()
         {
             return Some(ResolvedVariable {
                 name: name.to_string(),
@@ -107,6 +123,7 @@ impl ResolutionContext {
         // Collection variables
         if let Some(var) = self.collection.get(name)
             && var.enabled
+            && var.command.is_none()
         {
             return Some(ResolvedVariable {
                 name: name.to_string(),
@@ -118,6 +135,7 @@ impl ResolutionContext {
         // Global variables (lowest precedence)
         if let Some(var) = self.globals.get(name)
             && var.enabled
+            && var.command.is_none()
         {
             return Some(ResolvedVariable {
                 name: name.to_string(),
@@ -143,6 +161,7 @@ impl ResolutionContext {
             .keys()
             .chain(self.collection.keys())
             .chain(self.environment.keys())
+            .chain(self.data.keys())
             .chain(self.secrets.keys())
             .cloned()
             .collect();
@@ -152,6 +171,61 @@ impl ResolutionContext {
         names
     }
 
+    /// Returns every secret value in the context, for masking in output.
+    ///
+    /// Includes the secret scope and any enabled variable flagged as secret.
+    /// Empty values are skipped.
+    #[must_use]
+    pub fn secret_values(&self) -> Vec<String> {
+        let flagged = self
+            .globals
+            .values()
+            .chain(self.collection.values())
+            .chain(self.environment.values())
+            .filter(|var| var.secret && var.enabled)
+            .map(|var| &var.value);
+
+        let mut values: Vec<String> = self
+            .secrets
+            .values()
+            .chain(flagged)
+            .filter(|value| !value.is_empty())
+            .cloned()
+            .collect();
+
+        values.sort();
+        values.dedup();
+        values
+    }
+
+    /// Returns the commands of enabled command variables that still need a value.
+    ///
+    /// Yields `(name, command)` pairs sorted by name. A variable is skipped if
+    /// a plain variable overrides it or its value is already in the secret or
+    /// data scope. Run the commands and add their output to
+    /// [`secrets`](Self::secrets) to make the values resolvable.
+    #[must_use]
+    pub fn secret_commands(&self) -> Vec<(String, String)> {
+        let mut commands = BTreeMap::new();
+        for (name, var) in self
+            .globals
+            .iter()
+            .chain(&self.collection)
+            .chain(&self.environment)
+            .filter(|(_, var)| var.enabled)
+        {
+            match &var.command {
+                Some(command) => commands.insert(name.clone(), command.clone()),
+                None => commands.remove(name),
+            };
+        }
+
+        commands
+            .into_iter()
+            .filter(|(name, _)| !self.secrets.contains_key(name) && !self.data.contains_key(name))
+            .collect()
+    }
+
     /// Returns the count of variables across all scopes.
     #[must_use]
     pub fn total_variable_count(&self) -> usize {
@@ -180,6 +254,19 @@ impl ResolutionContext {
         self
     }
 
+    /// Sets the data row source for an iteration.
+    #[must_use]
+    pub fn with_data<'a, I>(mut self, row: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a String, &'a String)>,
+    {
+        self.data = row
+            .into_iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        self
+    }
+
     /// Sets the secrets source.
     #[must_use]
     pub fn with_secrets(mut self, secrets_store: &SecretsStore, environment_name: &str) -> Self {
@@ -369,4 +456,73 @@ mod tests {
             Some("sk-secret-123".to_string())
         );
     }
+
+    #[test]
+    fn test_data_precedence_between_environment_and_secrets() {
+        let env = create_test_environment();
+        let secrets = create_test_secrets();
+        let row = HashMap::from([
+            ("base_url".to_string(), "http://row:8080".to_string()),
+            ("api_key".to_string(), "row-key".to_string()),
+        ]);
+
+        let ctx = ResolutionContext::new()
+            .with_environment(&env)
+            .with_secrets(&secrets, &env.name)
+            .with_data(&row);
+
+        let base_url = ctx.resolve("base_url").unwrap();
+        assert_eq!(base_url.value, "http://row:8080");
+        assert_eq!(base_url.scope, VariableScope::Data);
+
+        let api_key = ctx.resolve("api_key").unwrap();
+        assert_eq!(api_key.value, "sk-secret-123");
+        assert_eq!(api_key.scope, VariableScope::Secret);
+    }
+
+    #[test]
+    fn test_secret_values() {
+        let mut secrets = SecretsStore::new();
+        secrets.set_secret("development", "api_key", "sk-secret-123");
+        let mut env = create_test_environment();
+        env.set_variable("password", Variable::secret("hunter2"));
+
+        let ctx = ResolutionContext::from_environment(&env, &secrets);
+
+        assert_eq!(ctx.secret_values(), ["hunter2", "sk-secret-123"]);
+    }
+
+    #[test]
+    fn test_secret_commands() {
+        let mut globals = Globals::new();
+        globals.set_variable("db_password", Variable::from_command("pass show db"));
+        globals.set_variable("shadowed", Variable::from_command("pass show shadowed"));
+        let mut env = create_test_environment();
+        env.add_secret_command("token", "pass show api/prod");
+        env.add_variable("shadowed", "plain");
+        env.add_secret_command("api_key", "pass show api/key");
+
+        let mut ctx = ResolutionContext::from_sources(
+            &globals,
+            &VariableMap::new(),
+            &env,
+            &create_test_secrets(),
+        );
+
+        assert_eq!(
+            ctx.secret_commands(),
+            [
+                ("db_password".to_string(), "pass show db".to_string()),
+                ("token".to_string(), "pass show api/prod".to_string()),
+            ]
+        );
+        assert_eq!(ctx.resolve("token"), None);
+        assert_eq!(ctx.resolve_value("shadowed"), Some("plain".to_string()));
+
+        ctx.secrets.insert("token".to_string(), "t0k3n".to_string());
+        let token = ctx.resolve("token").unwrap();
+        assert_eq!(token.value, "t0k3n");
+        assert_eq!(token.scope, VariableScope::Secret);
+        assert_eq!(ctx.secret_commands().len(), 1);
+    }
 }
@@ -18,6 +18,13 @@ pub struct Variable {
     /// Whether this variable is enabled
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+
+    /// Shell command whose output is the value (e.g. `pass show api/prod`).
+    ///
+    /// Command variables are always secret. The command runs when variables
+    /// are resolved and its output is never written to disk.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
 }
 
 const fn default_enabled() -> bool {
@@ -32,6 +39,7 @@ impl Variable {
             value: value.into(),
             secret: false,
             enabled: true,
+            command: None,
         }
     }
 
@@ -42,6 +50,7 @@ impl Variable {
             value: value.into(),
             secret: true,
             enabled: true,
+            command: None,
         }
     }
 
@@ -52,6 +61,18 @@ impl Variable {
             value: value.into(),
             secret: false,
             enabled: false,
+            command: None,
+        }
+    }
+
+    /// Creates a secret variable whose value is the output of a shell command.
+    #[must_use]
+    pub fn from_command(command: impl Into<String>) -> Self {
+        Self {
+            value: String::new(),
+            secret: true,
+            enabled: true,
+            command: Some(command.into()),
         }
     }
 
@@ -72,6 +93,7 @@ impl Default for Variable {
             value: String::new(),
             secret: false,
             enabled: true,
+            command: None,
         }
     }
 }
@@ -88,10 +110,12 @@ pub enum VariableScope {
     Collection = 1,
     /// Variables defined in environment files (environments/*.json)
     Environment = 2,
+    /// Values from the current row of a data file during iteration runs
+    Data = 3,
     /// Secret values from .vortex/secrets.json
-    Secret = 3,
+    Secret = 4,
     /// Built-in dynamic variables ($uuid, $timestamp, etc.) - highest precedence
-    BuiltIn = 4,
+    BuiltIn = 5,
 }
 
 impl VariableScope {
@@ -108,6 +132,7 @@ impl VariableScope {
             Self::Global => "Global",
             Self::Collection => "Collection",
             Self::Environment => "Environment",
+            Self::Data => "Data",
             Self::Secret => "Secret",
             Self::BuiltIn => "Built-in",
         }
@@ -228,6 +253,12 @@ impl Environment {
     pub fn add_secret(&mut self, name: impl Into<String>, value: impl Into<String>) {
         self.variables.insert(name.into(), Variable::secret(value));
     }
+
+    /// Adds a secret variable whose value is the output of a shell command.
+    pub fn add_secret_command(&mut self, name: impl Into<String>, command: impl Into<String>) {
+        self.variables
+            .insert(name.into(), Variable::from_command(command));
+    }
 }
 
 impl Default for Environment {
@@ -237,6 +268,7 @@ impl Default for Environment {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -265,6 +297,25 @@ mod tests {
         assert_eq!(var.enabled_value(), None);
     }
 
+    #[test]
+    fn test_variable_from_command() {
+        let var = Variable::from_command("pass show api/prod");
+        assert!(var.value.is_empty());
+        assert!(var.secret);
+        assert_eq!(var.command.as_deref(), Some("pass show api/prod"));
+
+        // The command is persisted, plain variables keep their format
+        let json = serde_json::to_string(&var).unwrap();
+        assert!(json.contains(r#""command":"pass show api/prod""#));
+        assert!(
+            !serde_json::to_string(&Variable::new("x"))
+                .unwrap()
+                .contains("command")
+        );
+        let parsed: Variable = serde_json::from_str(r#"{"value":"x"}"#).unwrap();
+        assert_eq!(parsed.command, None);
+    }
+
     #[test]
     fn test_environment_new() {
         let env = Environment::new("Development");
@@ -323,7 +374,8 @@ mod tests {
     #[test]
     fn test_variable_scope_precedence() {
         assert!(VariableScope::BuiltIn.precedence() > VariableScope::Secret.precedence());
-        assert!(VariableScope::Secret.precedence() > VariableScope::Environment.precedence());
+        assert!(VariableScope::Secret.precedence() > VariableScope::Data.precedence());
+        assert!(VariableScope::Data.precedence() > VariableScope::Environment.precedence());
         assert!(VariableScope::Environment.precedence() > VariableScope::Collection.precedence());
         assert!(VariableScope::Collection.precedence() > VariableScope::Global.precedence());
     }
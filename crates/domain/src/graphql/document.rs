@@ -0,0 +1,730 @@
+//! GraphQL document parsing
+//!
+//! A small parser for executable documents (operations and fragments). It
+//! keeps what is needed to pick an operation and check its selections against
+//! a schema; argument values and variable definitions are checked for syntax
+//! but not kept.
+
+use std::fmt;
+
+use super::GraphqlError;
+
+/// A location in the document, 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    /// Line number.
+    pub line: usize,
+    /// Column number.
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// The type of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    /// A read-only fetch.
+    Query,
+    /// A write followed by a fetch.
+    Mutation,
+    /// A long-lived request that receives events.
+    Subscription,
+}
+
+impl OperationKind {
+    /// Returns the keyword of the operation type.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Query => "query",
+            Self::Mutation => "mutation",
+            Self::Subscription => "subscription",
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "query" => Some(Self::Query),
+            "mutation" => Some(Self::Mutation),
+            "subscription" => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed executable document.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    /// Operations in document order.
+    pub operations: Vec<Operation>,
+    /// Fragment definitions in document order.
+    pub fragments: Vec<Fragment>,
+}
+
+/// An operation definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    /// Operation type.
+    pub kind: OperationKind,
+    /// Operation name, absent for anonymous operations.
+    pub name: Option<String>,
+    /// Top-level selections.
+    pub selections: Vec<Selection>,
+    /// Where the operation starts.
+    pub position: Position,
+}
+
+/// A fragment definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    /// Fragment name.
+    pub name: String,
+    /// The type the fragment applies to.
+    pub type_condition: String,
+    /// Selections of the fragment.
+    pub selections: Vec<Selection>,
+    /// Where the fragment starts.
+    pub position: Position,
+}
+
+/// An entry of a selection set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    /// A field, possibly with its own selections.
+    Field(Field),
+    /// A `...Name` fragment spread.
+    FragmentSpread {
+        /// Fragment name.
+        name: String,
+        /// Where the spread starts.
+        position: Position,
+    },
+    /// A `... on Type { }` inline fragment.
+    InlineFragment {
+        /// The type the selections apply to, if given.
+        type_condition: Option<String>,
+        /// Selections of the fragment.
+        selections: Vec<Self>,
+        /// Where the fragment starts.
+        position: Position,
+    },
+}
+
+/// A selected field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Response key alias, if given.
+    pub alias: Option<String>,
+    /// Field name.
+    pub name: String,
+    /// Names of the given arguments.
+    pub arguments: Vec<String>,
+    /// Sub-selections, empty for leaf fields.
+    pub selections: Vec<Selection>,
+    /// Where the field starts.
+    pub position: Position,
+}
+
+impl Document {
+    /// Parses a GraphQL document.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphqlError::Syntax` with the location of the first error.
+    pub fn parse(source: &str) -> Result<Self, GraphqlError> {
+        let (tokens, end) = Lexer::new(source).tokenize()?;
+        Parser {
+            tokens,
+            index: 0,
+            end,
+        }
+        .document()
+    }
+
+    /// Returns the names of the named operations.
+    #[must_use]
+    pub fn operation_names(&self) -> Vec<&str> {
+        self.operations
+            .iter()
+            .filter_map(|operation| operation.name.as_deref())
+            .collect()
+    }
+
+    /// Selects the operation to execute.
+    ///
+    /// Without a name, the document must contain exactly one operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no matching operation, or several
+    /// operations and no name.
+    pub fn operation(&self, name: Option<&str>) -> Result<&Operation, GraphqlError> {
+        if let Some(name) = name.filter(|name| !name.is_empty()) {
+            return self
+                .operations
+                .iter()
+                .find(|operation| operation.name.as_deref() == Some(name))
+                .ok_or_else(|| GraphqlError::UnknownOperation(name.to_string()));
+        }
+
+        match self.operations.as_slice() {
+            [] => Err(GraphqlError::NoOperation),
+            [operation] => Ok(operation),
+            _ => Err(GraphqlError::OperationNameRequired(
+                self.operation_names()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Returns the fragment with the given name.
+    #[must_use]
+    pub fn fragment(&self, name: &str) -> Option<&Fragment> {
+        self.fragments.iter().find(|fragment| fragment.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Punct(char),
+    Spread,
+    Name(String),
+    Number,
+    String,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Punct(c) => write!(f, "`{c}`"),
+            Self::Spread => f.write_str("`...`"),
+            Self::Name(name) => write!(f, "`{name}`"),
+            Self::Number => f.write_str("number"),
+            Self::String => f.write_str("string"),
+        }
+    }
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    index: usize,
+    position: Position,
+}
+
+impl Lexer {
+    fn new(source: &str) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            index: 0,
+            position: Position { line: 1, column: 1 },
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.index += 1;
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        Some(c)
+    }
+
+    fn tokenize(mut self) -> Result<(Vec<(Token, Position)>, Position), GraphqlError> {
+        let mut tokens = Vec::new();
+        while let Some(c) = self.peek(0) {
+            let position = self.position;
+            let token = match c {
+                ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => {
+                    self.bump();
+                    continue;
+                }
+                '#' => {
+                    while self.peek(0).is_some_and(|c| c != '\n') {
+                        self.bump();
+                    }
+                    continue;
+                }
+                '.' if self.peek(1) == Some('.') && self.peek(2) == Some('.') => {
+                    for _ in 0..3 {
+                        self.bump();
+                    }
+                    Token::Spread
+                }
+                '!' | '$' | '&' | '(' | ')' | ':' | '=' | '@' | '[' | ']' | '{' | '|' | '}' => {
+                    self.bump();
+                    Token::Punct(c)
+                }
+                '"' => {
+                    self.string(position)?;
+                    Token::String
+                }
+                c if c == '_' || c.is_ascii_alphabetic() => {
+                    let mut name = String::new();
+                    while let Some(c) = self
+                        .peek(0)
+                        .filter(|c| *c == '_' || c.is_ascii_alphanumeric())
+                    {
+                        name.push(c);
+                        self.bump();
+                    }
+                    Token::Name(name)
+                }
+                c if c == '-' || c.is_ascii_digit() => {
+                    self.bump();
+                    while self
+                        .peek(0)
+                        .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-'))
+                    {
+                        self.bump();
+                    }
+                    Token::Number
+                }
+                other => return Err(syntax(position, format!("Unexpected character `{other}`"))),
+            };
+            tokens.push((token, position));
+        }
+        Ok((tokens, self.position))
+    }
+
+    fn string(&mut self, start: Position) -> Result<(), GraphqlError> {
+        let unterminated = || syntax(start, "Unterminated string".to_string());
+
+        if self.peek(1) == Some('"') && self.peek(2) == Some('"') {
+            for _ in 0..3 {
+                self.bump();
+            }
+            loop {
+                match self.peek(0) {
+                    None => return Err(unterminated()),
+                    Some('\\') if (1..4).all(|offset| self.peek(offset) == Some('"')) => {
+                        for _ in 0..4 {
+                            self.bump();
+                        }
+                    }
+                    Some('"') if self.peek(1) == Some('"') && self.peek(2) == Some('"') => {
+                        for _ in 0..3 {
+                            self.bump();
+                        }
+                        return Ok(());
+                    }
+                    Some(_) => {
+                        self.bump();
+                    }
+                }
+            }
+        }
+
+        self.bump();
+        loop {
+            match self.bump() {
+                None | Some('\n') => return Err(unterminated()),
+                Some('\\') => {
+                    self.bump();
+                }
+                Some('"') => return Ok(()),
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, Position)>,
+    index: usize,
+    end: Position,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(token, _)| token)
+    }
+
+    fn position(&self) -> Position {
+        self.tokens
+            .get(self.index)
+            .map_or(self.end, |(_, position)| *position)
+    }
+
+    fn at(&self, punct: char) -> bool {
+        self.peek() == Some(&Token::Punct(punct))
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Name(name)) if name == keyword)
+    }
+
+    fn unexpected(&self) -> GraphqlError {
+        let Some(token) = self.peek() else {
+            return syntax(self.end, "Unexpected end of document".to_string());
+        };
+        syntax(self.position(), format!("Unexpected {token}"))
+    }
+
+    fn expect(&mut self, punct: char) -> Result<(), GraphqlError> {
+        if self.at(punct) {
+            self.index += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn name(&mut self) -> Result<(String, Position), GraphqlError> {
+        match self.tokens.get(self.index) {
+            Some((Token::Name(name), position)) => {
+                let name = (name.clone(), *position);
+                self.index += 1;
+                Ok(name)
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn document(&mut self) -> Result<Document, GraphqlError> {
+        let mut document = Document::default();
+        while let Some(token) = self.peek() {
+            let position = self.position();
+            match token {
+                Token::Punct('{') => document.operations.push(Operation {
+                    kind: OperationKind::Query,
+                    name: None,
+                    selections: self.selection_set()?,
+                    position,
+                }),
+                Token::Name(keyword) if keyword == "fragment" => {
+                    document.fragments.push(self.fragment()?);
+                }
+                Token::Name(keyword) => {
+                    let kind =
+                        OperationKind::from_keyword(keyword).ok_or_else(|| self.unexpected())?;
+                    document.operations.push(self.operation(kind)?);
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+        Ok(document)
+    }
+
+    fn operation(&mut self, kind: OperationKind) -> Result<Operation, GraphqlError> {
+        let (_, position) = self.name()?;
+        let name = match self.peek() {
+            Some(Token::Name(_)) => Some(self.name()?.0),
+            _ => None,
+        };
+        if self.at('(') {
+            self.variable_definitions()?;
+        }
+        self.directives()?;
+        Ok(Operation {
+            kind,
+            name,
+            selections: self.selection_set()?,
+            position,
+        })
+    }
+
+    fn fragment(&mut self) -> Result<Fragment, GraphqlError> {
+        let (_, position) = self.name()?;
+        if self.at_keyword("on") {
+            return Err(self.unexpected());
+        }
+        let (name, _) = self.name()?;
+        if !self.at_keyword("on") {
+            return Err(self.unexpected());
+        }
+        self.index += 1;
+        let (type_condition, _) = self.name()?;
+        self.directives()?;
+        Ok(Fragment {
+            name,
+            type_condition,
+            selections: self.selection_set()?,
+            position,
+        })
+    }
+
+    fn variable_definitions(&mut self) -> Result<(), GraphqlError> {
+        self.expect('(')?;
+        loop {
+            self.expect('$')?;
+            self.name()?;
+            self.expect(':')?;
+            self.type_reference()?;
+            if self.at('=') {
+                self.index += 1;
+                self.value()?;
+            }
+            self.directives()?;
+            if self.at(')') {
+                self.index += 1;
+                return Ok(());
+            }
+        }
+    }
+
+    fn type_reference(&mut self) -> Result<(), GraphqlError> {
+        if self.at('[') {
+            self.index += 1;
+            self.type_reference()?;
+            self.expect(']')?;
+        } else {
+            self.name()?;
+        }
+        if self.at('!') {
+            self.index += 1;
+        }
+        Ok(())
+    }
+
+    fn directives(&mut self) -> Result<(), GraphqlError> {
+        while self.at('@') {
+            self.index += 1;
+            self.name()?;
+            if self.at('(') {
+                self.arguments()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn arguments(&mut self) -> Result<Vec<String>, GraphqlError> {
+        self.expect('(')?;
+        let mut names = Vec::new();
+        loop {
+            names.push(self.name()?.0);
+            self.expect(':')?;
+            self.value()?;
+            if self.at(')') {
+                self.index += 1;
+                return Ok(names);
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<(), GraphqlError> {
+        let Some(token) = self.peek().cloned() else {
+            return Err(self.unexpected());
+        };
+        self.index += 1;
+        match token {
+            Token::Punct('$') => {
+                self.name()?;
+            }
+            Token::Number | Token::String | Token::Name(_) => {}
+            Token::Punct('[') => {
+                while !self.at(']') {
+                    self.value()?;
+                }
+                self.index += 1;
+            }
+            Token::Punct('{') => {
+                while !self.at('}') {
+                    self.name()?;
+                    self.expect(':')?;
+                    self.value()?;
+                }
+                self.index += 1;
+            }
+            _ => {
+                self.index -= 1;
+                return Err(self.unexpected());
+            }
+        }
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Selection>, GraphqlError> {
+        self.expect('{')?;
+        let mut selections = Vec::new();
+        loop {
+            selections.push(self.selection()?);
+            if self.at('}') {
+                self.index += 1;
+                return Ok(selections);
+            }
+        }
+    }
+
+    fn selection(&mut self) -> Result<Selection, GraphqlError> {
+        let position = self.position();
+        if self.peek() != Some(&Token::Spread) {
+            return self.field().map(Selection::Field);
+        }
+
+        self.index += 1;
+        let type_condition = if self.at_keyword("on") {
+            self.index += 1;
+            Some(self.name()?.0)
+        } else if matches!(self.peek(), Some(Token::Name(_))) {
+            let (name, _) = self.name()?;
+            self.directives()?;
+            return Ok(Selection::FragmentSpread { name, position });
+        } else {
+            None
+        };
+        self.directives()?;
+        Ok(Selection::InlineFragment {
+            type_condition,
+            selections: self.selection_set()?,
+            position,
+        })
+    }
+
+    fn field(&mut self) -> Result<Field, GraphqlError> {
+        let (name, position) = self.name()?;
+        let (alias, name) = if self.at(':') {
+            self.index += 1;
+            (Some(name), self.name()?.0)
+        } else {
+            (None, name)
+        };
+        let arguments = if self.at('(') {
+            self.arguments()?
+        } else {
+            Vec::new()
+        };
+        self.directives()?;
+        let selections = if self.at('{') {
+            self.selection_set()?
+        } else {
+            Vec::new()
+        };
+        Ok(Field {
+            alias,
+            name,
+            arguments,
+            selections,
+            position,
+        })
+    }
+}
+
+const fn syntax(position: Position, message: String) -> GraphqlError {
+    GraphqlError::Syntax {
+        line: position.line,
+        column: position.column,
+        message,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const QUERY: &str = r#"
+        # Fetch a user
+        query GetUser($id: ID!, $first: Int = 10) @cached(ttl: 60) {
+            user(id: $id) {
+                id
+                handle: login
+                posts(first: $first, filter: { tags: ["a", "b"], text: """x "y" z""" }) {
+                    ...PostFields
+                    ... on Article { title }
+                    ... @include(if: true) { id }
+                }
+            }
+        }
+
+        mutation Like { like(id: "1") { count } }
+
+        fragment PostFields on Post { id, createdAt }
+    "#;
+
+    #[test]
+    fn test_parse_document() {
+        let document = Document::parse(QUERY).unwrap();
+        assert_eq!(document.operation_names(), ["GetUser", "Like"]);
+        assert_eq!(document.fragments[0].type_condition, "Post");
+
+        let operation = document.operation(Some("GetUser")).unwrap();
+        assert_eq!(operation.kind, OperationKind::Query);
+        assert_eq!(operation.position, Position { line: 3, column: 9 });
+
+        let Selection::Field(user) = &operation.selections[0] else {
+            panic!("Expected a field");
+        };
+        assert_eq!(user.arguments, ["id"]);
+        let Selection::Field(handle) = &user.selections[1] else {
+            panic!("Expected a field");
+        };
+        assert_eq!(handle.alias.as_deref(), Some("handle"));
+        assert_eq!(handle.name, "login");
+
+        let Selection::Field(posts) = &user.selections[2] else {
+            panic!("Expected a field");
+        };
+        assert_eq!(posts.arguments, ["first", "filter"]);
+        assert!(matches!(
+            &posts.selections[..],
+            [
+                Selection::FragmentSpread { name, .. },
+                Selection::InlineFragment { type_condition: Some(on), .. },
+                Selection::InlineFragment { type_condition: None, .. },
+            ] if name == "PostFields" && on == "Article"
+        ));
+    }
+
+    #[test]
+    fn test_select_operation() {
+        let document = Document::parse(QUERY).unwrap();
+        assert!(matches!(
+            document.operation(None),
+            Err(GraphqlError::OperationNameRequired(names)) if names == ["GetUser", "Like"]
+        ));
+        assert!(matches!(
+            document.operation(Some("Missing")),
+            Err(GraphqlError::UnknownOperation(_))
+        ));
+
+        let shorthand = Document::parse("{ me { id } }").unwrap();
+        assert_eq!(shorthand.operation(None).unwrap().name, None);
+        assert!(matches!(
+            Document::parse("").unwrap().operation(None),
+            Err(GraphqlError::NoOperation)
+        ));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let error = |source: &str| match Document::parse(source) {
+            Err(GraphqlError::Syntax {
+                line,
+                column,
+                message,
+            }) => (line, column, message),
+            other => panic!("Expected a syntax error, got {other:?}"),
+        };
+
+        assert_eq!(error("{ user { } }"), (1, 10, "Unexpected `}`".to_string()));
+        assert_eq!(
+            error("query {\n  user(id: ) { id }\n}"),
+            (2, 12, "Unexpected `)`".to_string())
+        );
+        assert_eq!(
+            error("{ me { id }"),
+            (1, 12, "Unexpected end of document".to_string())
+        );
+        assert_eq!(
+            error("{ a(b: \"c) }"),
+            (1, 8, "Unterminated string".to_string())
+        );
+        assert_eq!(
+            error("select { a }"),
+            (1, 1, "Unexpected `select`".to_string())
+        );
+    }
+}
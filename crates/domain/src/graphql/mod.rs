@@ -0,0 +1,305 @@
+//! GraphQL support
+//!
+//! GraphQL requests are sent over HTTP as a JSON envelope
+//! (`{"query", "operationName", "variables"}`). This module encodes that
+//! envelope, parses documents to select an operation, and validates them
+//! against a schema downloaded with [`INTROSPECTION_QUERY`]. Subscriptions
+//! use the same envelope over the `graphql-transport-ws` protocol.
+
+mod document;
+mod schema;
+mod subscription;
+mod validation;
+
+use serde_json::{Map, Value as JsonValue};
+
+pub use document::{Document, Field, Fragment, Operation, OperationKind, Position, Selection};
+pub use schema::{GraphqlSchema, SchemaArgument, SchemaField, SchemaType, TypeKind};
+pub use subscription::{ClientMessage, GRAPHQL_TRANSPORT_WS, ServerMessage};
+pub use validation::{ValidationError, validate};
+
+/// Errors that can occur when handling GraphQL documents.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GraphqlError {
+    /// The document is not valid GraphQL.
+    #[error("Syntax error at {line}:{column}: {message}")]
+    Syntax {
+        /// Line of the error, 1-based.
+        line: usize,
+        /// Column of the error, 1-based.
+        column: usize,
+        /// What is wrong.
+        message: String,
+    },
+
+    /// The selected operation is not in the document.
+    #[error("The document has no operation named `{0}`")]
+    UnknownOperation(String),
+
+    /// The document has several operations and none was selected.
+    #[error("The document has several operations; choose one of: {}", .0.join(", "))]
+    OperationNameRequired(Vec<String>),
+
+    /// The document has no operations.
+    #[error("The document has no operations")]
+    NoOperation,
+
+    /// The variables are not a JSON object.
+    #[error("GraphQL variables must be a JSON object: {0}")]
+    InvalidVariables(String),
+
+    /// The introspection result cannot be used as a schema.
+    #[error("Invalid introspection result: {0}")]
+    InvalidSchema(String),
+
+    /// A subscription message does not follow the protocol.
+    #[error("Invalid subscription message: {0}")]
+    Protocol(String),
+}
+
+/// Encodes a GraphQL request as the JSON envelope sent over HTTP.
+///
+/// `variables` is JSON text and may be empty. Without an `operation_name`,
+/// the operation is picked from the document when there is only one, so
+/// documents with several operations need a name.
+///
+/// # Errors
+///
+/// Returns an error if the variables are not a JSON object, or the operation
+/// cannot be selected. Syntax errors are left for the server to report.
+pub fn encode_request(
+    query: &str,
+    variables: &str,
+    operation_name: Option<&str>,
+) -> Result<String, GraphqlError> {
+    request_payload(query, variables, operation_name).map(|payload| payload.to_string())
+}
+
+/// Builds the JSON envelope of a GraphQL request, as described for [`encode_request`].
+///
+/// # Errors
+///
+/// Returns an error if the variables are not a JSON object, or the operation
+/// cannot be selected.
+pub fn request_payload(
+    query: &str,
+    variables: &str,
+    operation_name: Option<&str>,
+) -> Result<JsonValue, GraphqlError> {
+    let mut envelope = Map::new();
+    envelope.insert("query".to_string(), query.into());
+
+    let operation_name = match operation_name.filter(|name| !name.is_empty()) {
+        Some(name) => Some(name.to_string()),
+        None => match Document::parse(query) {
+            Ok(document) => document.operation(None)?.name.clone(),
+            Err(_) => None,
+        },
+    };
+    if let Some(name) = operation_name {
+        envelope.insert("operationName".to_string(), name.into());
+    }
+
+    if !variables.trim().is_empty() {
+        match serde_json::from_str(variables) {
+            Ok(JsonValue::Object(variables)) => {
+                envelope.insert("variables".to_string(), variables.into());
+            }
+            Ok(JsonValue::Null) => {}
+            Ok(_) => {
+                return Err(GraphqlError::InvalidVariables(
+                    "expected an object".to_string(),
+                ));
+            }
+            Err(e) => return Err(GraphqlError::InvalidVariables(e.to_string())),
+        }
+    }
+
+    Ok(JsonValue::Object(envelope))
+}
+
+/// Query that downloads the schema, in the format [`GraphqlSchema::from_introspection`] reads.
+pub const INTROSPECTION_QUERY: &str = "query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: true) {
+        name
+        description
+        args { name description type { ...TypeRef } defaultValue }
+        type { ...TypeRef }
+        isDeprecated
+        deprecationReason
+      }
+      inputFields { name description type { ...TypeRef } defaultValue }
+      interfaces { ...TypeRef }
+      enumValues(includeDeprecated: true) { name description isDeprecated deprecationReason }
+      possibleTypes { ...TypeRef }
+    }
+    directives {
+      name
+      description
+      locations
+      args { name description type { ...TypeRef } defaultValue }
+    }
+  }
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType {
+    kind
+    name
+    ofType {
+      kind
+      name
+      ofType {
+        kind
+        name
+        ofType {
+          kind
+          name
+          ofType {
+            kind
+            name
+            ofType { kind name ofType { kind name ofType { kind name } } }
+          }
+        }
+      }
+    }
+  }
+}";
+
+/// Builds a small schema for tests:
+///
+/// ```graphql
+/// type Query { user(id: ID!): User, users(first: Int): [User!]!, search(text: String!): [SearchResult] }
+/// type Mutation { like(id: ID!): Post }
+/// type User { id: ID!, login: String, posts(first: Int = 10): [Post] }
+/// type Post { id: ID!, title: String }
+/// union SearchResult = User | Post
+/// ```
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) fn test_schema() -> GraphqlSchema {
+    use serde_json::json;
+
+    let named = |kind: &str, name: &str| json!({ "kind": kind, "name": name, "ofType": null });
+    let non_null = |inner: JsonValue| json!({ "kind": "NON_NULL", "name": null, "ofType": inner });
+    let list = |inner: JsonValue| json!({ "kind": "LIST", "name": null, "ofType": inner });
+    let field = |name: &str, ty: JsonValue, args: JsonValue| json!({ "name": name, "args": args, "type": ty });
+    let arg = |name: &str, ty: JsonValue, default: Option<&str>| json!({ "name": name, "type": ty, "defaultValue": default });
+    let object = |name: &str, fields: Vec<JsonValue>| json!({ "kind": "OBJECT", "name": name, "fields": fields });
+    let id = || non_null(named("SCALAR", "ID"));
+
+    let response = json!({ "data": { "__schema": {
+        "queryType": { "name": "Query" },
+        "mutationType": { "name": "Mutation" },
+        "subscriptionType": null,
+        "types": [
+            object("Query", vec![
+                field("user", named("OBJECT", "User"), json!([arg("id", id(), None)])),
+                field(
+                    "users",
+                    non_null(list(non_null(named("OBJECT", "User")))),
+                    json!([arg("first", named("SCALAR", "Int"), None)]),
+                ),
+                field(
+                    "search",
+                    list(named("UNION", "SearchResult")),
+                    json!([arg("text", non_null(named("SCALAR", "String")), None)]),
+                ),
+            ]),
+            object("Mutation", vec![
+                field("like", named("OBJECT", "Post"), json!([arg("id", id(), None)])),
+            ]),
+            object("User", vec![
+                field("id", id(), json!([])),
+                field("login", named("SCALAR", "String"), json!([])),
+                field(
+                    "posts",
+                    list(named("OBJECT", "Post")),
+                    json!([arg("first", non_null(named("SCALAR", "Int")), Some("10"))]),
+                ),
+            ]),
+            object("Post", vec![
+                field("id", id(), json!([])),
+                field("title", named("SCALAR", "String"), json!([])),
+            ]),
+            { "kind": "UNION", "name": "SearchResult", "fields": null },
+            { "kind": "SCALAR", "name": "ID" },
+            { "kind": "SCALAR", "name": "Int" },
+            { "kind": "SCALAR", "name": "String" },
+        ],
+    }}});
+
+    GraphqlSchema::from_introspection(&response).unwrap()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn decode(envelope: &str) -> JsonValue {
+        serde_json::from_str(envelope).unwrap()
+    }
+
+    #[test]
+    fn test_encode_request() {
+        let envelope = encode_request(
+            "query GetUser($id: ID!) { user(id: $id) { id } }",
+            r#"{ "id": "42" }"#,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            decode(&envelope),
+            serde_json::json!({
+                "query": "query GetUser($id: ID!) { user(id: $id) { id } }",
+                "operationName": "GetUser",
+                "variables": { "id": "42" },
+            })
+        );
+
+        // Anonymous operations and empty variables are left out
+        let envelope = encode_request("{ me { id } }", "  ", None).unwrap();
+        assert_eq!(
+            decode(&envelope),
+            serde_json::json!({ "query": "{ me { id } }" })
+        );
+    }
+
+    #[test]
+    fn test_encode_request_operation_selection() {
+        let query = "query A { a } query B { b }";
+        assert!(matches!(
+            encode_request(query, "", None),
+            Err(GraphqlError::OperationNameRequired(names)) if names == ["A", "B"]
+        ));
+        let envelope = encode_request(query, "null", Some("B")).unwrap();
+        assert_eq!(decode(&envelope)["operationName"], "B");
+
+        assert!(matches!(
+            encode_request("{ a }", "[1]", None),
+            Err(GraphqlError::InvalidVariables(_))
+        ));
+        assert!(matches!(
+            encode_request("{ a }", "{ id: 1 }", None),
+            Err(GraphqlError::InvalidVariables(_))
+        ));
+    }
+
+    #[test]
+    fn test_introspection_query_parses() {
+        let document = Document::parse(INTROSPECTION_QUERY).unwrap();
+        assert_eq!(document.operation_names(), ["IntrospectionQuery"]);
+        assert_eq!(document.fragments[0].name, "TypeRef");
+    }
+}
@@ -0,0 +1,269 @@
+//! GraphQL schema from an introspection result
+//!
+//! Keeps the parts of the schema needed to validate selections: the root
+//! operation types and, for every named type, its kind, fields and arguments.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+use super::GraphqlError;
+use super::document::OperationKind;
+
+/// The kind of a named type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    /// A leaf value such as `String` or `ID`.
+    Scalar,
+    /// An object with fields.
+    Object,
+    /// An abstract type with fields.
+    Interface,
+    /// An abstract type without fields.
+    Union,
+    /// A leaf value from a fixed set.
+    Enum,
+    /// An argument object.
+    InputObject,
+}
+
+impl TypeKind {
+    /// Returns true for types that are selected without sub-selections.
+    #[must_use]
+    pub const fn is_leaf(self) -> bool {
+        matches!(self, Self::Scalar | Self::Enum)
+    }
+}
+
+/// A named type of the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaType {
+    /// The kind of the type.
+    pub kind: TypeKind,
+    /// Fields by name, for objects and interfaces.
+    pub fields: HashMap<String, SchemaField>,
+}
+
+/// A field of an object or interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaField {
+    /// Name of the field type with list and non-null wrappers removed.
+    pub type_name: String,
+    /// The field arguments.
+    pub arguments: Vec<SchemaArgument>,
+}
+
+/// An argument of a field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaArgument {
+    /// Argument name.
+    pub name: String,
+    /// Whether the argument is non-null without a default value.
+    pub required: bool,
+}
+
+/// A GraphQL schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphqlSchema {
+    query_type: Option<String>,
+    mutation_type: Option<String>,
+    subscription_type: Option<String>,
+    types: HashMap<String, SchemaType>,
+}
+
+impl GraphqlSchema {
+    /// Builds the schema from the response to [`INTROSPECTION_QUERY`](super::INTROSPECTION_QUERY).
+    ///
+    /// Accepts the full response (`{"data": {"__schema": ...}}`) or just its data.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphqlError::InvalidSchema` if the response carries errors
+    /// instead of a schema, or the schema is malformed.
+    pub fn from_introspection(response: &JsonValue) -> Result<Self, GraphqlError> {
+        let schema = response
+            .pointer("/data/__schema")
+            .or_else(|| response.get("__schema"))
+            .filter(|schema| !schema.is_null())
+            .ok_or_else(|| {
+                let message = response
+                    .pointer("/errors/0/message")
+                    .and_then(JsonValue::as_str)
+                    .unwrap_or("the response has no `__schema`");
+                GraphqlError::InvalidSchema(message.to_string())
+            })?;
+
+        let raw = RawSchema::deserialize(schema)
+            .map_err(|e| GraphqlError::InvalidSchema(e.to_string()))?;
+        let types = raw
+            .types
+            .into_iter()
+            .filter_map(|raw_type| {
+                let kind = match raw_type.kind.as_str() {
+                    "SCALAR" => TypeKind::Scalar,
+                    "OBJECT" => TypeKind::Object,
+                    "INTERFACE" => TypeKind::Interface,
+                    "UNION" => TypeKind::Union,
+                    "ENUM" => TypeKind::Enum,
+                    "INPUT_OBJECT" => TypeKind::InputObject,
+                    _ => return None,
+                };
+                let fields = raw_type
+                    .fields
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|field| {
+                        let arguments = field
+                            .args
+                            .into_iter()
+                            .map(|arg| SchemaArgument {
+                                required: arg.ty.kind == "NON_NULL" && arg.default_value.is_none(),
+                                name: arg.name,
+                            })
+                            .collect();
+                        let schema_field = SchemaField {
+                            type_name: field.ty.named().to_string(),
+                            arguments,
+                        };
+                        (field.name, schema_field)
+                    })
+                    .collect();
+                Some((raw_type.name?, SchemaType { kind, fields }))
+            })
+            .collect();
+
+        Ok(Self {
+            query_type: raw.query_type.map(|t| t.name),
+            mutation_type: raw.mutation_type.map(|t| t.name),
+            subscription_type: raw.subscription_type.map(|t| t.name),
+            types,
+        })
+    }
+
+    /// Returns the root type name for an operation type, if the schema supports it.
+    #[must_use]
+    pub fn root_type(&self, kind: OperationKind) -> Option<&str> {
+        match kind {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        }
+    }
+
+    /// Returns the named type.
+    #[must_use]
+    pub fn get_type(&self, name: &str) -> Option<&SchemaType> {
+        self.types.get(name)
+    }
+
+    /// Returns the number of named types.
+    #[must_use]
+    pub fn type_count(&self) -> usize {
+        self.types.len()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSchema {
+    query_type: Option<RawName>,
+    mutation_type: Option<RawName>,
+    subscription_type: Option<RawName>,
+    types: Vec<RawType>,
+}
+
+#[derive(Deserialize)]
+struct RawName {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct RawType {
+    kind: String,
+    name: Option<String>,
+    fields: Option<Vec<RawField>>,
+}
+
+#[derive(Deserialize)]
+struct RawField {
+    name: String,
+    #[serde(default)]
+    args: Vec<RawArgument>,
+    #[serde(rename = "type")]
+    ty: RawTypeRef,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawArgument {
+    name: String,
+    #[serde(rename = "type")]
+    ty: RawTypeRef,
+    default_value: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTypeRef {
+    kind: String,
+    name: Option<String>,
+    of_type: Option<Box<Self>>,
+}
+
+impl RawTypeRef {
+    /// Unwraps list and non-null wrappers.
+    fn named(&self) -> &str {
+        match (&self.name, &self.of_type) {
+            (Some(name), _) => name,
+            (None, Some(inner)) => inner.named(),
+            (None, None) => "",
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::graphql::test_schema;
+
+    #[test]
+    fn test_from_introspection() {
+        let schema = test_schema();
+        assert_eq!(schema.root_type(OperationKind::Query), Some("Query"));
+        assert_eq!(schema.root_type(OperationKind::Mutation), Some("Mutation"));
+        assert_eq!(schema.root_type(OperationKind::Subscription), None);
+
+        let user = &schema.get_type("Query").unwrap().fields["user"];
+        assert_eq!(user.type_name, "User");
+        assert_eq!(
+            user.arguments,
+            [SchemaArgument {
+                name: "id".to_string(),
+                required: true,
+            }]
+        );
+        let posts = &schema.get_type("User").unwrap().fields["posts"];
+        assert_eq!(posts.type_name, "Post");
+        assert!(!posts.arguments[0].required);
+        assert!(schema.get_type("String").unwrap().kind.is_leaf());
+    }
+
+    #[test]
+    fn test_introspection_errors() {
+        let response = serde_json::json!({
+            "errors": [{ "message": "Introspection is disabled" }]
+        });
+        assert_eq!(
+            GraphqlSchema::from_introspection(&response),
+            Err(GraphqlError::InvalidSchema(
+                "Introspection is disabled".to_string()
+            ))
+        );
+        assert!(matches!(
+            GraphqlSchema::from_introspection(&serde_json::json!({"__schema": {"types": 1}})),
+            Err(GraphqlError::InvalidSchema(_))
+        ));
+    }
+}
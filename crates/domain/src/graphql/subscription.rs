@@ -0,0 +1,263 @@
+//! Messages of the `graphql-transport-ws` protocol
+//!
+//! Subscriptions run over a WebSocket: the client opens the connection with
+//! `connection_init` (carrying connection params, typically auth), waits for
+//! `connection_ack`, then sends `subscribe`. The server answers with a `next`
+//! message per event and ends with `complete` or `error`. Either side may
+//! `ping`, and the other answers `pong`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::{GraphqlError, request_payload};
+
+/// WebSocket subprotocol of the `graphql-transport-ws` protocol.
+pub const GRAPHQL_TRANSPORT_WS: &str = "graphql-transport-ws";
+
+/// A message sent by the client.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Opens the connection.
+    ConnectionInit {
+        /// Connection params.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// Checks that the server is alive.
+    Ping {
+        /// Optional payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// Answers a server ping.
+    Pong {
+        /// Optional payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// Starts an operation.
+    Subscribe {
+        /// Operation id, unique per connection.
+        id: String,
+        /// The request envelope (`query`, `operationName`, `variables`).
+        payload: JsonValue,
+    },
+    /// Stops an operation.
+    Complete {
+        /// Operation id.
+        id: String,
+    },
+}
+
+impl ClientMessage {
+    /// Creates a `connection_init` message.
+    ///
+    /// `params` is JSON text and may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphqlError::Protocol` if the params are not a JSON object.
+    pub fn connection_init(params: &str) -> Result<Self, GraphqlError> {
+        let payload = if params.trim().is_empty() {
+            None
+        } else {
+            match serde_json::from_str(params) {
+                Ok(JsonValue::Null) => None,
+                Ok(params @ JsonValue::Object(_)) => Some(params),
+                Ok(_) => {
+                    return Err(GraphqlError::Protocol(
+                        "connection params must be a JSON object".to_string(),
+                    ));
+                }
+                Err(e) => {
+                    return Err(GraphqlError::Protocol(format!(
+                        "connection params are not valid JSON: {e}"
+                    )));
+                }
+            }
+        };
+        Ok(Self::ConnectionInit { payload })
+    }
+
+    /// Creates a `subscribe` message for a query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variables are not a JSON object, or the
+    /// operation cannot be selected.
+    pub fn subscribe(
+        id: impl Into<String>,
+        query: &str,
+        variables: &str,
+        operation_name: Option<&str>,
+    ) -> Result<Self, GraphqlError> {
+        Ok(Self::Subscribe {
+            id: id.into(),
+            payload: request_payload(query, variables, operation_name)?,
+        })
+    }
+
+    /// Returns the message as JSON text.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// A message sent by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Accepts the connection.
+    ConnectionAck {
+        /// Optional payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// Checks that the client is alive.
+    Ping {
+        /// Optional payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// Answers a client ping.
+    Pong {
+        /// Optional payload.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        payload: Option<JsonValue>,
+    },
+    /// A result of an operation.
+    Next {
+        /// Operation id.
+        id: String,
+        /// The execution result (`data`, `errors`).
+        payload: JsonValue,
+    },
+    /// The operation failed before it produced results.
+    Error {
+        /// Operation id.
+        id: String,
+        /// The GraphQL errors.
+        payload: JsonValue,
+    },
+    /// The operation ended.
+    Complete {
+        /// Operation id.
+        id: String,
+    },
+}
+
+impl ServerMessage {
+    /// Parses a message received from the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GraphqlError::Protocol` if the text is not a protocol message.
+    pub fn parse(text: &str) -> Result<Self, GraphqlError> {
+        serde_json::from_str(text).map_err(|e| GraphqlError::Protocol(e.to_string()))
+    }
+
+    /// Returns the messages of the errors of an `error` message.
+    #[must_use]
+    pub fn error_messages(&self) -> Vec<String> {
+        let Self::Error { payload, .. } = self else {
+            return Vec::new();
+        };
+        let errors = payload
+            .as_array()
+            .map_or_else(|| std::slice::from_ref(payload), std::vec::Vec::as_slice);
+        errors
+            .iter()
+            .map(|error| {
+                error
+                    .get("message")
+                    .and_then(JsonValue::as_str)
+                    .map_or_else(|| error.to_string(), ToString::to_string)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn decode(message: &ClientMessage) -> JsonValue {
+        serde_json::from_str(&message.to_json()).unwrap()
+    }
+
+    #[test]
+    fn test_client_messages() {
+        let init = ClientMessage::connection_init(r#"{"authToken": "abc"}"#).unwrap();
+        assert_eq!(
+            decode(&init),
+            json!({ "type": "connection_init", "payload": { "authToken": "abc" } })
+        );
+        let init = ClientMessage::connection_init(" ").unwrap();
+        assert_eq!(decode(&init), json!({ "type": "connection_init" }));
+        assert!(matches!(
+            ClientMessage::connection_init("[1]"),
+            Err(GraphqlError::Protocol(_))
+        ));
+
+        let subscribe = ClientMessage::subscribe(
+            "1",
+            "subscription OnLike($id: ID!) { liked(id: $id) { id } }",
+            r#"{"id": "7"}"#,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            decode(&subscribe),
+            json!({
+                "type": "subscribe",
+                "id": "1",
+                "payload": {
+                    "query": "subscription OnLike($id: ID!) { liked(id: $id) { id } }",
+                    "operationName": "OnLike",
+                    "variables": { "id": "7" },
+                },
+            })
+        );
+        assert_eq!(
+            decode(&ClientMessage::Complete {
+                id: "1".to_string()
+            }),
+            json!({ "type": "complete", "id": "1" })
+        );
+    }
+
+    #[test]
+    fn test_server_messages() {
+        assert_eq!(
+            ServerMessage::parse(r#"{"type": "connection_ack"}"#).unwrap(),
+            ServerMessage::ConnectionAck { payload: None }
+        );
+        assert_eq!(
+            ServerMessage::parse(r#"{"type": "next", "id": "1", "payload": {"data": {"n": 1}}}"#)
+                .unwrap(),
+            ServerMessage::Next {
+                id: "1".to_string(),
+                payload: json!({ "data": { "n": 1 } }),
+            }
+        );
+
+        let error = ServerMessage::parse(
+            r#"{"type": "error", "id": "1", "payload": [{"message": "Unknown field"}, {"code": 1}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            error.error_messages(),
+            ["Unknown field".to_string(), r#"{"code":1}"#.to_string()]
+        );
+
+        assert!(matches!(
+            ServerMessage::parse(r#"{"type": "data", "id": "1"}"#),
+            Err(GraphqlError::Protocol(_))
+        ));
+    }
+}
@@ -0,0 +1,289 @@
+//! Validation of GraphQL documents against a schema
+//!
+//! Catches the mistakes a schema makes obvious before a request is sent:
+//! unknown fields, types and fragments, unknown or missing arguments, and
+//! selections on leaf fields (or their absence on objects). Variable types and
+//! argument values are left to the server.
+
+use std::fmt;
+
+use super::GraphqlError;
+use super::document::{Document, Field, Position, Selection};
+use super::schema::{GraphqlSchema, TypeKind};
+
+/// A problem found in a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// What is wrong.
+    pub message: String,
+    /// Where the problem is.
+    pub position: Position,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.position, self.message)
+    }
+}
+
+/// Validates a query document against a schema.
+///
+/// `operation_name` is checked like when the document is sent: it must name
+/// an operation, or the document must contain exactly one. Returns all
+/// problems found, in document order; an empty list means the document is valid.
+#[must_use]
+pub fn validate(
+    query: &str,
+    operation_name: Option<&str>,
+    schema: &GraphqlSchema,
+) -> Vec<ValidationError> {
+    let document = match Document::parse(query) {
+        Ok(document) => document,
+        Err(GraphqlError::Syntax {
+            line,
+            column,
+            message,
+        }) => {
+            return vec![ValidationError {
+                message: format!("Syntax error: {message}"),
+                position: Position { line, column },
+            }];
+        }
+        Err(error) => {
+            return vec![ValidationError {
+                message: error.to_string(),
+                position: Position { line: 1, column: 1 },
+            }];
+        }
+    };
+
+    let mut validator = Validator {
+        schema,
+        document: &document,
+        errors: Vec::new(),
+    };
+
+    if let Err(error) = document.operation(operation_name) {
+        validator.error(Position { line: 1, column: 1 }, error.to_string());
+    }
+
+    for operation in &document.operations {
+        match schema.root_type(operation.kind) {
+            Some(root) => validator.selections(&operation.selections, root),
+            None => validator.error(
+                operation.position,
+                format!(
+                    "The schema does not support {} operations",
+                    operation.kind.as_str()
+                ),
+            ),
+        }
+    }
+
+    for fragment in &document.fragments {
+        if schema.get_type(&fragment.type_condition).is_some() {
+            validator.selections(&fragment.selections, &fragment.type_condition);
+        } else {
+            validator.error(
+                fragment.position,
+                format!("Unknown type `{}`", fragment.type_condition),
+            );
+        }
+    }
+
+    validator
+        .errors
+        .sort_by_key(|error| (error.position.line, error.position.column));
+    validator.errors
+}
+
+struct Validator<'a> {
+    schema: &'a GraphqlSchema,
+    document: &'a Document,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, position: Position, message: String) {
+        self.errors.push(ValidationError { message, position });
+    }
+
+    fn selections(&mut self, selections: &[Selection], parent: &str) {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => self.field(field, parent),
+                Selection::FragmentSpread { name, position } => {
+                    if self.document.fragment(name).is_none() {
+                        self.error(*position, format!("Unknown fragment `{name}`"));
+                    }
+                }
+                Selection::InlineFragment {
+                    type_condition,
+                    selections,
+                    position,
+                } => match type_condition {
+                    Some(type_name) if self.schema.get_type(type_name).is_none() => {
+                        self.error(*position, format!("Unknown type `{type_name}`"));
+                    }
+                    Some(type_name) => self.selections(selections, type_name),
+                    None => self.selections(selections, parent),
+                },
+            }
+        }
+    }
+
+    fn field(&mut self, field: &Field, parent: &str) {
+        let name = &field.name;
+        if name == "__typename" {
+            if !field.selections.is_empty() {
+                self.error(
+                    field.position,
+                    "Field `__typename` of type `String` must not have a selection".to_string(),
+                );
+            }
+            return;
+        }
+        // Introspection fields are answered by the server itself
+        if name.starts_with("__") {
+            return;
+        }
+
+        let Some(parent_type) = self.schema.get_type(parent) else {
+            return;
+        };
+        if parent_type.kind == TypeKind::Union {
+            self.error(
+                field.position,
+                format!("Cannot query field `{name}` on union `{parent}`; select it in a fragment"),
+            );
+            return;
+        }
+        let Some(schema_field) = parent_type.fields.get(name) else {
+            self.error(
+                field.position,
+                format!("Cannot query field `{name}` on type `{parent}`"),
+            );
+            return;
+        };
+
+        for argument in &field.arguments {
+            if !schema_field.arguments.iter().any(|a| &a.name == argument) {
+                self.error(
+                    field.position,
+                    format!("Unknown argument `{argument}` on field `{parent}.{name}`"),
+                );
+            }
+        }
+        for argument in schema_field.arguments.iter().filter(|a| a.required) {
+            if !field.arguments.contains(&argument.name) {
+                self.error(
+                    field.position,
+                    format!(
+                        "Field `{parent}.{name}` is missing required argument `{}`",
+                        argument.name
+                    ),
+                );
+            }
+        }
+
+        let type_name = &schema_field.type_name;
+        let is_leaf = self
+            .schema
+            .get_type(type_name)
+            .is_none_or(|field_type| field_type.kind.is_leaf());
+        match (is_leaf, field.selections.is_empty()) {
+            (true, false) => self.error(
+                field.position,
+                format!("Field `{name}` of type `{type_name}` must not have a selection"),
+            ),
+            (false, true) => self.error(
+                field.position,
+                format!("Field `{name}` of type `{type_name}` must have a selection of subfields"),
+            ),
+            (false, false) => self.selections(&field.selections, type_name),
+            (true, true) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::graphql::test_schema;
+
+    fn messages(query: &str, operation_name: Option<&str>) -> Vec<String> {
+        validate(query, operation_name, &test_schema())
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_documents() {
+        let query = r"
+            query Me { user(id: 1) { id login posts(first: 5) { ...PostFields } } }
+            query Search {
+                search(text: $q) {
+                    __typename
+                    ... on User { login }
+                    ... on Post { title }
+                }
+                __schema { types { name } }
+            }
+            fragment PostFields on Post { id title }
+        ";
+        assert!(messages(query, Some("Search")).is_empty());
+        assert!(messages("{ users { id } }", None).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_fields() {
+        let query = "{
+  user { id email posts { id } }
+  users { login { length } }
+  search(text: \"a\")
+}";
+        assert_eq!(
+            messages(query, None),
+            [
+                "2:3: Field `Query.user` is missing required argument `id`",
+                "2:13: Cannot query field `email` on type `User`",
+                "3:11: Field `login` of type `String` must not have a selection",
+                "4:3: Field `search` of type `SearchResult` must have a selection of subfields",
+            ]
+        );
+        // A union only allows fragments
+        assert_eq!(
+            messages("{ search(text: \"a\") { id } }", None),
+            ["1:23: Cannot query field `id` on union `SearchResult`; select it in a fragment"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_arguments_fragments_and_operations() {
+        assert_eq!(
+            messages(
+                "{ user(id: 1, limit: 2) { ...Missing ... on Comment { id } } }",
+                None
+            ),
+            [
+                "1:3: Unknown argument `limit` on field `Query.user`",
+                "1:27: Unknown fragment `Missing`",
+                "1:38: Unknown type `Comment`",
+            ]
+        );
+        assert_eq!(
+            messages("subscription OnLike { like { id } }", None),
+            ["1:1: The schema does not support subscription operations"]
+        );
+        assert_eq!(
+            messages("query A { users { id } } query B { users { id } }", None),
+            ["1:1: The document has several operations; choose one of: A, B"]
+        );
+        assert_eq!(
+            messages("{ users { id }", None),
+            ["1:15: Syntax error: Unexpected end of document"]
+        );
+    }
+}
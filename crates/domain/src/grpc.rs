@@ -0,0 +1,440 @@
+//! gRPC call types.
+//!
+//! This module provides types for describing gRPC services, composing calls
+//! with JSON messages, and reporting call status. Endpoints use the `grpc://`
+//! (plaintext) or `grpcs://` (TLS) scheme so they can share the URL bar with
+//! HTTP requests.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// How a method exchanges messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GrpcMethodKind {
+    /// One request, one response.
+    #[default]
+    Unary,
+    /// One request, a stream of responses.
+    ServerStreaming,
+    /// A stream of requests, one response.
+    ClientStreaming,
+    /// Streams in both directions.
+    BidiStreaming,
+}
+
+impl GrpcMethodKind {
+    /// Returns the kind for the streaming flags of a method descriptor.
+    #[must_use]
+    pub const fn from_streaming(client_streaming: bool, server_streaming: bool) -> Self {
+        match (client_streaming, server_streaming) {
+            (false, false) => Self::Unary,
+            (false, true) => Self::ServerStreaming,
+            (true, false) => Self::ClientStreaming,
+            (true, true) => Self::BidiStreaming,
+        }
+    }
+
+    /// Returns true if the client sends a stream of messages.
+    #[must_use]
+    pub const fn is_client_streaming(self) -> bool {
+        matches!(self, Self::ClientStreaming | Self::BidiStreaming)
+    }
+
+    /// Returns true if the server sends a stream of messages.
+    #[must_use]
+    pub const fn is_server_streaming(self) -> bool {
+        matches!(self, Self::ServerStreaming | Self::BidiStreaming)
+    }
+
+    /// Returns a short label for display.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unary => "unary",
+            Self::ServerStreaming => "server streaming",
+            Self::ClientStreaming => "client streaming",
+            Self::BidiStreaming => "bidi streaming",
+        }
+    }
+}
+
+/// A method of a gRPC service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrpcMethod {
+    /// Method name (e.g. `SayHello`).
+    pub name: String,
+    /// How the method exchanges messages.
+    pub kind: GrpcMethodKind,
+    /// Fully qualified request message type.
+    pub input_type: String,
+    /// Fully qualified response message type.
+    pub output_type: String,
+    /// A request message with every field set to its default, as JSON.
+    #[serde(default)]
+    pub input_template: String,
+}
+
+/// A gRPC service and its methods.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GrpcService {
+    /// Fully qualified service name (e.g. `helloworld.Greeter`).
+    pub name: String,
+    /// The methods of the service.
+    pub methods: Vec<GrpcMethod>,
+}
+
+impl GrpcService {
+    /// Returns a method by name.
+    #[must_use]
+    pub fn method(&self, name: &str) -> Option<&GrpcMethod> {
+        self.methods.iter().find(|m| m.name == name)
+    }
+}
+
+/// Where service definitions come from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GrpcSchemaSource {
+    /// Ask the server through the server reflection protocol.
+    #[default]
+    Reflection,
+    /// Compile local `.proto` files.
+    ProtoFiles {
+        /// The files to compile.
+        files: Vec<PathBuf>,
+        /// Directories searched for imports. The directory of each file is
+        /// searched as well.
+        #[serde(default)]
+        import_paths: Vec<PathBuf>,
+    },
+}
+
+/// A gRPC call to make.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct GrpcCall {
+    /// The endpoint (`grpc://host:port` or `grpcs://host:port`).
+    pub endpoint: String,
+    /// Where the service definitions come from.
+    #[serde(default)]
+    pub source: GrpcSchemaSource,
+    /// Fully qualified service name.
+    pub service: String,
+    /// Method name.
+    pub method: String,
+    /// Metadata sent with the call.
+    #[serde(default)]
+    pub metadata: Vec<(String, String)>,
+    /// Request messages as JSON, sent in order.
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
+
+impl GrpcCall {
+    /// Returns the HTTP/2 URI of the endpoint.
+    ///
+    /// `grpc://` maps to `http://` and `grpcs://` to `https://`. Plain
+    /// `http://` and `https://` endpoints are accepted as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GrpcError::InvalidUrl` for any other scheme.
+    pub fn target_uri(&self) -> Result<String, GrpcError> {
+        target_uri(&self.endpoint)
+    }
+
+    /// Returns the HTTP/2 path of the method (`/package.Service/Method`).
+    #[must_use]
+    pub fn path(&self) -> String {
+        format!("/{}/{}", self.service, self.method)
+    }
+}
+
+/// Returns true if the URL uses the `grpc://` or `grpcs://` scheme.
+#[must_use]
+pub fn is_grpc_url(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("grpc://") || url.starts_with("grpcs://")
+}
+
+/// Returns the HTTP/2 URI of a gRPC endpoint.
+///
+/// # Errors
+///
+/// Returns `GrpcError::InvalidUrl` if the endpoint has no supported scheme.
+pub fn target_uri(endpoint: &str) -> Result<String, GrpcError> {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    if let Some(rest) = endpoint.strip_prefix("grpc://") {
+        return Ok(format!("http://{rest}"));
+    }
+    if let Some(rest) = endpoint.strip_prefix("grpcs://") {
+        return Ok(format!("https://{rest}"));
+    }
+    if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+        return Ok(endpoint.to_string());
+    }
+    Err(GrpcError::InvalidUrl(
+        "URL must start with grpc:// or grpcs://".to_string(),
+    ))
+}
+
+/// Splits the message editor text into request messages.
+///
+/// An object is a single message and an array is a stream of messages, one
+/// per element. Empty text is a single empty message.
+///
+/// # Errors
+///
+/// Returns `GrpcError::InvalidMessage` if the text is not a JSON object or an
+/// array of objects.
+pub fn split_messages(text: &str) -> Result<Vec<String>, GrpcError> {
+    if text.trim().is_empty() {
+        return Ok(vec!["{}".to_string()]);
+    }
+    let value: JsonValue = serde_json::from_str(text)
+        .map_err(|e| GrpcError::InvalidMessage(format!("not valid JSON: {e}")))?;
+    let values = match value {
+        JsonValue::Array(values) => values,
+        value => vec![value],
+    };
+    values
+        .into_iter()
+        .map(|value| {
+            if value.is_object() {
+                Ok(value.to_string())
+            } else {
+                Err(GrpcError::InvalidMessage(
+                    "messages must be JSON objects".to_string(),
+                ))
+            }
+        })
+        .collect()
+}
+
+/// gRPC status codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GrpcStatusCode {
+    /// Success.
+    #[default]
+    Ok,
+    /// The call was cancelled.
+    Cancelled,
+    /// Unknown error.
+    Unknown,
+    /// The client sent an invalid argument.
+    InvalidArgument,
+    /// The deadline expired.
+    DeadlineExceeded,
+    /// An entity was not found.
+    NotFound,
+    /// An entity already exists.
+    AlreadyExists,
+    /// The caller lacks permission.
+    PermissionDenied,
+    /// A resource is exhausted.
+    ResourceExhausted,
+    /// The system is not in the required state.
+    FailedPrecondition,
+    /// The operation was aborted.
+    Aborted,
+    /// The operation went out of range.
+    OutOfRange,
+    /// The method is not implemented.
+    Unimplemented,
+    /// Internal server error.
+    Internal,
+    /// The service is unavailable.
+    Unavailable,
+    /// Data was lost.
+    DataLoss,
+    /// The caller is not authenticated.
+    Unauthenticated,
+}
+
+impl GrpcStatusCode {
+    /// Returns the code for its numeric value. Unknown values map to `Unknown`.
+    #[must_use]
+    pub const fn from_i32(code: i32) -> Self {
+        match code {
+            0 => Self::Ok,
+            1 => Self::Cancelled,
+            3 => Self::InvalidArgument,
+            4 => Self::DeadlineExceeded,
+            5 => Self::NotFound,
+            6 => Self::AlreadyExists,
+            7 => Self::PermissionDenied,
+            8 => Self::ResourceExhausted,
+            9 => Self::FailedPrecondition,
+            10 => Self::Aborted,
+            11 => Self::OutOfRange,
+            12 => Self::Unimplemented,
+            13 => Self::Internal,
+            14 => Self::Unavailable,
+            15 => Self::DataLoss,
+            16 => Self::Unauthenticated,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Returns the numeric value of the code.
+    #[must_use]
+    pub const fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    /// Returns the canonical name of the code (e.g. `NOT_FOUND`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Cancelled => "CANCELLED",
+            Self::Unknown => "UNKNOWN",
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            Self::NotFound => "NOT_FOUND",
+            Self::AlreadyExists => "ALREADY_EXISTS",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Self::FailedPrecondition => "FAILED_PRECONDITION",
+            Self::Aborted => "ABORTED",
+            Self::OutOfRange => "OUT_OF_RANGE",
+            Self::Unimplemented => "UNIMPLEMENTED",
+            Self::Internal => "INTERNAL",
+            Self::Unavailable => "UNAVAILABLE",
+            Self::DataLoss => "DATA_LOSS",
+            Self::Unauthenticated => "UNAUTHENTICATED",
+        }
+    }
+}
+
+/// The final status of a call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct GrpcStatus {
+    /// The status code.
+    pub code: GrpcStatusCode,
+    /// The status message sent by the server.
+    #[serde(default)]
+    pub message: String,
+}
+
+impl GrpcStatus {
+    /// Returns true if the call succeeded.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.code == GrpcStatusCode::Ok
+    }
+}
+
+/// gRPC-related errors.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum GrpcError {
+    /// Invalid URL.
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+    /// The service definitions could not be loaded.
+    #[error("Failed to load service definitions: {0}")]
+    Schema(String),
+    /// The service is not defined.
+    #[error("Unknown service: {0}")]
+    UnknownService(String),
+    /// The method is not defined on the service.
+    #[error("Unknown method: {0}")]
+    UnknownMethod(String),
+    /// A request message does not match the method's input type.
+    #[error("Invalid message: {0}")]
+    InvalidMessage(String),
+    /// Connection failed.
+    #[error("Connection failed: {0}")]
+    ConnectionFailed(String),
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method_kind_from_streaming() {
+        assert_eq!(
+            GrpcMethodKind::from_streaming(false, false),
+            GrpcMethodKind::Unary
+        );
+        assert_eq!(
+            GrpcMethodKind::from_streaming(true, true),
+            GrpcMethodKind::BidiStreaming
+        );
+        assert!(GrpcMethodKind::ClientStreaming.is_client_streaming());
+        assert!(!GrpcMethodKind::ClientStreaming.is_server_streaming());
+        assert!(GrpcMethodKind::ServerStreaming.is_server_streaming());
+    }
+
+    #[test]
+    fn test_is_grpc_url() {
+        assert!(is_grpc_url("grpc://localhost:50051"));
+        assert!(is_grpc_url(" GRPCS://api.example.com"));
+        assert!(!is_grpc_url("https://api.example.com"));
+    }
+
+    #[test]
+    fn test_target_uri() {
+        assert_eq!(
+            target_uri("grpc://localhost:50051").unwrap(),
+            "http://localhost:50051"
+        );
+        assert_eq!(
+            target_uri(" grpcs://api.example.com/ ").unwrap(),
+            "https://api.example.com"
+        );
+        assert_eq!(
+            target_uri("http://localhost:8080").unwrap(),
+            "http://localhost:8080"
+        );
+        assert!(matches!(
+            target_uri("ws://localhost"),
+            Err(GrpcError::InvalidUrl(_))
+        ));
+    }
+
+    #[test]
+    fn test_call_path() {
+        let call = GrpcCall {
+            service: "helloworld.Greeter".to_string(),
+            method: "SayHello".to_string(),
+            ..GrpcCall::default()
+        };
+        assert_eq!(call.path(), "/helloworld.Greeter/SayHello");
+    }
+
+    #[test]
+    fn test_split_messages() {
+        assert_eq!(split_messages("  ").unwrap(), ["{}"]);
+        assert_eq!(
+            split_messages(r#"{"name": "a"}"#).unwrap(),
+            [r#"{"name":"a"}"#]
+        );
+        assert_eq!(
+            split_messages(r#"[{"name": "a"}, {"name": "b"}]"#).unwrap(),
+            [r#"{"name":"a"}"#, r#"{"name":"b"}"#]
+        );
+        assert!(matches!(
+            split_messages("[1]"),
+            Err(GrpcError::InvalidMessage(_))
+        ));
+        assert!(matches!(
+            split_messages("{"),
+            Err(GrpcError::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn test_status_codes() {
+        assert_eq!(GrpcStatusCode::from_i32(5), GrpcStatusCode::NotFound);
+        assert_eq!(GrpcStatusCode::from_i32(99), GrpcStatusCode::Unknown);
+        assert_eq!(GrpcStatusCode::Unauthenticated.as_i32(), 16);
+        assert_eq!(GrpcStatusCode::Unimplemented.as_str(), "UNIMPLEMENTED");
+        assert!(GrpcStatus::default().is_ok());
+    }
+}
@@ -3,11 +3,13 @@
 //! Defines the structure for tracking executed requests.
 
 use std::collections::VecDeque;
+use std::path::PathBuf;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::request::HttpMethod;
+use crate::response::ResponseTiming;
 
 /// A header stored in history.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -79,6 +81,12 @@ pub struct HistoryEntry {
     /// Authentication data.
     #[serde(default)]
     pub auth: Option<HistoryAuth>,
+    /// File the response body was saved to, for downloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_file: Option<PathBuf>,
+    /// Time spent in each phase of the exchange.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing: Option<ResponseTiming>,
 }
 
 impl HistoryEntry {
@@ -108,6 +116,8 @@ impl HistoryEntry {
             headers,
             params,
             auth,
+            response_file: None,
+            timing: None,
         }
     }
 
@@ -134,9 +144,25 @@ impl HistoryEntry {
             headers,
             params,
             auth,
+            response_file: None,
+            timing: None,
         }
     }
 
+    /// References the file the response body was saved to.
+    #[must_use]
+    pub fn with_response_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.response_file = Some(path.into());
+        self
+    }
+
+    /// Records the timing breakdown of the response.
+    #[must_use]
+    pub fn with_timing(mut self, timing: ResponseTiming) -> Self {
+        self.timing = Some(timing).filter(|timing| !timing.is_empty());
+        self
+    }
+
     /// Returns a human-readable "time ago" string.
     #[must_use]
     pub fn time_ago(&self) -> String {
@@ -244,6 +270,7 @@ impl RequestHistory {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -264,6 +291,30 @@ mod tests {
         assert_eq!(entry.method, HttpMethod::Get);
         assert_eq!(entry.status_code, Some(200));
         assert_eq!(entry.duration_ms, Some(150));
+        assert_eq!(entry.response_file, None);
+    }
+
+    #[test]
+    fn test_history_entry_response_file() {
+        let entry = HistoryEntry::new(
+            HttpMethod::Get,
+            "https://example.com/export".to_string(),
+            200,
+            150,
+            None,
+            None,
+            vec![],
+            vec![],
+            None,
+        )
+        .with_response_file("/tmp/export.csv");
+
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: HistoryEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored.response_file,
+            Some(PathBuf::from("/tmp/export.csv"))
+        );
     }
 
     #[test]
@@ -10,10 +10,13 @@ pub mod cookie;
 pub mod environment;
 pub mod error;
 pub mod export;
+pub mod graphql;
+pub mod grpc;
 pub mod history;
 pub mod id;
 pub mod persistence;
 pub mod proxy;
+pub mod report;
 pub mod request;
 pub mod response;
 pub mod scripting;
@@ -23,14 +26,17 @@ pub mod testing;
 pub mod tls;
 pub mod websocket;
 
-pub use auth::{ApiKeyLocation, AuthConfig, AuthError, AuthResolution, OAuth2Token};
+pub use auth::{ApiKeyLocation, AuthConfig, AuthError, AuthResolution, JwtAlgorithm, OAuth2Token};
 pub use codegen::{CodeGenOptions, CodeLanguage, CodeSnippet};
 pub use cookie::{Cookie, CookieJar, SameSite};
 pub use error::{DomainError, DomainResult};
 pub use export::{ExportFormat, ExportOptions, ExportResult, ExportWarning};
+pub use graphql::{GraphqlError, GraphqlSchema};
+pub use grpc::{GrpcCall, GrpcError, GrpcMethod, GrpcService, GrpcStatus, GrpcStatusCode};
 pub use history::{HistoryAuth, HistoryEntry, HistoryHeader, HistoryParam, RequestHistory};
 pub use id::{generate_id, generate_id_v7};
 pub use proxy::{GlobalProxySettings, ProxyConfig, ProxyError, ProxyType};
+pub use report::{IterationResult, ReportFormat, RequestRunResult, RunReport, RunSummary};
 pub use scripting::{RequestScripts, Script, ScriptCommand, ScriptLanguage, ScriptResult};
 pub use settings::{FontScale, ThemeMode, UserSettings};
 pub use state::{RequestErrorKind, RequestState};
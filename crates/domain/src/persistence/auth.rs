@@ -1,6 +1,9 @@
 //! Authentication types for requests and collections.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::auth::{self, AuthConfig, JwtAlgorithm};
 
 /// Authentication configuration.
 ///
@@ -23,6 +26,14 @@ pub enum PersistenceAuth {
         password: String,
     },
 
+    /// HTTP Digest authentication.
+    Digest {
+        /// Username for digest auth.
+        username: String,
+        /// Password for digest auth.
+        password: String,
+    },
+
     /// API Key authentication.
     ApiKey {
         /// Header or query parameter name.
@@ -62,6 +73,91 @@ pub enum PersistenceAuth {
         #[serde(skip_serializing_if = "Option::is_none")]
         scope: Option<String>,
     },
+
+    /// `OAuth2` Resource Owner Password Credentials flow.
+    Oauth2Password {
+        /// Token endpoint URL.
+        token_url: String,
+        /// Client ID.
+        client_id: String,
+        /// Client secret (empty for public clients).
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        client_secret: String,
+        /// Resource owner username.
+        username: String,
+        /// Resource owner password.
+        password: String,
+        /// OAuth scopes (space-separated).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<String>,
+    },
+
+    /// `OAuth2` Device Authorization Grant.
+    Oauth2DeviceCode {
+        /// Device authorization endpoint URL.
+        device_authorization_url: String,
+        /// Token endpoint URL.
+        token_url: String,
+        /// Client ID.
+        client_id: String,
+        /// Client secret (empty for public clients).
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        client_secret: String,
+        /// OAuth scopes (space-separated).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<String>,
+    },
+
+    /// `OAuth2` JWT Bearer assertion grant.
+    Oauth2JwtBearer {
+        /// Token endpoint URL.
+        token_url: String,
+        /// Signed JWT assertion.
+        assertion: String,
+        /// Client ID (optional).
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        client_id: String,
+        /// Client secret (optional).
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        client_secret: String,
+        /// OAuth scopes (space-separated).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        scope: Option<String>,
+    },
+
+    /// AWS Signature Version 4 request signing.
+    #[serde(rename = "aws_sigv4")]
+    AwsSigV4 {
+        /// Access key ID.
+        access_key: String,
+        /// Secret access key.
+        secret_key: String,
+        /// Session token for temporary credentials.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        session_token: Option<String>,
+        /// AWS region.
+        region: String,
+        /// Service name.
+        service: String,
+    },
+
+    /// JWT generated from a claims template and signed for every request.
+    Jwt {
+        /// Signing algorithm: "HS256", "RS256" or "ES256".
+        algorithm: JwtAlgorithm,
+        /// HMAC secret or PEM private key.
+        secret: String,
+        /// JSON claims template.
+        claims: String,
+        /// Key ID for the `kid` header.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        key_id: Option<String>,
+        /// Where to send the token: "header" or "query".
+        location: ApiKeyLocation,
+        /// Query parameter name when sent in the query.
+        #[serde(default = "auth::default_jwt_query_param")]
+        query_param: String,
+    },
 }
 
 impl PersistenceAuth {
@@ -113,6 +209,149 @@ pub enum ApiKeyLocation {
     Query,
 }
 
+impl From<ApiKeyLocation> for auth::ApiKeyLocation {
+    fn from(location: ApiKeyLocation) -> Self {
+        match location {
+            ApiKeyLocation::Header => Self::Header,
+            ApiKeyLocation::Query => Self::Query,
+        }
+    }
+}
+
+impl From<&PersistenceAuth> for AuthConfig {
+    fn from(auth: &PersistenceAuth) -> Self {
+        match auth.clone() {
+            PersistenceAuth::Bearer { token } => Self::bearer(token),
+            PersistenceAuth::Basic { username, password } => Self::basic(username, password),
+            PersistenceAuth::Digest { username, password } => Self::digest(username, password),
+            PersistenceAuth::ApiKey {
+                key,
+                value,
+                location,
+            } => Self::ApiKey {
+                key: value,
+                name: key,
+                location: location.into(),
+            },
+            oauth2 @ (PersistenceAuth::Oauth2ClientCredentials { .. }
+            | PersistenceAuth::Oauth2AuthCode { .. }
+            | PersistenceAuth::Oauth2Password { .. }
+            | PersistenceAuth::Oauth2DeviceCode { .. }
+            | PersistenceAuth::Oauth2JwtBearer { .. }) => oauth2_config(oauth2),
+            PersistenceAuth::AwsSigV4 {
+                access_key,
+                secret_key,
+                session_token,
+                region,
+                service,
+            } => Self::AwsSigV4 {
+                access_key,
+                secret_key,
+                session_token,
+                region,
+                service,
+            },
+            PersistenceAuth::Jwt {
+                algorithm,
+                secret,
+                claims,
+                key_id,
+                location,
+                query_param,
+            } => Self::Jwt {
+                algorithm,
+                secret,
+                claims,
+                key_id,
+                location: location.into(),
+                prefix: "Bearer".to_string(),
+                query_param,
+            },
+        }
+    }
+}
+
+/// Converts the `OAuth2` variants, which carry no extra parameters when
+/// persisted.
+fn oauth2_config(auth: PersistenceAuth) -> AuthConfig {
+    match auth {
+        PersistenceAuth::Oauth2ClientCredentials {
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+        } => AuthConfig::OAuth2ClientCredentials {
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+            extra_params: BTreeMap::new(),
+        },
+        PersistenceAuth::Oauth2AuthCode {
+            auth_url,
+            token_url,
+            client_id,
+            client_secret,
+            redirect_uri,
+            scope,
+        } => AuthConfig::OAuth2AuthorizationCode {
+            auth_url,
+            token_url,
+            client_id,
+            client_secret,
+            redirect_uri,
+            scope,
+            extra_params: BTreeMap::new(),
+        },
+        PersistenceAuth::Oauth2Password {
+            token_url,
+            client_id,
+            client_secret,
+            username,
+            password,
+            scope,
+        } => AuthConfig::OAuth2Password {
+            token_url,
+            client_id,
+            client_secret,
+            username,
+            password,
+            scope,
+            extra_params: BTreeMap::new(),
+        },
+        PersistenceAuth::Oauth2DeviceCode {
+            device_authorization_url,
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+        } => AuthConfig::OAuth2DeviceCode {
+            device_authorization_url,
+            token_url,
+            client_id,
+            client_secret,
+            scope,
+            extra_params: BTreeMap::new(),
+        },
+        PersistenceAuth::Oauth2JwtBearer {
+            token_url,
+            assertion,
+            client_id,
+            client_secret,
+            scope,
+        } => AuthConfig::OAuth2JwtBearer {
+            token_url,
+            assertion,
+            client_id,
+            client_secret,
+            scope,
+            extra_params: BTreeMap::new(),
+        },
+        // Not an `OAuth2` variant
+        other => AuthConfig::from(&other),
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
@@ -155,4 +394,76 @@ mod tests {
             _ => panic!("Expected ApiKey auth"),
         }
     }
+
+    #[test]
+    fn test_api_key_to_auth_config() {
+        let auth = AuthConfig::from(&PersistenceAuth::api_key_query("api_key", "secret"));
+        assert_eq!(
+            auth,
+            AuthConfig::ApiKey {
+                key: "secret".to_string(),
+                name: "api_key".to_string(),
+                location: auth::ApiKeyLocation::Query,
+            }
+        );
+    }
+
+    #[test]
+    fn test_aws_sigv4_to_auth_config() {
+        let json = r#"{"type":"aws_sigv4","access_key":"{{aws_key}}","secret_key":"{{aws_secret}}","region":"eu-west-1","service":"execute-api"}"#;
+        let auth: PersistenceAuth = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&auth).unwrap(), json);
+        assert_eq!(
+            AuthConfig::from(&auth),
+            AuthConfig::AwsSigV4 {
+                access_key: "{{aws_key}}".to_string(),
+                secret_key: "{{aws_secret}}".to_string(),
+                session_token: None,
+                region: "eu-west-1".to_string(),
+                service: "execute-api".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_jwt_to_auth_config() {
+        let json = r#"{"type":"jwt","algorithm":"HS256","secret":"{{jwt_secret}}","claims":"{\"iat\":{{$timestamp}}}","location":"query"}"#;
+        let auth: PersistenceAuth = serde_json::from_str(json).unwrap();
+        match AuthConfig::from(&auth) {
+            AuthConfig::Jwt {
+                algorithm,
+                secret,
+                location,
+                query_param,
+                ..
+            } => {
+                assert_eq!(algorithm, JwtAlgorithm::Hs256);
+                assert_eq!(secret, "{{jwt_secret}}");
+                assert_eq!(location, auth::ApiKeyLocation::Query);
+                assert_eq!(query_param, "access_token");
+            }
+            other => panic!("Expected Jwt auth, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_device_code_round_trip() {
+        let json = r#"{
+            "type": "oauth2_device_code",
+            "device_authorization_url": "https://auth.example.com/device",
+            "token_url": "https://auth.example.com/token",
+            "client_id": "cli",
+            "scope": "offline_access"
+        }"#;
+        let auth: PersistenceAuth = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&auth).unwrap();
+        assert!(!serialized.contains("client_secret"));
+
+        let config = AuthConfig::from(&auth);
+        assert!(config.is_oauth2());
+        assert!(matches!(
+            config,
+            AuthConfig::OAuth2DeviceCode { ref client_id, .. } if client_id == "cli"
+        ));
+    }
 }
@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::collections::BTreeMap;
 
+use crate::error::DomainError;
+use crate::request::{RequestBody, RequestBodyKind};
+
 /// Request body with multiple format support.
 ///
 /// The `type` field is used as the discriminator for JSON serialization.
@@ -47,6 +50,9 @@ pub enum PersistenceRequestBody {
         /// GraphQL variables as JSON object.
         #[serde(skip_serializing_if = "Option::is_none")]
         variables: Option<JsonValue>,
+        /// The operation to run when the query has several.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        operation_name: Option<String>,
     },
 }
 
@@ -89,10 +95,80 @@ impl PersistenceRequestBody {
         Self::Graphql {
             query: query.into(),
             variables,
+            operation_name: None,
+        }
+    }
+}
+
+impl TryFrom<&PersistenceRequestBody> for RequestBody {
+    type Error = DomainError;
+
+    /// Converts a persisted body into an executable request body.
+    ///
+    /// Multipart and binary bodies reference files on disk and cannot be
+    /// represented as an in-memory `RequestBody`, so they are rejected.
+    fn try_from(body: &PersistenceRequestBody) -> Result<Self, Self::Error> {
+        match body {
+            PersistenceRequestBody::Json { content } => Ok(Self::json(content.to_string())),
+            PersistenceRequestBody::Text { content } => Ok(Self::text(content.clone())),
+            PersistenceRequestBody::FormUrlencoded { fields } => Ok(Self {
+                kind: RequestBodyKind::FormUrlEncoded,
+                content: fields
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}={}",
+                            encode_form_component(key),
+                            encode_form_component(value)
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("&"),
+            }),
+            PersistenceRequestBody::Graphql {
+                query,
+                variables,
+                operation_name,
+            } => Ok(Self::graphql(
+                query.clone(),
+                variables
+                    .as_ref()
+                    .map(JsonValue::to_string)
+                    .unwrap_or_default(),
+                operation_name.clone(),
+            )),
+            PersistenceRequestBody::FormData { .. } => Err(DomainError::InvalidBody(
+                "multipart form data bodies are not supported here".to_string(),
+            )),
+            PersistenceRequestBody::Binary { path } => Err(DomainError::InvalidBody(format!(
+                "binary file bodies are not supported here: {path}"
+            ))),
         }
     }
 }
 
+/// Percent-encodes a form component while keeping `{{variable}}` placeholders
+/// intact so they can still be resolved after conversion.
+fn encode_form_component(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start..].find("}}") else {
+            break;
+        };
+        let end = start + len + 2;
+        output.extend(url::form_urlencoded::byte_serialize(
+            &rest.as_bytes()[..start],
+        ));
+        output.push_str(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    output.extend(url::form_urlencoded::byte_serialize(rest.as_bytes()));
+    output
+}
+
 /// A field in a multipart form-data body.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -181,4 +257,47 @@ mod tests {
             FormDataField::Text { .. } => panic!("Expected File field"),
         }
     }
+
+    #[test]
+    fn test_form_urlencoded_to_request_body_keeps_variables() {
+        let mut fields = BTreeMap::new();
+        fields.insert("q".to_string(), "a b&c".to_string());
+        fields.insert("token".to_string(), "{{api token}}".to_string());
+
+        let body = RequestBody::try_from(&PersistenceRequestBody::form_urlencoded(fields)).unwrap();
+
+        assert_eq!(body.kind, RequestBodyKind::FormUrlEncoded);
+        assert_eq!(body.content, "q=a+b%26c&token={{api token}}");
+    }
+
+    #[test]
+    fn test_graphql_to_request_body() {
+        let body = PersistenceRequestBody::Graphql {
+            query: "query Me { me { id } } query Other { other }".to_string(),
+            variables: Some(serde_json::json!({"id": "{{user_id}}"})),
+            operation_name: Some("Me".to_string()),
+        };
+        let converted = RequestBody::try_from(&body).unwrap();
+
+        assert_eq!(converted.content_type(), Some("application/json"));
+        assert_eq!(
+            converted.kind,
+            RequestBodyKind::Graphql {
+                variables: r#"{"id":"{{user_id}}"}"#.to_string(),
+                operation_name: Some("Me".to_string()),
+            }
+        );
+        let value: JsonValue = serde_json::from_str(&converted.encoded().unwrap()).unwrap();
+        assert_eq!(
+            value["query"],
+            "query Me { me { id } } query Other { other }"
+        );
+        assert_eq!(value["operationName"], "Me");
+    }
+
+    #[test]
+    fn test_binary_body_is_not_convertible() {
+        let body = PersistenceRequestBody::binary("files/data.bin");
+        assert!(RequestBody::try_from(&body).is_err());
+    }
 }
@@ -5,6 +5,7 @@ use std::collections::BTreeMap;
 
 use super::auth::PersistenceAuth;
 use super::common::{CURRENT_SCHEMA_VERSION, Id};
+use crate::environment::{Variable, VariableMap};
 
 /// Collection metadata stored in `collection.json` within a collection directory.
 ///
@@ -72,6 +73,15 @@ impl PersistenceCollection {
         self.variables.insert(key.into(), value.into());
         self
     }
+
+    /// Returns the collection variables as a resolvable variable map.
+    #[must_use]
+    pub fn variable_map(&self) -> VariableMap {
+        self.variables
+            .iter()
+            .map(|(key, value)| (key.clone(), Variable::new(value)))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -3,6 +3,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+use crate::error::DomainError;
+use crate::proxy::ProxyConfig;
+use crate::request::{DEFAULT_MAX_REDIRECTS, HttpMethod, RedirectPolicy};
+use crate::tls::TlsConfig;
+
 /// Current schema version for all Vortex file formats.
 pub const CURRENT_SCHEMA_VERSION: u32 = 1;
 
@@ -32,11 +37,44 @@ pub struct RequestSettings {
     /// Whether to verify SSL certificates.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verify_ssl: Option<bool>,
+
+    /// Proxy override for requests at this level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// TLS configuration for requests at this level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+}
+
+impl RequestSettings {
+    /// Returns the TLS configuration with `verify_ssl` applied.
+    ///
+    /// `None` means neither a TLS configuration nor `verify_ssl: false` is
+    /// set, so the caller's default applies.
+    #[must_use]
+    pub fn tls_config(&self) -> Option<TlsConfig> {
+        match (self.tls.clone(), self.verify_ssl) {
+            (tls, Some(false)) => Some(tls.unwrap_or_default().with_verify_certificates(false)),
+            (tls, _) => tls,
+        }
+    }
+
+    /// Returns the redirect policy, with defaults for unset values.
+    #[must_use]
+    pub fn redirect_policy(&self) -> RedirectPolicy {
+        match self.follow_redirects {
+            Some(false) => RedirectPolicy::none(),
+            _ => RedirectPolicy::limited(self.max_redirects.unwrap_or(DEFAULT_MAX_REDIRECTS)),
+        }
+    }
 }
 
 /// HTTP methods supported by Vortex (persistence format).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
-#[serde(rename_all = "UPPERCASE")]
+///
+/// Written as the method name, e.g. `"GET"` or `"PROPFIND"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(into = "String", try_from = "String")]
 pub enum PersistenceHttpMethod {
     /// HTTP GET method
     #[default]
@@ -55,6 +93,10 @@ pub enum PersistenceHttpMethod {
     Options,
     /// HTTP TRACE method
     Trace,
+    /// HTTP CONNECT method
+    Connect,
+    /// Any other method, such as `PROPFIND` from `WebDAV` or `PURGE`
+    Custom(String),
 }
 
 impl std::fmt::Display for PersistenceHttpMethod {
@@ -68,26 +110,48 @@ impl std::fmt::Display for PersistenceHttpMethod {
             Self::Head => "HEAD",
             Self::Options => "OPTIONS",
             Self::Trace => "TRACE",
+            Self::Connect => "CONNECT",
+            Self::Custom(name) => name,
         };
         write!(f, "{s}")
     }
 }
 
-impl From<crate::request::HttpMethod> for PersistenceHttpMethod {
-    fn from(method: crate::request::HttpMethod) -> Self {
+impl TryFrom<String> for PersistenceHttpMethod {
+    type Error = DomainError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        HttpMethod::try_from(name).map(Self::from)
+    }
+}
+
+impl From<PersistenceHttpMethod> for String {
+    fn from(method: PersistenceHttpMethod) -> Self {
+        match method {
+            PersistenceHttpMethod::Custom(name) => name,
+            method => method.to_string(),
+        }
+    }
+}
+
+impl From<HttpMethod> for PersistenceHttpMethod {
+    fn from(method: HttpMethod) -> Self {
         match method {
-            crate::request::HttpMethod::Get => Self::Get,
-            crate::request::HttpMethod::Post => Self::Post,
-            crate::request::HttpMethod::Put => Self::Put,
-            crate::request::HttpMethod::Patch => Self::Patch,
-            crate::request::HttpMethod::Delete => Self::Delete,
-            crate::request::HttpMethod::Head => Self::Head,
-            crate::request::HttpMethod::Options => Self::Options,
+            HttpMethod::Get => Self::Get,
+            HttpMethod::Post => Self::Post,
+            HttpMethod::Put => Self::Put,
+            HttpMethod::Patch => Self::Patch,
+            HttpMethod::Delete => Self::Delete,
+            HttpMethod::Head => Self::Head,
+            HttpMethod::Options => Self::Options,
+            HttpMethod::Trace => Self::Trace,
+            HttpMethod::Connect => Self::Connect,
+            HttpMethod::Custom(name) => Self::Custom(name),
         }
     }
 }
 
-impl From<PersistenceHttpMethod> for crate::request::HttpMethod {
+impl From<PersistenceHttpMethod> for HttpMethod {
     fn from(method: PersistenceHttpMethod) -> Self {
         match method {
             PersistenceHttpMethod::Get => Self::Get,
@@ -96,13 +160,16 @@ impl From<PersistenceHttpMethod> for crate::request::HttpMethod {
             PersistenceHttpMethod::Patch => Self::Patch,
             PersistenceHttpMethod::Delete => Self::Delete,
             PersistenceHttpMethod::Head => Self::Head,
-            PersistenceHttpMethod::Options | PersistenceHttpMethod::Trace => Self::Options,
-            // Map TRACE to OPTIONS as fallback
+            PersistenceHttpMethod::Options => Self::Options,
+            PersistenceHttpMethod::Trace => Self::Trace,
+            PersistenceHttpMethod::Connect => Self::Connect,
+            PersistenceHttpMethod::Custom(name) => Self::Custom(name),
         }
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -113,10 +180,43 @@ mod tests {
         assert_eq!(PersistenceHttpMethod::Trace.to_string(), "TRACE");
     }
 
+    #[test]
+    fn test_persistence_http_method_round_trip() {
+        for name in ["TRACE", "CONNECT", "PURGE", "MKCOL"] {
+            let json = format!("\"{name}\"");
+            let method: PersistenceHttpMethod = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&method).unwrap(), json);
+            let domain = HttpMethod::from(method.clone());
+            assert_eq!(domain.as_str(), name);
+            assert_eq!(PersistenceHttpMethod::from(domain), method);
+        }
+        assert_eq!(
+            serde_json::from_str::<PersistenceHttpMethod>(r#""get""#).unwrap(),
+            PersistenceHttpMethod::Get
+        );
+    }
+
     #[test]
     fn test_request_settings_default() {
         let settings = RequestSettings::default();
         assert!(settings.timeout_ms.is_none());
         assert!(settings.follow_redirects.is_none());
+        assert_eq!(settings.redirect_policy(), RedirectPolicy::default());
+    }
+
+    #[test]
+    fn test_request_settings_redirect_policy() {
+        let limited = RequestSettings {
+            max_redirects: Some(2),
+            ..RequestSettings::default()
+        };
+        assert_eq!(limited.redirect_policy(), RedirectPolicy::limited(2));
+
+        let disabled = RequestSettings {
+            follow_redirects: Some(false),
+            max_redirects: Some(2),
+            ..RequestSettings::default()
+        };
+        assert_eq!(disabled.redirect_policy(), RedirectPolicy::none());
     }
 }
@@ -0,0 +1,96 @@
+//! gRPC settings of saved requests.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::grpc::GrpcSchemaSource;
+
+/// The gRPC part of a saved request.
+///
+/// A gRPC request is stored like an HTTP request: the URL holds the
+/// `grpc://` or `grpcs://` endpoint, headers hold the metadata and the body
+/// holds the JSON message (an array for a stream of messages).
+///
+/// Fields are ordered alphabetically for deterministic serialization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct PersistenceGrpc {
+    /// Directories searched for imports, relative to the workspace.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub import_paths: Vec<String>,
+
+    /// Method name.
+    pub method: String,
+
+    /// `.proto` files, relative to the workspace. Server reflection is used
+    /// when empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proto_files: Vec<String>,
+
+    /// Fully qualified service name.
+    pub service: String,
+}
+
+impl PersistenceGrpc {
+    /// Creates gRPC settings for a method, using server reflection.
+    #[must_use]
+    pub fn new(service: impl Into<String>, method: impl Into<String>) -> Self {
+        Self {
+            import_paths: Vec::new(),
+            method: method.into(),
+            proto_files: Vec::new(),
+            service: service.into(),
+        }
+    }
+
+    /// Adds a `.proto` file.
+    #[must_use]
+    pub fn with_proto_file(mut self, path: impl Into<String>) -> Self {
+        self.proto_files.push(path.into());
+        self
+    }
+
+    /// Returns where the service definitions come from, resolving relative
+    /// paths against the workspace.
+    #[must_use]
+    pub fn source(&self, workspace: &Path) -> GrpcSchemaSource {
+        if self.proto_files.is_empty() {
+            return GrpcSchemaSource::Reflection;
+        }
+        let resolve = |path: &String| -> PathBuf { workspace.join(path) };
+        GrpcSchemaSource::ProtoFiles {
+            files: self.proto_files.iter().map(resolve).collect(),
+            import_paths: self.import_paths.iter().map(resolve).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source() {
+        let workspace = Path::new("/ws");
+        let grpc = PersistenceGrpc::new("helloworld.Greeter", "SayHello");
+        assert_eq!(grpc.source(workspace), GrpcSchemaSource::Reflection);
+
+        let grpc = grpc.with_proto_file("protos/hello.proto");
+        assert_eq!(
+            grpc.source(workspace),
+            GrpcSchemaSource::ProtoFiles {
+                files: vec![PathBuf::from("/ws/protos/hello.proto")],
+                import_paths: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_serialization() {
+        let grpc = PersistenceGrpc::new("helloworld.Greeter", "SayHello");
+        assert_eq!(
+            serde_json::to_string(&grpc).unwrap(),
+            r#"{"method":"SayHello","service":"helloworld.Greeter"}"#
+        );
+    }
+}
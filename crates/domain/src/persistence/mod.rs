@@ -9,6 +9,7 @@ mod body;
 mod collection;
 mod common;
 mod folder;
+mod grpc;
 mod request;
 mod test_assertion;
 mod workspace;
@@ -18,6 +19,7 @@ pub use body::*;
 pub use collection::*;
 pub use common::*;
 pub use folder::*;
+pub use grpc::*;
 pub use request::*;
 pub use test_assertion::*;
 pub use workspace::*;
@@ -6,7 +6,12 @@ use std::collections::BTreeMap;
 use super::auth::PersistenceAuth;
 use super::body::PersistenceRequestBody;
 use super::common::{CURRENT_SCHEMA_VERSION, Id, PersistenceHttpMethod, RequestSettings};
+use super::grpc::PersistenceGrpc;
 use super::test_assertion::TestAssertion;
+use crate::auth::AuthConfig;
+use crate::error::DomainError;
+use crate::request::{RequestBody, RequestSpec};
+use crate::testing::{Assertion, TestSuite};
 
 /// A saved HTTP request stored as a JSON file.
 ///
@@ -24,6 +29,10 @@ pub struct SavedRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<PersistenceRequestBody>,
 
+    /// gRPC service and method. Set for gRPC requests only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc: Option<PersistenceGrpc>,
+
     /// HTTP headers as key-value pairs.
     /// Values may contain `{{variables}}`.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
@@ -70,6 +79,7 @@ impl SavedRequest {
         Self {
             auth: None,
             body: None,
+            grpc: None,
             headers: BTreeMap::new(),
             id,
             method,
@@ -103,6 +113,13 @@ impl SavedRequest {
         self
     }
 
+    /// Makes this a gRPC request.
+    #[must_use]
+    pub fn with_grpc(mut self, grpc: PersistenceGrpc) -> Self {
+        self.grpc = Some(grpc);
+        self
+    }
+
     /// Sets the request authentication.
     #[must_use]
     pub fn with_auth(mut self, auth: PersistenceAuth) -> Self {
@@ -112,7 +129,7 @@ impl SavedRequest {
 
     /// Sets request-specific settings.
     #[must_use]
-    pub const fn with_settings(mut self, settings: RequestSettings) -> Self {
+    pub fn with_settings(mut self, settings: RequestSettings) -> Self {
         self.settings = Some(settings);
         self
     }
@@ -123,6 +140,58 @@ impl SavedRequest {
         self.tests.push(test);
         self
     }
+
+    /// Builds the test suite for this request's saved assertions.
+    #[must_use]
+    pub fn test_suite(&self) -> TestSuite {
+        self.tests
+            .iter()
+            .fold(TestSuite::new(&self.name), |suite, test| {
+                suite.with_assertion(Assertion::from(test))
+            })
+    }
+}
+
+impl TryFrom<&SavedRequest> for RequestSpec {
+    type Error = DomainError;
+
+    /// Converts a saved request into an executable request specification.
+    ///
+    /// Variables are left unresolved and request-level settings such as the
+    /// timeout, proxy and TLS configuration are applied. Inherited folder or
+    /// collection auth is not considered here; callers set `auth` themselves
+    /// when needed.
+    fn try_from(saved: &SavedRequest) -> Result<Self, Self::Error> {
+        let mut spec = Self::new(&saved.name);
+        if let Ok(id) = saved.id.parse() {
+            spec.id = id;
+        }
+        spec.method = saved.method.clone().into();
+        spec.url.clone_from(&saved.url);
+
+        for (name, value) in &saved.headers {
+            spec = spec.with_header(name, value);
+        }
+        for (key, value) in &saved.query_params {
+            spec = spec.with_query(key, value);
+        }
+        if let Some(body) = &saved.body {
+            spec.body = RequestBody::try_from(body)?;
+        }
+        if let Some(auth) = &saved.auth {
+            spec.auth = AuthConfig::from(auth);
+        }
+        if let Some(settings) = &saved.settings {
+            if let Some(timeout_ms) = settings.timeout_ms {
+                spec.timeout_ms = timeout_ms;
+            }
+            spec.proxy.clone_from(&settings.proxy);
+            spec.tls = settings.tls_config();
+            spec.redirect_policy = settings.redirect_policy();
+        }
+
+        Ok(spec)
+    }
 }
 
 impl Default for SavedRequest {
@@ -137,6 +206,7 @@ impl Default for SavedRequest {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -189,10 +259,83 @@ mod tests {
         assert!(request.body.is_some());
     }
 
+    #[test]
+    fn test_saved_grpc_request_round_trip() {
+        let request = SavedRequest::new(
+            "id".to_string(),
+            "Say Hello",
+            PersistenceHttpMethod::Post,
+            "grpc://localhost:50051",
+        )
+        .with_header("authorization", "Bearer {{token}}")
+        .with_body(PersistenceRequestBody::json(
+            serde_json::json!({"name": "{{name}}"}),
+        ))
+        .with_grpc(
+            PersistenceGrpc::new("helloworld.Greeter", "SayHello")
+                .with_proto_file("protos/hello.proto"),
+        );
+
+        let json = serde_json::to_string(&request).unwrap();
+        let parsed: SavedRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, request);
+
+        let http: SavedRequest = serde_json::from_str(
+            r#"{"id": "1", "method": "GET", "name": "Old", "schema_version": 1, "url": "https://x"}"#,
+        )
+        .unwrap();
+        assert!(http.grpc.is_none());
+    }
+
     #[test]
     fn test_saved_request_default() {
         let request = SavedRequest::default();
         assert_eq!(request.name, "New Request");
         assert_eq!(request.method, PersistenceHttpMethod::Get);
     }
+
+    #[test]
+    fn test_saved_request_to_request_spec() {
+        use crate::proxy::ProxyConfig;
+        use crate::request::RedirectPolicy;
+
+        let request = SavedRequest::new(
+            "0190a9a4-7c3e-7b2a-9d1e-3f4a5b6c7d8e".to_string(),
+            "Create User",
+            PersistenceHttpMethod::Post,
+            "{{base_url}}/users",
+        )
+        .with_header("X-Trace", "1")
+        .with_query_param("dry_run", "true")
+        .with_body(PersistenceRequestBody::json(
+            serde_json::json!({"name": "{{name}}"}),
+        ))
+        .with_auth(PersistenceAuth::bearer("{{token}}"))
+        .with_settings(RequestSettings {
+            timeout_ms: Some(5_000),
+            verify_ssl: Some(false),
+            max_redirects: Some(3),
+            proxy: Some(ProxyConfig::with_url("socks5://proxy.corp:1080")),
+            ..RequestSettings::default()
+        })
+        .with_test(TestAssertion::status("created", 201));
+
+        let spec = RequestSpec::try_from(&request).unwrap();
+
+        assert_eq!(spec.id.to_string(), request.id);
+        assert_eq!(spec.method, crate::request::HttpMethod::Post);
+        assert_eq!(spec.url, "{{base_url}}/users");
+        assert_eq!(spec.headers.len(), 1);
+        assert_eq!(spec.query_params.len(), 1);
+        assert_eq!(spec.body.content, r#"{"name":"{{name}}"}"#);
+        assert_eq!(spec.auth, AuthConfig::bearer("{{token}}"));
+        assert_eq!(spec.timeout_ms, 5_000);
+        assert_eq!(
+            spec.proxy.map(|proxy| proxy.url),
+            Some("socks5://proxy.corp:1080".to_string())
+        );
+        assert!(spec.tls.is_some_and(|tls| tls.accepts_invalid_certs()));
+        assert_eq!(spec.redirect_policy, RedirectPolicy::limited(3));
+        assert_eq!(request.test_suite().len(), 1);
+    }
 }
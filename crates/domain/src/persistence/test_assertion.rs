@@ -5,6 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::response::TimingPhase;
+use crate::testing::{Assertion, ComparisonOperator, StatusExpectation};
+
 /// A test assertion to run after request execution.
 ///
 /// The `type` field is used as the discriminator for JSON serialization.
@@ -80,6 +83,52 @@ pub enum TestAssertion {
         /// Maximum allowed response time in milliseconds.
         max_ms: u64,
     },
+
+    /// Assert one phase of the exchange, such as DNS lookup or time to
+    /// first byte, is under threshold.
+    PhaseTime {
+        /// Test name for display.
+        name: String,
+        /// Phase to check.
+        phase: TimingPhase,
+        /// Maximum allowed time in milliseconds.
+        max_ms: u64,
+    },
+
+    /// Assert a streamed response sent at least some events.
+    EventCount {
+        /// Test name for display.
+        name: String,
+        /// Minimum number of events.
+        min: usize,
+    },
+
+    /// Assert an event's data contains substring.
+    EventContains {
+        /// Test name for display.
+        name: String,
+        /// Only check events of this type.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        event: Option<String>,
+        /// Expected substring in the event data.
+        expected: String,
+    },
+
+    /// Assert the last followed redirect pointed to a path or URL.
+    RedirectedTo {
+        /// Test name for display.
+        name: String,
+        /// Path starting with `/`, or a whole URL.
+        location: String,
+    },
+
+    /// Assert at most some redirects were followed.
+    MaxRedirects {
+        /// Test name for display.
+        name: String,
+        /// Maximum number of redirects.
+        max: usize,
+    },
 }
 
 impl TestAssertion {
@@ -128,6 +177,78 @@ impl TestAssertion {
             expected: expected.into(),
         }
     }
+
+    /// Returns the display name of the assertion.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Status { name, .. }
+            | Self::StatusRange { name, .. }
+            | Self::HeaderExists { name, .. }
+            | Self::HeaderEquals { name, .. }
+            | Self::BodyContains { name, .. }
+            | Self::JsonPathExists { name, .. }
+            | Self::JsonPathEquals { name, .. }
+            | Self::ResponseTime { name, .. }
+            | Self::PhaseTime { name, .. }
+            | Self::EventCount { name, .. }
+            | Self::EventContains { name, .. }
+            | Self::RedirectedTo { name, .. }
+            | Self::MaxRedirects { name, .. } => name,
+        }
+    }
+}
+
+impl From<&TestAssertion> for Assertion {
+    fn from(assertion: &TestAssertion) -> Self {
+        match assertion.clone() {
+            TestAssertion::Status { expected, .. } => Self::StatusCode {
+                expected: StatusExpectation::Exact(expected),
+            },
+            TestAssertion::StatusRange { min, max, .. } => Self::StatusCode {
+                expected: StatusExpectation::Range { min, max },
+            },
+            TestAssertion::HeaderExists { header, .. } => Self::HeaderExists {
+                name: header,
+                value: None,
+            },
+            TestAssertion::HeaderEquals {
+                header, expected, ..
+            } => Self::HeaderExists {
+                name: header,
+                value: Some(expected),
+            },
+            TestAssertion::BodyContains { expected, .. } => Self::BodyContains {
+                text: expected,
+                ignore_case: false,
+            },
+            TestAssertion::JsonPathExists { path, .. } => Self::JsonPath {
+                path,
+                expected: None,
+            },
+            TestAssertion::JsonPathEquals { path, expected, .. } => Self::JsonPath {
+                path,
+                expected: Some(expected),
+            },
+            TestAssertion::ResponseTime { max_ms, .. } => Self::ResponseTime { max_ms },
+            TestAssertion::PhaseTime { phase, max_ms, .. } => Self::PhaseTime { phase, max_ms },
+            TestAssertion::EventCount { min, .. } => Self::EventCount {
+                operator: ComparisonOperator::GreaterThanOrEqual,
+                count: min,
+            },
+            TestAssertion::EventContains {
+                event, expected, ..
+            } => Self::EventContains {
+                event,
+                text: expected,
+            },
+            TestAssertion::RedirectedTo { location, .. } => Self::RedirectedTo { location },
+            TestAssertion::MaxRedirects { max, .. } => Self::RedirectCount {
+                operator: ComparisonOperator::LessThanOrEqual,
+                count: max,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -171,4 +292,67 @@ mod tests {
             _ => panic!("Expected ResponseTime assertion"),
         }
     }
+
+    #[test]
+    fn test_status_range_to_assertion() {
+        let assertion = Assertion::from(&TestAssertion::status_range("2xx", 200, 299));
+        match assertion {
+            Assertion::StatusCode {
+                expected: StatusExpectation::Range { min, max },
+            } => {
+                assert_eq!(min, 200);
+                assert_eq!(max, 299);
+            }
+            _ => panic!("Expected StatusCode range assertion"),
+        }
+    }
+
+    #[test]
+    fn test_event_contains_serialization() {
+        let json = r#"{"type":"event_contains","name":"Done","event":"done","expected":"ok"}"#;
+        let assertion: TestAssertion = serde_json::from_str(json).unwrap();
+        assert_eq!(assertion.name(), "Done");
+        assert_eq!(
+            Assertion::from(&assertion),
+            Assertion::EventContains {
+                event: Some("done".to_string()),
+                text: "ok".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_phase_time_serialization() {
+        let json = r#"{"type":"phase_time","name":"Fast TTFB","phase":"wait","max_ms":200}"#;
+        let assertion: TestAssertion = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Assertion::from(&assertion),
+            Assertion::PhaseTime {
+                phase: TimingPhase::Wait,
+                max_ms: 200,
+            }
+        );
+    }
+
+    #[test]
+    fn test_redirect_assertions_serialization() {
+        let json = r#"{"type":"max_redirects","name":"Few hops","max":2}"#;
+        let assertion: TestAssertion = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Assertion::from(&assertion),
+            Assertion::RedirectCount {
+                operator: ComparisonOperator::LessThanOrEqual,
+                count: 2,
+            }
+        );
+
+        let json = r#"{"type":"redirected_to","name":"Login","location":"/login"}"#;
+        let assertion: TestAssertion = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            Assertion::from(&assertion),
+            Assertion::RedirectedTo {
+                location: "/login".to_string(),
+            }
+        );
+    }
 }
@@ -14,6 +14,11 @@ pub struct WorkspaceManifest {
     /// Example: `["collections/users-api", "collections/payments-api"]`
     pub collections: Vec<String>,
 
+    /// Whether each environment keeps its own cookie jar instead of sharing
+    /// the workspace jar.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cookies_per_environment: bool,
+
     /// Default environment to activate on workspace open.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_environment: Option<String>,
@@ -35,6 +40,7 @@ impl WorkspaceManifest {
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             collections: Vec::new(),
+            cookies_per_environment: false,
             default_environment: None,
             name: name.into(),
             schema_version: CURRENT_SCHEMA_VERSION,
@@ -43,6 +49,8 @@ impl WorkspaceManifest {
                 follow_redirects: Some(true),
                 max_redirects: Some(10),
                 verify_ssl: Some(true),
+                proxy: None,
+                tls: None,
             }),
         }
     }
@@ -60,6 +68,7 @@ impl Default for WorkspaceManifest {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -70,6 +79,19 @@ mod tests {
         assert_eq!(manifest.schema_version, CURRENT_SCHEMA_VERSION);
         assert!(manifest.collections.is_empty());
         assert!(manifest.settings.is_some());
+        assert!(!manifest.cookies_per_environment);
+    }
+
+    #[test]
+    fn test_workspace_manifest_cookies_per_environment_serde() {
+        let mut manifest = WorkspaceManifest::new("Test");
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(!json.contains("cookies_per_environment"));
+
+        manifest.cookies_per_environment = true;
+        let json = serde_json::to_string(&manifest).unwrap();
+        let parsed: WorkspaceManifest = serde_json::from_str(&json).unwrap();
+        assert!(parsed.cookies_per_environment);
     }
 
     #[test]
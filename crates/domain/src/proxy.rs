@@ -47,6 +47,17 @@ impl ProxyConfig {
         }
     }
 
+    /// Create a proxy configuration that defers to the `HTTP_PROXY`,
+    /// `HTTPS_PROXY` and `NO_PROXY` environment variables.
+    #[must_use]
+    pub fn system() -> Self {
+        Self {
+            enabled: true,
+            use_system_proxy: true,
+            ..Default::default()
+        }
+    }
+
     /// Set authentication credentials.
     #[must_use]
     pub fn with_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
@@ -119,10 +130,13 @@ impl ProxyConfig {
             && !self.url.starts_with("http://")
             && !self.url.starts_with("https://")
             && !self.url.starts_with("socks4://")
+            && !self.url.starts_with("socks4a://")
             && !self.url.starts_with("socks5://")
+            && !self.url.starts_with("socks5h://")
         {
             return Err(ProxyError::InvalidUrl(
-                "URL must start with http://, https://, socks4://, or socks5://".to_string(),
+                "URL must start with http://, https://, socks4://, socks4a://, socks5://, or socks5h://"
+                    .to_string(),
             ));
         }
 
@@ -242,6 +256,15 @@ impl GlobalProxySettings {
         Self::default()
     }
 
+    /// Create settings that take proxies from the environment variables.
+    #[must_use]
+    pub fn system() -> Self {
+        Self {
+            http_proxy: ProxyConfig::system(),
+            ..Self::default()
+        }
+    }
+
     /// Get the effective proxy for a URL scheme.
     #[must_use]
     pub const fn get_proxy(&self, is_https: bool) -> Option<&ProxyConfig> {
@@ -333,6 +356,13 @@ mod tests {
         let config = ProxyConfig::with_url("ftp://proxy.example.com");
         assert!(matches!(config.validate(), Err(ProxyError::InvalidUrl(_))));
 
+        // SOCKS5 with remote DNS
+        let config = ProxyConfig::with_url("socks5h://proxy.example.com:1080");
+        assert!(config.validate().is_ok());
+
+        // System proxy without URL is valid
+        assert!(ProxyConfig::system().validate().is_ok());
+
         // Incomplete auth
         let mut config = ProxyConfig::with_url("http://proxy.example.com");
         config.username = Some("user".to_string());
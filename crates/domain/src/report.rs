@@ -0,0 +1,523 @@
+//! Collection run reports
+//!
+//! Types describing the outcome of running many requests in sequence,
+//! e.g. from the headless CLI runner.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::request::RequestSpec;
+use crate::response::ResponseSpec;
+use crate::testing::TestResults;
+
+/// Replacement shown in reports in place of secret values.
+pub const SECRET_MASK: &str = "********";
+
+/// Replaces every occurrence of the given secret values in `text` with [`SECRET_MASK`].
+///
+/// Longer secrets are replaced first so overlapping values are fully masked.
+#[must_use]
+pub fn mask_secrets(text: &str, secrets: &[String]) -> String {
+    let mut secrets: Vec<&str> = secrets
+        .iter()
+        .map(String::as_str)
+        .filter(|s| !s.is_empty())
+        .collect();
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+
+    secrets.into_iter().fold(text.to_string(), |text, secret| {
+        text.replace(secret, SECRET_MASK)
+    })
+}
+
+/// Machine-readable report formats for a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    /// `JUnit` XML, as understood by GitLab, Jenkins and most CI servers.
+    Junit,
+    /// Test Anything Protocol, version 13.
+    Tap,
+    /// Vortex JSON report.
+    Json,
+    /// Self-contained HTML page for people.
+    Html,
+}
+
+impl ReportFormat {
+    /// Get all available formats.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Junit, Self::Tap, Self::Json, Self::Html]
+    }
+
+    /// Get the short name used on the command line and in file names.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Junit => "junit",
+            Self::Tap => "tap",
+            Self::Json => "json",
+            Self::Html => "html",
+        }
+    }
+
+    /// Looks up a format by its short name (case-insensitive).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|format| format.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Get the file extension for this format.
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Junit => "xml",
+            Self::Tap => "tap",
+            Self::Json => "json",
+            Self::Html => "html",
+        }
+    }
+
+    /// Get the MIME type for this format.
+    #[must_use]
+    pub const fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Junit => "application/xml",
+            Self::Tap => "text/plain",
+            Self::Json => "application/json",
+            Self::Html => "text/html",
+        }
+    }
+
+    /// Get the display name for this format.
+    #[must_use]
+    pub const fn display_name(&self) -> &'static str {
+        match self {
+            Self::Junit => "JUnit XML",
+            Self::Tap => "TAP",
+            Self::Json => "JSON",
+            Self::Html => "HTML",
+        }
+    }
+}
+
+/// Result of executing a single request during a run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestRunResult {
+    /// ID of the saved request.
+    pub request_id: String,
+    /// Name of the request.
+    pub request_name: String,
+    /// Names of the folders containing the request, outermost first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folder_path: Vec<String>,
+    /// Zero-based data iteration this result belongs to, for data-driven runs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<usize>,
+    /// HTTP method that was sent.
+    pub method: String,
+    /// Fully resolved URL that was sent.
+    pub url: String,
+    /// The request as sent, after variable resolution and auth were applied.
+    ///
+    /// Never serialized, as it holds unmasked secret values.
+    #[serde(skip)]
+    pub request: Option<RequestSpec>,
+    /// The response, if the request completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<ResponseSpec>,
+    /// Results of the request's test assertions.
+    pub test_results: TestResults,
+    /// Error message if the request could not be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Reason the runner did not send the request, if it was skipped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+    /// Total duration in milliseconds, including auth and assertions.
+    pub duration_ms: u64,
+}
+
+impl RequestRunResult {
+    /// Returns true if the request succeeded and all tests passed.
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.error.is_none() && self.test_results.all_passed()
+    }
+
+    /// Returns true if the request itself failed (network error, etc.).
+    #[must_use]
+    pub const fn has_request_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns true if the runner skipped the request without sending it.
+    #[must_use]
+    pub const fn is_skipped(&self) -> bool {
+        self.skipped.is_some()
+    }
+
+    /// Returns the folder path and request name joined with `/`.
+    #[must_use]
+    pub fn display_path(&self) -> String {
+        self.folder_path
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once(self.request_name.as_str()))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// Summary statistics for a run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Total number of requests.
+    pub total_requests: usize,
+    /// Number of requests that passed all tests.
+    pub passed_requests: usize,
+    /// Number of requests with at least one failed test.
+    pub failed_requests: usize,
+    /// Number of requests that errored (network failure, etc.).
+    pub errored_requests: usize,
+    /// Number of requests the runner skipped without sending.
+    #[serde(default)]
+    pub skipped_requests: usize,
+    /// Total number of test assertions.
+    pub total_assertions: usize,
+    /// Number of passed assertions.
+    pub passed_assertions: usize,
+    /// Number of failed assertions.
+    pub failed_assertions: usize,
+    /// Total duration of the run in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl RunSummary {
+    /// Calculates the summary for a set of request results.
+    #[must_use]
+    pub fn from_results(results: &[RequestRunResult], duration_ms: u64) -> Self {
+        let mut summary = Self {
+            total_requests: results.len(),
+            duration_ms,
+            ..Self::default()
+        };
+
+        for result in results {
+            if result.has_request_error() {
+                summary.errored_requests += 1;
+            } else if result.is_skipped() {
+                summary.skipped_requests += 1;
+            } else if result.test_results.all_passed() {
+                summary.passed_requests += 1;
+            } else {
+                summary.failed_requests += 1;
+            }
+
+            summary.total_assertions += result.test_results.total;
+            summary.passed_assertions += result.test_results.passed;
+            summary.failed_assertions += result.test_results.failed;
+        }
+
+        summary
+    }
+}
+
+/// Aggregated outcome of one data-driven iteration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IterationResult {
+    /// Zero-based iteration index.
+    pub index: usize,
+    /// The data row injected for this iteration.
+    pub data: BTreeMap<String, String>,
+    /// Summary statistics for the requests of this iteration.
+    pub summary: RunSummary,
+    /// All assertion results of this iteration, in execution order.
+    pub test_results: TestResults,
+}
+
+impl IterationResult {
+    /// Aggregates the results that belong to the given iteration.
+    #[must_use]
+    pub fn from_results(
+        index: usize,
+        data: BTreeMap<String, String>,
+        results: &[RequestRunResult],
+    ) -> Self {
+        let results: Vec<_> = results
+            .iter()
+            .filter(|r| r.iteration == Some(index))
+            .cloned()
+            .collect();
+        let duration_ms = results.iter().map(|r| r.duration_ms).sum();
+        let assertions = results
+            .iter()
+            .flat_map(|r| r.test_results.results.iter().cloned())
+            .collect();
+        let assertions_ms = results.iter().map(|r| r.test_results.duration_ms).sum();
+
+        Self {
+            index,
+            data,
+            summary: RunSummary::from_results(&results, duration_ms),
+            test_results: TestResults::new(
+                format!("Iteration {}", index + 1),
+                assertions,
+                assertions_ms,
+            ),
+        }
+    }
+
+    /// Returns true if every request of the iteration passed.
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.summary.failed_requests == 0 && self.summary.errored_requests == 0
+    }
+}
+
+/// Complete report for a collection run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    /// Unique ID for this run.
+    pub run_id: Uuid,
+    /// Name of the collection that was run.
+    pub collection_name: String,
+    /// Environment name, if one was selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// When the run started.
+    pub started_at: DateTime<Utc>,
+    /// When the run finished.
+    pub finished_at: DateTime<Utc>,
+    /// Summary statistics.
+    pub summary: RunSummary,
+    /// Individual request results, in execution order.
+    pub results: Vec<RequestRunResult>,
+    /// Per-iteration aggregates for data-driven runs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub iterations: Vec<IterationResult>,
+    /// Vortex version that generated the report.
+    pub vortex_version: String,
+    /// Secret values used during the run, masked by human-readable reports.
+    /// Never serialized.
+    #[serde(skip)]
+    pub secret_values: Vec<String>,
+}
+
+impl RunReport {
+    /// Creates a report, computing the summary from the results.
+    #[must_use]
+    pub fn new(
+        collection_name: impl Into<String>,
+        environment: Option<String>,
+        results: Vec<RequestRunResult>,
+        started_at: DateTime<Utc>,
+        finished_at: DateTime<Utc>,
+    ) -> Self {
+        let duration_ms = u64::try_from((finished_at - started_at).num_milliseconds()).unwrap_or(0);
+
+        Self {
+            run_id: Uuid::now_v7(),
+            collection_name: collection_name.into(),
+            environment,
+            started_at,
+            finished_at,
+            summary: RunSummary::from_results(&results, duration_ms),
+            results,
+            iterations: Vec::new(),
+            vortex_version: env!("CARGO_PKG_VERSION").to_string(),
+            secret_values: Vec::new(),
+        }
+    }
+
+    /// Sets the secret values to mask in human-readable output.
+    #[must_use]
+    pub fn with_secret_values(mut self, values: Vec<String>) -> Self {
+        self.secret_values = values;
+        self
+    }
+
+    /// Masks the run's secret values in `text`.
+    #[must_use]
+    pub fn mask(&self, text: &str) -> String {
+        mask_secrets(text, &self.secret_values)
+    }
+
+    /// Returns all assertion results of the run combined into one set.
+    #[must_use]
+    pub fn test_results(&self) -> TestResults {
+        TestResults::new(
+            self.collection_name.clone(),
+            self.results
+                .iter()
+                .flat_map(|r| r.test_results.results.iter().cloned())
+                .collect(),
+            self.results
+                .iter()
+                .map(|r| r.test_results.duration_ms)
+                .sum(),
+        )
+    }
+
+    /// Attaches per-iteration aggregates for the given data rows.
+    #[must_use]
+    pub fn with_iterations(mut self, rows: &[BTreeMap<String, String>]) -> Self {
+        self.iterations = rows
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.results.iter().any(|r| r.iteration == Some(*index)))
+            .map(|(index, row)| IterationResult::from_results(index, row.clone(), &self.results))
+            .collect();
+        self
+    }
+
+    /// Returns true if all requests passed all tests.
+    #[must_use]
+    pub const fn all_passed(&self) -> bool {
+        self.summary.failed_requests == 0 && self.summary.errored_requests == 0
+    }
+
+    /// Returns the pass rate as a percentage (0.0 - 100.0).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pass_rate(&self) -> f64 {
+        if self.summary.total_requests == 0 {
+            return 100.0;
+        }
+        (self.summary.passed_requests as f64 / self.summary.total_requests as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::testing::{Assertion, AssertionResult};
+
+    fn result(name: &str, passed: bool, error: Option<&str>) -> RequestRunResult {
+        let assertion = Assertion::ResponseTime { max_ms: 100 };
+        let assertion_result = if passed {
+            AssertionResult::pass(assertion)
+        } else {
+            AssertionResult::fail(assertion, "too slow")
+        };
+        let results = if error.is_some() {
+            Vec::new()
+        } else {
+            vec![assertion_result]
+        };
+
+        RequestRunResult {
+            request_id: name.to_string(),
+            request_name: name.to_string(),
+            folder_path: vec!["Users".to_string()],
+            iteration: None,
+            method: "GET".to_string(),
+            url: "https://api.example.com".to_string(),
+            request: None,
+            response: None,
+            test_results: TestResults::new(name, results, 0),
+            error: error.map(ToString::to_string),
+            skipped: None,
+            duration_ms: 10,
+        }
+    }
+
+    #[test]
+    fn test_summary_counts() {
+        let mut skipped = result("d", true, None);
+        skipped.test_results = TestResults::new("d", Vec::new(), 0);
+        skipped.skipped = Some("not supported".to_string());
+        let results = vec![
+            result("a", true, None),
+            result("b", false, None),
+            result("c", true, Some("connection refused")),
+            skipped,
+        ];
+        let summary = RunSummary::from_results(&results, 30);
+
+        assert_eq!(summary.total_requests, 4);
+        assert_eq!(summary.passed_requests, 1);
+        assert_eq!(summary.failed_requests, 1);
+        assert_eq!(summary.errored_requests, 1);
+        assert_eq!(summary.skipped_requests, 1);
+        assert_eq!(summary.total_assertions, 2);
+        assert_eq!(summary.failed_assertions, 1);
+    }
+
+    #[test]
+    fn test_report_pass_rate() {
+        let now = Utc::now();
+        let report = RunReport::new(
+            "API",
+            None,
+            vec![result("a", true, None), result("b", false, None)],
+            now,
+            now,
+        );
+
+        assert!(!report.all_passed());
+        assert!((report.pass_rate() - 50.0).abs() < f64::EPSILON);
+        assert_eq!(report.results[0].display_path(), "Users/a");
+    }
+
+    #[test]
+    fn test_iterations_aggregate_results() {
+        let mut first = result("a", true, None);
+        first.iteration = Some(0);
+        let mut second = result("a", false, None);
+        second.iteration = Some(1);
+        let rows = vec![
+            BTreeMap::from([("user".to_string(), "alice".to_string())]),
+            BTreeMap::from([("user".to_string(), "bob".to_string())]),
+            BTreeMap::from([("user".to_string(), "carol".to_string())]),
+        ];
+
+        let now = Utc::now();
+        let report =
+            RunReport::new("API", None, vec![first, second], now, now).with_iterations(&rows);
+
+        assert_eq!(report.iterations.len(), 2);
+        assert!(report.iterations[0].passed());
+        assert!(!report.iterations[1].passed());
+        assert_eq!(report.iterations[1].data["user"], "bob");
+        assert_eq!(report.iterations[1].test_results.failed, 1);
+        assert_eq!(report.iterations[1].test_results.suite_name, "Iteration 2");
+    }
+
+    #[test]
+    fn test_serialized_report_omits_sent_request() {
+        let mut sent = result("a", true, None);
+        sent.request = Some(
+            RequestSpec::get("https://api.example.com?key=s3cr3t")
+                .with_header("Authorization", "Bearer s3cr3t"),
+        );
+        let now = Utc::now();
+        let report = RunReport::new("API", None, vec![sent], now, now)
+            .with_secret_values(vec!["s3cr3t".to_string()]);
+
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(!json.contains("s3cr3t"));
+
+        let restored: RunReport = serde_json::from_str(&json).unwrap();
+        assert!(restored.results[0].request.is_none());
+    }
+
+    #[test]
+    fn test_mask_secrets() {
+        let secrets = vec!["abc".to_string(), "abcdef".to_string(), String::new()];
+
+        assert_eq!(
+            mask_secrets("token=abcdef&key=abc", &secrets),
+            "token=********&key=********"
+        );
+        assert_eq!(mask_secrets("nothing here", &secrets), "nothing here");
+    }
+}
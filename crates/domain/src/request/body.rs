@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::graphql::{self, GraphqlError};
+
 /// The kind of request body.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -18,6 +20,15 @@ pub enum RequestBodyKind {
     FormUrlEncoded,
     /// Multipart form data
     FormData,
+    /// GraphQL operation; the content is the query document
+    Graphql {
+        /// Variables as JSON text. May contain `{{variables}}`.
+        #[serde(default, skip_serializing_if = "String::is_empty")]
+        variables: String,
+        /// The operation to run when the document has several
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        operation_name: Option<String>,
+    },
 }
 
 /// HTTP request body with content and type information.
@@ -62,6 +73,39 @@ impl RequestBody {
         }
     }
 
+    /// Creates a GraphQL body.
+    #[must_use]
+    pub fn graphql(
+        query: impl Into<String>,
+        variables: impl Into<String>,
+        operation_name: Option<String>,
+    ) -> Self {
+        Self {
+            kind: RequestBodyKind::Graphql {
+                variables: variables.into(),
+                operation_name,
+            },
+            content: query.into(),
+        }
+    }
+
+    /// Returns the content as sent on the wire.
+    ///
+    /// GraphQL bodies are encoded as a JSON envelope; other bodies are sent as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a GraphQL body cannot be encoded.
+    pub fn encoded(&self) -> Result<String, GraphqlError> {
+        match &self.kind {
+            RequestBodyKind::Graphql {
+                variables,
+                operation_name,
+            } => graphql::encode_request(&self.content, variables, operation_name.as_deref()),
+            _ => Ok(self.content.clone()),
+        }
+    }
+
     /// Returns whether the body is empty or none.
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // String::is_empty is not const
@@ -75,6 +119,7 @@ impl RequestBody {
         match &self.kind {
             RequestBodyKind::None => None,
             RequestBodyKind::Raw { content_type } => Some(content_type),
+            RequestBodyKind::Graphql { .. } => Some("application/json"),
             RequestBodyKind::FormUrlEncoded => Some("application/x-www-form-urlencoded"),
             RequestBodyKind::FormData => Some("multipart/form-data"),
         }
@@ -82,6 +127,7 @@ impl RequestBody {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -98,4 +144,15 @@ mod tests {
         assert!(body.is_empty());
         assert_eq!(body.content_type(), None);
     }
+
+    #[test]
+    fn test_graphql_body() {
+        let body = RequestBody::graphql("{ me { id } }", r#"{"a": 1}"#, None);
+        assert_eq!(body.content_type(), Some("application/json"));
+        assert_eq!(
+            body.encoded().unwrap(),
+            r#"{"query":"{ me { id } }","variables":{"a":1}}"#
+        );
+        assert_eq!(RequestBody::text("x").encoded().unwrap(), "x");
+    }
 }
@@ -7,8 +7,10 @@ use std::str::FromStr;
 use crate::error::{DomainError, DomainResult};
 
 /// Supported HTTP methods.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
-#[serde(rename_all = "UPPERCASE")]
+///
+/// Methods are written as their name, e.g. `"GET"` or `"PROPFIND"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(into = "String", try_from = "String")]
 pub enum HttpMethod {
     /// HTTP GET method
     #[default]
@@ -25,10 +27,19 @@ pub enum HttpMethod {
     Head,
     /// HTTP OPTIONS method
     Options,
+    /// HTTP TRACE method
+    Trace,
+    /// HTTP CONNECT method
+    Connect,
+    /// Any other method, such as `PROPFIND` from `WebDAV` or `PURGE`.
+    ///
+    /// Created by parsing, which keeps the standard methods in their own
+    /// variants and checks the name is a valid HTTP token.
+    Custom(String),
 }
 
 impl HttpMethod {
-    /// Returns all available HTTP methods.
+    /// Returns the standard HTTP methods.
     #[must_use]
     pub const fn all() -> &'static [Self] {
         &[
@@ -39,18 +50,22 @@ impl HttpMethod {
             Self::Delete,
             Self::Head,
             Self::Options,
+            Self::Trace,
+            Self::Connect,
         ]
     }
 
     /// Returns whether this method typically has a request body.
+    ///
+    /// Custom methods are assumed to take one, as many `WebDAV` methods do.
     #[must_use]
-    pub const fn has_body(self) -> bool {
-        matches!(self, Self::Post | Self::Put | Self::Patch)
+    pub const fn has_body(&self) -> bool {
+        matches!(self, Self::Post | Self::Put | Self::Patch | Self::Custom(_))
     }
 
-    /// Returns the method as a static string.
+    /// Returns the method name.
     #[must_use]
-    pub const fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Get => "GET",
             Self::Post => "POST",
@@ -59,6 +74,9 @@ impl HttpMethod {
             Self::Delete => "DELETE",
             Self::Head => "HEAD",
             Self::Options => "OPTIONS",
+            Self::Trace => "TRACE",
+            Self::Connect => "CONNECT",
+            Self::Custom(name) => name,
         }
     }
 }
@@ -72,6 +90,8 @@ impl fmt::Display for HttpMethod {
 impl FromStr for HttpMethod {
     type Err = DomainError;
 
+    /// Parses a method name. Standard methods are matched in any case,
+    /// other names are kept as written.
     fn from_str(s: &str) -> DomainResult<Self> {
         match s.to_uppercase().as_str() {
             "GET" => Ok(Self::Get),
@@ -81,11 +101,39 @@ impl FromStr for HttpMethod {
             "DELETE" => Ok(Self::Delete),
             "HEAD" => Ok(Self::Head),
             "OPTIONS" => Ok(Self::Options),
-            other => Err(DomainError::UnsupportedMethod(other.to_string())),
+            "TRACE" => Ok(Self::Trace),
+            "CONNECT" => Ok(Self::Connect),
+            _ if is_token(s) => Ok(Self::Custom(s.to_string())),
+            _ => Err(DomainError::UnsupportedMethod(s.to_string())),
         }
     }
 }
 
+impl TryFrom<String> for HttpMethod {
+    type Error = DomainError;
+
+    fn try_from(name: String) -> DomainResult<Self> {
+        name.parse()
+    }
+}
+
+impl From<HttpMethod> for String {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Custom(name) => name,
+            method => method.as_str().to_string(),
+        }
+    }
+}
+
+/// Returns true if `name` is a valid HTTP token (RFC 9110, section 5.6.2).
+fn is_token(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b))
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -97,18 +145,40 @@ mod tests {
         assert_eq!("get".parse::<HttpMethod>().unwrap(), HttpMethod::Get);
         assert_eq!("POST".parse::<HttpMethod>().unwrap(), HttpMethod::Post);
         assert_eq!("Put".parse::<HttpMethod>().unwrap(), HttpMethod::Put);
+        assert_eq!("trace".parse::<HttpMethod>().unwrap(), HttpMethod::Trace);
+        assert_eq!(
+            "CONNECT".parse::<HttpMethod>().unwrap(),
+            HttpMethod::Connect
+        );
     }
 
     #[test]
     fn test_method_display() {
         assert_eq!(HttpMethod::Get.to_string(), "GET");
         assert_eq!(HttpMethod::Post.to_string(), "POST");
+        assert_eq!(HttpMethod::Custom("PURGE".to_string()).to_string(), "PURGE");
     }
 
     #[test]
     fn test_invalid_method() {
-        let result = "INVALID".parse::<HttpMethod>();
-        assert!(result.is_err());
+        assert!("".parse::<HttpMethod>().is_err());
+        assert!("GET POST".parse::<HttpMethod>().is_err());
+        assert!("PROP(FIND)".parse::<HttpMethod>().is_err());
+    }
+
+    #[test]
+    fn test_custom_method() {
+        let method = "PROPFIND".parse::<HttpMethod>().unwrap();
+        assert_eq!(method, HttpMethod::Custom("PROPFIND".to_string()));
+
+        let json = serde_json::to_string(&method).unwrap();
+        assert_eq!(json, r#""PROPFIND""#);
+        assert_eq!(serde_json::from_str::<HttpMethod>(&json).unwrap(), method);
+        assert_eq!(
+            serde_json::from_str::<HttpMethod>(r#""OPTIONS""#).unwrap(),
+            HttpMethod::Options
+        );
+        assert!(serde_json::from_str::<HttpMethod>(r#""NOT A METHOD""#).is_err());
     }
 
     #[test]
@@ -118,5 +188,7 @@ mod tests {
         assert!(HttpMethod::Put.has_body());
         assert!(HttpMethod::Patch.has_body());
         assert!(!HttpMethod::Delete.has_body());
+        assert!(!HttpMethod::Trace.has_body());
+        assert!(HttpMethod::Custom("PROPFIND".to_string()).has_body());
     }
 }
@@ -4,10 +4,12 @@ mod body;
 mod header;
 mod method;
 mod query;
+mod redirect;
 mod spec;
 
 pub use body::{RequestBody, RequestBodyKind};
 pub use header::{Header, Headers};
 pub use method::HttpMethod;
 pub use query::{QueryParam, QueryParams};
+pub use redirect::{DEFAULT_MAX_REDIRECTS, RedirectPolicy};
 pub use spec::RequestSpec;
@@ -0,0 +1,48 @@
+//! Redirect policy type
+
+use serde::{Deserialize, Serialize};
+
+/// Default maximum number of redirects followed.
+pub const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+/// How a request follows HTTP redirects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectPolicy {
+    /// Whether to follow redirects. Otherwise the redirect response is the
+    /// final response.
+    pub follow: bool,
+    /// Maximum number of redirects to follow before failing.
+    pub max_redirects: u32,
+}
+
+impl RedirectPolicy {
+    /// Follows up to `max_redirects` redirects.
+    #[must_use]
+    pub const fn limited(max_redirects: u32) -> Self {
+        Self {
+            follow: true,
+            max_redirects,
+        }
+    }
+
+    /// Returns the redirect response instead of following it.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            follow: false,
+            max_redirects: 0,
+        }
+    }
+
+    /// Returns true if this is the default policy.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self::limited(DEFAULT_MAX_REDIRECTS)
+    }
+}
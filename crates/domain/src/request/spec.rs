@@ -7,8 +7,11 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 use uuid::Uuid;
 
-use super::{Header, Headers, HttpMethod, QueryParam, QueryParams, RequestBody};
+use super::{Header, Headers, HttpMethod, QueryParam, QueryParams, RedirectPolicy, RequestBody};
 use crate::auth::AuthConfig;
+use crate::proxy::ProxyConfig;
+use crate::response::DownloadOptions;
+use crate::tls::TlsConfig;
 
 /// Default timeout in milliseconds (30 seconds).
 const DEFAULT_TIMEOUT_MS: u64 = 30_000;
@@ -42,6 +45,18 @@ pub struct RequestSpec {
     /// Request timeout in milliseconds
     #[serde(default = "default_timeout_ms")]
     pub timeout_ms: u64,
+    /// Proxy override for this request (`None` uses the global settings)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+    /// TLS override for this request (`None` uses the client's configuration)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    /// Saves the response body to a file instead of keeping it in memory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub download: Option<DownloadOptions>,
+    /// How redirects are followed
+    #[serde(default, skip_serializing_if = "RedirectPolicy::is_default")]
+    pub redirect_policy: RedirectPolicy,
 }
 
 const fn default_timeout_ms() -> u64 {
@@ -63,6 +78,10 @@ impl RequestSpec {
             body: RequestBody::none(),
             auth: AuthConfig::default(),
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            proxy: None,
+            tls: None,
+            download: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
@@ -80,6 +99,10 @@ impl RequestSpec {
             body: RequestBody::none(),
             auth: AuthConfig::default(),
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            proxy: None,
+            tls: None,
+            download: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
@@ -97,6 +120,10 @@ impl RequestSpec {
             body: RequestBody::none(),
             auth: AuthConfig::default(),
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            proxy: None,
+            tls: None,
+            download: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
@@ -114,6 +141,10 @@ impl RequestSpec {
             body: RequestBody::none(),
             auth: AuthConfig::default(),
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            proxy: None,
+            tls: None,
+            download: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
@@ -131,6 +162,10 @@ impl RequestSpec {
             body: RequestBody::none(),
             auth: AuthConfig::default(),
             timeout_ms: DEFAULT_TIMEOUT_MS,
+            proxy: None,
+            tls: None,
+            download: None,
+            redirect_policy: RedirectPolicy::default(),
         }
     }
 
@@ -162,6 +197,36 @@ impl RequestSpec {
         self
     }
 
+    /// Sets a proxy override for this request.
+    ///
+    /// A disabled configuration sends the request without any proxy.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets a TLS override for this request.
+    #[must_use]
+    pub fn with_tls(mut self, tls: TlsConfig) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
+    /// Saves the response body to a file.
+    #[must_use]
+    pub fn with_download(mut self, download: DownloadOptions) -> Self {
+        self.download = Some(download);
+        self
+    }
+
+    /// Sets how redirects are followed.
+    #[must_use]
+    pub const fn with_redirect_policy(mut self, redirect_policy: RedirectPolicy) -> Self {
+        self.redirect_policy = redirect_policy;
+        self
+    }
+
     /// Sets the request name.
     #[must_use]
     pub fn with_name(mut self, name: impl Into<String>) -> Self {
@@ -0,0 +1,152 @@
+//! Download mode types
+//!
+//! In download mode the response body is written to a file as it arrives,
+//! and only the first bytes are kept in memory as a preview.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::spec::format_bytes;
+
+/// Default size of the in-memory preview of a downloaded body (1 MB).
+pub const DEFAULT_PREVIEW_BYTES: usize = 1024 * 1024;
+
+const fn default_preview_bytes() -> usize {
+    DEFAULT_PREVIEW_BYTES
+}
+
+/// Settings for saving a response body to a file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadOptions {
+    /// File the body is written to. An existing file is replaced.
+    pub path: PathBuf,
+    /// Number of bytes kept in memory as a preview of the body.
+    #[serde(default = "default_preview_bytes")]
+    pub preview_bytes: usize,
+}
+
+impl DownloadOptions {
+    /// Creates download settings with the default preview size.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            preview_bytes: DEFAULT_PREVIEW_BYTES,
+        }
+    }
+
+    /// Sets the size of the in-memory preview.
+    #[must_use]
+    pub const fn with_preview_bytes(mut self, preview_bytes: usize) -> Self {
+        self.preview_bytes = preview_bytes;
+        self
+    }
+}
+
+/// Progress of a download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Bytes received so far.
+    pub received: u64,
+    /// Total size from the `Content-Length` header, if known.
+    pub total: Option<u64>,
+    /// Time since the body started arriving.
+    pub elapsed: Duration,
+}
+
+impl DownloadProgress {
+    /// Returns the average rate in bytes per second.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn rate(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.received as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the completed fraction from 0.0 to 1.0, if the total is known.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|&total| total > 0)
+            .map(|total| (self.received as f64 / total as f64).min(1.0))
+    }
+
+    /// Returns the estimated time remaining at the average rate.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn eta(&self) -> Option<Duration> {
+        let remaining = self.total?.saturating_sub(self.received);
+        let rate = self.rate();
+        (rate > 0.0).then(|| Duration::from_secs_f64(remaining as f64 / rate))
+    }
+
+    /// Returns a summary such as `12.00 MB of 40.00 MB (30%), 2.00 MB/s, 14 s left`.
+    #[must_use]
+    pub fn display(&self) -> String {
+        let mut text = format_bytes(self.received);
+        if let (Some(total), Some(fraction)) = (self.total, self.fraction()) {
+            let _ = write!(
+                text,
+                " of {} ({:.0}%)",
+                format_bytes(total),
+                fraction * 100.0
+            );
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let rate = self.rate() as u64;
+        let _ = write!(text, ", {}/s", format_bytes(rate));
+        if let Some(eta) = self.eta() {
+            let _ = write!(text, ", {} s left", eta.as_secs());
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_rate_and_eta() {
+        let progress = DownloadProgress {
+            received: 12 * 1024 * 1024,
+            total: Some(40 * 1024 * 1024),
+            elapsed: Duration::from_secs(6),
+        };
+        assert!((progress.rate() - 2_097_152.0).abs() < f64::EPSILON);
+        assert!((progress.fraction().unwrap() - 0.3).abs() < f64::EPSILON);
+        assert_eq!(progress.eta(), Some(Duration::from_secs(14)));
+        assert_eq!(
+            progress.display(),
+            "12.00 MB of 40.00 MB (30%), 2.00 MB/s, 14 s left"
+        );
+    }
+
+    #[test]
+    fn test_progress_unknown_total() {
+        let progress = DownloadProgress {
+            received: 512,
+            total: None,
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(progress.fraction(), None);
+        assert_eq!(progress.eta(), None);
+        assert_eq!(progress.display(), "512 B, 0 B/s");
+    }
+
+    #[test]
+    fn test_options_default_preview() {
+        let options: DownloadOptions = serde_json::from_str(r#"{"path":"out.bin"}"#).unwrap();
+        assert_eq!(options, DownloadOptions::new("out.bin"));
+        assert_eq!(options.preview_bytes, DEFAULT_PREVIEW_BYTES);
+    }
+}
@@ -1,5 +1,13 @@
 //! HTTP Response domain types
 
+mod download;
+mod redirect;
 mod spec;
+mod stream;
+mod timing;
 
-pub use spec::{ResponseSpec, StatusCode, StatusColorCategory};
+pub use download::{DEFAULT_PREVIEW_BYTES, DownloadOptions, DownloadProgress};
+pub use redirect::RedirectHop;
+pub use spec::{ResponseSpec, StatusCode, StatusColorCategory, format_bytes};
+pub use stream::{StreamEvent, StreamFormat, StreamParser};
+pub use timing::{ResponseTiming, TimingPhase};
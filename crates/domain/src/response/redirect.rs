@@ -0,0 +1,74 @@
+//! Redirect chain types
+//!
+//! A response reached through redirects keeps every redirect response it
+//! passed, in the order they were received.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::timing::ResponseTiming;
+
+/// A redirect response that was followed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectHop {
+    /// HTTP status code of the redirect.
+    pub status: u16,
+    /// URL that answered with the redirect.
+    pub url: String,
+    /// Absolute URL the redirect pointed to, resolved from `Location`.
+    pub location: String,
+    /// Headers of the redirect response.
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    /// Time spent in each phase of the hop.
+    #[serde(default, skip_serializing_if = "ResponseTiming::is_empty")]
+    pub timing: ResponseTiming,
+}
+
+impl RedirectHop {
+    /// Returns true if the hop pointed to `target`.
+    ///
+    /// A target starting with `/` is compared with the path and query of the
+    /// location, any other target with the whole location.
+    #[must_use]
+    pub fn points_to(&self, target: &str) -> bool {
+        if !target.starts_with('/') {
+            return self.location == target;
+        }
+        let path = self
+            .location
+            .split_once("://")
+            .and_then(|(_, rest)| rest.find('/').map(|index| &rest[index..]))
+            .unwrap_or("/");
+        let path = path.split('#').next().unwrap_or(path);
+        path == target || (!target.contains('?') && path.split('?').next() == Some(target))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn hop(location: &str) -> RedirectHop {
+        RedirectHop {
+            status: 302,
+            url: "https://example.com/account".to_string(),
+            location: location.to_string(),
+            headers: HashMap::new(),
+            timing: ResponseTiming::default(),
+        }
+    }
+
+    #[test]
+    fn test_points_to_path_or_url() {
+        let login = hop("https://example.com/login?next=%2Faccount");
+        assert!(login.points_to("/login"));
+        assert!(login.points_to("/login?next=%2Faccount"));
+        assert!(login.points_to("https://example.com/login?next=%2Faccount"));
+        assert!(!login.points_to("/log"));
+        assert!(!login.points_to("https://example.com/login"));
+        assert!(hop("https://example.com").points_to("/"));
+    }
+}
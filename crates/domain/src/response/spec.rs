@@ -4,10 +4,14 @@
 //! status codes, headers, body, and timing information.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use super::redirect::RedirectHop;
+use super::stream::{StreamEvent, StreamFormat};
+use super::timing::ResponseTiming;
 use crate::request::Headers;
 
 /// HTTP status code with semantic helpers.
@@ -162,11 +166,26 @@ pub struct ResponseSpec {
     /// Response time.
     #[serde(with = "duration_millis")]
     pub duration: Duration,
-    /// Response size in bytes.
+    /// Response size in bytes, including any part not kept in `body`.
     pub size: usize,
     /// Content-Type header value (extracted for convenience).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+    /// Events of a Server-Sent Events or NDJSON body, in arrival order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<StreamEvent>,
+    /// True if `body` holds only a preview of a larger body.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub body_truncated: bool,
+    /// File the body was saved to in download mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saved_to: Option<PathBuf>,
+    /// Time spent in each phase of the exchange.
+    #[serde(default, skip_serializing_if = "ResponseTiming::is_empty")]
+    pub timing: ResponseTiming,
+    /// Redirects followed to reach this response, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<RedirectHop>,
 }
 
 impl ResponseSpec {
@@ -198,9 +217,26 @@ impl ResponseSpec {
             duration,
             size,
             content_type,
+            events: Vec::new(),
+            body_truncated: false,
+            saved_to: None,
+            timing: ResponseTiming::default(),
+            redirects: Vec::new(),
         }
     }
 
+    /// Marks the body as saved to a file.
+    ///
+    /// `body` keeps its current content as a preview; `size` is the size of
+    /// the whole body.
+    #[must_use]
+    pub fn with_saved_file(mut self, path: impl Into<PathBuf>, size: usize) -> Self {
+        self.body_truncated = size > self.body_bytes.len();
+        self.size = size;
+        self.saved_to = Some(path.into());
+        self
+    }
+
     /// Returns the status as a `StatusCode` struct.
     #[must_use]
     pub const fn status_code(&self) -> StatusCode {
@@ -263,6 +299,14 @@ impl ResponseSpec {
             .is_some_and(|ct| ct.contains("application/json") || ct.contains("+json"))
     }
 
+    /// Returns the stream format of the body, if it is split into events.
+    #[must_use]
+    pub fn stream_format(&self) -> Option<StreamFormat> {
+        self.content_type
+            .as_deref()
+            .and_then(StreamFormat::from_content_type)
+    }
+
     /// Returns true if the content type indicates text.
     #[must_use]
     pub fn is_text(&self) -> bool {
@@ -274,7 +318,7 @@ impl ResponseSpec {
     /// Returns a human-readable size string (e.g., "1.2 KB").
     #[must_use]
     pub fn size_display(&self) -> String {
-        format_bytes(self.size)
+        format_bytes(self.size as u64)
     }
 
     /// Returns a human-readable duration string (e.g., "124 ms").
@@ -298,11 +342,12 @@ impl ResponseSpec {
     }
 }
 
-/// Formats bytes into a human-readable string.
-fn format_bytes(bytes: usize) -> String {
-    const KB: usize = 1024;
-    const MB: usize = KB * 1024;
-    const GB: usize = MB * 1024;
+/// Formats bytes into a human-readable string (e.g., "1.2 KB").
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
 
     #[allow(clippy::cast_precision_loss)]
     if bytes >= GB {
@@ -328,6 +373,11 @@ impl Default for ResponseSpec {
             duration: Duration::ZERO,
             size: 0,
             content_type: None,
+            events: Vec::new(),
+            body_truncated: false,
+            saved_to: None,
+            timing: ResponseTiming::default(),
+            redirects: Vec::new(),
         }
     }
 }
@@ -383,6 +433,7 @@ mod serde_bytes_base64 {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
 
@@ -438,6 +489,21 @@ mod tests {
         assert!(response.is_success());
     }
 
+    #[test]
+    fn test_response_saved_file() {
+        let response = ResponseSpec::new(200, HashMap::new(), b"head".to_vec(), Duration::ZERO)
+            .with_saved_file("/tmp/export.csv", 4096);
+
+        assert_eq!(response.body, "head");
+        assert_eq!(response.size, 4096);
+        assert!(response.body_truncated);
+        assert_eq!(response.saved_to, Some(PathBuf::from("/tmp/export.csv")));
+
+        let json = serde_json::to_string(&ResponseSpec::default()).unwrap();
+        assert!(!json.contains("body_truncated"));
+        assert!(!json.contains("saved_to"));
+    }
+
     #[test]
     fn test_response_body_methods() {
         let response = ResponseSpec::new(
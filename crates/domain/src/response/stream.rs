@@ -0,0 +1,313 @@
+//! Streaming response types
+//!
+//! Parses Server-Sent Events (`text/event-stream`) and newline-delimited JSON
+//! incrementally, as the body arrives in chunks.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Format of a streamed response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamFormat {
+    /// Server-Sent Events (`text/event-stream`).
+    ServerSentEvents,
+    /// One JSON value per line (`application/x-ndjson`, `application/jsonl`).
+    Ndjson,
+}
+
+impl StreamFormat {
+    /// Detects the stream format from a `Content-Type` header value.
+    ///
+    /// Returns `None` for bodies that are not split into events.
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match mime.as_str() {
+            "text/event-stream" => Some(Self::ServerSentEvents),
+            "application/x-ndjson"
+            | "application/ndjson"
+            | "application/jsonl"
+            | "application/x-jsonlines"
+            | "application/json-seq" => Some(Self::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// An event received from a streamed response.
+///
+/// For NDJSON streams each line is an event with only `data` set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamEvent {
+    /// When the event arrived.
+    pub received_at: DateTime<Utc>,
+    /// Last event ID (`id` field), which carries over to later events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Event type (`event` field).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Event data; multiple `data` lines are joined with newlines.
+    pub data: String,
+    /// Reconnection time in milliseconds (`retry` field), if sent with this event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<u64>,
+}
+
+impl StreamEvent {
+    /// Creates an event with only data.
+    #[must_use]
+    pub fn data(data: impl Into<String>, received_at: DateTime<Utc>) -> Self {
+        Self {
+            received_at,
+            id: None,
+            event: None,
+            data: data.into(),
+            retry: None,
+        }
+    }
+
+    /// Returns the event type, which defaults to `message`.
+    #[must_use]
+    pub fn event_type(&self) -> &str {
+        self.event.as_deref().unwrap_or("message")
+    }
+
+    /// Attempts to parse the data as JSON.
+    #[must_use]
+    pub fn data_as_json(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(&self.data).ok()
+    }
+}
+
+/// Incremental parser for streamed response bodies.
+///
+/// Chunks may split lines and UTF-8 sequences anywhere; incomplete lines are
+/// kept until the next chunk.
+#[derive(Debug, Clone)]
+pub struct StreamParser {
+    format: StreamFormat,
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Vec<String>,
+    retry: Option<u64>,
+    last_event_id: Option<String>,
+}
+
+impl StreamParser {
+    /// Creates a parser for the given format.
+    #[must_use]
+    pub const fn new(format: StreamFormat) -> Self {
+        Self {
+            format,
+            buffer: Vec::new(),
+            event: None,
+            data: Vec::new(),
+            retry: None,
+            last_event_id: None,
+        }
+    }
+
+    /// Returns the format being parsed.
+    #[must_use]
+    pub const fn format(&self) -> StreamFormat {
+        self.format
+    }
+
+    /// Parses a chunk and returns the events it completed.
+    pub fn push(&mut self, chunk: &[u8], received_at: DateTime<Utc>) -> Vec<StreamEvent> {
+        self.buffer.extend_from_slice(chunk);
+        let mut events = Vec::new();
+        while let Some(line) = self.next_line(false) {
+            self.line(&line, received_at, &mut events);
+        }
+        events
+    }
+
+    /// Parses the rest of the body once the stream has ended.
+    ///
+    /// An unterminated last line is still parsed. As in browsers, an SSE event
+    /// without its closing blank line is discarded.
+    pub fn finish(&mut self, received_at: DateTime<Utc>) -> Vec<StreamEvent> {
+        let mut events = Vec::new();
+        while let Some(line) = self.next_line(true) {
+            self.line(&line, received_at, &mut events);
+        }
+        self.event = None;
+        self.data.clear();
+        self.retry = None;
+        events
+    }
+
+    /// Parses a complete body at once.
+    #[must_use]
+    pub fn parse_all(
+        format: StreamFormat,
+        body: &[u8],
+        received_at: DateTime<Utc>,
+    ) -> Vec<StreamEvent> {
+        let mut parser = Self::new(format);
+        let mut events = parser.push(body, received_at);
+        events.extend(parser.finish(received_at));
+        events
+    }
+
+    /// Takes the next line from the buffer.
+    ///
+    /// Lines end with CRLF, LF or CR. A trailing CR is only taken as a line
+    /// end at the end of the stream, as its LF may be in the next chunk.
+    fn next_line(&mut self, at_end: bool) -> Option<String> {
+        let position = self.buffer.iter().position(|&b| b == b'\n' || b == b'\r');
+        let (end, skip) = match position {
+            Some(i) if self.buffer[i] == b'\r' => match self.buffer.get(i + 1) {
+                Some(b'\n') => (i, 2),
+                Some(_) => (i, 1),
+                None if at_end => (i, 1),
+                None => return None,
+            },
+            Some(i) => (i, 1),
+            None if at_end && !self.buffer.is_empty() => (self.buffer.len(), 0),
+            None => return None,
+        };
+        let line = String::from_utf8_lossy(&self.buffer[..end]).into_owned();
+        self.buffer.drain(..end + skip);
+        Some(line)
+    }
+
+    fn line(&mut self, line: &str, received_at: DateTime<Utc>, events: &mut Vec<StreamEvent>) {
+        match self.format {
+            StreamFormat::Ndjson => {
+                let line = line.trim();
+                if !line.is_empty() {
+                    events.push(StreamEvent::data(line, received_at));
+                }
+            }
+            StreamFormat::ServerSentEvents => self.sse_line(line, received_at, events),
+        }
+    }
+
+    /// Applies one SSE line, following the WHATWG event stream rules.
+    fn sse_line(&mut self, line: &str, received_at: DateTime<Utc>, events: &mut Vec<StreamEvent>) {
+        let line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        if line.is_empty() {
+            self.dispatch(received_at, events);
+            return;
+        }
+        if line.starts_with(':') {
+            return;
+        }
+
+        let (field, value) = line.split_once(':').map_or((line, ""), |(field, value)| {
+            (field, value.strip_prefix(' ').unwrap_or(value))
+        });
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            "id" if !value.contains('\0') => {
+                self.last_event_id = Some(value.to_string()).filter(|id| !id.is_empty());
+            }
+            "retry" => {
+                if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+                    self.retry = value.parse().ok();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn dispatch(&mut self, received_at: DateTime<Utc>, events: &mut Vec<StreamEvent>) {
+        let event = self.event.take().filter(|event| !event.is_empty());
+        let retry = self.retry.take();
+        if self.data.is_empty() {
+            return;
+        }
+        events.push(StreamEvent {
+            received_at,
+            id: self.last_event_id.clone(),
+            event,
+            data: std::mem::take(&mut self.data).join("\n"),
+            retry,
+        });
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    #[test]
+    fn test_format_from_content_type() {
+        assert_eq!(
+            StreamFormat::from_content_type("text/event-stream; charset=utf-8"),
+            Some(StreamFormat::ServerSentEvents)
+        );
+        assert_eq!(
+            StreamFormat::from_content_type("application/x-ndjson"),
+            Some(StreamFormat::Ndjson)
+        );
+        assert_eq!(StreamFormat::from_content_type("application/json"), None);
+    }
+
+    #[test]
+    fn test_sse_fields() {
+        let body = b": comment\nretry: 3000\nid: 1\nevent: update\ndata: first\ndata:second\n\ndata: {\"n\": 2}\n\n";
+        let events = StreamParser::parse_all(StreamFormat::ServerSentEvents, body, now());
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].id.as_deref(), Some("1"));
+        assert_eq!(events[0].event_type(), "update");
+        assert_eq!(events[0].data, "first\nsecond");
+        assert_eq!(events[0].retry, Some(3000));
+
+        // The last event ID carries over, the event type does not
+        assert_eq!(events[1].id.as_deref(), Some("1"));
+        assert_eq!(events[1].event_type(), "message");
+        assert_eq!(events[1].retry, None);
+        assert_eq!(events[1].data_as_json().unwrap()["n"], 2);
+    }
+
+    #[test]
+    fn test_sse_split_chunks() {
+        let mut parser = StreamParser::new(StreamFormat::ServerSentEvents);
+        assert!(parser.push(b"data: caf", now()).is_empty());
+        assert!(parser.push(&[0xc3], now()).is_empty());
+        assert!(parser.push(&[0xa9, b'\r'], now()).is_empty());
+        assert!(parser.push(b"\n", now()).is_empty());
+
+        let events = parser.push(b"\r\n", now());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "café");
+    }
+
+    #[test]
+    fn test_sse_incomplete_event_is_discarded() {
+        let events =
+            StreamParser::parse_all(StreamFormat::ServerSentEvents, b"data: a\n\ndata: b", now());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, "a");
+    }
+
+    #[test]
+    fn test_ndjson_lines() {
+        let mut parser = StreamParser::new(StreamFormat::Ndjson);
+        let events = parser.push(b"{\"n\": 1}\n\n{\"n\"", now());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data, r#"{"n": 1}"#);
+
+        assert!(parser.push(b": 2}", now()).is_empty());
+        let events = parser.finish(now());
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].data_as_json().unwrap()["n"], 2);
+    }
+}
@@ -0,0 +1,195 @@
+//! Response timing breakdown
+//!
+//! Splits the time of a request into the phases of an HTTP exchange, in the
+//! order they happen. Phases that did not happen, such as DNS lookup and
+//! connecting on a reused connection, or that the client could not measure,
+//! are left out.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// A phase of an HTTP exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimingPhase {
+    /// Resolving the host name.
+    Dns,
+    /// Opening the TCP connection.
+    Connect,
+    /// The TLS handshake.
+    Tls,
+    /// Writing the request.
+    Send,
+    /// Waiting for the first byte of the response (time to first byte).
+    Wait,
+    /// Receiving the response body.
+    Download,
+}
+
+impl TimingPhase {
+    /// All phases, in the order they happen.
+    pub const ALL: [Self; 6] = [
+        Self::Dns,
+        Self::Connect,
+        Self::Tls,
+        Self::Send,
+        Self::Wait,
+        Self::Download,
+    ];
+
+    /// Returns the display label of the phase.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Dns => "DNS Lookup",
+            Self::Connect => "TCP Connect",
+            Self::Tls => "TLS Handshake",
+            Self::Send => "Request Sent",
+            Self::Wait => "Waiting (TTFB)",
+            Self::Download => "Content Download",
+        }
+    }
+}
+
+/// Durations of the phases of an HTTP exchange.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseTiming {
+    /// DNS lookup.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub dns: Option<Duration>,
+    /// TCP connect.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub connect: Option<Duration>,
+    /// TLS handshake.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub tls: Option<Duration>,
+    /// Writing the request.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub send: Option<Duration>,
+    /// Waiting for the response headers.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub wait: Option<Duration>,
+    /// Receiving the body.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "micros")]
+    pub download: Option<Duration>,
+}
+
+impl ResponseTiming {
+    /// Returns the duration of a phase, if it was measured.
+    #[must_use]
+    pub const fn get(&self, phase: TimingPhase) -> Option<Duration> {
+        match phase {
+            TimingPhase::Dns => self.dns,
+            TimingPhase::Connect => self.connect,
+            TimingPhase::Tls => self.tls,
+            TimingPhase::Send => self.send,
+            TimingPhase::Wait => self.wait,
+            TimingPhase::Download => self.download,
+        }
+    }
+
+    /// Returns true if no phase was measured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        TimingPhase::ALL
+            .iter()
+            .all(|&phase| self.get(phase).is_none())
+    }
+
+    /// Returns the measured phases in order, each with its start offset and
+    /// duration, for drawing a waterfall.
+    #[must_use]
+    pub fn waterfall(&self) -> Vec<(TimingPhase, Duration, Duration)> {
+        let mut offset = Duration::ZERO;
+        TimingPhase::ALL
+            .iter()
+            .filter_map(|&phase| {
+                let duration = self.get(phase)?;
+                let start = offset;
+                offset += duration;
+                Some((phase, start, duration))
+            })
+            .collect()
+    }
+
+    /// Returns the sum of the measured phases.
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        TimingPhase::ALL
+            .iter()
+            .filter_map(|&phase| self.get(phase))
+            .sum()
+    }
+}
+
+/// Serializes optional durations as whole microseconds.
+mod micros {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    #[allow(clippy::ref_option, clippy::cast_possible_truncation)]
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            // Truncation is acceptable: phases over ~584 thousand years are not realistic
+            Some(duration) => serializer.serialize_u64(duration.as_micros() as u64),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_micros))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_waterfall_skips_missing_phases() {
+        let timing = ResponseTiming {
+            dns: Some(Duration::from_millis(5)),
+            connect: Some(Duration::from_millis(20)),
+            wait: Some(Duration::from_millis(100)),
+            download: Some(Duration::from_millis(15)),
+            ..ResponseTiming::default()
+        };
+
+        let waterfall = timing.waterfall();
+        let phases: Vec<_> = waterfall.iter().map(|(phase, _, _)| *phase).collect();
+        assert_eq!(
+            phases,
+            vec![
+                TimingPhase::Dns,
+                TimingPhase::Connect,
+                TimingPhase::Wait,
+                TimingPhase::Download
+            ]
+        );
+        assert_eq!(waterfall[2].1, Duration::from_millis(25));
+        assert_eq!(timing.total(), Duration::from_millis(140));
+    }
+
+    #[test]
+    fn test_serialization_in_microseconds() {
+        let timing = ResponseTiming {
+            dns: Some(Duration::from_micros(1500)),
+            ..ResponseTiming::default()
+        };
+        let json = serde_json::to_string(&timing).unwrap();
+        assert_eq!(json, r#"{"dns":1500}"#);
+        assert_eq!(
+            serde_json::from_str::<ResponseTiming>(&json).unwrap(),
+            timing
+        );
+        assert!(ResponseTiming::default().is_empty());
+    }
+}
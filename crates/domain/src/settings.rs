@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::proxy::GlobalProxySettings;
+
 /// Theme mode preference.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -117,6 +119,10 @@ pub struct UserSettings {
     /// Sidebar width in pixels.
     #[serde(default = "default_sidebar_width")]
     pub sidebar_width: u32,
+
+    /// Global proxy settings.
+    #[serde(default = "default_proxy")]
+    pub proxy: GlobalProxySettings,
 }
 
 const fn default_history_visible() -> bool {
@@ -131,6 +137,10 @@ const fn default_sidebar_width() -> u32 {
     280
 }
 
+fn default_proxy() -> GlobalProxySettings {
+    GlobalProxySettings::system()
+}
+
 impl Default for UserSettings {
     fn default() -> Self {
         Self {
@@ -139,6 +149,7 @@ impl Default for UserSettings {
             history_visible: default_history_visible(),
             history_limit: default_history_limit(),
             sidebar_width: default_sidebar_width(),
+            proxy: default_proxy(),
         }
     }
 }
@@ -169,5 +180,6 @@ mod tests {
         assert!(settings.history_visible);
         assert_eq!(settings.history_limit, 100);
         assert_eq!(settings.sidebar_width, 280);
+        assert!(settings.proxy.http_proxy.use_system_proxy);
     }
 }
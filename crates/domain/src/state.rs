@@ -161,6 +161,9 @@ pub enum RequestErrorKind {
     /// Too many redirects.
     TooManyRedirects,
 
+    /// Proxy connection or authentication failed.
+    ProxyError,
+
     /// Request was cancelled by user.
     Cancelled,
 
@@ -205,6 +208,11 @@ impl RequestErrorKind {
                 "The server may have a redirect loop",
                 "Try the final URL directly",
             ],
+            Self::ProxyError => &[
+                "Check the proxy URL and port",
+                "Verify the proxy username and password",
+                "Add the host to the proxy bypass list if it is internal",
+            ],
             Self::Cancelled => &["Request was cancelled"],
             Self::Unknown => &[
                 "An unexpected error occurred",
@@ -225,6 +233,7 @@ impl RequestErrorKind {
             Self::TlsError => "SSL/TLS Error",
             Self::InvalidBody => "Invalid Request Body",
             Self::TooManyRedirects => "Too Many Redirects",
+            Self::ProxyError => "Proxy Error",
             Self::Cancelled => "Request Cancelled",
             Self::Unknown => "Unknown Error",
         }
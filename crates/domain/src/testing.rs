@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::response::TimingPhase;
+
 /// A test assertion to run against a response.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -19,6 +21,16 @@ pub enum Assertion {
         /// Maximum allowed time in milliseconds.
         max_ms: u64,
     },
+    /// Check the time of one phase of the exchange.
+    ///
+    /// Passes if the phase did not happen, e.g. connecting on a reused
+    /// connection, and fails if the HTTP client does not measure it.
+    PhaseTime {
+        /// Phase to check.
+        phase: TimingPhase,
+        /// Maximum allowed time in milliseconds.
+        max_ms: u64,
+    },
     /// Check header exists and optionally its value.
     HeaderExists {
         /// Header name (case-insensitive).
@@ -83,6 +95,33 @@ pub enum Assertion {
         /// Length to compare against.
         length: usize,
     },
+    /// Check the number of events in a Server-Sent Events or NDJSON body.
+    EventCount {
+        /// Comparison operator.
+        operator: ComparisonOperator,
+        /// Count to compare against.
+        count: usize,
+    },
+    /// Check that an event's data contains text.
+    EventContains {
+        /// Only check events of this type.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        event: Option<String>,
+        /// Text to search for.
+        text: String,
+    },
+    /// Check where the last followed redirect pointed to.
+    RedirectedTo {
+        /// Path starting with `/`, or a whole URL.
+        location: String,
+    },
+    /// Check the number of redirects followed.
+    RedirectCount {
+        /// Comparison operator.
+        operator: ComparisonOperator,
+        /// Count to compare against.
+        count: usize,
+    },
 }
 
 impl Assertion {
@@ -92,6 +131,7 @@ impl Assertion {
         match self {
             Self::StatusCode { expected } => format!("Status code {}", expected.description()),
             Self::ResponseTime { max_ms } => format!("Response time < {max_ms}ms"),
+            Self::PhaseTime { phase, max_ms } => format!("{} < {max_ms}ms", phase.label()),
             Self::HeaderExists {
                 name,
                 value: Some(v),
@@ -128,6 +168,52 @@ impl Assertion {
             Self::BodyLength { operator, length } => {
                 format!("Body length {} {}", operator.symbol(), length)
             }
+            Self::EventCount { operator, count } => {
+                format!("Event count {} {}", operator.symbol(), count)
+            }
+            Self::EventContains {
+                event: Some(event),
+                text,
+            } => format!("Event '{event}' contains '{text}'"),
+            Self::EventContains { event: None, text } => format!("An event contains '{text}'"),
+            Self::RedirectedTo { location } => format!("Redirected to '{location}'"),
+            Self::RedirectCount { operator, count } => {
+                format!("Redirect count {} {}", operator.symbol(), count)
+            }
+        }
+    }
+
+    /// Get the expected value of this assertion, for showing next to the actual value.
+    ///
+    /// Returns `None` for assertions without a comparable expected value.
+    #[must_use]
+    pub fn expected(&self) -> Option<String> {
+        match self {
+            Self::StatusCode { expected } => Some(expected.description()),
+            Self::ResponseTime { max_ms } | Self::PhaseTime { max_ms, .. } => {
+                Some(format!("< {max_ms}ms"))
+            }
+            Self::HeaderExists { value, .. } => value.clone(),
+            Self::HeaderMatches { pattern, .. } | Self::BodyMatches { pattern } => {
+                Some(format!("/{pattern}/"))
+            }
+            Self::BodyContains { text, .. } | Self::EventContains { text, .. } => {
+                Some(text.clone())
+            }
+            Self::JsonPath { expected, .. } => expected.as_ref().map(ToString::to_string),
+            Self::JsonPathMatches {
+                operator, value, ..
+            } => Some(format!("{} {value}", operator.symbol())),
+            Self::BodyEquals { expected }
+            | Self::ContentType { expected }
+            | Self::RedirectedTo { location: expected } => Some(expected.clone()),
+            Self::BodyLength { operator, length } => {
+                Some(format!("{} {length}", operator.symbol()))
+            }
+            Self::EventCount { operator, count } | Self::RedirectCount { operator, count } => {
+                Some(format!("{} {count}", operator.symbol()))
+            }
+            Self::IsJson | Self::IsXml => None,
         }
     }
 }
@@ -22,6 +22,10 @@ pub struct TlsConfig {
     #[serde(default)]
     pub min_tls_version: Option<TlsVersion>,
 
+    /// Maximum TLS version (default: newest supported).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tls_version: Option<TlsVersion>,
+
     /// Accept invalid/self-signed certificates (dangerous!).
     #[serde(default)]
     pub danger_accept_invalid_certs: bool,
@@ -38,6 +42,7 @@ impl Default for TlsConfig {
             ca_certificates: Vec::new(),
             client_certificate: None,
             min_tls_version: None,
+            max_tls_version: None,
             danger_accept_invalid_certs: false,
             danger_accept_invalid_hostnames: false,
         }
@@ -114,6 +119,26 @@ impl TlsConfig {
         self.min_tls_version = Some(version);
         self
     }
+
+    /// Set maximum TLS version.
+    #[must_use]
+    pub const fn with_max_tls_version(mut self, version: TlsVersion) -> Self {
+        self.max_tls_version = Some(version);
+        self
+    }
+
+    /// Enable or disable server certificate verification.
+    #[must_use]
+    pub const fn with_verify_certificates(mut self, verify: bool) -> Self {
+        self.verify_certificates = verify;
+        self
+    }
+
+    /// Check if invalid or untrusted server certificates are accepted.
+    #[must_use]
+    pub const fn accepts_invalid_certs(&self) -> bool {
+        !self.verify_certificates || self.danger_accept_invalid_certs
+    }
 }
 
 /// Source for a certificate.
@@ -347,5 +372,33 @@ mod tests {
 
         assert_eq!(config.ca_certificates.len(), 1);
         assert_eq!(config.min_tls_version, Some(TlsVersion::Tls13));
+        assert!(!config.accepts_invalid_certs());
+
+        let config = TlsConfig::new()
+            .with_max_tls_version(TlsVersion::Tls12)
+            .with_verify_certificates(false);
+        assert_eq!(config.max_tls_version, Some(TlsVersion::Tls12));
+        assert!(config.accepts_invalid_certs());
+        assert_eq!(
+            config.security_warnings(),
+            vec![TlsSecurityWarning::CertificateVerificationDisabled]
+        );
+    }
+
+    #[test]
+    fn test_tls_config_serialization() {
+        let config = TlsConfig::new()
+            .with_ca_pem_file("/ca.pem")
+            .with_client_cert(ClientCertificate::from_pem_files("/client.pem", "/key.pem"))
+            .with_max_tls_version(TlsVersion::Tls13);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains("\"max_tls_version\":\"1.3\""));
+        let parsed: TlsConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, config);
+
+        // Older files without the newer fields still load
+        let parsed: TlsConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(parsed, TlsConfig::default());
     }
 }
@@ -0,0 +1,172 @@
+//! Cookie store backed by the domain `CookieJar`.
+//!
+//! reqwest consults its cookie provider before every request and after every
+//! response, redirect hops included. This adapter forwards both directions to
+//! a shared [`CookieJar`] so that the UI can display, edit and persist the
+//! same cookies the HTTP client uses.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use reqwest::Url;
+use reqwest::cookie::CookieStore;
+use reqwest::header::HeaderValue;
+use vortex_domain::cookie::CookieJar;
+
+/// A [`CookieJar`] shared between the HTTP client and its owner.
+///
+/// Cheap to clone; all clones refer to the same jar.
+#[derive(Debug, Clone, Default)]
+pub struct SharedCookieJar {
+    jar: Arc<RwLock<CookieJar>>,
+    revision: Arc<AtomicU64>,
+}
+
+impl SharedCookieJar {
+    /// Creates a shared jar holding the given cookies.
+    #[must_use]
+    pub fn new(jar: CookieJar) -> Self {
+        Self {
+            jar: Arc::new(RwLock::new(jar)),
+            revision: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Returns a copy of the current jar.
+    #[must_use]
+    pub fn snapshot(&self) -> CookieJar {
+        self.jar
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the whole jar, e.g. after switching workspace or environment.
+    pub fn replace(&self, jar: CookieJar) {
+        self.update(|current| *current = jar);
+    }
+
+    /// Applies a change to the jar.
+    pub fn update<R>(&self, f: impl FnOnce(&mut CookieJar) -> R) -> R {
+        let result = f(&mut self.jar.write().unwrap_or_else(PoisonError::into_inner));
+        self.revision.fetch_add(1, Ordering::Relaxed);
+        result
+    }
+
+    /// Returns a counter that changes whenever the jar is modified.
+    ///
+    /// Comparing revisions tells whether the jar needs to be saved again.
+    #[must_use]
+    pub fn revision(&self) -> u64 {
+        self.revision.load(Ordering::Relaxed)
+    }
+}
+
+impl CookieStore for SharedCookieJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        let Some(host) = url.host_str() else {
+            return;
+        };
+        let headers: Vec<(String, String)> = cookie_headers
+            .filter_map(|value| value.to_str().ok())
+            .map(|value| ("set-cookie".to_string(), value.to_string()))
+            .collect();
+        if headers.is_empty() {
+            return;
+        }
+
+        self.update(|jar| jar.process_set_cookies(&headers, host));
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        let header = self
+            .jar
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .cookie_header(url.as_str())?;
+        HeaderValue::from_str(&header).ok()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use vortex_domain::cookie::Cookie;
+
+    use super::*;
+
+    #[test]
+    fn test_set_cookies_and_header() {
+        let store = SharedCookieJar::default();
+        let url = Url::parse("https://api.example.com/v1/login").unwrap();
+        let headers = [
+            HeaderValue::from_static("session=abc; Path=/; HttpOnly"),
+            HeaderValue::from_static("theme=dark; Domain=example.com"),
+        ];
+
+        store.set_cookies(&mut headers.iter(), &url);
+
+        assert_eq!(store.snapshot().len(), 2);
+        assert_eq!(store.revision(), 1);
+        let header = store
+            .cookies(&Url::parse("https://api.example.com/v1/me").unwrap())
+            .unwrap();
+        let header = header.to_str().unwrap();
+        assert!(header.contains("session=abc"));
+        assert!(header.contains("theme=dark"));
+
+        // Only the domain cookie applies to a sibling host
+        let header = store
+            .cookies(&Url::parse("https://www.example.com/").unwrap())
+            .unwrap();
+        assert_eq!(header.to_str().unwrap(), "theme=dark");
+    }
+
+    #[test]
+    fn test_set_cookies_rejects_public_suffix_domain() {
+        let store = SharedCookieJar::default();
+        let headers = [
+            HeaderValue::from_static("tracker=1; Domain=github.io"),
+            HeaderValue::from_static("session=abc"),
+        ];
+
+        store.set_cookies(
+            &mut headers.iter(),
+            &Url::parse("https://alice.github.io/").unwrap(),
+        );
+
+        assert_eq!(store.snapshot().len(), 1);
+        assert!(
+            store
+                .cookies(&Url::parse("https://bob.github.io/").unwrap())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_disabled_jar_neither_sends_nor_stores() {
+        let mut jar = CookieJar::new();
+        jar.add(Cookie::new("session", "abc", "example.com"));
+        jar.enabled = false;
+        let store = SharedCookieJar::new(jar);
+        let url = Url::parse("https://example.com/").unwrap();
+
+        assert!(store.cookies(&url).is_none());
+        let header = HeaderValue::from_static("other=1");
+        store.set_cookies(&mut std::iter::once(&header), &url);
+        assert_eq!(store.snapshot().len(), 1);
+    }
+
+    #[test]
+    fn test_clones_share_the_jar() {
+        let store = SharedCookieJar::default();
+        let clone = store.clone();
+
+        clone.update(|jar| jar.add(Cookie::new("a", "1", "example.com")));
+        assert_eq!(store.snapshot().len(), 1);
+
+        store.replace(CookieJar::new());
+        assert!(clone.snapshot().is_empty());
+        assert_eq!(clone.revision(), 2);
+    }
+}
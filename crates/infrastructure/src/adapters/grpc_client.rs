@@ -0,0 +1,831 @@
+//! gRPC client implementation using tonic.
+//!
+//! This adapter implements the `GrpcClient` port without generated code:
+//! service definitions are loaded at runtime, from server reflection
+//! (`grpc.reflection.v1`) or by compiling `.proto` files, and messages are
+//! converted between JSON and protobuf with `prost-reflect`.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::Duration;
+
+use base64::Engine;
+use futures_util::{Stream, StreamExt, stream};
+use prost::Message as _;
+use prost::bytes::Buf;
+use prost_reflect::{
+    DescriptorPool, DynamicMessage, MessageDescriptor, MethodDescriptor, SerializeOptions,
+};
+use prost_types::FileDescriptorProto;
+use tokio::sync::mpsc;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::metadata::{
+    AsciiMetadataKey, AsciiMetadataValue, BinaryMetadataKey, BinaryMetadataValue, MetadataMap,
+};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use tonic_reflection::pb::v1::{ServerReflectionRequest, ServerReflectionResponse};
+use vortex_application::ports::{GrpcClient, GrpcEvent, GrpcSender, GrpcSession};
+use vortex_domain::grpc::{
+    GrpcCall, GrpcError, GrpcMethod, GrpcMethodKind, GrpcSchemaSource, GrpcService, GrpcStatus,
+    GrpcStatusCode, target_uri,
+};
+
+/// Default timeout for opening a connection.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// gRPC client implementation using tonic.
+pub struct TonicGrpcClient {
+    connect_timeout: Duration,
+}
+
+impl TonicGrpcClient {
+    /// Creates a new gRPC client with default settings.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+        }
+    }
+
+    /// Sets the timeout for opening a connection.
+    #[must_use]
+    pub const fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Opens a channel to an endpoint.
+    async fn connect(&self, endpoint: &str) -> Result<Channel, GrpcError> {
+        let uri = target_uri(endpoint)?;
+        let tls = uri.starts_with("https://");
+        let mut endpoint = Endpoint::from_shared(uri)
+            .map_err(|e| GrpcError::InvalidUrl(e.to_string()))?
+            .connect_timeout(self.connect_timeout);
+        if tls {
+            endpoint = endpoint
+                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .map_err(|e| GrpcError::ConnectionFailed(e.to_string()))?;
+        }
+        endpoint
+            .connect()
+            .await
+            .map_err(|e| GrpcError::ConnectionFailed(error_chain(&e)))
+    }
+
+    /// Loads the descriptors of an endpoint.
+    async fn descriptor_pool(
+        &self,
+        endpoint: &str,
+        source: &GrpcSchemaSource,
+        metadata: &[(String, String)],
+    ) -> Result<DescriptorPool, GrpcError> {
+        match source {
+            GrpcSchemaSource::ProtoFiles {
+                files,
+                import_paths,
+            } => compile_proto_files(files, import_paths),
+            GrpcSchemaSource::Reflection => {
+                let channel = self.connect(endpoint).await?;
+                reflect(channel, metadata).await
+            }
+        }
+    }
+}
+
+impl Default for TonicGrpcClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GrpcClient for TonicGrpcClient {
+    fn list_services(
+        &self,
+        endpoint: &str,
+        source: &GrpcSchemaSource,
+        metadata: &[(String, String)],
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GrpcService>, GrpcError>> + Send + '_>> {
+        let endpoint = endpoint.to_string();
+        let source = source.clone();
+        let metadata = metadata.to_vec();
+
+        Box::pin(async move {
+            let pool = self.descriptor_pool(&endpoint, &source, &metadata).await?;
+            let mut services: Vec<GrpcService> = pool
+                .services()
+                .map(|service| GrpcService {
+                    name: service.full_name().to_string(),
+                    methods: service.methods().map(|m| describe_method(&m)).collect(),
+                })
+                .collect();
+            services.sort_by(|a, b| a.name.cmp(&b.name));
+            Ok(services)
+        })
+    }
+
+    fn invoke(
+        &self,
+        call: &GrpcCall,
+    ) -> Pin<Box<dyn Future<Output = Result<GrpcSession, GrpcError>> + Send + '_>> {
+        let call = call.clone();
+
+        Box::pin(async move {
+            let pool = self
+                .descriptor_pool(&call.endpoint, &call.source, &call.metadata)
+                .await?;
+            let service = pool
+                .get_service_by_name(&call.service)
+                .ok_or_else(|| GrpcError::UnknownService(call.service.clone()))?;
+            let method = service
+                .methods()
+                .find(|m| m.name() == call.method)
+                .ok_or_else(|| GrpcError::UnknownMethod(call.method.clone()))?;
+
+            let kind = GrpcMethodKind::from_streaming(
+                method.is_client_streaming(),
+                method.is_server_streaming(),
+            );
+            if !kind.is_client_streaming() && call.messages.len() != 1 {
+                return Err(GrpcError::InvalidMessage(format!(
+                    "{} calls take exactly one message, got {}",
+                    kind.as_str(),
+                    call.messages.len()
+                )));
+            }
+            let messages = call
+                .messages
+                .iter()
+                .map(|json| parse_message(&method.input(), json))
+                .collect::<Result<Vec<_>, _>>()?;
+            let metadata = to_metadata(&call.metadata)?;
+
+            let mut grpc = tonic::client::Grpc::new(self.connect(&call.endpoint).await?);
+            grpc.ready()
+                .await
+                .map_err(|e| GrpcError::ConnectionFailed(error_chain(&e)))?;
+
+            let (message_tx, message_rx) = mpsc::unbounded_channel();
+            let (event_tx, event_rx) = mpsc::unbounded_channel();
+            let requests = request_stream(
+                method.input(),
+                messages,
+                kind.is_client_streaming().then_some(message_rx),
+            );
+            let mut request = tonic::Request::new(requests);
+            *request.metadata_mut() = metadata;
+            let path = call
+                .path()
+                .parse()
+                .map_err(|_| GrpcError::UnknownMethod(call.path()))?;
+            let codec = DynamicCodec {
+                output: method.output(),
+            };
+
+            tokio::spawn(async move {
+                let (status, trailers) = match grpc.streaming(request, path, codec).await {
+                    Ok(response) => {
+                        let _ =
+                            event_tx.send(GrpcEvent::Headers(from_metadata(response.metadata())));
+                        let mut messages = response.into_inner();
+                        loop {
+                            match messages.message().await {
+                                Ok(Some(message)) => {
+                                    let event = GrpcEvent::Message {
+                                        content: to_json(&message),
+                                        received_at: chrono::Utc::now(),
+                                    };
+                                    if event_tx.send(event).is_err() {
+                                        return;
+                                    }
+                                }
+                                Ok(None) => {
+                                    let trailers = messages.trailers().await.ok().flatten();
+                                    break (
+                                        GrpcStatus::default(),
+                                        trailers.as_ref().map(from_metadata).unwrap_or_default(),
+                                    );
+                                }
+                                Err(status) => break to_status(&status),
+                            }
+                        }
+                    }
+                    Err(status) => to_status(&status),
+                };
+                let _ = event_tx.send(GrpcEvent::Finished { status, trailers });
+            });
+
+            Ok(GrpcSession::new(GrpcSender::new(message_tx), event_rx))
+        })
+    }
+}
+
+/// Codec for messages described at runtime.
+struct DynamicCodec {
+    output: MessageDescriptor,
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicEncoder;
+    type Decoder = DynamicDecoder;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        DynamicEncoder
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        DynamicDecoder {
+            output: self.output.clone(),
+        }
+    }
+}
+
+/// Encodes request messages.
+struct DynamicEncoder;
+
+impl Encoder for DynamicEncoder {
+    type Item = DynamicMessage;
+    type Error = tonic::Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.reserve(item.encoded_len());
+        item.encode(dst)
+            .map_err(|e| tonic::Status::internal(e.to_string()))
+    }
+}
+
+/// Decodes response messages of one type.
+struct DynamicDecoder {
+    output: MessageDescriptor,
+}
+
+impl Decoder for DynamicDecoder {
+    type Item = DynamicMessage;
+    type Error = tonic::Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let bytes = src.copy_to_bytes(src.remaining());
+        DynamicMessage::decode(self.output.clone(), bytes)
+            .map(Some)
+            .map_err(|e| tonic::Status::internal(e.to_string()))
+    }
+}
+
+/// Builds the request stream: the given messages, then the messages queued
+/// through the sender, if any.
+///
+/// Queued messages that do not match the input type end the stream.
+fn request_stream(
+    input: MessageDescriptor,
+    messages: Vec<DynamicMessage>,
+    queued: Option<mpsc::UnboundedReceiver<String>>,
+) -> impl Stream<Item = DynamicMessage> + Send + 'static {
+    let queued = stream::unfold(queued, move |queued| {
+        let input = input.clone();
+        async move {
+            let mut queued = queued?;
+            let json = queued.recv().await?;
+            let message = parse_message(&input, &json).ok()?;
+            Some((message, Some(queued)))
+        }
+    });
+    stream::iter(messages).chain(queued)
+}
+
+/// Parses a JSON message of the given type.
+fn parse_message(desc: &MessageDescriptor, json: &str) -> Result<DynamicMessage, GrpcError> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let message = DynamicMessage::deserialize(desc.clone(), &mut deserializer)
+        .and_then(|message| deserializer.end().map(|()| message))
+        .map_err(|e| GrpcError::InvalidMessage(format!("{}: {e}", desc.full_name())))?;
+    Ok(message)
+}
+
+/// Formats a message as pretty-printed JSON, including default values.
+fn to_json(message: &DynamicMessage) -> String {
+    let options = SerializeOptions::new().skip_default_fields(false);
+    let mut serializer = serde_json::Serializer::pretty(Vec::new());
+    match message.serialize_with_options(&mut serializer, &options) {
+        Ok(()) => String::from_utf8_lossy(&serializer.into_inner()).into_owned(),
+        Err(e) => format!("<{e}>"),
+    }
+}
+
+/// Describes a method for listings.
+fn describe_method(method: &MethodDescriptor) -> GrpcMethod {
+    GrpcMethod {
+        name: method.name().to_string(),
+        kind: GrpcMethodKind::from_streaming(
+            method.is_client_streaming(),
+            method.is_server_streaming(),
+        ),
+        input_type: method.input().full_name().to_string(),
+        output_type: method.output().full_name().to_string(),
+        input_template: to_json(&DynamicMessage::new(method.input())),
+    }
+}
+
+/// Converts metadata pairs to a metadata map.
+///
+/// Keys ending in `-bin` carry binary values, given as base64.
+fn to_metadata(pairs: &[(String, String)]) -> Result<MetadataMap, GrpcError> {
+    let invalid = |key: &str| GrpcError::InvalidMessage(format!("Invalid metadata: {key}"));
+    let mut metadata = MetadataMap::new();
+    for (key, value) in pairs {
+        let key = key.trim().to_ascii_lowercase();
+        if key.ends_with("-bin") {
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(value.trim())
+                .map_err(|_| invalid(&key))?;
+            let name = BinaryMetadataKey::from_bytes(key.as_bytes()).map_err(|_| invalid(&key))?;
+            metadata.append_bin(name, BinaryMetadataValue::from_bytes(&bytes));
+        } else {
+            let name = AsciiMetadataKey::from_bytes(key.as_bytes()).map_err(|_| invalid(&key))?;
+            let value = AsciiMetadataValue::try_from(value.as_str()).map_err(|_| invalid(&key))?;
+            metadata.append(name, value);
+        }
+    }
+    Ok(metadata)
+}
+
+/// Converts a metadata map to pairs, with binary values as base64.
+fn from_metadata(metadata: &MetadataMap) -> Vec<(String, String)> {
+    metadata
+        .clone()
+        .into_headers()
+        .iter()
+        .map(|(key, value)| {
+            let value = if key.as_str().ends_with("-bin") {
+                base64::engine::general_purpose::STANDARD.encode(value.as_bytes())
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (key.to_string(), value)
+        })
+        .collect()
+}
+
+/// Converts a tonic status to the final status and trailers of a call.
+fn to_status(status: &tonic::Status) -> (GrpcStatus, Vec<(String, String)>) {
+    (
+        GrpcStatus {
+            code: GrpcStatusCode::from_i32(status.code() as i32),
+            message: status.message().to_string(),
+        },
+        from_metadata(status.metadata()),
+    )
+}
+
+/// Formats an error with its sources, which carry the useful detail for
+/// transport errors.
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+/// Compiles `.proto` files.
+///
+/// The directory of each file is searched for imports after the given
+/// import paths.
+fn compile_proto_files(
+    files: &[PathBuf],
+    import_paths: &[PathBuf],
+) -> Result<DescriptorPool, GrpcError> {
+    let mut includes = import_paths.to_vec();
+    for dir in files.iter().filter_map(|file| file.parent()) {
+        if !includes.iter().any(|include| include == dir) {
+            includes.push(dir.to_path_buf());
+        }
+    }
+    let includes = includes.into_iter().map(|dir| {
+        if dir.as_os_str().is_empty() {
+            Path::new(".").to_path_buf()
+        } else {
+            dir
+        }
+    });
+
+    let mut compiler =
+        protox::Compiler::new(includes).map_err(|e| GrpcError::Schema(e.to_string()))?;
+    compiler
+        .include_imports(true)
+        .open_files(files)
+        .map_err(|e| GrpcError::Schema(e.to_string()))?;
+    Ok(compiler.descriptor_pool())
+}
+
+/// Loads the descriptors of every service through server reflection.
+async fn reflect(
+    channel: Channel,
+    metadata: &[(String, String)],
+) -> Result<DescriptorPool, GrpcError> {
+    let (request_tx, request_rx) = mpsc::unbounded_channel();
+    let requests = stream::unfold(request_rx, |mut rx| async move {
+        rx.recv().await.map(|request| (request, rx))
+    });
+    let mut request = tonic::Request::new(requests);
+    *request.metadata_mut() = to_metadata(metadata)?;
+
+    let mut client = ServerReflectionClient::new(channel);
+    let ask = |message: MessageRequest| {
+        request_tx
+            .send(ServerReflectionRequest {
+                host: String::new(),
+                message_request: Some(message),
+            })
+            .map_err(|_| GrpcError::Schema("reflection stream closed".to_string()))
+    };
+    // Queue the first request before opening the stream, as servers answer
+    // headers only once a request arrives.
+    ask(MessageRequest::ListServices(String::new()))?;
+    let mut responses = client
+        .server_reflection_info(request)
+        .await
+        .map_err(|status| reflection_error(&status))?
+        .into_inner();
+    let mut next = async || -> Result<MessageResponse, GrpcError> {
+        let response: Option<ServerReflectionResponse> = responses
+            .message()
+            .await
+            .map_err(|status| reflection_error(&status))?;
+        match response.and_then(|r| r.message_response) {
+            Some(MessageResponse::ErrorResponse(error)) => {
+                Err(GrpcError::Schema(error.error_message))
+            }
+            Some(response) => Ok(response),
+            None => Err(GrpcError::Schema("reflection stream closed".to_string())),
+        }
+    };
+
+    let MessageResponse::ListServicesResponse(list) = next().await? else {
+        return Err(GrpcError::Schema(
+            "unexpected reflection response".to_string(),
+        ));
+    };
+
+    let mut files = HashMap::new();
+    for service in list.service {
+        ask(MessageRequest::FileContainingSymbol(service.name))?;
+        add_files(&mut files, next().await?)?;
+    }
+
+    // Servers usually send dependencies along; ask for any that are missing.
+    let mut requested = HashSet::new();
+    loop {
+        let missing: Vec<String> = files
+            .values()
+            .flat_map(|file| file.dependency.iter())
+            .filter(|dep| !files.contains_key(*dep) && !requested.contains(*dep))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            break;
+        }
+        for name in missing {
+            requested.insert(name.clone());
+            ask(MessageRequest::FileByFilename(name))?;
+            add_files(&mut files, next().await?)?;
+        }
+    }
+
+    build_pool(files.into_values().collect())
+}
+
+/// Adds the files of a reflection response.
+fn add_files(
+    files: &mut HashMap<String, FileDescriptorProto>,
+    response: MessageResponse,
+) -> Result<(), GrpcError> {
+    let MessageResponse::FileDescriptorResponse(response) = response else {
+        return Err(GrpcError::Schema(
+            "unexpected reflection response".to_string(),
+        ));
+    };
+    for bytes in response.file_descriptor_proto {
+        let file = FileDescriptorProto::decode(bytes.as_slice())
+            .map_err(|e| GrpcError::Schema(e.to_string()))?;
+        files.insert(file.name().to_string(), file);
+    }
+    Ok(())
+}
+
+/// Describes a reflection failure, pointing out servers without reflection.
+fn reflection_error(status: &tonic::Status) -> GrpcError {
+    if status.code() == tonic::Code::Unimplemented {
+        GrpcError::Schema("the server does not support reflection; import .proto files".to_string())
+    } else {
+        GrpcError::Schema(format!("{:?}: {}", status.code(), status.message()))
+    }
+}
+
+/// Builds a descriptor pool, adding files after their dependencies.
+fn build_pool(mut files: Vec<FileDescriptorProto>) -> Result<DescriptorPool, GrpcError> {
+    let mut pool = DescriptorPool::new();
+    while !files.is_empty() {
+        let (ready, waiting): (Vec<_>, Vec<_>) = files.into_iter().partition(|file| {
+            file.dependency
+                .iter()
+                .all(|dep| pool.get_file_by_name(dep).is_some())
+        });
+        if ready.is_empty() {
+            let names: Vec<&str> = waiting.iter().map(FileDescriptorProto::name).collect();
+            return Err(GrpcError::Schema(format!(
+                "unresolved imports in {}",
+                names.join(", ")
+            )));
+        }
+        pool.add_file_descriptor_protos(ready)
+            .map_err(|e| GrpcError::Schema(e.to_string()))?;
+        files = waiting;
+    }
+    Ok(pool)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use tokio::net::TcpListener;
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+
+    use super::*;
+
+    const HEALTH_PROTO: &str = r#"
+        syntax = "proto3";
+        package grpc.health.v1;
+
+        message HealthCheckRequest { string service = 1; }
+        message HealthCheckResponse {
+            enum ServingStatus {
+                UNKNOWN = 0;
+                SERVING = 1;
+                NOT_SERVING = 2;
+                SERVICE_UNKNOWN = 3;
+            }
+            ServingStatus status = 1;
+        }
+
+        service Health {
+            rpc Check(HealthCheckRequest) returns (HealthCheckResponse);
+            rpc Watch(HealthCheckRequest) returns (stream HealthCheckResponse);
+        }
+    "#;
+
+    /// Starts a local server with the health and reflection services.
+    ///
+    /// Calls carrying an `x-api-key` other than `secret` are rejected.
+    async fn grpc_server() -> String {
+        let (_reporter, health) = tonic_health::server::health_reporter();
+        let reflection = tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+            .build_v1()
+            .unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .layer(tonic::service::InterceptorLayer::new(
+                    |request: tonic::Request<()>| match request.metadata().get("x-api-key") {
+                        Some(key) if key != "secret" => {
+                            Err(tonic::Status::unauthenticated("bad api key"))
+                        }
+                        _ => Ok(request),
+                    },
+                ))
+                .add_service(health)
+                .add_service(reflection)
+                .serve_with_incoming(TcpIncoming::from(listener)),
+        );
+        format!("grpc://{addr}")
+    }
+
+    fn call(endpoint: &str, service: &str, method: &str, messages: &[&str]) -> GrpcCall {
+        GrpcCall {
+            endpoint: endpoint.to_string(),
+            source: GrpcSchemaSource::Reflection,
+            service: service.to_string(),
+            method: method.to_string(),
+            metadata: Vec::new(),
+            messages: messages.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    /// Collects the messages and final status of a call.
+    async fn collect(mut session: GrpcSession) -> (Vec<serde_json::Value>, GrpcStatus) {
+        let mut messages = Vec::new();
+        while let Some(event) = session.next_event().await {
+            match event {
+                GrpcEvent::Headers(_) => {}
+                GrpcEvent::Message { content, .. } => {
+                    messages.push(serde_json::from_str(&content).unwrap());
+                }
+                GrpcEvent::Finished { status, .. } => return (messages, status),
+            }
+        }
+        panic!("call ended without a status");
+    }
+
+    #[tokio::test]
+    async fn test_list_services_with_reflection() {
+        let endpoint = grpc_server().await;
+        let client = TonicGrpcClient::new();
+
+        let services = client
+            .list_services(&endpoint, &GrpcSchemaSource::Reflection, &[])
+            .await
+            .unwrap();
+        let names: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "grpc.health.v1.Health",
+                "grpc.reflection.v1.ServerReflection"
+            ]
+        );
+
+        let health = &services[0];
+        let check = health.method("Check").unwrap();
+        assert_eq!(check.kind, GrpcMethodKind::Unary);
+        assert_eq!(check.input_type, "grpc.health.v1.HealthCheckRequest");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&check.input_template).unwrap(),
+            serde_json::json!({ "service": "" })
+        );
+        assert_eq!(
+            health.method("Watch").unwrap().kind,
+            GrpcMethodKind::ServerStreaming
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unary_call() {
+        let endpoint = grpc_server().await;
+        let client = TonicGrpcClient::new();
+
+        let session = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.health.v1.Health",
+                "Check",
+                &[r#"{"service": ""}"#],
+            ))
+            .await
+            .unwrap();
+        let (messages, status) = collect(session).await;
+        assert!(status.is_ok());
+        assert_eq!(messages, [serde_json::json!({ "status": "SERVING" })]);
+
+        let session = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.health.v1.Health",
+                "Check",
+                &[r#"{"service": "missing"}"#],
+            ))
+            .await
+            .unwrap();
+        let (messages, status) = collect(session).await;
+        assert!(messages.is_empty());
+        assert_eq!(status.code, GrpcStatusCode::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_server_streaming_call() {
+        let endpoint = grpc_server().await;
+        let client = TonicGrpcClient::new();
+
+        let mut session = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.health.v1.Health",
+                "Watch",
+                &[r#"{"service": ""}"#],
+            ))
+            .await
+            .unwrap();
+        // Watch never ends on its own; the first message is the current status.
+        loop {
+            match session.next_event().await.unwrap() {
+                GrpcEvent::Headers(_) => {}
+                GrpcEvent::Message { content, .. } => {
+                    assert!(content.contains("SERVING"));
+                    break;
+                }
+                GrpcEvent::Finished { status, .. } => panic!("unexpected end: {status:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bidi_streaming_call() {
+        let endpoint = grpc_server().await;
+        let client = TonicGrpcClient::new();
+
+        let mut session = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.reflection.v1.ServerReflection",
+                "ServerReflectionInfo",
+                &[r#"{"listServices": ""}"#],
+            ))
+            .await
+            .unwrap();
+        session
+            .sender()
+            .unwrap()
+            .send(r#"{"fileContainingSymbol": "grpc.health.v1.Health"}"#)
+            .unwrap();
+        session.close_send();
+        let (messages, status) = collect(session).await;
+
+        assert!(status.is_ok());
+        assert_eq!(messages.len(), 2);
+        assert!(messages[0]["listServicesResponse"]["service"].is_array());
+        assert!(messages[1]["fileDescriptorResponse"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_call_with_proto_files_and_metadata() {
+        let endpoint = grpc_server().await;
+        let dir = tempfile::tempdir().unwrap();
+        let proto = dir.path().join("health.proto");
+        std::fs::write(&proto, HEALTH_PROTO).unwrap();
+        let source = GrpcSchemaSource::ProtoFiles {
+            files: vec![proto],
+            import_paths: Vec::new(),
+        };
+        let client = TonicGrpcClient::new();
+
+        let services = client
+            .list_services("grpc://127.0.0.1:1", &source, &[])
+            .await
+            .unwrap();
+        assert_eq!(services[0].name, "grpc.health.v1.Health");
+
+        let mut call = call(
+            &endpoint,
+            "grpc.health.v1.Health",
+            "Check",
+            &[r#"{"service": ""}"#],
+        );
+        call.source = source;
+        call.metadata = vec![("X-Api-Key".to_string(), "wrong".to_string())];
+        let (_, status) = collect(client.invoke(&call).await.unwrap()).await;
+        assert_eq!(status.code, GrpcStatusCode::Unauthenticated);
+        assert_eq!(status.message, "bad api key");
+
+        call.metadata = vec![("X-Api-Key".to_string(), "secret".to_string())];
+        let (messages, status) = collect(client.invoke(&call).await.unwrap()).await;
+        assert!(status.is_ok());
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_calls() {
+        let endpoint = grpc_server().await;
+        let client = TonicGrpcClient::new();
+
+        let result = client
+            .invoke(&call(&endpoint, "grpc.health.v1.Missing", "Check", &["{}"]))
+            .await;
+        assert!(matches!(result, Err(GrpcError::UnknownService(_))));
+
+        let result = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.health.v1.Health",
+                "Check",
+                &["{}", "{}"],
+            ))
+            .await;
+        assert!(matches!(result, Err(GrpcError::InvalidMessage(_))));
+
+        let result = client
+            .invoke(&call(
+                &endpoint,
+                "grpc.health.v1.Health",
+                "Check",
+                &[r#"{"unknown": 1}"#],
+            ))
+            .await;
+        assert!(matches!(result, Err(GrpcError::InvalidMessage(_))));
+
+        let result = client
+            .list_services("grpc://127.0.0.1:1", &GrpcSchemaSource::Reflection, &[])
+            .await;
+        assert!(matches!(result, Err(GrpcError::ConnectionFailed(_))));
+    }
+}
@@ -1,7 +1,15 @@
 //! Infrastructure adapters
 
+mod cookie_store;
+mod grpc_client;
 mod reqwest_client;
+mod shell_secret_command;
 mod system_clock;
+mod websocket_client;
 
+pub use cookie_store::SharedCookieJar;
+pub use grpc_client::TonicGrpcClient;
 pub use reqwest_client::ReqwestHttpClient;
+pub use shell_secret_command::ShellSecretCommandRunner;
 pub use system_clock::SystemClock;
+pub use websocket_client::TungsteniteWebSocketClient;
@@ -4,23 +4,63 @@
 //! It handles all HTTP communication for the application.
 
 use std::collections::HashMap;
+use std::error::Error as _;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
 use std::time::{Duration, Instant};
 
-use reqwest::{Client, Method, Url};
-use vortex_application::ports::{HttpClient, HttpClientError};
+use chrono::Utc;
+use reqwest::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, HeaderValue, LOCATION,
+    PROXY_AUTHORIZATION, TRANSFER_ENCODING, WWW_AUTHENTICATE,
+};
+use reqwest::{Client, Method, StatusCode, Url};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use vortex_application::ports::{
+    CancellationReceiver, CancellationToken, HttpClient, HttpClientError, ResponseStreamEvent,
+};
 use vortex_domain::{
-    request::{HttpMethod, RequestBody, RequestBodyKind, RequestSpec},
-    response::ResponseSpec,
+    auth::AuthConfig,
+    proxy::{ProxyConfig, ProxyError},
+    request::{Header, HttpMethod, RedirectPolicy, RequestBody, RequestBodyKind, RequestSpec},
+    response::{
+        DownloadOptions, DownloadProgress, RedirectHop, ResponseSpec, StreamFormat, StreamParser,
+    },
+    tls::TlsConfig,
+};
+
+use super::SharedCookieJar;
+use crate::auth::{
+    DigestChallenge, DigestSession, SIGNATURE_HEADERS, SigV4Credentials, sign_request,
+};
+use crate::http::{
+    PhaseRecorder, ProxyResolver, TimedConnectLayer, TimedResolver, apply_tls, build_proxy,
 };
 
 /// HTTP client implementation using reqwest.
 ///
 /// This is the primary HTTP adapter for Vortex. It wraps `reqwest::Client`
 /// and implements the `HttpClient` port from the application layer.
+///
+/// Requests go direct unless the [`ProxyResolver`] selects a proxy for them,
+/// and use the client's [`TlsConfig`] unless they carry their own. Each
+/// distinct proxy and TLS combination gets its own underlying client, built
+/// on first use.
+///
+/// Requests with AWS Signature Version 4 or Digest auth are signed here,
+/// once they are final. Digest challenges are answered with one retry and
+/// kept for later requests to the same origin.
 pub struct ReqwestHttpClient {
     client: Client,
+    cookie_jar: Option<SharedCookieJar>,
+    proxy_resolver: RwLock<ProxyResolver>,
+    tls_config: RwLock<TlsConfig>,
+    clients: Mutex<Vec<(Option<ProxyConfig>, TlsConfig, Client)>>,
+    /// Digest challenges per user and origin, reused until the server
+    /// rejects their nonce.
+    digest_sessions: Mutex<HashMap<String, DigestSession>>,
 }
 
 impl ReqwestHttpClient {
@@ -28,32 +68,166 @@ impl ReqwestHttpClient {
     ///
     /// Default configuration:
     /// - Connection timeout: 30 seconds
-    /// - Follow redirects: up to 10
+    /// - Follow redirects: up to 10, unless set per request
     /// - TLS verification: enabled
+    /// - Proxy: none, unless set per request
     /// - User-Agent: "Vortex/0.1.0"
     ///
     /// # Errors
     ///
     /// Returns an error if the client cannot be created.
     pub fn new() -> Result<Self, HttpClientError> {
-        let client = Client::builder()
-            .user_agent("Vortex/0.1.0")
-            .redirect(reqwest::redirect::Policy::limited(10))
-            .build()
-            .map_err(|e| HttpClientError::Other(e.to_string()))?;
+        Self::build(None)
+    }
 
-        Ok(Self { client })
+    /// Creates a new HTTP client that sends and stores cookies in a shared jar.
+    ///
+    /// Matching cookies are attached to every request, and `Set-Cookie`
+    /// headers are stored from every response, including redirect hops.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be created.
+    pub fn with_cookie_jar(jar: SharedCookieJar) -> Result<Self, HttpClientError> {
+        Self::build(Some(jar))
     }
 
     /// Creates a new HTTP client with a custom reqwest client.
+    ///
+    /// The custom client is used for direct requests with the default TLS
+    /// configuration only; other requests use clients built with the
+    /// default settings.
+    #[must_use]
+    pub fn with_client(client: Client) -> Self {
+        Self {
+            client,
+            cookie_jar: None,
+            proxy_resolver: RwLock::default(),
+            tls_config: RwLock::default(),
+            clients: Mutex::default(),
+            digest_sessions: Mutex::default(),
+        }
+    }
+
+    /// Sets the resolver that picks a proxy for each request.
     #[must_use]
-    pub const fn with_client(client: Client) -> Self {
-        Self { client }
+    pub fn with_proxy_resolver(self, resolver: ProxyResolver) -> Self {
+        self.set_proxy_resolver(resolver);
+        self
+    }
+
+    /// Replaces the proxy resolver, e.g. after the proxy settings changed.
+    pub fn set_proxy_resolver(&self, resolver: ProxyResolver) {
+        *self
+            .proxy_resolver
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = resolver;
+        self.clear_clients();
+    }
+
+    /// Sets the TLS configuration for requests without their own.
+    #[must_use]
+    pub fn with_tls_config(self, config: TlsConfig) -> Self {
+        self.set_tls_config(config);
+        self
+    }
+
+    /// Replaces the TLS configuration, e.g. after the workspace changed.
+    pub fn set_tls_config(&self, config: TlsConfig) {
+        *self
+            .tls_config
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = config;
+        self.clear_clients();
+    }
+
+    fn clear_clients(&self) {
+        self.clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Returns a client builder with the default settings.
+    ///
+    /// Redirects are followed by [`Self::send`], which records each hop.
+    fn builder(cookie_jar: Option<&SharedCookieJar>) -> reqwest::ClientBuilder {
+        let builder = Client::builder()
+            .user_agent("Vortex/0.1.0")
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(TimedResolver)
+            .connector_layer(TimedConnectLayer);
+        match cookie_jar {
+            Some(jar) => builder.cookie_provider(Arc::new(jar.clone())),
+            None => builder,
+        }
+    }
+
+    fn build(cookie_jar: Option<SharedCookieJar>) -> Result<Self, HttpClientError> {
+        // Proxies are resolved per request, so the direct client must not
+        // pick up the system proxy on its own.
+        let client = Self::builder(cookie_jar.as_ref())
+            .no_proxy()
+            .build()
+            .map_err(|e| HttpClientError::Other(e.to_string()))?;
+        Ok(Self {
+            client,
+            cookie_jar,
+            proxy_resolver: RwLock::default(),
+            tls_config: RwLock::default(),
+            clients: Mutex::default(),
+            digest_sessions: Mutex::default(),
+        })
+    }
+
+    /// Returns the client for a resolved proxy and TLS configuration.
+    ///
+    /// Direct requests with the default TLS configuration use the base client.
+    fn client_for(
+        &self,
+        proxy: Option<&ProxyConfig>,
+        tls: &TlsConfig,
+    ) -> Result<Client, HttpClientError> {
+        let default_tls = *tls == TlsConfig::default();
+        if proxy.is_none() && default_tls {
+            return Ok(self.client.clone());
+        }
+
+        let cached = self
+            .clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(existing_proxy, existing_tls, _)| {
+                existing_proxy.as_ref() == proxy && existing_tls == tls
+            })
+            .map(|(_, _, client)| client.clone());
+        if let Some(client) = cached {
+            return Ok(client);
+        }
+
+        let mut builder = apply_tls(Self::builder(self.cookie_jar.as_ref()), tls)?;
+        builder = match proxy {
+            Some(config) => builder.proxy(build_proxy(config)?),
+            None => builder.no_proxy(),
+        };
+        let client = builder.build().map_err(|e| {
+            if default_tls {
+                HttpClientError::Other(e.to_string())
+            } else {
+                HttpClientError::TlsError(error_chain(&e))
+            }
+        })?;
+        self.clients
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((proxy.cloned(), tls.clone(), client.clone()));
+        Ok(client)
     }
 
     /// Converts domain `HttpMethod` to reqwest `Method`.
-    const fn to_reqwest_method(method: HttpMethod) -> Method {
-        match method {
+    fn to_reqwest_method(method: &HttpMethod) -> Result<Method, HttpClientError> {
+        Ok(match method {
             HttpMethod::Get => Method::GET,
             HttpMethod::Post => Method::POST,
             HttpMethod::Put => Method::PUT,
@@ -61,7 +235,11 @@ impl ReqwestHttpClient {
             HttpMethod::Delete => Method::DELETE,
             HttpMethod::Head => Method::HEAD,
             HttpMethod::Options => Method::OPTIONS,
-        }
+            HttpMethod::Trace => Method::TRACE,
+            HttpMethod::Connect => Method::CONNECT,
+            HttpMethod::Custom(name) => Method::from_bytes(name.as_bytes())
+                .map_err(|_| HttpClientError::Other(format!("Invalid HTTP method: {name}")))?,
+        })
     }
 
     /// Builds the request body from domain `RequestBody`.
@@ -92,19 +270,152 @@ impl ReqwestHttpClient {
                 Ok(builder.body(body.content.clone()))
             }
 
+            RequestBodyKind::Graphql { .. } => {
+                let envelope = body
+                    .encoded()
+                    .map_err(|e| HttpClientError::InvalidBody(e.to_string()))?;
+                Ok(builder.body(envelope))
+            }
+
             RequestBodyKind::FormData => Err(HttpClientError::Other(
                 "Multipart form data not yet implemented".to_string(),
             )),
         }
     }
 
+    /// Signs a built request for auth schemes that cover the whole request.
+    fn sign(request: &mut reqwest::Request, auth: &AuthConfig) -> Result<(), HttpClientError> {
+        if let AuthConfig::AwsSigV4 {
+            access_key,
+            secret_key,
+            session_token,
+            region,
+            service,
+        } = auth
+        {
+            let credentials = SigV4Credentials {
+                access_key,
+                secret_key,
+                session_token: session_token.as_deref(),
+                region,
+                service,
+            };
+            sign_request(request, &credentials, Utc::now())?;
+        }
+        Ok(())
+    }
+
+    /// Signs a redirected request again, as the signature covers the URL.
+    ///
+    /// The signature is dropped instead when the redirect leaves the origin.
+    fn sign_redirect(
+        next: &mut reqwest::Request,
+        previous: &reqwest::Request,
+        auth: &AuthConfig,
+    ) -> Result<(), HttpClientError> {
+        if !matches!(auth, AuthConfig::AwsSigV4 { .. }) {
+            return Ok(());
+        }
+        for header in SIGNATURE_HEADERS {
+            next.headers_mut().remove(*header);
+        }
+        if next.url().origin() == previous.url().origin() {
+            Self::sign(next, auth)?;
+        }
+        Ok(())
+    }
+
+    /// Adds Digest credentials from an earlier challenge, if any.
+    ///
+    /// Returns an unsigned copy of the request to retry with if the server
+    /// sends a new challenge.
+    fn authorize_digest(
+        &self,
+        request: &mut reqwest::Request,
+        auth: &AuthConfig,
+    ) -> Option<reqwest::Request> {
+        let AuthConfig::Digest { username, password } = auth else {
+            return None;
+        };
+        let retry = request.try_clone();
+        let authorization = self
+            .digest_sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(&digest_key(username, request.url()))
+            .map(|session| digest_authorization(session, username, password, request));
+        if let Some(value) = authorization.and_then(|value| HeaderValue::try_from(value).ok()) {
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+        retry
+    }
+
+    /// Builds the retry for a Digest challenge in a 401 response.
+    ///
+    /// Returns `None` when there is nothing to answer, or when the server
+    /// rejected credentials for a nonce that is still valid.
+    fn answer_digest_challenge(
+        &self,
+        response: &reqwest::Response,
+        mut retry: reqwest::Request,
+        auth: &AuthConfig,
+    ) -> Result<Option<reqwest::Request>, HttpClientError> {
+        let AuthConfig::Digest { username, password } = auth else {
+            return Ok(None);
+        };
+        if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(None);
+        }
+        let Some(challenge) = DigestChallenge::from_headers(
+            response
+                .headers()
+                .get_all(WWW_AUTHENTICATE)
+                .iter()
+                .filter_map(|value| value.to_str().ok()),
+        ) else {
+            return Ok(None);
+        };
+
+        let key = digest_key(username, retry.url());
+        let rejected = !challenge.stale
+            && self
+                .digest_sessions
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get(&key)
+                .is_some_and(|session| session.challenge().nonce == challenge.nonce);
+        if rejected {
+            return Ok(None);
+        }
+
+        let mut session = DigestSession::new(challenge);
+        let value = digest_authorization(&mut session, username, password, &retry);
+        let value = HeaderValue::try_from(value).map_err(|e| {
+            HttpClientError::Other(format!("Invalid Digest authorization header: {e}"))
+        })?;
+        retry.headers_mut().insert(AUTHORIZATION, value);
+        self.digest_sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, session);
+        Ok(Some(retry))
+    }
+
     /// Maps reqwest errors to domain `HttpClientError`.
     #[allow(clippy::needless_pass_by_value)]
-    fn map_error(error: reqwest::Error, timeout_ms: u64) -> HttpClientError {
+    fn map_error(error: reqwest::Error, timeout_ms: u64, proxied: bool) -> HttpClientError {
         if error.is_timeout() {
             return HttpClientError::Timeout { timeout_ms };
         }
 
+        if proxied && let Some(proxy_error) = Self::map_proxy_error(&error) {
+            return HttpClientError::ProxyError(proxy_error);
+        }
+
+        if let Some(tls_error) = Self::map_tls_error(&error) {
+            return tls_error;
+        }
+
         if error.is_connect() {
             let message = error.to_string();
             if message.to_lowercase().contains("dns") || message.to_lowercase().contains("resolve")
@@ -129,12 +440,77 @@ impl ReqwestHttpClient {
             return HttpClientError::ConnectionFailed(message);
         }
 
-        if error.is_redirect() {
-            return HttpClientError::TooManyRedirects { max: 10 };
+        HttpClientError::Other(error.to_string())
+    }
+
+    /// Recognizes failures to reach or authenticate with a proxy.
+    ///
+    /// Tunnel and SOCKS handshake errors are only visible in the error's
+    /// source chain, so the whole chain is inspected.
+    fn map_proxy_error(error: &reqwest::Error) -> Option<ProxyError> {
+        let message = error_chain(error);
+        let lower = message.to_lowercase();
+        if lower.contains("proxy authorization required")
+            || lower.contains("credentials not accepted")
+        {
+            return Some(ProxyError::AuthenticationFailed);
+        }
+        error
+            .is_connect()
+            .then_some(ProxyError::ConnectionFailed(message))
+    }
+
+    /// Recognizes failed TLS handshakes, such as untrusted certificates,
+    /// rejected client certificates or incompatible protocol versions.
+    ///
+    /// With TLS 1.3 a rejected client certificate only shows up once the
+    /// request is sent, so request errors are inspected as well. Only the
+    /// causes are matched, as the outer message contains the URL.
+    fn map_tls_error(error: &reqwest::Error) -> Option<HttpClientError> {
+        if !error.is_connect() && !error.is_request() {
+            return None;
         }
+        let causes = error.source().map(error_chain)?.to_lowercase();
+        ["certificate", "handshake", "alert", "tls"]
+            .iter()
+            .any(|keyword| causes.contains(keyword))
+            .then(|| HttpClientError::TlsError(error_chain(error)))
+    }
+}
 
-        HttpClientError::Other(error.to_string())
+/// Digest sessions are kept per user and origin.
+fn digest_key(username: &str, url: &Url) -> String {
+    format!("{username}@{}", url.origin().ascii_serialization())
+}
+
+/// Computes the Digest `Authorization` value for a request.
+fn digest_authorization(
+    session: &mut DigestSession,
+    username: &str,
+    password: &str,
+    request: &reqwest::Request,
+) -> String {
+    let url = request.url();
+    let uri = url.query().map_or_else(
+        || url.path().to_string(),
+        |query| format!("{}?{query}", url.path()),
+    );
+    let body = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .unwrap_or_default();
+    session.authorization(username, password, request.method().as_str(), &uri, body)
+}
+
+/// Formats an error with its whole source chain.
+fn error_chain(error: &dyn std::error::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message = format!("{message}: {cause}");
+        source = cause.source();
     }
+    message
 }
 
 impl Default for ReqwestHttpClient {
@@ -144,68 +520,345 @@ impl Default for ReqwestHttpClient {
     }
 }
 
+/// The parts of a request needed to send it, cloned so that sending does
+/// not borrow the request.
+struct Outgoing {
+    method: HttpMethod,
+    url: String,
+    headers: Vec<Header>,
+    body: RequestBody,
+    timeout_ms: u64,
+    proxy: Option<ProxyConfig>,
+    tls: Option<TlsConfig>,
+    auth: AuthConfig,
+    redirect_policy: RedirectPolicy,
+}
+
+impl From<&RequestSpec> for Outgoing {
+    fn from(request: &RequestSpec) -> Self {
+        Self {
+            method: request.method.clone(),
+            url: request.full_url(),
+            headers: request.enabled_headers().cloned().collect(),
+            body: request.body.clone(),
+            timeout_ms: request.timeout_ms,
+            proxy: request.proxy.clone(),
+            tls: request.tls.clone(),
+            auth: request.auth.clone(),
+            redirect_policy: request.redirect_policy,
+        }
+    }
+}
+
+/// A response whose headers arrived.
+struct Sent {
+    response: reqwest::Response,
+    /// Timing of the request that got the response.
+    phases: PhaseRecorder,
+    /// Redirects followed to reach the response.
+    redirects: Vec<RedirectHop>,
+}
+
+impl ReqwestHttpClient {
+    /// Sends a request and waits for the response headers.
+    ///
+    /// With `whole_body` the request timeout also covers reading the body;
+    /// otherwise it only covers waiting for the headers, for each request of
+    /// a redirect chain.
+    async fn send(&self, outgoing: Outgoing, whole_body: bool) -> Result<Sent, HttpClientError> {
+        let Outgoing {
+            method,
+            url,
+            headers,
+            body,
+            timeout_ms,
+            proxy: request_proxy,
+            tls: request_tls,
+            auth,
+            redirect_policy,
+        } = outgoing;
+
+        // Parse URL
+        let parsed_url =
+            Url::parse(&url).map_err(|e| HttpClientError::InvalidUrl(format!("{e}: {url}")))?;
+
+        // Pick the proxy, if any
+        let proxy = self
+            .proxy_resolver
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .resolve(request_proxy.as_ref(), &parsed_url)?;
+        let tls = request_tls.unwrap_or_else(|| {
+            self.tls_config
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        });
+        let client = self.client_for(proxy.as_ref(), &tls)?;
+
+        // Start timing
+        let phases = PhaseRecorder::start();
+
+        // Build request
+        let timeout = Duration::from_millis(timeout_ms);
+        let mut builder = client.request(Self::to_reqwest_method(&method)?, parsed_url);
+        if whole_body {
+            builder = builder.timeout(timeout);
+        }
+
+        // Add headers
+        for header in &headers {
+            builder = builder.header(&header.name, &header.value);
+        }
+
+        // Add Content-Type if body has one and not already set
+        if let Some(content_type) = body.content_type() {
+            let has_content_type = headers
+                .iter()
+                .any(|h| h.name.eq_ignore_ascii_case("content-type"));
+            if !has_content_type {
+                builder = builder.header("Content-Type", content_type);
+            }
+        }
+
+        // Add body
+        builder = Self::build_body(builder, &body)?;
+
+        // Sign the final request if the auth scheme requires it
+        let map_error = |e| Self::map_error(e, timeout_ms, proxy.is_some());
+        let mut built = builder.build().map_err(map_error)?;
+        Self::sign(&mut built, &auth)?;
+        let retry = self.authorize_digest(&mut built, &auth);
+
+        // Execute request, answering a Digest challenge once
+        let execute = |request| async {
+            phases
+                .send(tokio::time::timeout(timeout, client.execute(request)))
+                .await
+                .map_err(|_| HttpClientError::Timeout { timeout_ms })?
+                .map_err(map_error)
+        };
+        let mut previous = built.try_clone();
+        let mut response = execute(built).await?;
+        if let Some(retry) = retry
+            && let Some(retry) = self.answer_digest_challenge(&response, retry, &auth)?
+        {
+            previous = retry.try_clone();
+            response = execute(retry).await?;
+        }
+
+        // Follow redirects, recording each hop
+        let mut redirects = Vec::new();
+        while redirect_policy.follow
+            && let Some(last) = previous.as_ref()
+            && let Some(mut next) = redirect_request(last, &response)
+        {
+            if redirects.len() >= redirect_policy.max_redirects as usize {
+                return Err(HttpClientError::TooManyRedirects {
+                    max: redirect_policy.max_redirects,
+                });
+            }
+            redirects.push(RedirectHop {
+                status: response.status().as_u16(),
+                url: response.url().to_string(),
+                location: next.url().to_string(),
+                headers: response_headers(&response),
+                timing: phases.redirected(),
+            });
+            Self::sign_redirect(&mut next, last, &auth)?;
+            previous = next.try_clone();
+            response = execute(next).await?;
+        }
+
+        if proxy.is_some()
+            && response.status() == reqwest::StatusCode::PROXY_AUTHENTICATION_REQUIRED
+        {
+            return Err(ProxyError::AuthenticationFailed.into());
+        }
+        Ok(Sent {
+            response,
+            phases,
+            redirects,
+        })
+    }
+}
+
+/// Returns the request that follows a redirect response, or `None` if the
+/// response is not a redirect or the request cannot be repeated.
+///
+/// Like browsers, a 303 response, or a 301 or 302 response to a POST, is
+/// followed with a GET request without body. Credentials are only kept for
+/// redirects within the same origin.
+fn redirect_request(
+    previous: &reqwest::Request,
+    response: &reqwest::Response,
+) -> Option<reqwest::Request> {
+    let status = response.status();
+    if !matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    ) {
+        return None;
+    }
+    let location = response.headers().get(LOCATION)?.to_str().ok()?;
+    let url = response.url().join(location).ok()?;
+
+    let method = previous.method();
+    let to_get = (status == StatusCode::SEE_OTHER && method != Method::HEAD)
+        || (status != StatusCode::TEMPORARY_REDIRECT
+            && status != StatusCode::PERMANENT_REDIRECT
+            && method == Method::POST);
+    let mut next = if to_get {
+        let mut next = reqwest::Request::new(Method::GET, url);
+        *next.headers_mut() = previous.headers().clone();
+        for header in [CONTENT_TYPE, CONTENT_LENGTH, TRANSFER_ENCODING] {
+            next.headers_mut().remove(header);
+        }
+        *next.timeout_mut() = previous.timeout().copied();
+        next
+    } else {
+        let mut next = previous.try_clone()?;
+        *next.url_mut() = url;
+        next
+    };
+    if next.url().origin() != previous.url().origin() {
+        for header in [AUTHORIZATION, PROXY_AUTHORIZATION, COOKIE] {
+            next.headers_mut().remove(header);
+        }
+    }
+    Some(next)
+}
+
+/// Collects the response headers.
+fn response_headers(response: &reqwest::Response) -> HashMap<String, String> {
+    response
+        .headers()
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("<binary>").to_string()))
+        .collect()
+}
+
+/// Decodes a body chunk as UTF-8, keeping a sequence split at its end for
+/// the next chunk.
+fn decode_chunk(pending: &mut Vec<u8>, chunk: &[u8]) -> String {
+    pending.extend_from_slice(chunk);
+    let valid = match std::str::from_utf8(pending) {
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => pending.len(),
+    };
+    let text = String::from_utf8_lossy(&pending[..valid]).into_owned();
+    pending.drain(..valid);
+    text
+}
+
+/// Minimum time between download progress reports.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Writes a response body to the download file, keeping a preview in memory.
+///
+/// The timeout does not apply to the body, as large downloads take long. A
+/// cancelled or failed download removes the partial file.
+async fn download(
+    sent: Sent,
+    options: &DownloadOptions,
+    updates: &mpsc::UnboundedSender<ResponseStreamEvent>,
+    cancel: &mut CancellationReceiver,
+) -> Result<ResponseSpec, HttpClientError> {
+    let Sent {
+        mut response,
+        phases,
+        redirects,
+    } = sent;
+    let status = response.status().as_u16();
+    let headers = response_headers(&response);
+    let total = response.content_length();
+    let write_error = |e: std::io::Error| {
+        HttpClientError::Other(format!("Failed to write {}: {e}", options.path.display()))
+    };
+
+    let mut file = tokio::fs::File::create(&options.path)
+        .await
+        .map_err(write_error)?;
+    let body_start = Instant::now();
+    let mut preview = Vec::new();
+    let mut received = 0u64;
+    let mut reported = body_start;
+    let result = loop {
+        let chunk = tokio::select! {
+            chunk = response.chunk() => chunk,
+            () = cancel.cancelled() => break Err(HttpClientError::Cancelled),
+        };
+        let progress = |received| DownloadProgress {
+            received,
+            total,
+            elapsed: body_start.elapsed(),
+        };
+        match chunk {
+            Ok(Some(chunk)) => {
+                if let Err(e) = file.write_all(&chunk).await {
+                    break Err(write_error(e));
+                }
+                let keep = options
+                    .preview_bytes
+                    .saturating_sub(preview.len())
+                    .min(chunk.len());
+                preview.extend_from_slice(&chunk[..keep]);
+                received += chunk.len() as u64;
+                if reported.elapsed() >= PROGRESS_INTERVAL {
+                    reported = Instant::now();
+                    let _ = updates.send(ResponseStreamEvent::Progress(progress(received)));
+                }
+            }
+            Ok(None) => {
+                let _ = updates.send(ResponseStreamEvent::Progress(progress(received)));
+                break file.flush().await.map_err(write_error);
+            }
+            Err(e) => break Err(HttpClientError::Other(format!("Failed to read body: {e}"))),
+        }
+    };
+    drop(file);
+
+    if let Err(error) = result {
+        let _ = tokio::fs::remove_file(&options.path).await;
+        return Err(error);
+    }
+    let size = usize::try_from(received).unwrap_or(usize::MAX);
+    let mut response = ResponseSpec::new(status, headers, preview, phases.elapsed())
+        .with_saved_file(&options.path, size);
+    response.timing = phases.timing();
+    response.redirects = redirects;
+    Ok(response)
+}
+
 impl HttpClient for ReqwestHttpClient {
     fn execute(
         &self,
         request: &RequestSpec,
     ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>> {
-        // Clone what we need to move into the async block
-        let method = request.method;
-        let url = request.full_url();
-        let headers: Vec<_> = request.enabled_headers().cloned().collect();
-        let body = request.body.clone();
-        let timeout_ms = request.timeout_ms;
+        let outgoing = Outgoing::from(request);
+        let download_options = request.download.clone();
 
         Box::pin(async move {
-            // Parse URL
-            let parsed_url =
-                Url::parse(&url).map_err(|e| HttpClientError::InvalidUrl(format!("{e}: {url}")))?;
-
-            // Start timing
-            let start = Instant::now();
-
-            // Build request
-            let mut builder = self
-                .client
-                .request(Self::to_reqwest_method(method), parsed_url)
-                .timeout(Duration::from_millis(timeout_ms));
-
-            // Add headers
-            for header in &headers {
-                builder = builder.header(&header.name, &header.value);
-            }
-
-            // Add Content-Type if body has one and not already set
-            if let Some(content_type) = body.content_type() {
-                let has_content_type = headers
-                    .iter()
-                    .any(|h| h.name.eq_ignore_ascii_case("content-type"));
-                if !has_content_type {
-                    builder = builder.header("Content-Type", content_type);
-                }
+            if let Some(options) = download_options {
+                let sent = self.send(outgoing, false).await?;
+                let (_token, mut cancel) = CancellationToken::new();
+                let (updates, _) = mpsc::unbounded_channel();
+                return download(sent, &options, &updates, &mut cancel).await;
             }
 
-            // Add body
-            builder = Self::build_body(builder, &body)?;
-
-            // Execute request
-            let response = builder
-                .send()
-                .await
-                .map_err(|e| Self::map_error(e, timeout_ms))?;
-
-            // Calculate duration
-            let duration = start.elapsed();
+            let Sent {
+                response,
+                phases,
+                redirects,
+            } = self.send(outgoing, true).await?;
 
             // Extract response data
             let status = response.status().as_u16();
-
-            // Collect headers
-            let response_headers: HashMap<String, String> = response
-                .headers()
-                .iter()
-                .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("<binary>").to_string()))
-                .collect();
+            let headers = response_headers(&response);
 
             // Read body
             let body_bytes = response
@@ -214,37 +867,1121 @@ impl HttpClient for ReqwestHttpClient {
                 .map_err(|e| HttpClientError::Other(format!("Failed to read body: {e}")))?
                 .to_vec();
 
-            Ok(ResponseSpec::new(
+            let mut response = ResponseSpec::new(status, headers, body_bytes, phases.elapsed());
+            response.timing = phases.timing();
+            response.redirects = redirects;
+            if let Some(format) = response.stream_format() {
+                response.events = StreamParser::parse_all(format, &response.body_bytes, Utc::now());
+            }
+            Ok(response)
+        })
+    }
+
+    fn execute_streaming(
+        &self,
+        request: &RequestSpec,
+        updates: mpsc::UnboundedSender<ResponseStreamEvent>,
+        mut cancel: CancellationReceiver,
+    ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>> {
+        let outgoing = Outgoing::from(request);
+        let download_options = request.download.clone();
+
+        Box::pin(async move {
+            let sent = tokio::select! {
+                result = self.send(outgoing, false) => result?,
+                () = cancel.cancelled() => return Err(HttpClientError::Cancelled),
+            };
+
+            let status = sent.response.status().as_u16();
+            let headers = response_headers(&sent.response);
+            let _ = updates.send(ResponseStreamEvent::Started {
                 status,
-                response_headers,
-                body_bytes,
-                duration,
-            ))
+                headers: headers.clone(),
+            });
+            if let Some(options) = download_options {
+                return download(sent, &options, &updates, &mut cancel).await;
+            }
+            let Sent {
+                mut response,
+                phases,
+                redirects,
+            } = sent;
+
+            let mut parser = headers
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                .and_then(|(_, value)| StreamFormat::from_content_type(value))
+                .map(StreamParser::new);
+            let mut body = Vec::new();
+            let mut events = Vec::new();
+            let mut pending = Vec::new();
+            loop {
+                let chunk = tokio::select! {
+                    chunk = response.chunk() => chunk.map_err(|e| {
+                        HttpClientError::Other(format!("Failed to read body: {e}"))
+                    })?,
+                    // Stopping a stream keeps what arrived so far
+                    () = cancel.cancelled() => break,
+                };
+                let received_at = Utc::now();
+                let new_events = match (chunk, parser.as_mut()) {
+                    (Some(chunk), Some(parser)) => {
+                        body.extend_from_slice(&chunk);
+                        parser.push(&chunk, received_at)
+                    }
+                    (Some(chunk), None) => {
+                        body.extend_from_slice(&chunk);
+                        let text = decode_chunk(&mut pending, &chunk);
+                        if !text.is_empty() {
+                            let _ = updates.send(ResponseStreamEvent::Chunk(text));
+                        }
+                        continue;
+                    }
+                    (None, Some(parser)) => {
+                        let rest = parser.finish(received_at);
+                        for event in rest {
+                            let _ = updates.send(ResponseStreamEvent::Event(event.clone()));
+                            events.push(event);
+                        }
+                        break;
+                    }
+                    (None, None) => break,
+                };
+                for event in new_events {
+                    let _ = updates.send(ResponseStreamEvent::Event(event.clone()));
+                    events.push(event);
+                }
+            }
+
+            let mut response = ResponseSpec::new(status, headers, body, phases.elapsed());
+            response.events = events;
+            response.timing = phases.timing();
+            response.redirects = redirects;
+            Ok(response)
         })
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_rustls::TlsAcceptor;
+    use tokio_rustls::rustls::pki_types::pem::PemObject;
+    use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+    use tokio_rustls::rustls::server::WebPkiClientVerifier;
+    use tokio_rustls::rustls::{
+        RootCertStore, ServerConfig, SupportedProtocolVersion, crypto, version,
+    };
+    use vortex_application::ports::CancellationToken;
+    use vortex_domain::cookie::{Cookie, CookieJar};
+    use vortex_domain::proxy::GlobalProxySettings;
+    use vortex_domain::tls::{ClientCertificate, TlsVersion};
+
     use super::*;
+    use crate::http::ProxyEnvironment;
 
-    #[test]
-    fn test_to_reqwest_method() {
+    /// Starts an HTTP server that logs in on `/login` with a redirect to
+    /// `/home` and records the `Cookie` header of every request by path.
+    async fn cookie_server() -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let path = head.split(' ').nth(1).unwrap_or("/").to_string();
+                let cookie = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .starts_with("cookie:")
+                            .then(|| line[7..].trim().to_string())
+                    })
+                    .unwrap_or_default();
+                log.lock().unwrap().push((path.clone(), cookie));
+
+                let response = if path == "/login" {
+                    "HTTP/1.1 302 Found\r\nLocation: /home\r\nSet-Cookie: session=abc; Path=/\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                } else {
+                    "HTTP/1.1 200 OK\r\nSet-Cookie: theme=dark\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, seen)
+    }
+
+    #[tokio::test]
+    async fn test_cookie_jar_follows_redirects() {
+        let (base, seen) = cookie_server().await;
+        let jar = SharedCookieJar::default();
+        let client = ReqwestHttpClient::with_cookie_jar(jar.clone()).unwrap();
+
+        let response = client
+            .execute(&RequestSpec::get(format!("{base}/login")))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        // The redirect target already received the cookie set by the 302
+        let requests = seen.lock().unwrap().clone();
+        assert_eq!(requests[0], ("/login".to_string(), String::new()));
+        assert_eq!(
+            requests[1],
+            ("/home".to_string(), "session=abc".to_string())
+        );
+
+        let names: Vec<_> = jar
+            .snapshot()
+            .sorted()
+            .iter()
+            .map(|c| c.name.clone())
+            .collect();
+        assert_eq!(names, vec!["session", "theme"]);
+    }
+
+    /// Starts an HTTP server with a redirect chain `/old` -> `/step` ->
+    /// `/final`, and `/keep` -> `/final` with a 308. Records the method and
+    /// path of every request.
+    async fn redirect_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let request_line = head.lines().next().unwrap_or_default();
+                let path = request_line.split(' ').nth(1).unwrap_or("/").to_string();
+                log.lock()
+                    .unwrap()
+                    .push(request_line.rsplit_once(' ').unwrap().0.to_string());
+
+                let response = match path.as_str() {
+                    "/old" => {
+                        "HTTP/1.1 302 Found\r\nLocation: /step\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    }
+                    "/step" => {
+                        "HTTP/1.1 307 Temporary Redirect\r\nLocation: /final?x=1\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    }
+                    "/keep" => {
+                        "HTTP/1.1 308 Permanent Redirect\r\nLocation: /final\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    }
+                    _ => "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, seen)
+    }
+
+    #[tokio::test]
+    async fn test_redirect_chain_is_recorded() {
+        let (base, seen) = redirect_server().await;
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let request = RequestSpec::post(format!("{base}/old")).with_body(RequestBody::json("{}"));
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 200);
+
+        let hops: Vec<_> = response
+            .redirects
+            .iter()
+            .map(|hop| (hop.status, hop.location.clone()))
+            .collect();
+        assert_eq!(
+            hops,
+            vec![
+                (302, format!("{base}/step")),
+                (307, format!("{base}/final?x=1")),
+            ]
+        );
+        assert_eq!(response.redirects[0].url, format!("{base}/old"));
+        assert_eq!(
+            response.redirects[0]
+                .headers
+                .get("location")
+                .map(String::as_str),
+            Some("/step")
+        );
+        assert!(response.redirects[0].timing.wait.is_some());
+
+        // The 302 turned the POST into a GET, which the 307 kept
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["POST /old", "GET /step", "GET /final?x=1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redirect_308_keeps_method() {
+        let (base, seen) = redirect_server().await;
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let request = RequestSpec::post(format!("{base}/keep")).with_body(RequestBody::json("{}"));
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(*seen.lock().unwrap(), vec!["POST /keep", "POST /final"]);
+    }
+
+    #[tokio::test]
+    async fn test_redirect_policy() {
+        let (base, _) = redirect_server().await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let request = RequestSpec::get(format!("{base}/old"));
+
+        let limited = request
+            .clone()
+            .with_redirect_policy(RedirectPolicy::limited(1));
+        let result = client.execute(&limited).await;
+        assert!(matches!(
+            result,
+            Err(HttpClientError::TooManyRedirects { max: 1 })
+        ));
+
+        let none = request.with_redirect_policy(RedirectPolicy::none());
+        let response = client.execute(&none).await.unwrap();
+        assert_eq!(response.status, 302);
+        assert!(response.redirects.is_empty());
+    }
+
+    /// Starts a server that answers with the given content type and writes
+    /// the body parts with a pause between them. With `hold_open` the
+    /// connection stays open after the last part.
+    async fn stream_server(
+        content_type: &'static str,
+        parts: &'static [&'static str],
+        hold_open: bool,
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let _ = stream.read(&mut buf).await.unwrap();
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nConnection: close\r\n\r\n"
+                );
+                stream.write_all(head.as_bytes()).await.unwrap();
+                for part in parts {
+                    stream.write_all(part.as_bytes()).await.unwrap();
+                    stream.flush().await.unwrap();
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                }
+                if hold_open {
+                    tokio::time::sleep(Duration::from_secs(30)).await;
+                }
+            }
+        });
+
+        base
+    }
+
+    #[tokio::test]
+    async fn test_streaming_server_sent_events() {
+        let base = stream_server(
+            "text/event-stream",
+            &[
+                "id: 1\ndata: first\n\n",
+                "event: done\nda",
+                "ta: second\n\n",
+            ],
+            false,
+        )
+        .await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let (_token, cancel) = CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let response = client
+            .execute_streaming(&RequestSpec::get(base), tx, cancel)
+            .await
+            .unwrap();
+        assert_eq!(response.events.len(), 2);
+        assert_eq!(response.events[1].id.as_deref(), Some("1"));
+        assert_eq!(response.events[1].event_type(), "done");
+        assert!(response.events[0].received_at < response.events[1].received_at);
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(ResponseStreamEvent::Started { status: 200, .. })
+        ));
+        let mut data = Vec::new();
+        while let Some(ResponseStreamEvent::Event(event)) = rx.recv().await {
+            data.push(event.data);
+        }
+        assert_eq!(data, vec!["first", "second"]);
+    }
+
+    #[tokio::test]
+    async fn test_streaming_cancel_keeps_events() {
+        let base = stream_server("application/x-ndjson", &["{\"n\": 1}\n"], true).await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let (token, cancel) = CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let request = RequestSpec::get(base);
+        let execution = client.execute_streaming(&request, tx, cancel);
+        let stop = async {
+            while let Some(update) = rx.recv().await {
+                if matches!(update, ResponseStreamEvent::Event(_)) {
+                    token.cancel();
+                }
+            }
+        };
+        let (response, ()) = tokio::join!(execution, stop);
+
+        let response = response.unwrap();
+        assert_eq!(response.events.len(), 1);
+        assert_eq!(response.events[0].data_as_json().unwrap()["n"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_streaming_text_chunks() {
+        let base = stream_server("text/plain", &["hello ", "caf\u{e9}"], false).await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let (_token, cancel) = CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let response = client
+            .execute_streaming(&RequestSpec::get(base.clone()), tx, cancel)
+            .await
+            .unwrap();
+        assert_eq!(response.body, "hello café");
+        assert!(response.events.is_empty());
+
+        let mut text = String::new();
+        while let Some(update) = rx.recv().await {
+            if let ResponseStreamEvent::Chunk(chunk) = update {
+                text.push_str(&chunk);
+            }
+        }
+        assert_eq!(text, "hello café");
+    }
+
+    #[tokio::test]
+    async fn test_buffered_response_collects_events() {
+        let base = stream_server("text/event-stream", &["data: a\n\ndata: b\n\n"], false).await;
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let response = client.execute(&RequestSpec::get(base)).await.unwrap();
+        let data: Vec<_> = response.events.iter().map(|e| e.data.as_str()).collect();
+        assert_eq!(data, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_response_timing_phases() {
+        let base = stream_server("text/plain", &["first", "second"], false).await;
+        let url = base.replace("127.0.0.1", "localhost");
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let response = client.execute(&RequestSpec::get(url)).await.unwrap();
+        let timing = response.timing;
+        assert!(timing.dns.is_some());
+        assert!(timing.connect.is_some());
+        assert!(timing.wait.is_some());
+        // The second part follows the first after a pause
+        assert!(timing.download.unwrap() >= Duration::from_millis(20));
+        assert!(timing.total() <= response.duration);
+    }
+
+    #[tokio::test]
+    async fn test_download_to_file() {
+        let base = stream_server("text/csv", &["id,name\n", "1,alpha\n", "2,beta\n"], false).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.csv");
+        let client = ReqwestHttpClient::new().unwrap();
+        let (_token, cancel) = CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let request = RequestSpec::get(base)
+            .with_download(DownloadOptions::new(&path).with_preview_bytes(10));
+        let response = client
+            .execute_streaming(&request, tx, cancel)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "id,name\n1,alpha\n2,beta\n"
+        );
+        assert_eq!(response.body, "id,name\n1,");
+        assert_eq!(response.size, 23);
+        assert!(response.body_truncated);
+        assert_eq!(response.saved_to, Some(path));
+
+        let mut last = None;
+        while let Some(update) = rx.recv().await {
+            if let ResponseStreamEvent::Progress(progress) = update {
+                last = Some(progress);
+            }
+        }
+        assert_eq!(last.unwrap().received, 23);
+    }
+
+    #[tokio::test]
+    async fn test_download_cancel_removes_file() {
+        let base = stream_server("application/octet-stream", &["partial"], true).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("large.bin");
+        let client = ReqwestHttpClient::new().unwrap();
+        let (token, cancel) = CancellationToken::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+
+        let request = RequestSpec::get(base).with_download(DownloadOptions::new(&path));
+        let execution = client.execute_streaming(&request, tx, cancel);
+        let stop = async {
+            while let Some(update) = rx.recv().await {
+                if matches!(update, ResponseStreamEvent::Started { .. }) {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    token.cancel();
+                }
+            }
+        };
+        let (response, ()) = tokio::join!(execution, stop);
+
+        assert!(matches!(response, Err(HttpClientError::Cancelled)));
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_buffered_download_is_not_kept_in_memory() {
+        let base = stream_server("text/plain", &["0123456789"], false).await;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("body.txt");
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let request =
+            RequestSpec::get(base).with_download(DownloadOptions::new(&path).with_preview_bytes(4));
+        let response = client.execute(&request).await.unwrap();
+
+        assert_eq!(response.body, "0123");
+        assert_eq!(response.size, 10);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "0123456789");
+    }
+
+    #[tokio::test]
+    async fn test_cookie_jar_attaches_stored_cookies() {
+        let (base, seen) = cookie_server().await;
+        let mut stored = CookieJar::new();
+        stored.add(Cookie::new("token", "xyz", "127.0.0.1"));
+        stored.add(Cookie::new("other", "1", "example.com"));
+        let client = ReqwestHttpClient::with_cookie_jar(SharedCookieJar::new(stored)).unwrap();
+
+        client
+            .execute(&RequestSpec::get(format!("{base}/profile")))
+            .await
+            .unwrap();
+
+        let requests = seen.lock().unwrap().clone();
         assert_eq!(
-            ReqwestHttpClient::to_reqwest_method(HttpMethod::Get),
-            Method::GET
+            requests,
+            vec![("/profile".to_string(), "token=xyz".to_string())]
         );
+    }
+
+    #[tokio::test]
+    async fn test_aws_sigv4_signs_request() {
+        // Echoes the request head back as the body
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0; 4096];
+            let n = stream.read(&mut buf).await.unwrap();
+            let head = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{head}",
+                head.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let mut request = RequestSpec::get(format!("{base}/bucket/key?list-type=2"));
+        request.auth = AuthConfig::AwsSigV4 {
+            access_key: "AKID".to_string(),
+            secret_key: "secret".to_string(),
+            session_token: Some("session".to_string()),
+            region: "us-east-1".to_string(),
+            service: "s3".to_string(),
+        };
+        let response = ReqwestHttpClient::new()
+            .unwrap()
+            .execute(&request)
+            .await
+            .unwrap();
+
+        let head = response.body;
+        assert!(head.contains("authorization: aws4-hmac-sha256 credential=akid/"));
+        assert!(
+            head.contains(
+                "signedheaders=host;x-amz-content-sha256;x-amz-date;x-amz-security-token,"
+            )
+        );
+        assert!(head.contains("x-amz-security-token: session"));
+        assert!(head.contains("x-amz-date: "));
+    }
+
+    /// Starts an HTTP server that redirects `/from` to `location` with a
+    /// 307 and records the lowercase head of every request.
+    async fn head_server(location: &str) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        let location = location.to_string();
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+                let response = if head.starts_with("get /from ") {
+                    format!(
+                        "HTTP/1.1 307 Temporary Redirect\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    )
+                } else {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                        .to_string()
+                };
+                log.lock().unwrap().push(head);
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, seen)
+    }
+
+    fn sigv4_request(url: String) -> RequestSpec {
+        let mut request = RequestSpec::get(url);
+        request.auth = AuthConfig::AwsSigV4 {
+            access_key: "AKID".to_string(),
+            secret_key: "secret".to_string(),
+            session_token: Some("session".to_string()),
+            region: "us-east-1".to_string(),
+            service: "s3".to_string(),
+        };
+        request
+    }
+
+    #[tokio::test]
+    async fn test_aws_sigv4_signs_each_redirect() {
+        let (base, seen) = head_server("/to").await;
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let response = client
+            .execute(&sigv4_request(format!("{base}/from")))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        // The redirected request carries a signature of its own path
+        let heads = seen.lock().unwrap().clone();
+        let signature = |head: &str| {
+            head.split("signature=")
+                .nth(1)
+                .and_then(|rest| rest.lines().next())
+                .map(ToString::to_string)
+        };
+        assert!(heads[1].starts_with("get /to "));
+        let (first, second) = (signature(&heads[0]), signature(&heads[1]));
+        assert!(first.is_some() && second.is_some());
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_aws_sigv4_dropped_on_cross_origin_redirect() {
+        let (other, other_seen) = head_server("/").await;
+        let (base, _) = head_server(&format!("{other}/to")).await;
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let response = client
+            .execute(&sigv4_request(format!("{base}/from")))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        let head = other_seen.lock().unwrap()[0].clone();
+        assert!(head.starts_with("get /to "));
+        for header in SIGNATURE_HEADERS {
+            assert!(!head.contains(&format!("{header}:")), "{header} was sent");
+        }
+    }
+
+    /// Starts a server that requires Digest auth (MD5, `qop=auth`) for user
+    /// `admin` with password `secret`, checking responses independently.
+    /// Records the nonce count of each request (`-` when unauthenticated).
+    async fn digest_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        use md5::{Digest as _, Md5};
+
+        let md5 = |input: String| {
+            Md5::digest(input.as_bytes())
+                .iter()
+                .fold(String::new(), |out, byte| format!("{out}{byte:02x}"))
+        };
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let method = head.split(' ').next().unwrap_or_default().to_string();
+                let params: HashMap<String, String> = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("authorization: Digest "))
+                    .map(|value| {
+                        value
+                            .split(", ")
+                            .filter_map(|pair| pair.split_once('='))
+                            .map(|(k, v)| (k.to_string(), v.trim_matches('"').to_string()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let valid = !params.is_empty() && {
+                    let ha1 = md5(format!("{}:test:secret", params["username"]));
+                    let ha2 = md5(format!("{method}:{}", params["uri"]));
+                    let expected = md5(format!(
+                        "{ha1}:n1:{}:{}:auth:{ha2}",
+                        params["nc"], params["cnonce"]
+                    ));
+                    params["username"] == "admin" && params["response"] == expected
+                };
+                log.lock()
+                    .unwrap()
+                    .push(params.get("nc").cloned().unwrap_or_else(|| "-".to_string()));
+
+                let response = if valid {
+                    "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                } else {
+                    "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Digest realm=\"test\", qop=\"auth\", nonce=\"n1\", algorithm=MD5\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, seen)
+    }
+
+    #[tokio::test]
+    async fn test_digest_auth_challenge_and_reuse() {
+        let (base, seen) = digest_server().await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let mut request = RequestSpec::get(format!("{base}/status?verbose=1"));
+        request.auth = AuthConfig::digest("admin", "secret");
+
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 200);
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 200);
+
+        // The second request reuses the challenge with the next nonce count
+        assert_eq!(*seen.lock().unwrap(), vec!["-", "00000001", "00000002"]);
+    }
+
+    #[tokio::test]
+    async fn test_digest_auth_wrong_password() {
+        let (base, seen) = digest_server().await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let mut request = RequestSpec::get(format!("{base}/status"));
+        request.auth = AuthConfig::digest("admin", "wrong");
+
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 401);
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.status, 401);
+
+        // A rejected nonce is not retried again
+        assert_eq!(*seen.lock().unwrap(), vec!["-", "00000001", "00000002"]);
+    }
+
+    /// Starts an HTTP proxy that answers every request itself and records
+    /// the request line and `Proxy-Authorization` header.
+    async fn http_proxy(status: &'static str) -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 4096];
+                let n = stream.read(&mut buf).await.unwrap();
+                let head = String::from_utf8_lossy(&buf[..n]).to_string();
+                let request_line = head.lines().next().unwrap_or_default().to_string();
+                let auth = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .starts_with("proxy-authorization:")
+                            .then(|| line[20..].trim().to_string())
+                    })
+                    .unwrap_or_default();
+                log.lock().unwrap().push((request_line, auth));
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: 7\r\nConnection: close\r\n\r\nproxied"
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, seen)
+    }
+
+    async fn read_field(stream: &mut TcpStream) -> String {
+        let len = stream.read_u8().await.unwrap();
+        let mut field = vec![0; usize::from(len)];
+        stream.read_exact(&mut field).await.unwrap();
+        String::from_utf8(field).unwrap()
+    }
+
+    /// Starts a SOCKS5 proxy that requires username/password authentication,
+    /// records `user:pass@host:port` and answers the tunneled HTTP request.
+    async fn socks5_proxy() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                // Greeting: pick username/password authentication
+                let mut greeting = [0; 2];
+                stream.read_exact(&mut greeting).await.unwrap();
+                let mut methods = vec![0; usize::from(greeting[1])];
+                stream.read_exact(&mut methods).await.unwrap();
+                assert!(methods.contains(&2));
+                stream.write_all(&[5, 2]).await.unwrap();
+
+                assert_eq!(stream.read_u8().await.unwrap(), 1);
+                let user = read_field(&mut stream).await;
+                let pass = read_field(&mut stream).await;
+                stream.write_all(&[1, 0]).await.unwrap();
+
+                // Connect request with a domain name (remote DNS)
+                let mut request = [0; 4];
+                stream.read_exact(&mut request).await.unwrap();
+                assert_eq!(request[3], 3);
+                let host = read_field(&mut stream).await;
+                let port = stream.read_u16().await.unwrap();
+                stream
+                    .write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0])
+                    .await
+                    .unwrap();
+                log.lock()
+                    .unwrap()
+                    .push(format!("{user}:{pass}@{host}:{port}"));
+
+                let mut buf = vec![0; 4096];
+                let _ = stream.read(&mut buf).await.unwrap();
+                stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nsocks",
+                    )
+                    .await
+                    .unwrap();
+            }
+        });
+
+        (address, seen)
+    }
+
+    fn global_proxy(config: ProxyConfig) -> ProxyResolver {
+        let settings = GlobalProxySettings {
+            http_proxy: config,
+            ..GlobalProxySettings::default()
+        };
+        ProxyResolver::with_environment(settings, ProxyEnvironment::default())
+    }
+
+    #[tokio::test]
+    async fn test_global_http_proxy_with_auth() {
+        let (proxy, seen) = http_proxy("200 OK").await;
+        let client = ReqwestHttpClient::new()
+            .unwrap()
+            .with_proxy_resolver(global_proxy(
+                ProxyConfig::with_url(proxy).with_auth("user", "pass"),
+            ));
+
+        let response = client
+            .execute(&RequestSpec::get("http://api.example.test/users"))
+            .await
+            .unwrap();
+        assert_eq!(response.body_as_string_lossy(), "proxied");
+
+        let requests = seen.lock().unwrap().clone();
         assert_eq!(
-            ReqwestHttpClient::to_reqwest_method(HttpMethod::Post),
-            Method::POST
+            requests,
+            vec![(
+                "GET http://api.example.test/users HTTP/1.1".to_string(),
+                "Basic dXNlcjpwYXNz".to_string()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_request_socks5_proxy_with_auth() {
+        let (proxy, seen) = socks5_proxy().await;
+        let client = ReqwestHttpClient::new().unwrap();
+        let request = RequestSpec::get("http://api.example.test:8080/users").with_proxy(
+            ProxyConfig::with_url(format!("socks5h://{proxy}")).with_auth("alice", "s3cret"),
         );
+
+        let response = client.execute(&request).await.unwrap();
+        assert_eq!(response.body_as_string_lossy(), "socks");
         assert_eq!(
-            ReqwestHttpClient::to_reqwest_method(HttpMethod::Put),
-            Method::PUT
+            seen.lock().unwrap().clone(),
+            vec!["alice:s3cret@api.example.test:8080".to_string()]
         );
+    }
+
+    #[tokio::test]
+    async fn test_proxy_bypass_goes_direct() {
+        let (base, seen) = cookie_server().await;
+        // Nothing listens on the proxy port, so only a direct request succeeds
+        let client = ReqwestHttpClient::new()
+            .unwrap()
+            .with_proxy_resolver(global_proxy(
+                ProxyConfig::with_url("http://127.0.0.1:9")
+                    .with_bypass(vec!["127.0.0.1".to_string()]),
+            ));
+
+        let response = client
+            .execute(&RequestSpec::get(format!("{base}/direct")))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(seen.lock().unwrap()[0].0, "/direct");
+    }
+
+    #[tokio::test]
+    async fn test_proxy_errors() {
+        let client = ReqwestHttpClient::new().unwrap();
+        let target = "http://api.example.test/users";
+
+        let unreachable =
+            RequestSpec::get(target).with_proxy(ProxyConfig::with_url("http://127.0.0.1:9"));
+        assert!(matches!(
+            client.execute(&unreachable).await,
+            Err(HttpClientError::ProxyError(ProxyError::ConnectionFailed(_)))
+        ));
+
+        let (proxy, _) = http_proxy("407 Proxy Authentication Required").await;
+        let rejected = RequestSpec::get(target).with_proxy(ProxyConfig::with_url(proxy));
+        assert!(matches!(
+            client.execute(&rejected).await,
+            Err(HttpClientError::ProxyError(
+                ProxyError::AuthenticationFailed
+            ))
+        ));
+
+        let mut missing_url = ProxyConfig::new();
+        missing_url.enabled = true;
+        let invalid = RequestSpec::get(target).with_proxy(missing_url);
+        assert!(matches!(
+            client.execute(&invalid).await,
+            Err(HttpClientError::ProxyError(ProxyError::MissingUrl))
+        ));
+    }
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/tls")
+            .join(name)
+    }
+
+    /// Starts an HTTPS server with a certificate for `localhost` issued by
+    /// the test CA. With `require_client_cert` only clients presenting a
+    /// certificate from the same CA are accepted.
+    async fn tls_server(
+        require_client_cert: bool,
+        versions: &[&'static SupportedProtocolVersion],
+    ) -> u16 {
+        let certs = CertificateDer::pem_file_iter(fixture("server.pem"))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let key = PrivateKeyDer::from_pem_file(fixture("server.key")).unwrap();
+        let provider = Arc::new(crypto::aws_lc_rs::default_provider());
+
+        let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+            .with_protocol_versions(versions)
+            .unwrap();
+        let builder = if require_client_cert {
+            let mut roots = RootCertStore::empty();
+            roots
+                .add(CertificateDer::from_pem_file(fixture("ca.pem")).unwrap())
+                .unwrap();
+            let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                .build()
+                .unwrap();
+            builder.with_client_cert_verifier(verifier)
+        } else {
+            builder.with_no_client_auth()
+        };
+        let acceptor = TlsAcceptor::from(Arc::new(builder.with_single_cert(certs, key).unwrap()));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let acceptor = acceptor.clone();
+                tokio::spawn(async move {
+                    let Ok(mut stream) = acceptor.accept(stream).await else {
+                        return;
+                    };
+                    let mut buf = vec![0; 4096];
+                    if stream.read(&mut buf).await.is_ok() {
+                        let response = "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\nsecure";
+                        let _ = stream.write_all(response.as_bytes()).await;
+                        let _ = stream.shutdown().await;
+                    }
+                });
+            }
+        });
+        port
+    }
+
+    fn trusted() -> TlsConfig {
+        TlsConfig::new().with_ca_pem_file(fixture("ca.pem"))
+    }
+
+    #[tokio::test]
+    async fn test_tls_custom_ca() {
+        let port = tls_server(false, &[&version::TLS13]).await;
+        let target = format!("https://localhost:{port}/");
+
+        let untrusted = ReqwestHttpClient::new().unwrap();
+        assert!(matches!(
+            untrusted.execute(&RequestSpec::get(&target)).await,
+            Err(HttpClientError::TlsError(_))
+        ));
+
+        // Skipping verification works without the CA
+        let unverified =
+            RequestSpec::get(&target).with_tls(TlsConfig::new().with_verify_certificates(false));
+        let response = untrusted.execute(&unverified).await.unwrap();
+        assert_eq!(response.body_as_string_lossy(), "secure");
+
+        let client = ReqwestHttpClient::new().unwrap().with_tls_config(trusted());
+        let response = client.execute(&RequestSpec::get(&target)).await.unwrap();
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_tls_invalid_hostname() {
+        let port = tls_server(false, &[&version::TLS13]).await;
+        // The certificate is issued for `localhost` only
+        let target = format!("https://127.0.0.1:{port}/");
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let strict = RequestSpec::get(&target).with_tls(trusted());
+        assert!(matches!(
+            client.execute(&strict).await,
+            Err(HttpClientError::TlsError(_))
+        ));
+
+        let mut lenient = trusted();
+        lenient.danger_accept_invalid_hostnames = true;
+        let response = client
+            .execute(&RequestSpec::get(&target).with_tls(lenient))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+    }
+
+    #[tokio::test]
+    async fn test_tls_client_certificate() {
+        let port = tls_server(true, &[&version::TLS13]).await;
+        let target = format!("https://localhost:{port}/");
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let anonymous = RequestSpec::get(&target).with_tls(trusted());
+        assert!(matches!(
+            client.execute(&anonymous).await,
+            Err(HttpClientError::TlsError(_))
+        ));
+
+        let mtls = trusted().with_client_cert(ClientCertificate::from_pem_files(
+            fixture("client.pem"),
+            fixture("client.key"),
+        ));
+        let response = client
+            .execute(&RequestSpec::get(&target).with_tls(mtls))
+            .await
+            .unwrap();
+        assert_eq!(response.body_as_string_lossy(), "secure");
+    }
+
+    #[tokio::test]
+    async fn test_tls_version_pinning() {
+        let port = tls_server(false, &[&version::TLS12]).await;
+        let target = format!("https://localhost:{port}/");
+        let client = ReqwestHttpClient::new().unwrap();
+
+        let modern = trusted().with_min_tls_version(TlsVersion::Tls13);
+        assert!(matches!(
+            client
+                .execute(&RequestSpec::get(&target).with_tls(modern))
+                .await,
+            Err(HttpClientError::TlsError(_))
+        ));
+
+        let pinned = trusted().with_max_tls_version(TlsVersion::Tls12);
+        let response = client
+            .execute(&RequestSpec::get(&target).with_tls(pinned))
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        // An empty version range cannot be built
+        let empty = trusted()
+            .with_min_tls_version(TlsVersion::Tls13)
+            .with_max_tls_version(TlsVersion::Tls12);
+        assert!(matches!(
+            client
+                .execute(&RequestSpec::get(&target).with_tls(empty))
+                .await,
+            Err(HttpClientError::TlsError(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_reqwest_method() {
+        let convert = |method: HttpMethod| ReqwestHttpClient::to_reqwest_method(&method).unwrap();
+        assert_eq!(convert(HttpMethod::Get), Method::GET);
+        assert_eq!(convert(HttpMethod::Post), Method::POST);
+        assert_eq!(convert(HttpMethod::Put), Method::PUT);
+        assert_eq!(convert(HttpMethod::Delete), Method::DELETE);
+        assert_eq!(convert(HttpMethod::Trace), Method::TRACE);
+        assert_eq!(convert(HttpMethod::Connect), Method::CONNECT);
         assert_eq!(
-            ReqwestHttpClient::to_reqwest_method(HttpMethod::Delete),
-            Method::DELETE
+            convert(HttpMethod::Custom("PROPFIND".to_string())).as_str(),
+            "PROPFIND"
+        );
+        assert!(
+            ReqwestHttpClient::to_reqwest_method(&HttpMethod::Custom("BAD NAME".to_string()))
+                .is_err()
         );
     }
 
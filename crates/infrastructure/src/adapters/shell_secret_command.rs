@@ -0,0 +1,202 @@
+//! Shell command secret runner
+//!
+//! Runs the commands of command variables through the platform shell
+//! (`sh -c` on Unix, `cmd /C` on Windows) and caches their output for the
+//! session, so a password manager is only asked once per command.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::process::Command;
+use tokio::sync::Mutex;
+use vortex_application::ports::{SecretCommandError, SecretCommandRunner};
+
+/// Default time a command may take, generous enough for a passphrase prompt.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Runs secret commands in the system shell.
+///
+/// Successful output is cached per command line. Clones share the cache, so
+/// one runner per session is enough.
+#[derive(Clone)]
+pub struct ShellSecretCommandRunner {
+    timeout: Duration,
+    cache: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl fmt::Debug for ShellSecretCommandRunner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShellSecretCommandRunner")
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ShellSecretCommandRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShellSecretCommandRunner {
+    /// Creates a runner with an empty cache.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            timeout: DEFAULT_TIMEOUT,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Sets how long a command may run before it is killed.
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Forgets all cached values, so the commands run again.
+    pub async fn clear(&self) {
+        self.cache.lock().await.clear();
+    }
+
+    async fn execute(&self, command: &str) -> Result<String, SecretCommandError> {
+        let mut process = shell(command);
+        process
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        let output = tokio::time::timeout(self.timeout, process.output())
+            .await
+            .map_err(|_| SecretCommandError::Timeout {
+                command: command.to_string(),
+                timeout: self.timeout,
+            })?
+            .map_err(|e| SecretCommandError::Spawn {
+                command: command.to_string(),
+                message: e.to_string(),
+            })?;
+
+        if !output.status.success() {
+            return Err(SecretCommandError::Failed {
+                command: command.to_string(),
+                status: output.status.to_string(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        let stdout =
+            String::from_utf8(output.stdout).map_err(|_| SecretCommandError::InvalidOutput {
+                command: command.to_string(),
+            })?;
+        // Tools like `pass` end the value with a newline
+        Ok(stdout.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+#[async_trait]
+impl SecretCommandRunner for ShellSecretCommandRunner {
+    async fn run(&self, command: &str) -> Result<String, SecretCommandError> {
+        // Held while the command runs, so concurrent lookups prompt only once
+        let mut cache = self.cache.lock().await;
+        if let Some(value) = cache.get(command) {
+            return Ok(value.clone());
+        }
+
+        let value = self.execute(command).await?;
+        cache.insert(command.to_string(), value.clone());
+        drop(cache);
+        Ok(value)
+    }
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut process = Command::new("cmd");
+    process.arg("/C").arg(command);
+    process
+}
+
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut process = Command::new("sh");
+    process.arg("-c").arg(command);
+    process
+}
+
+#[cfg(all(test, unix))]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
+
+    use super::*;
+
+    /// Writes an executable script standing in for a password manager.
+    fn script(dir: &Path, body: &str) -> PathBuf {
+        let path = dir.join("fake-pass");
+        std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_runs_command_once_per_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let calls = dir.path().join("calls");
+        let pass = script(
+            dir.path(),
+            &format!(
+                "echo \"$1\" >> '{}'\nprintf 's3cr3t value\\n'",
+                calls.display()
+            ),
+        );
+        let command = format!("'{}' show api/prod", pass.display());
+
+        let runner = ShellSecretCommandRunner::new();
+        assert_eq!(runner.run(&command).await.unwrap(), "s3cr3t value");
+        assert_eq!(runner.clone().run(&command).await.unwrap(), "s3cr3t value");
+        assert_eq!(std::fs::read_to_string(&calls).unwrap(), "show\n");
+
+        runner.clear().await;
+        runner.run(&command).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&calls).unwrap(), "show\nshow\n");
+    }
+
+    #[tokio::test]
+    async fn test_failing_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let pass = script(
+            dir.path(),
+            "echo 'api/prod is not in the store' >&2\nexit 1",
+        );
+
+        let err = ShellSecretCommandRunner::new()
+            .run(&pass.display().to_string())
+            .await
+            .unwrap_err();
+
+        match err {
+            SecretCommandError::Failed { stderr, .. } => {
+                assert_eq!(stderr, "api/prod is not in the store");
+            }
+            other => panic!("Expected failure, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let runner = ShellSecretCommandRunner::new().with_timeout(Duration::from_millis(100));
+
+        assert!(matches!(
+            runner.run("sleep 5").await,
+            Err(SecretCommandError::Timeout { .. })
+        ));
+    }
+}
@@ -0,0 +1,724 @@
+//! WebSocket client implementation using tokio-tungstenite.
+//!
+//! This adapter implements the `WebSocketClient` port. Each session runs on
+//! its own task that owns the socket, answers pings, sends keep-alive pings
+//! and reconnects as configured.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, Interval};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::header::SEC_WEBSOCKET_PROTOCOL;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue};
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+use vortex_application::ports::{
+    WebSocketClient, WebSocketCommand, WebSocketEvent, WebSocketSender, WebSocketSession,
+};
+use vortex_domain::websocket::{
+    ConnectionInfo, ConnectionState, MessageType, WebSocketConfig, WebSocketError, WebSocketMessage,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Default delay before the first reconnection attempt.
+const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Upper bound for the delay between reconnection attempts.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// WebSocket client implementation using tokio-tungstenite.
+pub struct TungsteniteWebSocketClient {
+    reconnect_delay: Duration,
+}
+
+impl TungsteniteWebSocketClient {
+    /// Creates a new WebSocket client with default settings.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            reconnect_delay: DEFAULT_RECONNECT_DELAY,
+        }
+    }
+
+    /// Sets the delay before the first reconnection attempt.
+    ///
+    /// Later attempts back off linearly, up to 30 seconds.
+    #[must_use]
+    pub const fn with_reconnect_delay(mut self, delay: Duration) -> Self {
+        self.reconnect_delay = delay;
+        self
+    }
+}
+
+impl Default for TungsteniteWebSocketClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebSocketClient for TungsteniteWebSocketClient {
+    fn connect(
+        &self,
+        config: &WebSocketConfig,
+    ) -> Pin<Box<dyn Future<Output = Result<WebSocketSession, WebSocketError>> + Send + '_>> {
+        let config = config.clone();
+        let reconnect_delay = self.reconnect_delay;
+
+        Box::pin(async move {
+            config.validate()?;
+            let (socket, subprotocol) = handshake(&config).await?;
+
+            let mut info = ConnectionInfo::new(&config.url);
+            info.connected(subprotocol);
+
+            let (command_tx, command_rx) = mpsc::unbounded_channel();
+            let (event_tx, event_rx) = mpsc::unbounded_channel();
+            let _ = event_tx.send(WebSocketEvent::StateChanged(info.clone()));
+
+            let connection = Connection {
+                config,
+                reconnect_delay,
+                info,
+                commands: command_rx,
+                events: event_tx,
+            };
+            tokio::spawn(connection.run(socket));
+
+            Ok(WebSocketSession::new(
+                WebSocketSender::new(command_tx),
+                event_rx,
+            ))
+        })
+    }
+}
+
+/// Performs the opening handshake and returns the negotiated subprotocol.
+async fn handshake(config: &WebSocketConfig) -> Result<(Socket, Option<String>), WebSocketError> {
+    let mut request = config
+        .url
+        .as_str()
+        .into_client_request()
+        .map_err(|e| WebSocketError::InvalidUrl(e.to_string()))?;
+
+    for (name, value) in &config.headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+            WebSocketError::ConnectionFailed(format!("Invalid header name: {name}"))
+        })?;
+        let header_value = HeaderValue::from_str(value).map_err(|_| {
+            WebSocketError::ConnectionFailed(format!("Invalid value for header {name}"))
+        })?;
+        request.headers_mut().append(header_name, header_value);
+    }
+
+    if !config.subprotocols.is_empty() {
+        let protocols = HeaderValue::from_str(&config.subprotocols.join(", "))
+            .map_err(|_| WebSocketError::ConnectionFailed("Invalid subprotocol".to_string()))?;
+        request
+            .headers_mut()
+            .insert(SEC_WEBSOCKET_PROTOCOL, protocols);
+    }
+
+    let (socket, response) = tokio::time::timeout(config.connect_timeout(), connect_async(request))
+        .await
+        .map_err(|_| WebSocketError::Timeout)?
+        .map_err(|e| WebSocketError::ConnectionFailed(e.to_string()))?;
+
+    let subprotocol = response
+        .headers()
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+
+    Ok((socket, subprotocol))
+}
+
+/// Why a connection stopped.
+enum Disconnect {
+    /// The user closed the session or dropped every sender.
+    Requested,
+    /// The server closed the connection or the transport failed.
+    Lost(String),
+}
+
+/// State owned by the background task of a session.
+struct Connection {
+    config: WebSocketConfig,
+    reconnect_delay: Duration,
+    info: ConnectionInfo,
+    commands: mpsc::UnboundedReceiver<WebSocketCommand>,
+    events: mpsc::UnboundedSender<WebSocketEvent>,
+}
+
+impl Connection {
+    async fn run(mut self, mut socket: Socket) {
+        loop {
+            match self.drive(&mut socket).await {
+                Disconnect::Requested => break,
+                Disconnect::Lost(reason) => {
+                    self.emit(WebSocketEvent::Error(WebSocketError::ConnectionClosed(
+                        reason,
+                    )));
+                    if !self.config.auto_reconnect {
+                        break;
+                    }
+                    match self.reconnect().await {
+                        Some(new_socket) => socket = new_socket,
+                        None => return,
+                    }
+                }
+            }
+        }
+
+        self.info.disconnected();
+        self.emit_state();
+    }
+
+    /// Pumps messages until the connection ends.
+    async fn drive(&mut self, socket: &mut Socket) -> Disconnect {
+        let mut keep_alive = self
+            .config
+            .ping_interval()
+            .map(|period| tokio::time::interval_at(Instant::now() + period, period));
+
+        loop {
+            tokio::select! {
+                command = self.commands.recv() => match command {
+                    Some(WebSocketCommand::Send(message)) => {
+                        if let Err(e) = socket.send(to_frame(&message)).await {
+                            self.emit(WebSocketEvent::Error(WebSocketError::SendFailed(
+                                e.to_string(),
+                            )));
+                            return Disconnect::Lost(e.to_string());
+                        }
+                        self.info.record_sent(message.size);
+                        self.emit(WebSocketEvent::Message(message));
+                        self.emit_state();
+                    }
+                    Some(WebSocketCommand::Close) | None => {
+                        self.info.state = ConnectionState::Closing;
+                        self.emit_state();
+                        let _ = socket.close(None).await;
+                        // Wait for the server to acknowledge the close frame.
+                        while let Some(Ok(_)) = socket.next().await {}
+                        return Disconnect::Requested;
+                    }
+                },
+                frame = socket.next() => match frame {
+                    Some(Ok(Message::Text(text))) => {
+                        self.received(WebSocketMessage::received_text(text.as_str()));
+                    }
+                    Some(Ok(Message::Binary(data))) => {
+                        self.received(WebSocketMessage::received_binary(data.to_vec()));
+                    }
+                    Some(Ok(Message::Ping(_))) => {
+                        self.emit_system("Ping received", MessageType::Ping);
+                        // Tungstenite queues the pong reply; flushing sends it now.
+                        if let Err(e) = socket.flush().await {
+                            return Disconnect::Lost(e.to_string());
+                        }
+                        self.emit_system("Pong sent", MessageType::Pong);
+                    }
+                    Some(Ok(Message::Pong(_))) => {
+                        self.emit_system("Pong received", MessageType::Pong);
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        let reason = close_reason(frame.as_ref());
+                        self.emit_system(format!("Closed by server: {reason}"), MessageType::Close);
+                        return Disconnect::Lost(reason);
+                    }
+                    Some(Ok(Message::Frame(_))) => {}
+                    Some(Err(e)) => return Disconnect::Lost(e.to_string()),
+                    None => return Disconnect::Lost("Connection closed".to_string()),
+                },
+                () = tick(keep_alive.as_mut()) => {
+                    if let Err(e) = socket.send(Message::Ping(Vec::new().into())).await {
+                        return Disconnect::Lost(e.to_string());
+                    }
+                    self.emit_system("Ping sent", MessageType::Ping);
+                }
+            }
+        }
+    }
+
+    /// Reconnects with a linear backoff.
+    ///
+    /// Returns `None` if the user closed the session while waiting or the
+    /// attempt limit was reached.
+    async fn reconnect(&mut self) -> Option<Socket> {
+        let max_attempts = self.config.max_reconnect_attempts;
+        let mut attempt: u32 = 0;
+
+        loop {
+            if max_attempts > 0 && attempt >= max_attempts {
+                self.info.state = ConnectionState::Failed;
+                self.emit_state();
+                return None;
+            }
+            attempt += 1;
+
+            self.info.state = ConnectionState::Reconnecting;
+            self.info.reconnect_attempts = attempt;
+            self.emit_state();
+
+            let delay = self
+                .reconnect_delay
+                .saturating_mul(attempt)
+                .min(MAX_RECONNECT_DELAY);
+            if !self.wait(delay).await {
+                self.info.disconnected();
+                self.emit_state();
+                return None;
+            }
+
+            match handshake(&self.config).await {
+                Ok((socket, subprotocol)) => {
+                    self.info.connected(subprotocol);
+                    self.emit_system("Reconnected", MessageType::Text);
+                    self.emit_state();
+                    return Some(socket);
+                }
+                Err(e) => self.emit(WebSocketEvent::Error(e)),
+            }
+        }
+    }
+
+    /// Sleeps for `delay`, rejecting sends meanwhile.
+    ///
+    /// Returns false if the session was closed while waiting.
+    async fn wait(&mut self, delay: Duration) -> bool {
+        let sleep = tokio::time::sleep(delay);
+        tokio::pin!(sleep);
+
+        loop {
+            tokio::select! {
+                () = &mut sleep => return true,
+                command = self.commands.recv() => match command {
+                    Some(WebSocketCommand::Send(_)) => {
+                        self.emit(WebSocketEvent::Error(WebSocketError::NotConnected));
+                    }
+                    Some(WebSocketCommand::Close) | None => return false,
+                },
+            }
+        }
+    }
+
+    fn received(&mut self, message: WebSocketMessage) {
+        self.info.record_received(message.size);
+        self.emit(WebSocketEvent::Message(message));
+        self.emit_state();
+    }
+
+    fn emit_system(&self, content: impl Into<String>, message_type: MessageType) {
+        self.emit(WebSocketEvent::Message(WebSocketMessage::system(
+            content,
+            message_type,
+        )));
+    }
+
+    fn emit_state(&self) {
+        self.emit(WebSocketEvent::StateChanged(self.info.clone()));
+    }
+
+    fn emit(&self, event: WebSocketEvent) {
+        // Nobody listening is fine: the session keeps running until closed.
+        let _ = self.events.send(event);
+    }
+}
+
+/// Converts an outgoing domain message into a WebSocket frame.
+fn to_frame(message: &WebSocketMessage) -> Message {
+    match message.message_type {
+        MessageType::Binary => Message::binary(message.binary.clone()),
+        MessageType::Ping => Message::Ping(message.content.clone().into_bytes().into()),
+        MessageType::Pong => Message::Pong(message.content.clone().into_bytes().into()),
+        MessageType::Close => Message::Close(None),
+        MessageType::Text => Message::text(message.content.clone()),
+    }
+}
+
+/// Describes a close frame for the message log.
+fn close_reason(frame: Option<&CloseFrame>) -> String {
+    match frame {
+        Some(frame) if frame.reason.is_empty() => format!("code {}", u16::from(frame.code)),
+        Some(frame) => format!("code {} ({})", u16::from(frame.code), frame.reason),
+        None => "no close frame".to_string(),
+    }
+}
+
+/// Waits for the next keep-alive tick, or forever when pings are disabled.
+async fn tick(interval: Option<&mut Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_hdr_async;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use vortex_application::{
+        GraphqlSubscription, GraphqlSubscriptionEvent, GraphqlSubscriptionInput,
+    };
+    use vortex_domain::websocket::MessageDirection;
+
+    use super::*;
+
+    /// Handshake headers seen by the echo server.
+    type SeenHeaders = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Starts a local echo server.
+    ///
+    /// It accepts the first requested subprotocol, records handshake headers,
+    /// pings the client after the handshake, and drops the connection when it
+    /// receives the text `drop`.
+    async fn echo_server() -> (String, SeenHeaders) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let seen: SeenHeaders = Arc::default();
+
+        let seen_by_server = Arc::clone(&seen);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let seen = Arc::clone(&seen_by_server);
+                tokio::spawn(async move {
+                    let callback = |request: &Request, mut response: Response| {
+                        let headers = request.headers().iter().map(|(name, value)| {
+                            (
+                                name.to_string(),
+                                value.to_str().unwrap_or_default().to_string(),
+                            )
+                        });
+                        seen.lock().unwrap().extend(headers);
+                        if let Some(protocols) = request.headers().get(SEC_WEBSOCKET_PROTOCOL) {
+                            let first = protocols.to_str().unwrap().split(',').next().unwrap();
+                            response.headers_mut().insert(
+                                SEC_WEBSOCKET_PROTOCOL,
+                                HeaderValue::from_str(first.trim()).unwrap(),
+                            );
+                        }
+                        Ok(response)
+                    };
+                    let Ok(mut socket) = accept_hdr_async(stream, callback).await else {
+                        return;
+                    };
+                    let _ = socket.send(Message::Ping(b"hi".to_vec().into())).await;
+                    while let Some(Ok(message)) = socket.next().await {
+                        match message {
+                            Message::Text(text) if text.as_str() == "drop" => return,
+                            Message::Text(_) | Message::Binary(_) => {
+                                let _ = socket.send(message).await;
+                            }
+                            _ => {}
+                        }
+                    }
+                });
+            }
+        });
+
+        (url, seen)
+    }
+
+    /// Waits for the next event matching `predicate`.
+    async fn expect_event(
+        session: &mut WebSocketSession,
+        predicate: fn(&WebSocketEvent) -> bool,
+    ) -> WebSocketEvent {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let event = session.next_event().await.expect("session ended");
+                if predicate(&event) {
+                    return event;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for event")
+    }
+
+    fn received_message(event: &WebSocketEvent) -> bool {
+        matches!(event, WebSocketEvent::Message(m) if m.direction == MessageDirection::Received)
+    }
+
+    #[tokio::test]
+    async fn test_connect_sends_headers_and_subprotocols() {
+        let (url, seen) = echo_server().await;
+        let client = TungsteniteWebSocketClient::new();
+        let config = WebSocketConfig::new(&url)
+            .with_headers(vec![("X-Api-Key".to_string(), "abc".to_string())]);
+        let config = WebSocketConfig {
+            subprotocols: vec!["graphql-ws".to_string(), "json".to_string()],
+            ..config
+        };
+
+        let mut session = client.connect(&config).await.unwrap();
+
+        let WebSocketEvent::StateChanged(info) = session.next_event().await.unwrap() else {
+            panic!("expected a state change");
+        };
+        assert_eq!(info.state, ConnectionState::Connected);
+        assert_eq!(info.subprotocol.as_deref(), Some("graphql-ws"));
+
+        let seen = seen.lock().unwrap().clone();
+        assert!(seen.contains(&("x-api-key".to_string(), "abc".to_string())));
+        assert!(seen.contains(&(
+            "sec-websocket-protocol".to_string(),
+            "graphql-ws, json".to_string()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_send_text_and_binary_are_echoed() {
+        let (url, _) = echo_server().await;
+        let client = TungsteniteWebSocketClient::new();
+        let mut session = client.connect(&WebSocketConfig::new(&url)).await.unwrap();
+
+        session.sender().send_text("hello").unwrap();
+        let WebSocketEvent::Message(echo) = expect_event(&mut session, received_message).await
+        else {
+            unreachable!()
+        };
+        assert_eq!(echo.message_type, MessageType::Text);
+        assert_eq!(echo.content, "hello");
+
+        session
+            .sender()
+            .send_binary(vec![0, 159, 146, 150])
+            .unwrap();
+        let WebSocketEvent::Message(echo) = expect_event(&mut session, received_message).await
+        else {
+            unreachable!()
+        };
+        assert_eq!(echo.message_type, MessageType::Binary);
+        assert_eq!(echo.binary, vec![0, 159, 146, 150]);
+
+        let WebSocketEvent::StateChanged(info) = expect_event(
+            &mut session,
+            |e| matches!(e, WebSocketEvent::StateChanged(i) if i.messages_received == 2),
+        )
+        .await
+        else {
+            unreachable!()
+        };
+        assert_eq!(info.messages_sent, 2);
+        assert_eq!(info.bytes_received, 9);
+    }
+
+    #[tokio::test]
+    async fn test_ping_is_answered() {
+        let (url, _) = echo_server().await;
+        let client = TungsteniteWebSocketClient::new();
+        let mut session = client.connect(&WebSocketConfig::new(&url)).await.unwrap();
+
+        expect_event(
+            &mut session,
+            |e| matches!(e, WebSocketEvent::Message(m) if m.message_type == MessageType::Pong),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_close_ends_session() {
+        let (url, _) = echo_server().await;
+        let client = TungsteniteWebSocketClient::new();
+        let mut session = client.connect(&WebSocketConfig::new(&url)).await.unwrap();
+
+        session.sender().close().unwrap();
+        expect_event(&mut session, |e| {
+            matches!(e, WebSocketEvent::StateChanged(i) if i.state == ConnectionState::Disconnected)
+        })
+        .await;
+        assert!(
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while session.next_event().await.is_some() {}
+            })
+            .await
+            .is_ok()
+        );
+        assert!(session.sender().send_text("late").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_after_connection_loss() {
+        let (url, _) = echo_server().await;
+        let client =
+            TungsteniteWebSocketClient::new().with_reconnect_delay(Duration::from_millis(10));
+        let config = WebSocketConfig::new(&url).with_auto_reconnect(true);
+        let mut session = client.connect(&config).await.unwrap();
+
+        session.sender().send_text("drop").unwrap();
+        expect_event(&mut session, |e| {
+            matches!(e, WebSocketEvent::StateChanged(i) if i.state == ConnectionState::Reconnecting)
+        })
+        .await;
+        expect_event(&mut session, |e| {
+            matches!(e, WebSocketEvent::StateChanged(i) if i.state == ConnectionState::Connected)
+        })
+        .await;
+
+        session.sender().send_text("again").unwrap();
+        let WebSocketEvent::Message(echo) = expect_event(&mut session, received_message).await
+        else {
+            unreachable!()
+        };
+        assert_eq!(echo.content, "again");
+    }
+
+    #[tokio::test]
+    async fn test_connection_loss_without_reconnect_disconnects() {
+        let (url, _) = echo_server().await;
+        let client = TungsteniteWebSocketClient::new();
+        let mut session = client.connect(&WebSocketConfig::new(&url)).await.unwrap();
+
+        session.sender().send_text("drop").unwrap();
+        expect_event(&mut session, |e| {
+            matches!(
+                e,
+                WebSocketEvent::Error(WebSocketError::ConnectionClosed(_))
+            )
+        })
+        .await;
+        expect_event(&mut session, |e| {
+            matches!(e, WebSocketEvent::StateChanged(i) if i.state == ConnectionState::Disconnected)
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_connect_rejects_invalid_url() {
+        let client = TungsteniteWebSocketClient::new();
+
+        let result = client
+            .connect(&WebSocketConfig::new("http://example.com"))
+            .await;
+
+        assert!(matches!(result, Err(WebSocketError::InvalidUrl(_))));
+    }
+
+    /// Starts a local `graphql-transport-ws` subscription server.
+    ///
+    /// It acknowledges connections whose params carry `{"token": "secret"}`
+    /// and closes others with code 4403. Each subscription receives three
+    /// results counting up, then `complete`.
+    async fn subscription_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/graphql", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let callback = |_: &Request, mut response: Response| {
+                        response.headers_mut().insert(
+                            SEC_WEBSOCKET_PROTOCOL,
+                            HeaderValue::from_static("graphql-transport-ws"),
+                        );
+                        Ok(response)
+                    };
+                    let Ok(mut socket) = accept_hdr_async(stream, callback).await else {
+                        return;
+                    };
+                    while let Some(Ok(Message::Text(text))) = socket.next().await {
+                        let message: serde_json::Value = serde_json::from_str(&text).unwrap();
+                        match message["type"].as_str() {
+                            Some("connection_init") if message["payload"]["token"] == "secret" => {
+                                let ack = r#"{"type": "connection_ack"}"#;
+                                let _ = socket.send(Message::text(ack)).await;
+                            }
+                            Some("connection_init") => {
+                                let frame = CloseFrame {
+                                    code: 4403.into(),
+                                    reason: "Forbidden".into(),
+                                };
+                                let _ = socket.close(Some(frame)).await;
+                            }
+                            Some("subscribe") => {
+                                let id = &message["id"];
+                                for count in 1..=3 {
+                                    let next = serde_json::json!({
+                                        "type": "next",
+                                        "id": id,
+                                        "payload": { "data": { "count": count } },
+                                    });
+                                    let _ = socket.send(Message::text(next.to_string())).await;
+                                }
+                                let complete = serde_json::json!({ "type": "complete", "id": id });
+                                let _ = socket.send(Message::text(complete.to_string())).await;
+                            }
+                            _ => {}
+                        }
+                    }
+                });
+            }
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_graphql_subscription_against_local_server() {
+        let url = subscription_server().await;
+        let use_case = GraphqlSubscription::new(Arc::new(TungsteniteWebSocketClient::new()));
+        let input = GraphqlSubscriptionInput {
+            config: WebSocketConfig::new(&url),
+            query: "subscription { count }".to_string(),
+            connection_params: r#"{"token": "secret"}"#.to_string(),
+            ..GraphqlSubscriptionInput::default()
+        };
+
+        let mut session = use_case.execute(input.clone()).await.unwrap();
+        let mut counts = Vec::new();
+        let mut completed = false;
+        let mut disconnected = false;
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while let Some(event) = session.next_event().await {
+                match event {
+                    GraphqlSubscriptionEvent::Next { payload, .. } => {
+                        counts.push(payload["data"]["count"].as_u64().unwrap());
+                    }
+                    GraphqlSubscriptionEvent::Complete => completed = true,
+                    GraphqlSubscriptionEvent::StateChanged(info) => {
+                        disconnected = info.state == ConnectionState::Disconnected;
+                    }
+                    GraphqlSubscriptionEvent::Error(_) | GraphqlSubscriptionEvent::Warning(_) => {}
+                }
+            }
+        })
+        .await
+        .expect("subscription did not end");
+        assert_eq!(counts, [1, 2, 3]);
+        assert!(completed);
+        assert!(disconnected);
+
+        let rejected = GraphqlSubscriptionInput {
+            connection_params: r#"{"token": "wrong"}"#.to_string(),
+            ..input
+        };
+        let error = use_case.execute(rejected).await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Connection closed: code 4403 (Forbidden)"
+        );
+    }
+
+    #[test]
+    fn test_to_frame() {
+        assert_eq!(to_frame(&WebSocketMessage::text("hi")), Message::text("hi"));
+        assert_eq!(
+            to_frame(&WebSocketMessage::binary(vec![1])),
+            Message::binary(vec![1])
+        );
+    }
+}
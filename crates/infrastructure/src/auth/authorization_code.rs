@@ -0,0 +1,469 @@
+//! Building blocks of the `OAuth2` Authorization Code flow.
+//!
+//! Provides the PKCE verifier and S256 challenge (RFC 7636), the
+//! authorization URL, and a temporary loopback server that receives the
+//! redirect from the browser (RFC 8252).
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+use aws_lc_rs::digest::{SHA256, digest};
+use base64::Engine as _;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use url::Url;
+use uuid::Uuid;
+use vortex_domain::AuthError;
+
+/// PKCE code verifier and its S256 challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pkce {
+    /// Secret sent with the token request.
+    pub verifier: String,
+    /// `BASE64URL(SHA256(verifier))`, sent with the authorization request.
+    pub challenge: String,
+}
+
+impl Pkce {
+    /// Generates a random verifier of 96 unreserved characters.
+    #[must_use]
+    pub fn new() -> Self {
+        let verifier = (0..3)
+            .map(|_| Uuid::new_v4().simple().to_string())
+            .collect();
+        Self::from_verifier(verifier)
+    }
+
+    /// Derives the S256 challenge for a known verifier.
+    #[must_use]
+    pub fn from_verifier(verifier: String) -> Self {
+        let challenge = URL_SAFE_NO_PAD.encode(digest(&SHA256, verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parameters of an authorization request.
+#[derive(Debug, Clone)]
+pub struct AuthorizationRequest<'a> {
+    /// Authorization endpoint URL.
+    pub auth_url: &'a str,
+    /// Client ID.
+    pub client_id: &'a str,
+    /// Redirect URI the server sends the browser back to.
+    pub redirect_uri: &'a str,
+    /// Space-separated scopes.
+    pub scope: Option<&'a str>,
+    /// Opaque value the callback must echo back.
+    pub state: &'a str,
+    /// PKCE challenge to send.
+    pub pkce: &'a Pkce,
+    /// Additional query parameters.
+    pub extra_params: &'a BTreeMap<String, String>,
+}
+
+impl AuthorizationRequest<'_> {
+    /// Builds the URL to open in the browser.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::InvalidConfiguration` if the authorization URL is
+    /// invalid.
+    pub fn url(&self) -> Result<String, AuthError> {
+        let mut url = Url::parse(self.auth_url).map_err(|e| AuthError::InvalidConfiguration {
+            message: format!("Invalid authorization URL {}: {e}", self.auth_url),
+        })?;
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", self.client_id)
+                .append_pair("redirect_uri", self.redirect_uri);
+            if let Some(scope) = self.scope.filter(|scope| !scope.is_empty()) {
+                query.append_pair("scope", scope);
+            }
+            query
+                .append_pair("state", self.state)
+                .append_pair("code_challenge", &self.pkce.challenge)
+                .append_pair("code_challenge_method", "S256");
+            for (name, value) in self.extra_params {
+                query.append_pair(name, value);
+            }
+        }
+        Ok(url.into())
+    }
+}
+
+/// Temporary HTTP listener for the authorization callback.
+#[derive(Debug)]
+pub struct CallbackServer {
+    listener: TcpListener,
+    redirect_uri: String,
+    path: String,
+}
+
+impl CallbackServer {
+    /// Binds the loopback address of `redirect_uri`.
+    ///
+    /// The redirect URI must use `http` and a loopback host. Without a port,
+    /// `default_port` is used; port `0` picks any free port, which is then
+    /// reflected in [`redirect_uri`](Self::redirect_uri).
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::InvalidConfiguration` for unsupported redirect URIs
+    /// and `AuthError::CallbackServerError` if the port cannot be bound.
+    pub async fn bind(redirect_uri: &str, default_port: u16) -> Result<Self, AuthError> {
+        let invalid = |reason: &str| AuthError::InvalidConfiguration {
+            message: format!("Redirect URI {redirect_uri} {reason}"),
+        };
+        let mut url = Url::parse(redirect_uri).map_err(|_| invalid("is not a valid URL"))?;
+        if url.scheme() != "http" {
+            return Err(invalid("must use http:// for the loopback callback"));
+        }
+        let ip = match url.host_str() {
+            Some("localhost") => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            Some(host) => host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<IpAddr>()
+                .ok()
+                .filter(IpAddr::is_loopback)
+                .ok_or_else(|| invalid("must point to localhost or a loopback address"))?,
+            None => return Err(invalid("has no host")),
+        };
+
+        let port = url.port().unwrap_or(default_port);
+        let listener =
+            TcpListener::bind((ip, port))
+                .await
+                .map_err(|e| AuthError::CallbackServerError {
+                    message: format!("Failed to listen on {ip}:{port}: {e}"),
+                })?;
+        let bound = listener
+            .local_addr()
+            .map_err(|e| AuthError::CallbackServerError {
+                message: e.to_string(),
+            })?
+            .port();
+        url.set_port(Some(bound))
+            .map_err(|()| invalid("cannot carry a port"))?;
+
+        Ok(Self {
+            listener,
+            path: url.path().to_string(),
+            redirect_uri: url.into(),
+        })
+    }
+
+    /// Returns the redirect URI with the port actually bound.
+    #[must_use]
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    /// Waits for the browser to hit the callback path and returns the code.
+    ///
+    /// Requests to other paths are answered with `404` and ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuthError::UserCancelled` if access was denied,
+    /// `AuthError::OAuth2AuthorizationFailed` for other authorization errors
+    /// or a state mismatch, and `AuthError::CallbackServerError` on timeout.
+    pub async fn wait_for_code(
+        self,
+        expected_state: &str,
+        timeout: Duration,
+    ) -> Result<String, AuthError> {
+        tokio::time::timeout(timeout, self.accept_callback(expected_state))
+            .await
+            .map_err(|_| AuthError::CallbackServerError {
+                message: format!(
+                    "Timed out after {}s waiting for the authorization callback",
+                    timeout.as_secs()
+                ),
+            })?
+    }
+
+    async fn accept_callback(&self, expected_state: &str) -> Result<String, AuthError> {
+        loop {
+            let (mut stream, _) =
+                self.listener
+                    .accept()
+                    .await
+                    .map_err(|e| AuthError::CallbackServerError {
+                        message: e.to_string(),
+                    })?;
+            let Some(target) = read_request_target(&mut stream).await else {
+                continue;
+            };
+            let Ok(url) = Url::parse("http://localhost").and_then(|base| base.join(&target)) else {
+                continue;
+            };
+            if url.path() != self.path {
+                respond(&mut stream, "404 Not Found", "Not found").await;
+                continue;
+            }
+
+            let params: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+            let result = callback_result(&params, expected_state);
+            let page = match &result {
+                Ok(_) => "Authorization complete. You can close this window.".to_string(),
+                Err(e) => format!("Authorization failed: {e}"),
+            };
+            respond(&mut stream, "200 OK", &page).await;
+            return result;
+        }
+    }
+}
+
+/// Extracts the code from the callback parameters.
+fn callback_result(
+    params: &BTreeMap<String, String>,
+    expected_state: &str,
+) -> Result<String, AuthError> {
+    if let Some(error) = params.get("error") {
+        if error == "access_denied" {
+            return Err(AuthError::UserCancelled);
+        }
+        let description = params.get("error_description").unwrap_or(error);
+        return Err(AuthError::OAuth2AuthorizationFailed {
+            message: description.clone(),
+        });
+    }
+    if params.get("state").map(String::as_str) != Some(expected_state) {
+        return Err(AuthError::OAuth2AuthorizationFailed {
+            message: "State mismatch in authorization callback".to_string(),
+        });
+    }
+    params
+        .get("code")
+        .filter(|code| !code.is_empty())
+        .cloned()
+        .ok_or_else(|| AuthError::OAuth2AuthorizationFailed {
+            message: "Authorization callback carried no code".to_string(),
+        })
+}
+
+/// Reads the request head and returns the request target of a `GET`.
+async fn read_request_target(stream: &mut TcpStream) -> Option<String> {
+    let mut head = Vec::new();
+    let mut buf = [0; 1024];
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") && head.len() < 16 * 1024 {
+        let n = stream.read(&mut buf).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    let head = String::from_utf8_lossy(&head);
+    let mut parts = head.lines().next()?.split(' ');
+    (parts.next()? == "GET").then(|| parts.next().map(str::to_string))?
+}
+
+/// Writes an HTML page showing `message`, which may carry provider-supplied text.
+async fn respond(stream: &mut TcpStream, status: &str, message: &str) {
+    let body = page(message);
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Renders the callback page, HTML-escaping `message`.
+fn page(message: &str) -> String {
+    let mut escaped = String::with_capacity(message.len());
+    for c in message.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    format!(
+        "<!DOCTYPE html><html><head><title>Vortex</title></head>\
+         <body><p>{escaped}</p></body></html>"
+    )
+}
+
+/// Opens a URL in the system's default browser.
+///
+/// # Errors
+///
+/// Returns a message if the browser could not be launched.
+pub fn open_in_browser(url: &str) -> Result<(), String> {
+    let mut command = if cfg!(target_os = "macos") {
+        std::process::Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = std::process::Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        std::process::Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .spawn()
+        .map(drop)
+        .map_err(|e| format!("Failed to open the browser: {e}"))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pkce_s256_challenge() {
+        // Example from RFC 7636, Appendix B
+        let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string());
+        assert_eq!(
+            pkce.challenge,
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+
+        let random = Pkce::new();
+        assert_eq!(random.verifier.len(), 96);
+        assert_ne!(random.verifier, Pkce::new().verifier);
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let pkce = Pkce::from_verifier("verifier".to_string());
+        let extra = BTreeMap::from([("prompt".to_string(), "consent".to_string())]);
+        let url = AuthorizationRequest {
+            auth_url: "https://auth.example.com/authorize?audience=api",
+            client_id: "vortex",
+            redirect_uri: "http://127.0.0.1:5000/callback",
+            scope: Some("read write"),
+            state: "xyz",
+            pkce: &pkce,
+            extra_params: &extra,
+        }
+        .url()
+        .unwrap();
+
+        let params: BTreeMap<String, String> = Url::parse(&url)
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect();
+        assert_eq!(params["audience"], "api");
+        assert_eq!(params["response_type"], "code");
+        assert_eq!(params["redirect_uri"], "http://127.0.0.1:5000/callback");
+        assert_eq!(params["scope"], "read write");
+        assert_eq!(params["state"], "xyz");
+        assert_eq!(params["code_challenge"], pkce.challenge);
+        assert_eq!(params["code_challenge_method"], "S256");
+        assert_eq!(params["prompt"], "consent");
+    }
+
+    #[tokio::test]
+    async fn test_callback_server_rejects_non_loopback() {
+        for uri in ["https://127.0.0.1/callback", "http://example.com/callback"] {
+            assert!(matches!(
+                CallbackServer::bind(uri, 0).await,
+                Err(AuthError::InvalidConfiguration { .. })
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_callback_server_receives_code() {
+        let server = CallbackServer::bind("http://localhost:0/callback", 0)
+            .await
+            .unwrap();
+        let redirect_uri = server.redirect_uri().to_string();
+        assert!(redirect_uri.starts_with("http://localhost:"));
+        assert!(!redirect_uri.contains(":0/"));
+
+        let wait = tokio::spawn(server.wait_for_code("s1", Duration::from_secs(5)));
+        let client = reqwest::Client::new();
+        let favicon = redirect_uri.replace("/callback", "/favicon.ico");
+        assert_eq!(client.get(favicon).send().await.unwrap().status(), 404);
+        let page = client
+            .get(format!("{redirect_uri}?code=abc&state=s1"))
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert!(page.contains("Authorization complete"));
+        assert_eq!(wait.await.unwrap().unwrap(), "abc");
+    }
+
+    #[test]
+    fn test_callback_result_errors() {
+        let params = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+                .collect()
+        };
+
+        assert!(matches!(
+            callback_result(&params(&[("code", "abc"), ("state", "other")]), "s1"),
+            Err(AuthError::OAuth2AuthorizationFailed { message }) if message.contains("State")
+        ));
+        assert!(matches!(
+            callback_result(&params(&[("error", "access_denied")]), "s1"),
+            Err(AuthError::UserCancelled)
+        ));
+        assert!(matches!(
+            callback_result(
+                &params(&[("error", "invalid_scope"), ("error_description", "Bad scope")]),
+                "s1"
+            ),
+            Err(AuthError::OAuth2AuthorizationFailed { message }) if message == "Bad scope"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_callback_server_escapes_error_description() {
+        let server = CallbackServer::bind("http://127.0.0.1:0/cb", 0)
+            .await
+            .unwrap();
+        let redirect_uri = server.redirect_uri().to_string();
+        let wait = tokio::spawn(server.wait_for_code("s1", Duration::from_secs(5)));
+
+        let mut url = Url::parse(&redirect_uri).unwrap();
+        url.query_pairs_mut()
+            .append_pair("error", "invalid_request")
+            .append_pair("error_description", "<script>alert('x')</script>");
+        let page = reqwest::get(url).await.unwrap().text().await.unwrap();
+
+        assert!(!page.contains("<script>"));
+        assert!(page.contains("&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt;"));
+        assert!(wait.await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_callback_server_timeout() {
+        let server = CallbackServer::bind("http://127.0.0.1:0/cb", 0)
+            .await
+            .unwrap();
+        assert!(matches!(
+            server.wait_for_code("s1", Duration::from_millis(50)).await,
+            Err(AuthError::CallbackServerError { .. })
+        ));
+    }
+}
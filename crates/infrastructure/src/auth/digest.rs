@@ -0,0 +1,428 @@
+//! HTTP Digest authentication (RFC 7616).
+//!
+//! Parses `WWW-Authenticate: Digest` challenges and computes the matching
+//! `Authorization` header. A [`DigestSession`] keeps the challenge and its
+//! nonce count so later requests can authenticate without another 401.
+
+use std::fmt::Write as _;
+
+use aws_lc_rs::digest;
+use md5::{Digest as _, Md5};
+use uuid::Uuid;
+
+/// Hash algorithm named in a Digest challenge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestAlgorithm {
+    /// `MD5` (the default when the challenge names none).
+    Md5,
+    /// `MD5-sess`
+    Md5Sess,
+    /// `SHA-256`
+    Sha256,
+    /// `SHA-256-sess`
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "MD5" => Some(Self::Md5),
+            "MD5-SESS" => Some(Self::Md5Sess),
+            "SHA-256" => Some(Self::Sha256),
+            "SHA-256-SESS" => Some(Self::Sha256Sess),
+            _ => None,
+        }
+    }
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Md5 => "MD5",
+            Self::Md5Sess => "MD5-sess",
+            Self::Sha256 => "SHA-256",
+            Self::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    const fn is_session(self) -> bool {
+        matches!(self, Self::Md5Sess | Self::Sha256Sess)
+    }
+
+    const fn strength(self) -> u8 {
+        match self {
+            Self::Md5 | Self::Md5Sess => 0,
+            Self::Sha256 | Self::Sha256Sess => 1,
+        }
+    }
+
+    fn hash(self, data: &[u8]) -> String {
+        match self {
+            Self::Md5 | Self::Md5Sess => hex(&Md5::digest(data)),
+            Self::Sha256 | Self::Sha256Sess => hex(digest::digest(&digest::SHA256, data).as_ref()),
+        }
+    }
+}
+
+/// Quality of protection offered by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Qop {
+    /// Authenticates the method and URI.
+    Auth,
+    /// Also covers a hash of the request body.
+    AuthInt,
+}
+
+impl Qop {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Auth => "auth",
+            Self::AuthInt => "auth-int",
+        }
+    }
+}
+
+/// A parsed `WWW-Authenticate: Digest` challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestChallenge {
+    /// Protection space.
+    pub realm: String,
+    /// Server nonce.
+    pub nonce: String,
+    /// Opaque value to echo back.
+    pub opaque: Option<String>,
+    /// Hash algorithm.
+    pub algorithm: DigestAlgorithm,
+    /// Offered qop values; empty for RFC 2069 servers.
+    pub qop: Vec<Qop>,
+    /// Whether the previous nonce was rejected only for being stale.
+    pub stale: bool,
+}
+
+impl DigestChallenge {
+    /// Picks the strongest supported Digest challenge from
+    /// `WWW-Authenticate` header values.
+    #[must_use]
+    pub fn from_headers<'a>(values: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        values
+            .into_iter()
+            .flat_map(split_challenges)
+            .filter_map(|challenge| Self::parse(&challenge))
+            .max_by_key(|challenge| challenge.algorithm.strength())
+    }
+
+    /// Parses a single challenge such as `Digest realm="x", nonce="y"`.
+    #[must_use]
+    pub fn parse(challenge: &str) -> Option<Self> {
+        let (scheme, params) = challenge.trim().split_once(char::is_whitespace)?;
+        if !scheme.eq_ignore_ascii_case("digest") {
+            return None;
+        }
+
+        let mut realm = None;
+        let mut nonce = None;
+        let mut opaque = None;
+        let mut algorithm = DigestAlgorithm::Md5;
+        let mut qop = Vec::new();
+        let mut stale = false;
+        for (name, value) in parse_params(params) {
+            match name.to_ascii_lowercase().as_str() {
+                "realm" => realm = Some(value),
+                "nonce" => nonce = Some(value),
+                "opaque" => opaque = Some(value),
+                "algorithm" => algorithm = DigestAlgorithm::parse(&value)?,
+                "qop" => {
+                    qop = value
+                        .split(',')
+                        .filter_map(|option| match option.trim() {
+                            "auth" => Some(Qop::Auth),
+                            "auth-int" => Some(Qop::AuthInt),
+                            _ => None,
+                        })
+                        .collect();
+                    if qop.is_empty() {
+                        return None;
+                    }
+                }
+                "stale" => stale = value.eq_ignore_ascii_case("true"),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            realm: realm?,
+            nonce: nonce?,
+            opaque,
+            algorithm,
+            qop,
+            stale,
+        })
+    }
+}
+
+/// A challenge in use, with its nonce count.
+#[derive(Debug, Clone)]
+pub struct DigestSession {
+    challenge: DigestChallenge,
+    nonce_count: u32,
+    cnonce: String,
+}
+
+impl DigestSession {
+    /// Starts a session for a fresh challenge.
+    #[must_use]
+    pub fn new(challenge: DigestChallenge) -> Self {
+        Self {
+            challenge,
+            nonce_count: 0,
+            cnonce: Uuid::new_v4().simple().to_string(),
+        }
+    }
+
+    /// The challenge this session answers.
+    #[must_use]
+    pub const fn challenge(&self) -> &DigestChallenge {
+        &self.challenge
+    }
+
+    /// Computes the `Authorization` header for the next request, counting
+    /// it against the nonce.
+    ///
+    /// `uri` is the request target (path and query). `body` is only hashed
+    /// when the server requires `auth-int`.
+    pub fn authorization(
+        &mut self,
+        username: &str,
+        password: &str,
+        method: &str,
+        uri: &str,
+        body: &[u8],
+    ) -> String {
+        self.nonce_count += 1;
+        let challenge = &self.challenge;
+        let algorithm = challenge.algorithm;
+        let nc = format!("{:08x}", self.nonce_count);
+        // Prefer plain `auth` when the server offers both
+        let qop = if challenge.qop.contains(&Qop::Auth) {
+            Some(Qop::Auth)
+        } else {
+            challenge.qop.first().copied()
+        };
+
+        let mut ha1 =
+            algorithm.hash(format!("{username}:{}:{password}", challenge.realm).as_bytes());
+        if algorithm.is_session() {
+            ha1 = algorithm.hash(format!("{ha1}:{}:{}", challenge.nonce, self.cnonce).as_bytes());
+        }
+        let ha2 = if qop == Some(Qop::AuthInt) {
+            algorithm.hash(format!("{method}:{uri}:{}", algorithm.hash(body)).as_bytes())
+        } else {
+            algorithm.hash(format!("{method}:{uri}").as_bytes())
+        };
+        let response = match qop {
+            Some(qop) => algorithm.hash(
+                format!(
+                    "{ha1}:{}:{nc}:{}:{}:{ha2}",
+                    challenge.nonce,
+                    self.cnonce,
+                    qop.as_str()
+                )
+                .as_bytes(),
+            ),
+            None => algorithm.hash(format!("{ha1}:{}:{ha2}", challenge.nonce).as_bytes()),
+        };
+
+        let mut header = format!(
+            "Digest username=\"{}\", realm=\"{}\", nonce=\"{}\", uri=\"{uri}\", algorithm={}, response=\"{response}\"",
+            quote(username),
+            quote(&challenge.realm),
+            quote(&challenge.nonce),
+            algorithm.as_str(),
+        );
+        if let Some(opaque) = &challenge.opaque {
+            let _ = write!(header, ", opaque=\"{}\"", quote(opaque));
+        }
+        if let Some(qop) = qop {
+            let _ = write!(
+                header,
+                ", qop={}, nc={nc}, cnonce=\"{}\"",
+                qop.as_str(),
+                self.cnonce
+            );
+        }
+        header
+    }
+}
+
+/// Splits a header value that may hold several challenges, e.g.
+/// `Digest realm="a", nonce="b", Basic realm="a"`.
+fn split_challenges(value: &str) -> Vec<String> {
+    let mut challenges: Vec<String> = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                // A new challenge starts when the next item is `scheme param=`
+                let rest = value[i + 1..].trim_start();
+                let token_end = rest
+                    .find(|c: char| c == '=' || c.is_whitespace())
+                    .unwrap_or(rest.len());
+                if rest[token_end..].starts_with(char::is_whitespace)
+                    && !rest[token_end..].trim_start().starts_with('=')
+                {
+                    challenges.push(value[start..i].to_string());
+                    start = i + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    challenges.push(value[start..].to_string());
+    challenges
+}
+
+/// Parses `name=value` pairs where values may be quoted strings.
+fn parse_params(params: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut chars = params.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace() || *c == ',').is_some() {}
+        let name: String = std::iter::from_fn(|| chars.next_if(|c| *c != '=')).collect();
+        if chars.next().is_none() {
+            break;
+        }
+        let mut value = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => value.extend(chars.next()),
+                    '"' => break,
+                    _ => value.push(c),
+                }
+            }
+        } else {
+            value = std::iter::from_fn(|| chars.next_if(|c| *c != ',')).collect();
+        }
+        pairs.push((name.trim().to_string(), value.trim().to_string()));
+    }
+    pairs
+}
+
+/// Escapes a value for a quoted string.
+fn quote(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut out, byte| {
+        let _ = write!(out, "{byte:02x}");
+        out
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    /// Challenge from RFC 7616 section 3.9.1.
+    const RFC_CHALLENGE: &str = r#"Digest realm="http-auth@example.org", qop="auth, auth-int", algorithm=SHA-256, nonce="7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v", opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#;
+
+    fn session_with_cnonce(challenge: &str) -> DigestSession {
+        let mut session = DigestSession::new(DigestChallenge::parse(challenge).unwrap());
+        session.cnonce = "f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ".to_string();
+        session
+    }
+
+    fn response_of(header: &str) -> &str {
+        header
+            .split("response=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_challenge() {
+        let challenge = DigestChallenge::parse(RFC_CHALLENGE).unwrap();
+        assert_eq!(challenge.realm, "http-auth@example.org");
+        assert_eq!(challenge.algorithm, DigestAlgorithm::Sha256);
+        assert_eq!(challenge.qop, vec![Qop::Auth, Qop::AuthInt]);
+        assert_eq!(
+            challenge.opaque.as_deref(),
+            Some("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS")
+        );
+        assert!(!challenge.stale);
+
+        assert!(DigestChallenge::parse(r#"Basic realm="x""#).is_none());
+        assert!(DigestChallenge::parse(r#"Digest realm="x""#).is_none());
+        assert!(
+            DigestChallenge::parse(r#"Digest realm="x", nonce="y", algorithm=SHA-512"#).is_none()
+        );
+    }
+
+    #[test]
+    fn test_from_headers_prefers_sha256() {
+        let md5 = RFC_CHALLENGE.replace("SHA-256", "MD5");
+        let combined = format!(r#"Basic realm="x", {md5}"#);
+        let challenge = DigestChallenge::from_headers([combined.as_str(), RFC_CHALLENGE]).unwrap();
+        assert_eq!(challenge.algorithm, DigestAlgorithm::Sha256);
+
+        let challenge = DigestChallenge::from_headers([combined.as_str()]).unwrap();
+        assert_eq!(challenge.algorithm, DigestAlgorithm::Md5);
+        assert_eq!(challenge.realm, "http-auth@example.org");
+    }
+
+    #[test]
+    fn test_rfc7616_sha256_response() {
+        let mut session = session_with_cnonce(RFC_CHALLENGE);
+        let header =
+            session.authorization("Mufasa", "Circle of Life", "GET", "/dir/index.html", b"");
+
+        assert_eq!(
+            response_of(&header),
+            "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1"
+        );
+        assert!(header.contains("qop=auth, nc=00000001"));
+        assert!(header.contains(r#"opaque="FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS""#));
+    }
+
+    #[test]
+    fn test_rfc7616_md5_response() {
+        let mut session = session_with_cnonce(&RFC_CHALLENGE.replace("SHA-256", "MD5"));
+        let header =
+            session.authorization("Mufasa", "Circle of Life", "GET", "/dir/index.html", b"");
+
+        assert_eq!(response_of(&header), "8ca523f5e9506fed4657c9700eebdbec");
+    }
+
+    #[test]
+    fn test_nonce_count_increments() {
+        let mut session = session_with_cnonce(RFC_CHALLENGE);
+        let first = session.authorization("u", "p", "GET", "/", b"");
+        let second = session.authorization("u", "p", "GET", "/", b"");
+        assert!(first.contains("nc=00000001"));
+        assert!(second.contains("nc=00000002"));
+        assert_ne!(response_of(&first), response_of(&second));
+    }
+
+    #[test]
+    fn test_auth_int_covers_body() {
+        let challenge = RFC_CHALLENGE.replace(r#"qop="auth, auth-int""#, r#"qop="auth-int""#);
+        let mut first = session_with_cnonce(&challenge);
+        let mut second = session_with_cnonce(&challenge);
+        let a = first.authorization("u", "p", "POST", "/", b"{}");
+        let b = second.authorization("u", "p", "POST", "/", b"[]");
+        assert!(a.contains("qop=auth-int"));
+        assert_ne!(response_of(&a), response_of(&b));
+    }
+
+    #[test]
+    fn test_rfc2069_challenge_without_qop() {
+        let mut session =
+            DigestSession::new(DigestChallenge::parse(r#"Digest realm="r", nonce="n""#).unwrap());
+        let header = session.authorization("u", "p", "GET", "/", b"");
+        assert!(!header.contains("qop="));
+        assert!(!header.contains("cnonce="));
+    }
+}
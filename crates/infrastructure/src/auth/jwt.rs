@@ -0,0 +1,258 @@
+//! JSON Web Token generation.
+//!
+//! Builds a compact JWS (RFC 7515) from a resolved claims template and signs
+//! it with HS256, RS256 or ES256 (RFC 7518).
+
+use aws_lc_rs::hmac;
+use aws_lc_rs::rand::SystemRandom;
+use aws_lc_rs::signature::{
+    ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, RSA_PKCS1_SHA256, RsaKeyPair,
+};
+use base64::Engine as _;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use serde_json::{Map, Value};
+use vortex_domain::auth::{AuthError, JwtAlgorithm};
+
+/// Builds and signs a JWT.
+///
+/// `claims` must be a JSON object. `secret` is the shared secret for HS256
+/// and a PEM encoded private key for RS256 and ES256: PKCS#8
+/// (`PRIVATE KEY`), PKCS#1 (`RSA PRIVATE KEY`) or SEC1 (`EC PRIVATE KEY`).
+///
+/// # Errors
+///
+/// Returns `AuthError::InvalidConfiguration` if the claims are not a JSON
+/// object or the key cannot be used with the algorithm.
+pub fn encode_jwt(
+    algorithm: JwtAlgorithm,
+    secret: &str,
+    claims: &str,
+    key_id: Option<&str>,
+) -> Result<String, AuthError> {
+    let claims = match serde_json::from_str(claims) {
+        Ok(Value::Object(claims)) => claims,
+        Ok(_) => return Err(invalid("JWT claims must be a JSON object")),
+        Err(e) => return Err(invalid(&format!("Invalid JWT claims: {e}"))),
+    };
+
+    let mut header = Map::new();
+    header.insert("alg".to_string(), algorithm.as_str().into());
+    header.insert("typ".to_string(), "JWT".into());
+    if let Some(key_id) = key_id.filter(|id| !id.is_empty()) {
+        header.insert("kid".to_string(), key_id.into());
+    }
+
+    let signing_input = format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(Value::Object(header).to_string()),
+        URL_SAFE_NO_PAD.encode(Value::Object(claims).to_string())
+    );
+    let signature = sign(algorithm, secret, signing_input.as_bytes())?;
+    Ok(format!(
+        "{signing_input}.{}",
+        URL_SAFE_NO_PAD.encode(signature)
+    ))
+}
+
+fn sign(algorithm: JwtAlgorithm, secret: &str, message: &[u8]) -> Result<Vec<u8>, AuthError> {
+    match algorithm {
+        JwtAlgorithm::Hs256 => {
+            if secret.is_empty() {
+                return Err(invalid("JWT secret is empty"));
+            }
+            let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+            Ok(hmac::sign(&key, message).as_ref().to_vec())
+        }
+        JwtAlgorithm::Rs256 => {
+            let (label, der) = decode_pem(secret)?;
+            let key_pair = match label.as_str() {
+                "PRIVATE KEY" => RsaKeyPair::from_pkcs8(&der),
+                "RSA PRIVATE KEY" => RsaKeyPair::from_der(&der),
+                _ => return Err(unsupported_key(algorithm, &label)),
+            }
+            .map_err(|e| invalid(&format!("Invalid RSA private key: {e}")))?;
+
+            let mut signature = vec![0; key_pair.public_modulus_len()];
+            key_pair
+                .sign(
+                    &RSA_PKCS1_SHA256,
+                    &SystemRandom::new(),
+                    message,
+                    &mut signature,
+                )
+                .map_err(|_| invalid("Failed to sign JWT with the RSA key"))?;
+            Ok(signature)
+        }
+        JwtAlgorithm::Es256 => {
+            let (label, der) = decode_pem(secret)?;
+            let alg = &ECDSA_P256_SHA256_FIXED_SIGNING;
+            let key_pair = match label.as_str() {
+                "PRIVATE KEY" => EcdsaKeyPair::from_pkcs8(alg, &der),
+                "EC PRIVATE KEY" => EcdsaKeyPair::from_private_key_der(alg, &der),
+                _ => return Err(unsupported_key(algorithm, &label)),
+            }
+            .map_err(|e| invalid(&format!("Invalid P-256 private key: {e}")))?;
+
+            let signature = key_pair
+                .sign(&SystemRandom::new(), message)
+                .map_err(|_| invalid("Failed to sign JWT with the EC key"))?;
+            Ok(signature.as_ref().to_vec())
+        }
+    }
+}
+
+/// Extracts the label and DER bytes of the first PEM block.
+fn decode_pem(pem: &str) -> Result<(String, Vec<u8>), AuthError> {
+    let (_, rest) = pem
+        .split_once("-----BEGIN ")
+        .ok_or_else(|| invalid("JWT signing key must be a PEM encoded private key"))?;
+    let (label, rest) = rest
+        .split_once("-----")
+        .ok_or_else(|| invalid("Malformed PEM header in JWT signing key"))?;
+    let end = format!("-----END {label}-----");
+    let (body, _) = rest
+        .split_once(&end)
+        .ok_or_else(|| invalid("Missing PEM footer in JWT signing key"))?;
+
+    let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    let der = STANDARD
+        .decode(body)
+        .map_err(|e| invalid(&format!("Invalid PEM data in JWT signing key: {e}")))?;
+    Ok((label.to_string(), der))
+}
+
+fn unsupported_key(algorithm: JwtAlgorithm, label: &str) -> AuthError {
+    let hint = if label.contains("ENCRYPTED") {
+        "; decrypt the key with `openssl pkey -in <key> -out key.pem`"
+    } else {
+        ""
+    };
+    invalid(&format!(
+        "Unsupported {label} for {}{hint}",
+        algorithm.as_str()
+    ))
+}
+
+fn invalid(message: &str) -> AuthError {
+    AuthError::InvalidConfiguration {
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::path::Path;
+
+    use aws_lc_rs::encoding::AsDer;
+    use aws_lc_rs::rsa::KeySize;
+    use aws_lc_rs::signature::{
+        ECDSA_P256_SHA256_FIXED, KeyPair, RSA_PKCS1_2048_8192_SHA256, UnparsedPublicKey,
+    };
+
+    use super::*;
+
+    /// Splits a token into its signing input, decoded parts and signature.
+    fn split(token: &str) -> (String, Value, Value, Vec<u8>) {
+        let (signing_input, signature) = token.rsplit_once('.').unwrap();
+        let (header, claims) = signing_input.split_once('.').unwrap();
+        let decode = |part: &str| -> Value {
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(part).unwrap()).unwrap()
+        };
+        (
+            signing_input.to_string(),
+            decode(header),
+            decode(claims),
+            URL_SAFE_NO_PAD.decode(signature).unwrap(),
+        )
+    }
+
+    fn to_pem(label: &str, der: &[u8]) -> String {
+        format!(
+            "-----BEGIN {label}-----\n{}\n-----END {label}-----\n",
+            STANDARD.encode(der)
+        )
+    }
+
+    #[test]
+    fn test_hs256() {
+        let token = encode_jwt(
+            JwtAlgorithm::Hs256,
+            "s3cr3t",
+            r#"{ "sub": "svc", "iat": 1700000000 }"#,
+            Some("key-1"),
+        )
+        .unwrap();
+
+        let (signing_input, header, claims, signature) = split(&token);
+        assert_eq!(
+            header,
+            serde_json::json!({"alg": "HS256", "typ": "JWT", "kid": "key-1"})
+        );
+        assert_eq!(
+            claims,
+            serde_json::json!({"sub": "svc", "iat": 1_700_000_000})
+        );
+
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"s3cr3t");
+        assert!(hmac::verify(&key, signing_input.as_bytes(), &signature).is_ok());
+    }
+
+    #[test]
+    fn test_rs256() {
+        let key_pair = RsaKeyPair::generate(KeySize::Rsa2048).unwrap();
+        let pem = to_pem("PRIVATE KEY", AsDer::as_der(&key_pair).unwrap().as_ref());
+
+        let token = encode_jwt(JwtAlgorithm::Rs256, &pem, r#"{"sub":"svc"}"#, None).unwrap();
+        let (signing_input, header, _, signature) = split(&token);
+        assert_eq!(header["alg"], "RS256");
+        assert!(header.get("kid").is_none());
+
+        let public_key =
+            UnparsedPublicKey::new(&RSA_PKCS1_2048_8192_SHA256, key_pair.public_key().as_ref());
+        assert!(
+            public_key
+                .verify(signing_input.as_bytes(), &signature)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_es256() {
+        let pem = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tls/client.key"),
+        )
+        .unwrap();
+
+        let token = encode_jwt(JwtAlgorithm::Es256, &pem, r#"{"sub":"svc"}"#, None).unwrap();
+        let (signing_input, header, _, signature) = split(&token);
+        assert_eq!(header["alg"], "ES256");
+        assert_eq!(signature.len(), 64);
+
+        let (_, der) = decode_pem(&pem).unwrap();
+        let key_pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &der).unwrap();
+        let public_key =
+            UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, key_pair.public_key().as_ref());
+        assert!(
+            public_key
+                .verify(signing_input.as_bytes(), &signature)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = |result: Result<String, AuthError>| match result {
+            Err(AuthError::InvalidConfiguration { message }) => message,
+            other => panic!("Expected configuration error, got {other:?}"),
+        };
+
+        assert!(err(encode_jwt(JwtAlgorithm::Hs256, "k", "[1, 2]", None)).contains("object"));
+        assert!(err(encode_jwt(JwtAlgorithm::Hs256, "k", "{\"iat\": }", None)).contains("claims"));
+        assert!(err(encode_jwt(JwtAlgorithm::Hs256, "", "{}", None)).contains("empty"));
+        assert!(err(encode_jwt(JwtAlgorithm::Rs256, "s3cr3t", "{}", None)).contains("PEM"));
+
+        let encrypted = to_pem("ENCRYPTED PRIVATE KEY", b"key");
+        assert!(err(encode_jwt(JwtAlgorithm::Es256, &encrypted, "{}", None)).contains("decrypt"));
+    }
+}
@@ -2,8 +2,20 @@
 //!
 //! This module provides concrete implementations of authentication providers:
 //! - `OAuth2` Client Credentials flow
-//! - `OAuth2` Authorization Code flow (with callback server)
+//! - `OAuth2` Authorization Code flow (with PKCE and a loopback callback server)
+//! - `OAuth2` Password, Device Authorization and JWT Bearer grants
+//! - AWS Signature Version 4 request signing
+//! - HTTP Digest challenge/response
+//! - JWT generation from a claims template
 
+mod authorization_code;
+mod aws_sigv4;
+mod digest;
+mod jwt;
 mod oauth2_provider;
 
-pub use oauth2_provider::OAuth2Provider;
+pub use authorization_code::{AuthorizationRequest, CallbackServer, Pkce, open_in_browser};
+pub use aws_sigv4::{SIGNATURE_HEADERS, SigV4Credentials, sign_request};
+pub use digest::{DigestAlgorithm, DigestChallenge, DigestSession, Qop};
+pub use jwt::encode_jwt;
+pub use oauth2_provider::{BrowserOpener, OAuth2Provider};
@@ -1,18 +1,42 @@
 //! `OAuth2` authentication provider implementation.
 //!
-//! This module provides `OAuth2` Client Credentials and Authorization Code
-//! flow implementations.
+//! This module provides `OAuth2` Client Credentials, Authorization Code,
+//! Resource Owner Password, Device Authorization and JWT Bearer flow
+//! implementations.
 
 #![allow(missing_docs)]
 
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use vortex_application::{AuthProvider, TokenStore};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+use vortex_application::{AuthEvent, AuthProvider, TokenStore};
 use vortex_domain::{AuthConfig, AuthError, AuthResolution, OAuth2Token};
 
+use super::authorization_code::{AuthorizationRequest, CallbackServer, Pkce, open_in_browser};
+use super::jwt::encode_jwt;
+
+/// Opens the authorization URL for the user, usually in a browser.
+pub type BrowserOpener = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
 /// Content-Type for form-urlencoded data.
 const FORM_CONTENT_TYPE: &str = "application/x-www-form-urlencoded";
 
+/// Grant type for the Device Authorization Grant (RFC 8628).
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Grant type for JWT Bearer assertions (RFC 7523).
+const JWT_BEARER_GRANT: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// Polling interval when the device authorization response names none.
+const DEFAULT_DEVICE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How much to back off when the server answers `slow_down`.
+const DEVICE_SLOW_DOWN_STEP: Duration = Duration::from_secs(5);
+
 /// `OAuth2` token response from token endpoint.
 #[derive(Debug, Deserialize)]
 struct TokenResponse {
@@ -26,6 +50,23 @@ struct TokenResponse {
     scope: Option<String>,
 }
 
+impl TokenResponse {
+    fn into_token(self) -> OAuth2Token {
+        let scopes: Vec<String> = self
+            .scope
+            .map(|s: String| s.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+
+        OAuth2Token::new(
+            self.access_token,
+            self.token_type,
+            self.expires_in,
+            self.refresh_token,
+            scopes,
+        )
+    }
+}
+
 /// `OAuth2` error response.
 #[derive(Debug, Deserialize)]
 struct TokenErrorResponse {
@@ -34,29 +75,53 @@ struct TokenErrorResponse {
     error_description: Option<String>,
 }
 
+impl TokenErrorResponse {
+    fn into_auth_error(self) -> AuthError {
+        AuthError::OAuth2AuthorizationFailed {
+            message: self.error_description.unwrap_or(self.error),
+        }
+    }
+}
+
+/// Device authorization response (RFC 8628 section 3.2).
+#[derive(Debug, Deserialize)]
+struct DeviceAuthorizationResponse {
+    device_code: String,
+    user_code: String,
+    /// Some providers still use the draft name `verification_url`.
+    #[serde(alias = "verification_url")]
+    verification_uri: String,
+    #[serde(default)]
+    verification_uri_complete: Option<String>,
+    expires_in: u64,
+    #[serde(default)]
+    interval: Option<u64>,
+}
+
 /// `OAuth2` authentication provider.
 ///
-/// Handles `OAuth2` Client Credentials and Authorization Code flows
-/// with automatic token caching and refresh.
+/// Handles the `OAuth2` Client Credentials, Authorization Code, Password,
+/// Device Authorization and JWT Bearer flows with automatic token caching
+/// and refresh.
 pub struct OAuth2Provider {
     token_store: Arc<TokenStore>,
     http_client: reqwest::Client,
     /// Callback server port for Authorization Code flow.
     callback_port: u16,
+    /// How long to wait for the browser to return to the callback.
+    callback_timeout: Duration,
+    browser_opener: BrowserOpener,
+    events: Option<mpsc::UnboundedSender<AuthEvent>>,
+    /// Overrides the polling interval announced by the device authorization
+    /// endpoint.
+    device_poll_interval: Option<Duration>,
 }
 
 impl OAuth2Provider {
     /// Create a new `OAuth2` provider.
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            token_store: Arc::new(TokenStore::new()),
-            http_client: reqwest::Client::builder()
-                .redirect(reqwest::redirect::Policy::none())
-                .build()
-                .unwrap_or_else(|_| reqwest::Client::new()),
-            callback_port: 8080,
-        }
+        Self::with_token_store(Arc::new(TokenStore::new()))
     }
 
     /// Create with custom token store (for sharing between providers).
@@ -69,16 +134,51 @@ impl OAuth2Provider {
                 .build()
                 .unwrap_or_else(|_| reqwest::Client::new()),
             callback_port: 8080,
+            callback_timeout: Duration::from_secs(300),
+            browser_opener: Arc::new(open_in_browser),
+            events: None,
+            device_poll_interval: None,
         }
     }
 
     /// Set the callback port for Authorization Code flow.
+    ///
+    /// Used when the redirect URI does not name a port itself.
     #[must_use]
     pub const fn with_callback_port(mut self, port: u16) -> Self {
         self.callback_port = port;
         self
     }
 
+    /// Set how long the Authorization Code flow waits for the callback.
+    #[must_use]
+    pub const fn with_callback_timeout(mut self, timeout: Duration) -> Self {
+        self.callback_timeout = timeout;
+        self
+    }
+
+    /// Set how the authorization URL is opened (the system browser by default).
+    #[must_use]
+    pub fn with_browser_opener(mut self, opener: BrowserOpener) -> Self {
+        self.browser_opener = opener;
+        self
+    }
+
+    /// Poll the token endpoint at a fixed interval during the Device
+    /// Authorization flow, instead of the interval the server announces.
+    #[must_use]
+    pub const fn with_device_poll_interval(mut self, interval: Duration) -> Self {
+        self.device_poll_interval = Some(interval);
+        self
+    }
+
+    /// Send `AuthEvent`s about flow progress to a channel.
+    #[must_use]
+    pub fn with_event_sender(mut self, events: mpsc::UnboundedSender<AuthEvent>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
     /// Get access to the token store.
     #[must_use]
     pub fn token_store(&self) -> &TokenStore {
@@ -94,89 +194,387 @@ impl OAuth2Provider {
         scope: Option<&str>,
     ) -> Result<OAuth2Token, AuthError> {
         let mut params = vec![
-            ("grant_type".to_string(), "client_credentials".to_string()),
-            ("client_id".to_string(), client_id.to_string()),
-            ("client_secret".to_string(), client_secret.to_string()),
+            ("grant_type", "client_credentials"),
+            ("client_id", client_id),
+            ("client_secret", client_secret),
         ];
 
         if let Some(s) = scope {
-            params.push(("scope".to_string(), s.to_string()));
+            params.push(("scope", s));
         }
 
-        let body = serde_urlencoded::to_string(&params).map_err(|e| AuthError::NetworkError {
-            message: format!("Failed to encode form: {e}"),
-        })?;
+        self.request_token(token_url, &params).await
+    }
 
-        let response = self
-            .http_client
-            .post(token_url)
-            .header("Content-Type", FORM_CONTENT_TYPE)
-            .body(body)
-            .send()
+    /// Execute refresh token flow.
+    ///
+    /// The old refresh token is kept if the server does not issue a new one.
+    async fn refresh_token_flow(
+        &self,
+        token_url: &str,
+        client_id: &str,
+        client_secret: &str,
+        refresh_token: &str,
+    ) -> Result<OAuth2Token, AuthError> {
+        let mut params = vec![
+            ("grant_type", "refresh_token"),
+            ("client_id", client_id),
+            ("refresh_token", refresh_token),
+        ];
+        if !client_secret.is_empty() {
+            params.push(("client_secret", client_secret));
+        }
+
+        let mut token = self
+            .request_token(token_url, &params)
             .await
-            .map_err(|e: reqwest::Error| AuthError::NetworkError {
-                message: e.to_string(),
+            .map_err(|e| match e {
+                AuthError::OAuth2AuthorizationFailed { message } => {
+                    AuthError::RefreshFailed { message }
+                }
+                other => other,
             })?;
+        if token.refresh_token.is_none() {
+            token.refresh_token = Some(refresh_token.to_string());
+        }
+        Ok(token)
+    }
 
-        if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            if let Ok(error_response) = serde_json::from_str::<TokenErrorResponse>(&error_text) {
-                return Err(AuthError::OAuth2AuthorizationFailed {
-                    message: error_response
-                        .error_description
-                        .unwrap_or(error_response.error),
+    /// Run an interactive flow: Authorization Code with PKCE, or Device
+    /// Authorization.
+    ///
+    /// For Authorization Code, starts a loopback server on the redirect URI,
+    /// opens the authorization URL, waits for the callback, validates its
+    /// `state` and exchanges the code for a token. For Device Authorization,
+    /// announces the user code with `AuthEvent::DeviceCodeIssued` and polls
+    /// the token endpoint until the user approves. The token is stored for
+    /// later requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if any step fails, or `AuthError::UserCancelled`
+    /// if the user denied access.
+    pub async fn authorize(&self, config: &AuthConfig) -> Result<OAuth2Token, AuthError> {
+        let flow = match config {
+            AuthConfig::OAuth2AuthorizationCode {
+                auth_url,
+                token_url,
+                client_id,
+                client_secret,
+                redirect_uri,
+                scope,
+                extra_params,
+            } => {
+                self.emit(AuthEvent::Started {
+                    config_key: config.cache_key().unwrap_or_default(),
                 });
+                self.authorization_code_flow(
+                    auth_url,
+                    token_url,
+                    client_id,
+                    client_secret,
+                    redirect_uri,
+                    scope.as_deref(),
+                    extra_params,
+                )
+                .await
+            }
+            AuthConfig::OAuth2DeviceCode {
+                device_authorization_url,
+                token_url,
+                client_id,
+                client_secret,
+                scope,
+                extra_params,
+            } => {
+                self.emit(AuthEvent::Started {
+                    config_key: config.cache_key().unwrap_or_default(),
+                });
+                self.device_code_flow(
+                    device_authorization_url,
+                    token_url,
+                    client_id,
+                    client_secret,
+                    scope.as_deref(),
+                    extra_params,
+                )
+                .await
+            }
+            _ => {
+                return Err(AuthError::InvalidConfiguration {
+                    message: "Config is not an interactive OAuth2 config".to_string(),
+                });
+            }
+        };
+        self.finish_flow(config, flow).await
+    }
+
+    /// Request a token with a non-interactive grant: Resource Owner
+    /// Password or JWT Bearer. The token is stored for later requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if the token endpoint rejects the grant.
+    pub async fn request_grant(&self, config: &AuthConfig) -> Result<OAuth2Token, AuthError> {
+        let flow = match config {
+            AuthConfig::OAuth2Password {
+                token_url,
+                client_id,
+                client_secret,
+                username,
+                password,
+                scope,
+                extra_params,
+            } => {
+                self.emit(AuthEvent::Started {
+                    config_key: config.cache_key().unwrap_or_default(),
+                });
+                let mut params = vec![
+                    ("grant_type", "password"),
+                    ("username", username.as_str()),
+                    ("password", password.as_str()),
+                ];
+                push_client_params(&mut params, client_id, client_secret, scope.as_deref());
+                params.extend(extra_params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+                self.request_token(token_url, &params).await
+            }
+            AuthConfig::OAuth2JwtBearer {
+                token_url,
+                assertion,
+                client_id,
+                client_secret,
+                scope,
+                extra_params,
+            } => {
+                self.emit(AuthEvent::Started {
+                    config_key: config.cache_key().unwrap_or_default(),
+                });
+                let mut params = vec![
+                    ("grant_type", JWT_BEARER_GRANT),
+                    ("assertion", assertion.as_str()),
+                ];
+                push_client_params(&mut params, client_id, client_secret, scope.as_deref());
+                params.extend(extra_params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+                self.request_token(token_url, &params).await
+            }
+            _ => {
+                return Err(AuthError::InvalidConfiguration {
+                    message: "Config is not an OAuth2 password or JWT bearer config".to_string(),
+                });
+            }
+        };
+        self.finish_flow(config, flow).await
+    }
+
+    /// Report the outcome of a flow and store the token on success.
+    async fn finish_flow(
+        &self,
+        config: &AuthConfig,
+        result: Result<OAuth2Token, AuthError>,
+    ) -> Result<OAuth2Token, AuthError> {
+        match result {
+            Ok(token) => {
+                self.emit(AuthEvent::TokenObtained {
+                    token_preview: AuthEvent::token_preview(&token.access_token),
+                    expires_in: expires_in(&token),
+                });
+                if let Some(cache_key) = config.cache_key() {
+                    self.token_store.store(cache_key, token.clone()).await;
+                }
+                Ok(token)
+            }
+            Err(AuthError::UserCancelled) => {
+                self.emit(AuthEvent::Cancelled);
+                Err(AuthError::UserCancelled)
+            }
+            Err(e) => {
+                self.emit(AuthEvent::Failed {
+                    error: e.to_string(),
+                });
+                Err(e)
             }
-            return Err(AuthError::OAuth2AuthorizationFailed {
-                message: format!("Token request failed: {error_text}"),
-            });
         }
+    }
 
-        let token_response: TokenResponse =
-            response
-                .json()
-                .await
-                .map_err(|e: reqwest::Error| AuthError::NetworkError {
-                    message: format!("Failed to parse token response: {e}"),
-                })?;
+    #[allow(clippy::too_many_arguments)]
+    async fn authorization_code_flow(
+        &self,
+        auth_url: &str,
+        token_url: &str,
+        client_id: &str,
+        client_secret: &str,
+        redirect_uri: &str,
+        scope: Option<&str>,
+        extra_params: &BTreeMap<String, String>,
+    ) -> Result<OAuth2Token, AuthError> {
+        let server = CallbackServer::bind(redirect_uri, self.callback_port).await?;
+        let redirect_uri = server.redirect_uri().to_string();
+        let pkce = Pkce::new();
+        let state = Uuid::new_v4().simple().to_string();
 
-        let scopes: Vec<String> = token_response
-            .scope
-            .map(|s: String| s.split_whitespace().map(String::from).collect())
-            .unwrap_or_default();
+        let url = AuthorizationRequest {
+            auth_url,
+            client_id,
+            redirect_uri: &redirect_uri,
+            scope,
+            state: &state,
+            pkce: &pkce,
+            extra_params,
+        }
+        .url()?;
+        (self.browser_opener)(&url)
+            .map_err(|message| AuthError::CallbackServerError { message })?;
+        self.emit(AuthEvent::BrowserOpened { url });
 
-        Ok(OAuth2Token::new(
-            token_response.access_token,
-            token_response.token_type,
-            token_response.expires_in,
-            token_response.refresh_token,
-            scopes,
-        ))
+        let code = server.wait_for_code(&state, self.callback_timeout).await?;
+        self.emit(AuthEvent::CallbackReceived);
+
+        self.emit(AuthEvent::ExchangingToken);
+        let mut params = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code.as_str()),
+            ("redirect_uri", redirect_uri.as_str()),
+            ("client_id", client_id),
+            ("code_verifier", pkce.verifier.as_str()),
+        ];
+        if !client_secret.is_empty() {
+            params.push(("client_secret", client_secret));
+        }
+        self.request_token(token_url, &params).await
     }
 
-    /// Execute refresh token flow.
-    async fn refresh_token_flow(
+    /// Run the Device Authorization flow (RFC 8628).
+    ///
+    /// Polls the token endpoint until the user approves the code, denies it,
+    /// or the device code expires.
+    async fn device_code_flow(
         &self,
+        device_authorization_url: &str,
         token_url: &str,
         client_id: &str,
         client_secret: &str,
-        refresh_token: &str,
+        scope: Option<&str>,
+        extra_params: &BTreeMap<String, String>,
     ) -> Result<OAuth2Token, AuthError> {
-        let params = [
-            ("grant_type".to_string(), "refresh_token".to_string()),
-            ("client_id".to_string(), client_id.to_string()),
-            ("client_secret".to_string(), client_secret.to_string()),
-            ("refresh_token".to_string(), refresh_token.to_string()),
+        let mut params = Vec::new();
+        push_client_params(&mut params, client_id, client_secret, scope);
+        params.extend(extra_params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        let device: DeviceAuthorizationResponse = self
+            .post_form(device_authorization_url, &params)
+            .await?
+            .map_err(TokenErrorResponse::into_auth_error)?;
+
+        self.emit(AuthEvent::DeviceCodeIssued {
+            user_code: device.user_code.clone(),
+            verification_uri: device.verification_uri.clone(),
+            verification_uri_complete: device.verification_uri_complete.clone(),
+            expires_in: device.expires_in,
+        });
+
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(device.expires_in);
+        let mut interval = self.device_poll_interval.unwrap_or_else(|| {
+            device
+                .interval
+                .map_or(DEFAULT_DEVICE_POLL_INTERVAL, Duration::from_secs)
+        });
+        let mut params = vec![
+            ("grant_type", DEVICE_CODE_GRANT),
+            ("device_code", device.device_code.as_str()),
         ];
+        push_client_params(&mut params, client_id, client_secret, None);
+
+        loop {
+            tokio::time::sleep(interval).await;
+            if tokio::time::Instant::now() >= deadline {
+                return Err(AuthError::OAuth2AuthorizationFailed {
+                    message: "Device code expired before it was approved".to_string(),
+                });
+            }
+
+            let error = match self.post_form::<TokenResponse>(token_url, &params).await? {
+                Ok(response) => {
+                    self.emit(AuthEvent::ExchangingToken);
+                    return Ok(response.into_token());
+                }
+                Err(error) => error,
+            };
+            match error.error.as_str() {
+                "authorization_pending" => {}
+                "slow_down" => interval += DEVICE_SLOW_DOWN_STEP,
+                "access_denied" => return Err(AuthError::UserCancelled),
+                "expired_token" => {
+                    return Err(AuthError::OAuth2AuthorizationFailed {
+                        message: "Device code expired before it was approved".to_string(),
+                    });
+                }
+                _ => return Err(error.into_auth_error()),
+            }
+        }
+    }
+
+    /// Resolve a config whose tokens can be refreshed.
+    ///
+    /// Uses the cached token while it is valid, refreshes it shortly before
+    /// it expires, and runs the grant's flow otherwise.
+    async fn resolve_with_refresh(&self, config: &AuthConfig) -> AuthResolution {
+        let cache_key = config.cache_key().unwrap_or_default();
+
+        if self.token_store.needs_refresh(&cache_key).await
+            && let Some(refresh_token) = self
+                .token_store
+                .get(&cache_key)
+                .await
+                .and_then(|token| token.refresh_token)
+            && let Ok(token) = self.refresh_token(config, &refresh_token).await
+        {
+            return Self::token_header(&token);
+        }
+        if let Some(token) = self.token_store.get_valid(&cache_key).await {
+            return Self::token_header(&token);
+        }
 
-        let body = serde_urlencoded::to_string(&params).map_err(|e| AuthError::NetworkError {
+        let result = match config {
+            AuthConfig::OAuth2Password { .. } | AuthConfig::OAuth2JwtBearer { .. } => {
+                self.request_grant(config).await
+            }
+            _ => self.authorize(config).await,
+        };
+        match result {
+            Ok(token) => Self::token_header(&token),
+            Err(error) => AuthResolution::Failed { error },
+        }
+    }
+
+    /// Post a form to the token endpoint and parse the token response.
+    async fn request_token(
+        &self,
+        token_url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<OAuth2Token, AuthError> {
+        self.post_form::<TokenResponse>(token_url, params)
+            .await?
+            .map(TokenResponse::into_token)
+            .map_err(TokenErrorResponse::into_auth_error)
+    }
+
+    /// Post a form to an `OAuth2` endpoint and parse the JSON response.
+    ///
+    /// Standard `OAuth2` error responses are returned in the inner `Err` so
+    /// callers can react to specific error codes.
+    async fn post_form<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Result<T, TokenErrorResponse>, AuthError> {
+        let body = serde_urlencoded::to_string(params).map_err(|e| AuthError::NetworkError {
             message: format!("Failed to encode form: {e}"),
         })?;
 
         let response = self
             .http_client
-            .post(token_url)
+            .post(url)
             .header("Content-Type", FORM_CONTENT_TYPE)
+            .header("Accept", "application/json")
             .body(body)
             .send()
             .await
@@ -186,31 +584,34 @@ impl OAuth2Provider {
 
         if !response.status().is_success() {
             let error_text = response.text().await.unwrap_or_default();
-            return Err(AuthError::RefreshFailed {
-                message: error_text,
+            if let Ok(error_response) = serde_json::from_str::<TokenErrorResponse>(&error_text) {
+                return Ok(Err(error_response));
+            }
+            return Err(AuthError::OAuth2AuthorizationFailed {
+                message: format!("Token request failed: {error_text}"),
             });
         }
 
-        let token_response: TokenResponse =
-            response
-                .json()
-                .await
-                .map_err(|e: reqwest::Error| AuthError::NetworkError {
-                    message: format!("Failed to parse token response: {e}"),
-                })?;
+        response
+            .json()
+            .await
+            .map(Ok)
+            .map_err(|e: reqwest::Error| AuthError::NetworkError {
+                message: format!("Failed to parse response from {url}: {e}"),
+            })
+    }
 
-        let scopes: Vec<String> = token_response
-            .scope
-            .map(|s: String| s.split_whitespace().map(String::from).collect())
-            .unwrap_or_default();
+    fn emit(&self, event: AuthEvent) {
+        if let Some(events) = &self.events {
+            let _ = events.send(event);
+        }
+    }
 
-        Ok(OAuth2Token::new(
-            token_response.access_token,
-            token_response.token_type,
-            token_response.expires_in,
-            token_response.refresh_token,
-            scopes,
-        ))
+    fn token_header(token: &OAuth2Token) -> AuthResolution {
+        AuthResolution::Header {
+            name: "Authorization".to_string(),
+            value: token.authorization_header(),
+        }
     }
 
     /// Resolve Bearer auth (simple token formatting).
@@ -251,6 +652,35 @@ impl OAuth2Provider {
     }
 }
 
+/// Add client authentication and scope to a token or device request.
+///
+/// The secret is only sent for confidential clients, and the client ID only
+/// when one is configured (JWT Bearer grants may authenticate through the
+/// assertion alone).
+fn push_client_params<'a>(
+    params: &mut Vec<(&'a str, &'a str)>,
+    client_id: &'a str,
+    client_secret: &'a str,
+    scope: Option<&'a str>,
+) {
+    if !client_id.is_empty() {
+        params.push(("client_id", client_id));
+    }
+    if !client_secret.is_empty() {
+        params.push(("client_secret", client_secret));
+    }
+    if let Some(scope) = scope {
+        params.push(("scope", scope));
+    }
+}
+
+/// Seconds until a token expires, for progress events.
+fn expires_in(token: &OAuth2Token) -> Option<u64> {
+    token
+        .seconds_until_expiry()
+        .and_then(|seconds| u64::try_from(seconds).ok())
+}
+
 impl Default for OAuth2Provider {
     fn default() -> Self {
         Self::new()
@@ -264,7 +694,11 @@ impl AuthProvider for OAuth2Provider {
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = AuthResolution> + Send + 'a>> {
         Box::pin(async move {
             match config {
-                AuthConfig::None => AuthResolution::None,
+                // AWS and Digest requests are signed by the HTTP client once
+                // they are final
+                AuthConfig::None | AuthConfig::AwsSigV4 { .. } | AuthConfig::Digest { .. } => {
+                    AuthResolution::None
+                }
 
                 AuthConfig::Bearer { token, prefix } => Self::resolve_bearer(token, prefix),
 
@@ -276,6 +710,27 @@ impl AuthProvider for OAuth2Provider {
                     location,
                 } => Self::resolve_api_key(key, name, *location),
 
+                // Signed anew on every send so time-based claims stay fresh
+                AuthConfig::Jwt {
+                    algorithm,
+                    secret,
+                    claims,
+                    key_id,
+                    location,
+                    prefix,
+                    query_param,
+                } => match encode_jwt(*algorithm, secret, claims, key_id.as_deref()) {
+                    Ok(token) => match location {
+                        vortex_domain::ApiKeyLocation::Header => {
+                            Self::resolve_bearer(&token, prefix)
+                        }
+                        vortex_domain::ApiKeyLocation::Query => {
+                            Self::resolve_api_key(&token, query_param, *location)
+                        }
+                    },
+                    Err(error) => AuthResolution::Failed { error },
+                },
+
                 AuthConfig::OAuth2ClientCredentials {
                     token_url,
                     client_id,
@@ -320,13 +775,10 @@ impl AuthProvider for OAuth2Provider {
                     }
                 }
 
-                AuthConfig::OAuth2AuthorizationCode { .. } => {
-                    // Authorization Code flow requires user interaction
-                    // For now, return pending - full implementation would open browser
-                    AuthResolution::Pending {
-                        message: "Authorization Code flow requires browser authentication. Click 'Authorize' to continue.".to_string(),
-                    }
-                }
+                AuthConfig::OAuth2AuthorizationCode { .. }
+                | AuthConfig::OAuth2Password { .. }
+                | AuthConfig::OAuth2DeviceCode { .. }
+                | AuthConfig::OAuth2JwtBearer { .. } => self.resolve_with_refresh(config).await,
             }
         })
     }
@@ -342,7 +794,10 @@ impl AuthProvider for OAuth2Provider {
             #[allow(clippy::manual_let_else)]
             let token_url = match config {
                 AuthConfig::OAuth2ClientCredentials { token_url, .. }
-                | AuthConfig::OAuth2AuthorizationCode { token_url, .. } => token_url,
+                | AuthConfig::OAuth2AuthorizationCode { token_url, .. }
+                | AuthConfig::OAuth2Password { token_url, .. }
+                | AuthConfig::OAuth2DeviceCode { token_url, .. }
+                | AuthConfig::OAuth2JwtBearer { token_url, .. } => token_url,
                 _ => {
                     return Err(AuthError::InvalidConfiguration {
                         message: "Config is not an OAuth2 type".to_string(),
@@ -361,6 +816,21 @@ impl AuthProvider for OAuth2Provider {
                     client_id,
                     client_secret,
                     ..
+                }
+                | AuthConfig::OAuth2Password {
+                    client_id,
+                    client_secret,
+                    ..
+                }
+                | AuthConfig::OAuth2DeviceCode {
+                    client_id,
+                    client_secret,
+                    ..
+                }
+                | AuthConfig::OAuth2JwtBearer {
+                    client_id,
+                    client_secret,
+                    ..
                 } => (client_id, client_secret),
                 _ => {
                     return Err(AuthError::InvalidConfiguration {
@@ -373,6 +843,11 @@ impl AuthProvider for OAuth2Provider {
                 .refresh_token_flow(token_url, client_id, client_secret, refresh_token)
                 .await?;
 
+            self.emit(AuthEvent::TokenRefreshed {
+                token_preview: AuthEvent::token_preview(&new_token.access_token),
+                expires_in: expires_in(&new_token),
+            });
+
             // Update cache
             if let Some(cache_key) = config.cache_key() {
                 self.token_store.store(cache_key, new_token.clone()).await;
@@ -410,6 +885,12 @@ impl AuthProvider for OAuth2Provider {
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
+    use std::sync::Mutex;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use url::Url;
+
     use super::*;
 
     #[test]
@@ -469,9 +950,404 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_resolve_jwt() {
+        let provider = OAuth2Provider::new();
+        let config = AuthConfig::jwt(
+            vortex_domain::JwtAlgorithm::Hs256,
+            "s3cr3t",
+            r#"{"sub":"svc"}"#,
+        );
+        match provider.resolve(&config).await {
+            AuthResolution::Header { name, value } => {
+                assert_eq!(name, "Authorization");
+                assert!(value.starts_with("Bearer eyJ"));
+                assert_eq!(value.matches('.').count(), 2);
+            }
+            other => panic!("Expected Header resolution, got {other:?}"),
+        }
+
+        let query = AuthConfig::Jwt {
+            algorithm: vortex_domain::JwtAlgorithm::Hs256,
+            secret: "s3cr3t".to_string(),
+            claims: r#"{"sub":"svc"}"#.to_string(),
+            key_id: None,
+            location: vortex_domain::ApiKeyLocation::Query,
+            prefix: "Bearer".to_string(),
+            query_param: "token".to_string(),
+        };
+        assert!(matches!(
+            provider.resolve(&query).await,
+            AuthResolution::QueryParam { name, .. } if name == "token"
+        ));
+
+        let invalid = AuthConfig::jwt(vortex_domain::JwtAlgorithm::Hs256, "s3cr3t", "not json");
+        assert!(matches!(
+            provider.resolve(&invalid).await,
+            AuthResolution::Failed {
+                error: AuthError::InvalidConfiguration { .. }
+            }
+        ));
+    }
+
     #[tokio::test]
     async fn test_oauth2_provider_creation() {
         let provider = OAuth2Provider::new();
         assert_eq!(provider.callback_port, 8080);
     }
+
+    /// Stand-in authorization server.
+    ///
+    /// `/authorize` redirects back with code `abc` and the echoed state (or
+    /// `denied` access); `/device` issues device code `dev-1`; `/token`
+    /// checks the PKCE verifier against the challenge it saw, answers the
+    /// first device code poll with `authorization_pending` (or denies it) and
+    /// issues numbered tokens. Returns the base URL and the grant types
+    /// received, in order.
+    async fn authorization_server(deny: bool) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let grants = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&grants);
+
+        tokio::spawn(async move {
+            let mut challenge = String::new();
+            let mut redirect_uri = String::new();
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = vec![0; 8192];
+                let n = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]).to_string();
+                let target = request.split(' ').nth(1).unwrap_or("/").to_string();
+                let url = Url::parse(&format!("http://localhost{target}")).unwrap();
+
+                let response = if url.path() == "/device" {
+                    let body = r#"{"device_code":"dev-1","user_code":"WDJB-MJHT","verification_uri":"https://example.com/device","expires_in":600,"interval":5}"#;
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                } else if url.path() == "/authorize" {
+                    let query: BTreeMap<String, String> = url.query_pairs().into_owned().collect();
+                    challenge.clone_from(&query["code_challenge"]);
+                    redirect_uri.clone_from(&query["redirect_uri"]);
+                    let location = if deny {
+                        format!(
+                            "{redirect_uri}?error=access_denied&state={}",
+                            query["state"]
+                        )
+                    } else {
+                        format!("{redirect_uri}?code=abc&state={}", query["state"])
+                    };
+                    format!(
+                        "HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    )
+                } else {
+                    let body = request.split("\r\n\r\n").nth(1).unwrap_or_default();
+                    let form: BTreeMap<String, String> = serde_urlencoded::from_str(body).unwrap();
+                    let grant = form["grant_type"].clone();
+                    let valid = match grant.as_str() {
+                        "authorization_code" => {
+                            form["code"] == "abc"
+                                && form["redirect_uri"] == redirect_uri
+                                && Pkce::from_verifier(form["code_verifier"].clone()).challenge
+                                    == challenge
+                        }
+                        "refresh_token" => form["refresh_token"] == "refresh-1",
+                        "password" => form["username"] == "alice" && form["password"] == "hunter2",
+                        JWT_BEARER_GRANT => form["assertion"] == "a.b.c",
+                        DEVICE_CODE_GRANT => form["device_code"] == "dev-1",
+                        _ => false,
+                    };
+                    let (count, polls) = {
+                        let mut log = log.lock().unwrap();
+                        log.push(grant.clone());
+                        let polls = log.iter().filter(|g| *g == DEVICE_CODE_GRANT).count();
+                        (log.len(), polls)
+                    };
+                    let pending = grant == DEVICE_CODE_GRANT && (deny || polls == 1);
+                    let body = if pending {
+                        let error = if deny {
+                            "access_denied"
+                        } else {
+                            "authorization_pending"
+                        };
+                        format!(r#"{{"error":"{error}"}}"#)
+                    } else if valid {
+                        format!(
+                            r#"{{"access_token":"token-{count}","token_type":"Bearer","expires_in":3600,"refresh_token":"refresh-1"}}"#
+                        )
+                    } else {
+                        r#"{"error":"invalid_grant"}"#.to_string()
+                    };
+                    let status = if valid && !pending {
+                        "200 OK"
+                    } else {
+                        "400 Bad Request"
+                    };
+                    format!(
+                        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, grants)
+    }
+
+    /// Stands in for the browser: follows the authorization redirect to the
+    /// callback server.
+    fn browser() -> BrowserOpener {
+        Arc::new(|url: &str| {
+            let url = url.to_string();
+            tokio::spawn(async move {
+                let _ = reqwest::get(url).await;
+            });
+            Ok(())
+        })
+    }
+
+    fn auth_code_config(base: &str) -> AuthConfig {
+        AuthConfig::OAuth2AuthorizationCode {
+            auth_url: format!("{base}/authorize"),
+            token_url: format!("{base}/token"),
+            client_id: "vortex".to_string(),
+            client_secret: String::new(),
+            redirect_uri: "http://127.0.0.1:0/callback".to_string(),
+            scope: Some("read".to_string()),
+            extra_params: BTreeMap::new(),
+        }
+    }
+
+    fn header_value(resolution: AuthResolution) -> String {
+        match resolution {
+            AuthResolution::Header { value, .. } => value,
+            other => panic!("Expected Header resolution, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_flow() {
+        let (base, grants) = authorization_server(false).await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let provider = OAuth2Provider::new()
+            .with_browser_opener(browser())
+            .with_event_sender(tx);
+        let config = auth_code_config(&base);
+
+        let value = header_value(provider.resolve(&config).await);
+        assert_eq!(value, "Bearer token-1");
+
+        // The stored token is reused without another round trip
+        let value = header_value(provider.resolve(&config).await);
+        assert_eq!(value, "Bearer token-1");
+        assert_eq!(*grants.lock().unwrap(), vec!["authorization_code"]);
+
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        assert!(matches!(events[0], AuthEvent::Started { .. }));
+        assert!(
+            matches!(&events[1], AuthEvent::BrowserOpened { url } if url.contains("code_challenge_method=S256"))
+        );
+        assert!(matches!(events[2], AuthEvent::CallbackReceived));
+        assert!(matches!(events[3], AuthEvent::ExchangingToken));
+        assert!(matches!(
+            &events[4],
+            AuthEvent::TokenObtained { token_preview, expires_in: Some(_) } if token_preview == "token-1"
+        ));
+        assert_eq!(events.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_refreshes_expiring_token() {
+        let (base, grants) = authorization_server(false).await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let provider = OAuth2Provider::new()
+            .with_browser_opener(Arc::new(|_: &str| Err("no browser".to_string())))
+            .with_event_sender(tx);
+        let config = auth_code_config(&base);
+
+        let expiring = OAuth2Token::new(
+            "token-0".to_string(),
+            "Bearer".to_string(),
+            Some(10),
+            Some("refresh-1".to_string()),
+            vec![],
+        );
+        provider
+            .token_store()
+            .store(config.cache_key().unwrap(), expiring)
+            .await;
+
+        let value = header_value(provider.resolve(&config).await);
+        assert_eq!(value, "Bearer token-1");
+        assert_eq!(*grants.lock().unwrap(), vec!["refresh_token"]);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            AuthEvent::TokenRefreshed { .. }
+        ));
+
+        let stored = provider
+            .token_store()
+            .get(&config.cache_key().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stored.access_token, "token-1");
+        assert_eq!(stored.refresh_token.as_deref(), Some("refresh-1"));
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_denied() {
+        let (base, grants) = authorization_server(true).await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let provider = OAuth2Provider::new()
+            .with_browser_opener(browser())
+            .with_event_sender(tx);
+
+        let result = provider.authorize(&auth_code_config(&base)).await;
+        assert!(matches!(result, Err(AuthError::UserCancelled)));
+        assert!(grants.lock().unwrap().is_empty());
+
+        let mut last = None;
+        while let Ok(event) = rx.try_recv() {
+            last = Some(event);
+        }
+        assert!(matches!(last, Some(AuthEvent::Cancelled)));
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_browser_failure() {
+        let provider = OAuth2Provider::new()
+            .with_browser_opener(Arc::new(|_: &str| Err("no browser".to_string())));
+        let config = auth_code_config("http://127.0.0.1:9");
+
+        match provider.resolve(&config).await {
+            AuthResolution::Failed {
+                error: AuthError::CallbackServerError { message },
+            } => assert_eq!(message, "no browser"),
+            other => panic!("Expected Failed resolution, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_password_grant() {
+        let (base, grants) = authorization_server(false).await;
+        let provider = OAuth2Provider::new();
+        let config = AuthConfig::OAuth2Password {
+            token_url: format!("{base}/token"),
+            client_id: "vortex".to_string(),
+            client_secret: String::new(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            scope: None,
+            extra_params: BTreeMap::new(),
+        };
+
+        assert_eq!(
+            header_value(provider.resolve(&config).await),
+            "Bearer token-1"
+        );
+        assert_eq!(
+            header_value(provider.resolve(&config).await),
+            "Bearer token-1"
+        );
+        assert_eq!(*grants.lock().unwrap(), vec!["password"]);
+
+        let wrong = AuthConfig::OAuth2Password {
+            token_url: format!("{base}/token"),
+            client_id: "vortex".to_string(),
+            client_secret: String::new(),
+            username: "bob".to_string(),
+            password: "wrong".to_string(),
+            scope: None,
+            extra_params: BTreeMap::new(),
+        };
+        assert!(matches!(
+            provider.resolve(&wrong).await,
+            AuthResolution::Failed {
+                error: AuthError::OAuth2AuthorizationFailed { message }
+            } if message == "invalid_grant"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_jwt_bearer_grant() {
+        let (base, grants) = authorization_server(false).await;
+        let provider = OAuth2Provider::new();
+        let config = AuthConfig::OAuth2JwtBearer {
+            token_url: format!("{base}/token"),
+            assertion: "a.b.c".to_string(),
+            client_id: String::new(),
+            client_secret: String::new(),
+            scope: Some("read".to_string()),
+            extra_params: BTreeMap::new(),
+        };
+
+        assert_eq!(
+            header_value(provider.resolve(&config).await),
+            "Bearer token-1"
+        );
+        assert_eq!(*grants.lock().unwrap(), vec![JWT_BEARER_GRANT]);
+        let stored = provider
+            .token_store()
+            .get(&config.cache_key().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stored.access_token, "token-1");
+    }
+
+    fn device_code_config(base: &str) -> AuthConfig {
+        AuthConfig::OAuth2DeviceCode {
+            device_authorization_url: format!("{base}/device"),
+            token_url: format!("{base}/token"),
+            client_id: "vortex".to_string(),
+            client_secret: String::new(),
+            scope: None,
+            extra_params: BTreeMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_device_code_flow() {
+        let (base, grants) = authorization_server(false).await;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let provider = OAuth2Provider::new()
+            .with_device_poll_interval(Duration::from_millis(10))
+            .with_event_sender(tx);
+        let config = device_code_config(&base);
+
+        assert_eq!(
+            header_value(provider.resolve(&config).await),
+            "Bearer token-2"
+        );
+        assert_eq!(
+            *grants.lock().unwrap(),
+            vec![DEVICE_CODE_GRANT, DEVICE_CODE_GRANT]
+        );
+
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        assert!(matches!(events[0], AuthEvent::Started { .. }));
+        assert!(matches!(
+            &events[1],
+            AuthEvent::DeviceCodeIssued { user_code, verification_uri, expires_in: 600, .. }
+                if user_code == "WDJB-MJHT" && verification_uri == "https://example.com/device"
+        ));
+        assert!(matches!(events[2], AuthEvent::ExchangingToken));
+        assert!(matches!(events[3], AuthEvent::TokenObtained { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_device_code_denied() {
+        let (base, _) = authorization_server(true).await;
+        let provider = OAuth2Provider::new().with_device_poll_interval(Duration::from_millis(10));
+
+        let result = provider.authorize(&device_code_config(&base)).await;
+        assert!(matches!(result, Err(AuthError::UserCancelled)));
+    }
 }
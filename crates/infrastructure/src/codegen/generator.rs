@@ -1,7 +1,9 @@
 //! Code generator implementations for various languages.
 
+use vortex_domain::auth::AuthConfig;
 use vortex_domain::codegen::{CodeGenOptions, CodeLanguage, CodeSnippet};
-use vortex_domain::request::RequestSpec;
+use vortex_domain::grpc::is_grpc_url;
+use vortex_domain::request::{HttpMethod, RequestSpec};
 
 /// Generate code for a request in the specified language.
 #[must_use]
@@ -24,9 +26,23 @@ impl<'a> CodeGenerator<'a> {
     }
 
     /// Generate code for the given request.
+    ///
+    /// gRPC requests produce a comment saying they are not supported.
     #[must_use]
     pub fn generate(&self, request: &RequestSpec) -> CodeSnippet {
-        match self.options.language {
+        let language = self.options.language;
+        if is_grpc_url(&request.url) {
+            return CodeSnippet::new(
+                format!(
+                    "{} Code generation is not supported for gRPC requests ({})\n",
+                    language.line_comment(),
+                    request.url
+                ),
+                language,
+            );
+        }
+
+        match language {
             CodeLanguage::Curl => self.generate_curl(request),
             CodeLanguage::Python => self.generate_python(request),
             CodeLanguage::JavaScript => self.generate_javascript_fetch(request),
@@ -48,8 +64,8 @@ impl<'a> CodeGenerator<'a> {
         let url = request.full_url();
 
         // Method (GET is default, others need -X)
-        if request.method != vortex_domain::request::HttpMethod::Get {
-            parts.push(format!("-X {}", request.method.as_str().to_uppercase()));
+        if request.method != HttpMethod::Get {
+            parts.push(format!("-X {}", request.method.as_str()));
         }
 
         // Headers
@@ -57,6 +73,22 @@ impl<'a> CodeGenerator<'a> {
             parts.push(format!("-H '{}: {}'", header.name, header.value));
         }
 
+        // AWS Signature Version 4 (curl 7.75+ signs the request itself)
+        if let AuthConfig::AwsSigV4 {
+            access_key,
+            secret_key,
+            session_token,
+            region,
+            service,
+        } = &request.auth
+        {
+            parts.push(format!("--aws-sigv4 'aws:amz:{region}:{service}'"));
+            parts.push(format!("--user '{access_key}:{secret_key}'"));
+            if let Some(token) = session_token {
+                parts.push(format!("-H 'x-amz-security-token: {token}'"));
+            }
+        }
+
         // Body
         if !request.body.content.is_empty() {
             let escaped = request.body.content.replace('\'', "'\\''");
@@ -117,8 +149,16 @@ impl<'a> CodeGenerator<'a> {
             code.push_str(&format!("data = '''{}'''\n\n", request.body.content));
         }
 
-        // Request
-        code.push_str(&format!("response = requests.{method}(\n"));
+        // Request, with a shortcut function for the common methods
+        if matches!(
+            request.method,
+            HttpMethod::Trace | HttpMethod::Connect | HttpMethod::Custom(_)
+        ) {
+            code.push_str("response = requests.request(\n");
+            code.push_str(&format!("{indent}'{}',\n", request.method));
+        } else {
+            code.push_str(&format!("response = requests.{method}(\n"));
+        }
         code.push_str(&format!("{indent}'{url}',\n"));
 
         if !headers.is_empty() || request.body.content_type().is_some() {
@@ -153,7 +193,7 @@ impl<'a> CodeGenerator<'a> {
         code.push_str(&format!(
             "{}method: '{}',\n",
             indent,
-            request.method.as_str().to_uppercase()
+            request.method.as_str()
         ));
 
         // Headers
@@ -257,7 +297,7 @@ impl<'a> CodeGenerator<'a> {
         code.push_str(&format!(
             "{}method: '{}',\n",
             indent,
-            request.method.as_str().to_uppercase()
+            request.method.as_str()
         ));
 
         // Headers
@@ -299,12 +339,23 @@ impl<'a> CodeGenerator<'a> {
     fn generate_rust(&self, request: &RequestSpec) -> CodeSnippet {
         let indent = self.options.indent();
         let url = request.full_url();
-        let method = request.method.as_str().lower();
+        let builder = match &request.method {
+            HttpMethod::Get
+            | HttpMethod::Post
+            | HttpMethod::Put
+            | HttpMethod::Patch
+            | HttpMethod::Delete
+            | HttpMethod::Head => format!("{}(\"{url}\")", request.method.as_str().lower()),
+            HttpMethod::Custom(name) => {
+                format!("request(reqwest::Method::from_bytes(b\"{name}\")?, \"{url}\")")
+            }
+            method => format!("request(reqwest::Method::{method}, \"{url}\")"),
+        };
 
         let mut code = String::new();
 
         code.push_str("let client = reqwest::Client::new();\n\n");
-        code.push_str(&format!("let response = client.{method}(\"{url}\")\n"));
+        code.push_str(&format!("let response = client.{builder}\n"));
 
         // Headers
         for h in request.enabled_headers() {
@@ -337,7 +388,7 @@ impl<'a> CodeGenerator<'a> {
     fn generate_go(&self, request: &RequestSpec) -> CodeSnippet {
         let indent = self.options.indent();
         let url = request.full_url();
-        let method = request.method.as_str().to_uppercase();
+        let method = request.method.as_str();
 
         let mut code = String::new();
 
@@ -403,7 +454,7 @@ impl<'a> CodeGenerator<'a> {
     fn generate_java(&self, request: &RequestSpec) -> CodeSnippet {
         let indent = self.options.indent();
         let url = request.full_url();
-        let method = request.method.as_str().to_uppercase();
+        let method = request.method.as_str();
 
         let mut code = String::new();
 
@@ -462,14 +513,16 @@ impl<'a> CodeGenerator<'a> {
     fn generate_csharp(&self, request: &RequestSpec) -> CodeSnippet {
         let indent = self.options.indent();
         let url = request.full_url();
-        let method = match request.method {
-            vortex_domain::request::HttpMethod::Get => "HttpMethod.Get",
-            vortex_domain::request::HttpMethod::Post => "HttpMethod.Post",
-            vortex_domain::request::HttpMethod::Put => "HttpMethod.Put",
-            vortex_domain::request::HttpMethod::Patch => "HttpMethod.Patch",
-            vortex_domain::request::HttpMethod::Delete => "HttpMethod.Delete",
-            vortex_domain::request::HttpMethod::Head => "HttpMethod.Head",
-            vortex_domain::request::HttpMethod::Options => "HttpMethod.Options",
+        let method = match &request.method {
+            HttpMethod::Get => "HttpMethod.Get".to_string(),
+            HttpMethod::Post => "HttpMethod.Post".to_string(),
+            HttpMethod::Put => "HttpMethod.Put".to_string(),
+            HttpMethod::Patch => "HttpMethod.Patch".to_string(),
+            HttpMethod::Delete => "HttpMethod.Delete".to_string(),
+            HttpMethod::Head => "HttpMethod.Head".to_string(),
+            HttpMethod::Options => "HttpMethod.Options".to_string(),
+            HttpMethod::Trace => "HttpMethod.Trace".to_string(),
+            method => format!("new HttpMethod(\"{method}\")"),
         };
 
         let mut code = String::new();
@@ -514,7 +567,7 @@ impl<'a> CodeGenerator<'a> {
     #[allow(clippy::unused_self)]
     fn generate_php(&self, request: &RequestSpec) -> CodeSnippet {
         let url = request.full_url();
-        let method = request.method.as_str().to_uppercase();
+        let method = request.method.as_str();
 
         let mut code = String::new();
 
@@ -577,17 +630,36 @@ impl<'a> CodeGenerator<'a> {
         code.push_str("http = Net::HTTP.new(uri.host, uri.port)\n");
         code.push_str("http.use_ssl = uri.scheme == 'https'\n\n");
 
-        let request_class = match request.method {
-            vortex_domain::request::HttpMethod::Get => "Get",
-            vortex_domain::request::HttpMethod::Post => "Post",
-            vortex_domain::request::HttpMethod::Put => "Put",
-            vortex_domain::request::HttpMethod::Patch => "Patch",
-            vortex_domain::request::HttpMethod::Delete => "Delete",
-            vortex_domain::request::HttpMethod::Head => "Head",
-            vortex_domain::request::HttpMethod::Options => "Options",
+        // Net::HTTP has classes for the standard and WebDAV methods
+        let request_class = match &request.method {
+            HttpMethod::Get => Some("Get"),
+            HttpMethod::Post => Some("Post"),
+            HttpMethod::Put => Some("Put"),
+            HttpMethod::Patch => Some("Patch"),
+            HttpMethod::Delete => Some("Delete"),
+            HttpMethod::Head => Some("Head"),
+            HttpMethod::Options => Some("Options"),
+            HttpMethod::Trace => Some("Trace"),
+            HttpMethod::Custom(name) => match name.as_str() {
+                "PROPFIND" => Some("Propfind"),
+                "PROPPATCH" => Some("Proppatch"),
+                "MKCOL" => Some("Mkcol"),
+                "COPY" => Some("Copy"),
+                "MOVE" => Some("Move"),
+                "LOCK" => Some("Lock"),
+                "UNLOCK" => Some("Unlock"),
+                _ => None,
+            },
+            HttpMethod::Connect => None,
         };
 
-        code.push_str(&format!("request = Net::HTTP::{request_class}::new(uri)\n"));
+        match request_class {
+            Some(class) => code.push_str(&format!("request = Net::HTTP::{class}::new(uri)\n")),
+            None => code.push_str(&format!(
+                "request = Net::HTTPGenericRequest.new('{}', true, true, uri)\n",
+                request.method
+            )),
+        }
 
         // Headers
         for h in request.enabled_headers() {
@@ -622,7 +694,7 @@ impl<'a> CodeGenerator<'a> {
     fn generate_swift(&self, request: &RequestSpec) -> CodeSnippet {
         let indent = self.options.indent();
         let url = request.full_url();
-        let method = request.method.as_str().to_uppercase();
+        let method = request.method.as_str();
 
         let mut code = String::new();
 
@@ -705,12 +777,24 @@ impl<'a> CodeGenerator<'a> {
         code.push_str("val request = Request.Builder()\n");
         code.push_str(&format!("{indent}.url(\"{url}\")\n"));
 
-        // Method with body
-        let method = request.method.as_str().lower();
-        if !request.body.content.is_empty() {
-            code.push_str(&format!("{indent}.{method}(body)\n"));
-        } else if method != "get" {
-            code.push_str(&format!("{indent}.{method}(null)\n"));
+        // Method with body, with a shortcut function for the common methods
+        let body = if request.body.content.is_empty() {
+            "null"
+        } else {
+            "body"
+        };
+        match &request.method {
+            HttpMethod::Get if request.body.content.is_empty() => {}
+            HttpMethod::Get
+            | HttpMethod::Post
+            | HttpMethod::Put
+            | HttpMethod::Patch
+            | HttpMethod::Delete
+            | HttpMethod::Head => {
+                let method = request.method.as_str().lower();
+                code.push_str(&format!("{indent}.{method}({body})\n"));
+            }
+            method => code.push_str(&format!("{indent}.method(\"{method}\", {body})\n")),
         }
 
         // Headers
@@ -771,6 +855,55 @@ mod tests {
         assert!(snippet.code.contains("Authorization"));
     }
 
+    #[test]
+    fn test_generate_grpc_is_unsupported() {
+        let req = RequestSpec::post("grpc://localhost:50051");
+        for (language, comment) in [(CodeLanguage::Curl, "#"), (CodeLanguage::Rust, "//")] {
+            let snippet = generate_code(&req, &CodeGenOptions::for_language(language));
+
+            assert!(snippet.code.starts_with(comment));
+            assert!(snippet.code.contains("not supported for gRPC requests"));
+        }
+    }
+
+    #[test]
+    fn test_generate_curl_aws_sigv4() {
+        let mut req = RequestSpec::get("https://bucket.s3.amazonaws.com/key");
+        req.auth = AuthConfig::AwsSigV4 {
+            access_key: "AKID".to_string(),
+            secret_key: "secret".to_string(),
+            session_token: Some("session".to_string()),
+            region: "eu-west-1".to_string(),
+            service: "s3".to_string(),
+        };
+        let options = CodeGenOptions::for_language(CodeLanguage::Curl);
+        let snippet = generate_code(&req, &options);
+
+        assert!(snippet.code.contains("--aws-sigv4 'aws:amz:eu-west-1:s3'"));
+        assert!(snippet.code.contains("--user 'AKID:secret'"));
+        assert!(snippet.code.contains("-H 'x-amz-security-token: session'"));
+    }
+
+    #[test]
+    fn test_generate_custom_method() {
+        let mut req = RequestSpec::get("https://cdn.example.com/asset");
+        req.method = HttpMethod::Custom("PURGE".to_string());
+        let code = |req: &RequestSpec, language| {
+            generate_code(req, &CodeGenOptions::for_language(language)).code
+        };
+
+        assert!(code(&req, CodeLanguage::Curl).contains("-X PURGE"));
+        assert!(code(&req, CodeLanguage::Python).contains("requests.request(\n    'PURGE'"));
+        assert!(code(&req, CodeLanguage::Rust).contains("reqwest::Method::from_bytes(b\"PURGE\")"));
+        assert!(code(&req, CodeLanguage::Kotlin).contains(".method(\"PURGE\", null)"));
+        assert!(code(&req, CodeLanguage::CSharp).contains("new HttpMethod(\"PURGE\")"));
+        assert!(code(&req, CodeLanguage::Ruby).contains("Net::HTTPGenericRequest.new('PURGE'"));
+
+        req.method = HttpMethod::Trace;
+        assert!(code(&req, CodeLanguage::Rust).contains("reqwest::Method::TRACE"));
+        assert!(code(&req, CodeLanguage::Ruby).contains("Net::HTTP::Trace::new"));
+    }
+
     #[test]
     fn test_generate_python() {
         let req = sample_request();
@@ -8,7 +8,7 @@ use vortex_domain::export::{ExportFormat, ExportOptions, ExportResult, ExportWar
 use vortex_domain::request::{RequestBodyKind, RequestSpec};
 use vortex_domain::response::ResponseSpec;
 
-use super::ExportError;
+use super::{ExportError, is_exportable};
 
 /// HAR format exporter.
 pub struct HarExporter;
@@ -21,16 +21,16 @@ impl HarExporter {
         responses: &[ResponseSpec],
         options: &ExportOptions,
     ) -> Result<ExportResult, ExportError> {
-        let mut result = ExportResult::new(String::new(), ExportFormat::Har, requests.len());
+        let mut result = ExportResult::new(String::new(), ExportFormat::Har, 0);
 
-        let entries: Vec<HarEntry> = requests
-            .iter()
-            .enumerate()
-            .map(|(i, req)| {
+        let mut entries = Vec::new();
+        for (i, req) in requests.iter().enumerate() {
+            if is_exportable(req, &mut result) {
                 let response = responses.get(i);
-                Self::create_entry(req, response, options, &mut result)
-            })
-            .collect();
+                entries.push(Self::create_entry(req, response, options, &mut result));
+            }
+        }
+        result.request_count = entries.len();
 
         let har = Har {
             log: HarLog {
@@ -155,7 +155,7 @@ impl HarExporter {
         };
 
         HarRequest {
-            method: request.method.as_str().to_uppercase(),
+            method: request.method.to_string(),
             url,
             http_version: "HTTP/1.1".to_string(),
             headers,
@@ -228,6 +228,20 @@ impl HarExporter {
                     params,
                 })
             }
+            RequestBodyKind::Graphql { .. } => {
+                let text = request.body.encoded().unwrap_or_else(|e| {
+                    result.add_warning(
+                        ExportWarning::new(format!("GraphQL body exported as is: {e}"))
+                            .with_source(&request.url),
+                    );
+                    request.body.content.clone()
+                });
+                Some(HarPostData {
+                    mime_type: "application/json".to_string(),
+                    text: Some(text),
+                    params: Vec::new(),
+                })
+            }
             RequestBodyKind::FormData => {
                 result.add_warning(
                     ExportWarning::new("Multipart form data may not export correctly")
@@ -9,7 +9,8 @@ pub use har::HarExporter;
 pub use openapi::OpenApiExporter;
 
 use thiserror::Error;
-use vortex_domain::export::{ExportFormat, ExportOptions, ExportResult};
+use vortex_domain::export::{ExportFormat, ExportOptions, ExportResult, ExportWarning};
+use vortex_domain::grpc::is_grpc_url;
 use vortex_domain::request::RequestSpec;
 use vortex_domain::response::ResponseSpec;
 
@@ -61,25 +62,65 @@ pub fn export_requests(
         ExportFormat::Har => HarExporter::export(requests, responses, options),
         ExportFormat::OpenApi3 => OpenApiExporter::export(requests, options),
         ExportFormat::Curl => {
+            let mut result = ExportResult::new(String::new(), ExportFormat::Curl, 0);
+            let code_options = vortex_domain::codegen::CodeGenOptions {
+                language: vortex_domain::codegen::CodeLanguage::Curl,
+                ..Default::default()
+            };
             // Use the code generator for cURL
-            let content = requests
+            let commands: Vec<_> = requests
                 .iter()
-                .map(|req| {
-                    let code_options = vortex_domain::codegen::CodeGenOptions {
-                        language: vortex_domain::codegen::CodeLanguage::Curl,
-                        ..Default::default()
-                    };
-                    crate::codegen::generate_code(req, &code_options).code
-                })
-                .collect::<Vec<_>>()
-                .join("\n\n");
+                .filter(|req| is_exportable(req, &mut result))
+                .map(|req| crate::codegen::generate_code(req, &code_options).code)
+                .collect();
 
-            Ok(ExportResult::new(
-                content,
-                ExportFormat::Curl,
-                requests.len(),
-            ))
+            result.request_count = commands.len();
+            result.content = commands.join("\n\n");
+            Ok(result)
         }
         format => Err(ExportError::UnsupportedFormat { format }),
     }
 }
+
+/// Returns false for gRPC requests, which no export format can describe,
+/// and records a warning for each one skipped.
+fn is_exportable(request: &RequestSpec, result: &mut ExportResult) -> bool {
+    if is_grpc_url(&request.url) {
+        result.add_warning(
+            ExportWarning::new("gRPC requests cannot be exported, skipped")
+                .with_source(&request.url),
+        );
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_skips_grpc_requests() {
+        let requests = [
+            RequestSpec::get("https://api.example.com/users"),
+            RequestSpec::post("grpc://localhost:50051"),
+        ];
+
+        for format in [
+            ExportFormat::Har,
+            ExportFormat::OpenApi3,
+            ExportFormat::Curl,
+        ] {
+            let options = ExportOptions {
+                format,
+                ..Default::default()
+            };
+            let result = export_requests(&requests, &[], &options).unwrap();
+
+            assert_eq!(result.request_count, 1, "{format:?}");
+            assert!(!result.content.contains("grpc://"), "{format:?}");
+            assert_eq!(result.warnings.len(), 1, "{format:?}");
+        }
+    }
+}
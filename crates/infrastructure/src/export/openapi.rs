@@ -8,7 +8,7 @@ use serde::Serialize;
 use vortex_domain::export::{ExportFormat, ExportOptions, ExportResult, ExportWarning};
 use vortex_domain::request::{HttpMethod, RequestBodyKind, RequestSpec};
 
-use super::ExportError;
+use super::{ExportError, is_exportable};
 
 /// `OpenAPI` 3.0 exporter.
 pub struct OpenApiExporter;
@@ -20,30 +20,41 @@ impl OpenApiExporter {
         requests: &[RequestSpec],
         options: &ExportOptions,
     ) -> Result<ExportResult, ExportError> {
-        let mut result = ExportResult::new(String::new(), ExportFormat::OpenApi3, requests.len());
+        let mut result = ExportResult::new(String::new(), ExportFormat::OpenApi3, 0);
+        let requests: Vec<_> = requests
+            .iter()
+            .filter(|request| is_exportable(request, &mut result))
+            .cloned()
+            .collect();
+        result.request_count = requests.len();
 
         // Group requests by path
         let mut paths: BTreeMap<String, PathItem> = BTreeMap::new();
 
-        for request in requests {
+        for request in &requests {
             if let Some((path, _base_url)) = Self::extract_path(&request.url) {
                 let operation = Self::create_operation(request, options, &mut result);
-                let method = request.method.as_str().to_lowercase();
-
                 let path_item = paths.entry(path).or_default();
-                match method.as_str() {
-                    "get" => path_item.get = Some(operation),
-                    "post" => path_item.post = Some(operation),
-                    "put" => path_item.put = Some(operation),
-                    "delete" => path_item.delete = Some(operation),
-                    "patch" => path_item.patch = Some(operation),
-                    "head" => path_item.head = Some(operation),
-                    "options" => path_item.options = Some(operation),
-                    _ => {
+                match &request.method {
+                    HttpMethod::Get => path_item.get = Some(operation),
+                    HttpMethod::Post => path_item.post = Some(operation),
+                    HttpMethod::Put => path_item.put = Some(operation),
+                    HttpMethod::Delete => path_item.delete = Some(operation),
+                    HttpMethod::Patch => path_item.patch = Some(operation),
+                    HttpMethod::Head => path_item.head = Some(operation),
+                    HttpMethod::Options => path_item.options = Some(operation),
+                    HttpMethod::Trace => path_item.trace = Some(operation),
+                    // OpenAPI has no field for other methods, so they are
+                    // kept as `x-<method>` extensions
+                    method => {
+                        let extension = format!("x-{}", method.as_str().to_lowercase());
                         result.add_warning(
-                            ExportWarning::new(format!("Unsupported method: {method}"))
-                                .with_source(&request.url),
+                            ExportWarning::new(format!(
+                                "{method} is not an OpenAPI method, exported as '{extension}'"
+                            ))
+                            .with_source(&request.url),
                         );
+                        path_item.extensions.insert(extension, operation);
                     }
                 }
             } else {
@@ -67,7 +78,7 @@ impl OpenApiExporter {
                     .unwrap_or_else(|| "1.0.0".to_string()),
                 description: options.api_description.clone(),
             },
-            servers: Self::extract_servers(requests),
+            servers: Self::extract_servers(&requests),
             paths,
         };
 
@@ -221,6 +232,9 @@ impl OpenApiExporter {
                 Some(request.body.content.clone()),
             ),
             RequestBodyKind::FormData => ("multipart/form-data".to_string(), None),
+            RequestBodyKind::Graphql { .. } => {
+                ("application/json".to_string(), request.body.encoded().ok())
+            }
         };
 
         let mut content = BTreeMap::new();
@@ -326,6 +340,10 @@ struct PathItem {
     head: Option<Operation>,
     #[serde(skip_serializing_if = "Option::is_none")]
     options: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace: Option<Operation>,
+    #[serde(flatten)]
+    extensions: BTreeMap<String, Operation>,
 }
 
 #[allow(clippy::struct_field_names)]
@@ -396,6 +414,21 @@ mod tests {
         assert!(result.content.contains("get:"));
     }
 
+    #[test]
+    fn test_export_trace_and_custom_methods() {
+        let mut trace = RequestSpec::get("https://api.example.com/users");
+        trace.method = HttpMethod::Trace;
+        let mut purge = RequestSpec::get("https://api.example.com/users");
+        purge.method = HttpMethod::Custom("PURGE".to_string());
+        let options = ExportOptions::new(ExportFormat::OpenApi3);
+
+        let result = OpenApiExporter::export(&[trace, purge], &options).unwrap();
+
+        assert!(result.content.contains("trace:"));
+        assert!(result.content.contains("x-purge:"));
+        assert_eq!(result.warnings.len(), 1);
+    }
+
     #[test]
     fn test_export_with_path_params() {
         let mut request = RequestSpec::get("https://api.example.com/users/{id}");
@@ -7,6 +7,7 @@
 
 use reqwest::multipart::{Form, Part};
 use std::path::Path;
+use vortex_domain::graphql;
 use vortex_domain::persistence::{FormDataField, PersistenceRequestBody};
 
 /// Error type for body building operations.
@@ -106,15 +107,19 @@ pub async fn build_body(
             })
         }
 
-        PersistenceRequestBody::Graphql { query, variables } => {
-            let graphql_body = serde_json::json!({
-                "query": query,
-                "variables": variables,
-            });
-            let json_str = serde_json::to_string(&graphql_body).map_err(|e| {
-                BodyBuildError::SerializationError {
-                    message: e.to_string(),
-                }
+        PersistenceRequestBody::Graphql {
+            query,
+            variables,
+            operation_name,
+        } => {
+            let variables = variables.as_ref().map(ToString::to_string);
+            let json_str = graphql::encode_request(
+                query,
+                variables.as_deref().unwrap_or_default(),
+                operation_name.as_deref(),
+            )
+            .map_err(|e| BodyBuildError::InvalidConfig {
+                message: e.to_string(),
             })?;
             Ok(BuiltBody::Text {
                 content: json_str,
@@ -281,6 +286,7 @@ mod tests {
         let body = PersistenceRequestBody::Graphql {
             query: "query { user { id name } }".to_string(),
             variables: Some(serde_json::json!({"id": "123"})),
+            operation_name: None,
         };
 
         let result = build_body(&body, None).await.unwrap();
@@ -2,8 +2,18 @@
 //!
 //! This module provides:
 //! - Body building for various content types
-//! - TLS configuration (planned)
+//! - Proxy resolution and environment proxy variables
+//! - TLS configuration: custom CAs, client certificates and version limits
+//! - Per-phase timing of requests
 
 mod body_builder;
+mod proxy;
+mod timing;
+mod tls;
 
 pub use body_builder::{BodyBuildError, BuiltBody, build_body};
+pub use proxy::{ProxyEnvironment, ProxyResolver, build_proxy};
+pub use timing::{
+    PhaseRecorder, TimedConnect, TimedConnectLayer, TimedResolver, UNMEASURED_PHASES,
+};
+pub use tls::apply_tls;
@@ -0,0 +1,214 @@
+//! Per-phase timing of HTTP requests.
+//!
+//! reqwest does not report where the time of a request went, so clients are
+//! built with a resolver and a connector layer that record the DNS lookup
+//! and connection setup. Neither sees the request they work for, so the
+//! [`PhaseRecorder`] of the request reaches them through a task-local while
+//! the request is sent.
+//!
+//! reqwest performs the TLS handshake inside its connector and writes the
+//! request inside hyper, so the handshake is part of the connect phase and
+//! writing the request is part of the wait phase. Neither is ever reported
+//! on its own; see [`UNMEASURED_PHASES`].
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use tower::{Layer, Service};
+use vortex_domain::response::{ResponseTiming, TimingPhase};
+
+/// Phases the HTTP client folds into others and never reports on their own.
+pub const UNMEASURED_PHASES: [TimingPhase; 2] = [TimingPhase::Tls, TimingPhase::Send];
+
+tokio::task_local! {
+    static RECORDER: PhaseRecorder;
+}
+
+/// Records when the phases of one request happened.
+#[derive(Debug, Clone)]
+pub struct PhaseRecorder {
+    started_at: Instant,
+    phases: Arc<Mutex<Phases>>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Phases {
+    dns: Option<Duration>,
+    connect: Option<Duration>,
+    connected_at: Option<Instant>,
+    sent_at: Option<Instant>,
+    headers_at: Option<Instant>,
+}
+
+impl PhaseRecorder {
+    /// Starts timing a request.
+    #[must_use]
+    pub fn start() -> Self {
+        Self {
+            started_at: Instant::now(),
+            phases: Arc::default(),
+        }
+    }
+
+    /// Returns the time since the request was started.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Sends a request, recording its DNS lookup and connection setup.
+    ///
+    /// The response headers are taken to arrive when `sending` completes.
+    pub async fn send<F: Future>(&self, sending: F) -> F::Output {
+        self.update(|phases| phases.sent_at = Some(Instant::now()));
+        let output = RECORDER.scope(self.clone(), sending).await;
+        self.update(|phases| phases.headers_at = Some(Instant::now()));
+        output
+    }
+
+    /// Returns the timing so far, with the body taken to be complete now.
+    #[must_use]
+    pub fn timing(&self) -> ResponseTiming {
+        let phases = *self.phases.lock().unwrap_or_else(PoisonError::into_inner);
+        let waiting_since = match (phases.sent_at, phases.connected_at) {
+            (Some(sent_at), Some(connected_at)) => Some(sent_at.max(connected_at)),
+            (sent_at, connected_at) => sent_at.or(connected_at),
+        };
+        ResponseTiming {
+            dns: phases.dns,
+            connect: phases.connect,
+            wait: phases
+                .headers_at
+                .zip(waiting_since)
+                .map(|(headers_at, since)| headers_at.saturating_duration_since(since)),
+            download: phases.headers_at.map(|headers_at| headers_at.elapsed()),
+            ..ResponseTiming::default()
+        }
+    }
+
+    /// Returns the timing of a redirect response whose body is not read,
+    /// and starts timing the request that follows it.
+    #[must_use]
+    pub fn redirected(&self) -> ResponseTiming {
+        let timing = ResponseTiming {
+            download: None,
+            ..self.timing()
+        };
+        self.update(|phases| *phases = Phases::default());
+        timing
+    }
+
+    fn current() -> Option<Self> {
+        RECORDER.try_with(Clone::clone).ok()
+    }
+
+    fn update(&self, update: impl FnOnce(&mut Phases)) {
+        update(&mut self.phases.lock().unwrap_or_else(PoisonError::into_inner));
+    }
+}
+
+/// DNS resolver that records lookups in the current [`PhaseRecorder`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimedResolver;
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let recorder = PhaseRecorder::current();
+        Box::pin(async move {
+            let started = Instant::now();
+            let host = (name.as_str().to_owned(), 0);
+            let addrs = tokio::net::lookup_host(host).await?;
+            if let Some(recorder) = recorder {
+                recorder.update(|phases| phases.dns = Some(started.elapsed()));
+            }
+            Ok(Box::new(addrs) as Addrs)
+        })
+    }
+}
+
+/// Connector layer that records connection setup in the current
+/// [`PhaseRecorder`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimedConnectLayer;
+
+impl<S> Layer<S> for TimedConnectLayer {
+    type Service = TimedConnect<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TimedConnect { inner }
+    }
+}
+
+/// Connector service created by [`TimedConnectLayer`].
+#[derive(Debug, Clone)]
+pub struct TimedConnect<S> {
+    inner: S,
+}
+
+impl<S, R> Service<R> for TimedConnect<S>
+where
+    S: Service<R>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let recorder = PhaseRecorder::current();
+        if let Some(recorder) = &recorder {
+            recorder.update(|phases| phases.dns = None);
+        }
+        let started = Instant::now();
+        let connecting = self.inner.call(request);
+        Box::pin(async move {
+            let result = connecting.await;
+            if let (Ok(_), Some(recorder)) = (&result, recorder) {
+                recorder.update(|phases| {
+                    let total = started.elapsed();
+                    phases.connect = Some(total.saturating_sub(phases.dns.unwrap_or_default()));
+                    phases.connected_at = Some(Instant::now());
+                });
+            }
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_recorder_phases() {
+        let recorder = PhaseRecorder::start();
+        recorder
+            .send(async {
+                let mut connect = TimedConnectLayer.layer(tower::service_fn(|()| async {
+                    let addrs = TimedResolver.resolve("localhost".parse().unwrap()).await?;
+                    assert!(addrs.count() > 0);
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    Ok::<_, Box<dyn std::error::Error + Send + Sync>>(())
+                }));
+                connect.call(()).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            })
+            .await;
+
+        let timing = recorder.timing();
+        assert!(timing.dns.is_some());
+        assert!(timing.connect.unwrap() >= Duration::from_millis(10));
+        assert!(timing.wait.unwrap() >= Duration::from_millis(10));
+        assert!(timing.download.is_some());
+        assert_eq!(timing.tls, None);
+    }
+}
@@ -0,0 +1,252 @@
+//! TLS configuration for outgoing requests.
+//!
+//! Applies a [`TlsConfig`] to a reqwest client builder: custom CA
+//! certificates, client certificates for mTLS, TLS version limits and the
+//! options that weaken server verification.
+
+use std::fs;
+use std::path::Path;
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use reqwest::ClientBuilder;
+use reqwest::tls::{Certificate, Identity, Version};
+use vortex_application::ports::HttpClientError;
+use vortex_domain::tls::{
+    CertificateSource, ClientCertificate, PrivateKeySource, TlsConfig, TlsVersion,
+};
+
+/// Applies a TLS configuration to a client builder.
+///
+/// Custom CA certificates are trusted in addition to the system store.
+/// Accepting invalid hostnames is only supported together with custom CA
+/// certificates, which then become the only trusted roots.
+///
+/// # Errors
+///
+/// Returns `HttpClientError::TlsError` if a certificate or key cannot be
+/// loaded, or the configuration cannot be honored.
+pub fn apply_tls(
+    mut builder: ClientBuilder,
+    config: &TlsConfig,
+) -> Result<ClientBuilder, HttpClientError> {
+    let ca_certificates = load_ca_certificates(&config.ca_certificates)?;
+
+    if config.accepts_invalid_certs() {
+        builder = builder
+            .tls_danger_accept_invalid_certs(true)
+            .tls_certs_merge(ca_certificates);
+    } else if config.danger_accept_invalid_hostnames {
+        if ca_certificates.is_empty() {
+            return Err(HttpClientError::TlsError(
+                "Accepting invalid hostnames requires a custom CA certificate".to_string(),
+            ));
+        }
+        builder = builder
+            .tls_danger_accept_invalid_hostnames(true)
+            .tls_certs_only(ca_certificates);
+    } else {
+        builder = builder.tls_certs_merge(ca_certificates);
+    }
+
+    if let Some(client_certificate) = &config.client_certificate {
+        builder = builder.identity(load_identity(client_certificate)?);
+    }
+    if let Some(version) = config.min_tls_version {
+        builder = builder.tls_version_min(to_reqwest_version(version));
+    }
+    if let Some(version) = config.max_tls_version {
+        builder = builder.tls_version_max(to_reqwest_version(version));
+    }
+    Ok(builder)
+}
+
+/// Loads the CA certificates to trust. `System` sources are skipped, since
+/// the system store is always trusted.
+fn load_ca_certificates(
+    sources: &[CertificateSource],
+) -> Result<Vec<Certificate>, HttpClientError> {
+    let mut certificates = Vec::new();
+    for source in sources {
+        let loaded = match source {
+            CertificateSource::PemFile { path } => parse_pem_bundle(
+                &read_file(path, "CA certificate")?,
+                &path.display().to_string(),
+            )?,
+            CertificateSource::DerFile { path } => {
+                vec![
+                    Certificate::from_der(&read_file(path, "CA certificate")?).map_err(|e| {
+                        tls_error(&format!("Invalid CA certificate {}", path.display()), &e)
+                    })?,
+                ]
+            }
+            CertificateSource::PemContent { content } => {
+                parse_pem_bundle(content.as_bytes(), "inline PEM")?
+            }
+            CertificateSource::System => Vec::new(),
+        };
+        certificates.extend(loaded);
+    }
+    Ok(certificates)
+}
+
+fn parse_pem_bundle(pem: &[u8], origin: &str) -> Result<Vec<Certificate>, HttpClientError> {
+    let certificates = Certificate::from_pem_bundle(pem)
+        .map_err(|e| tls_error(&format!("Invalid CA certificate in {origin}"), &e))?;
+    if certificates.is_empty() {
+        return Err(HttpClientError::TlsError(format!(
+            "No certificates found in {origin}"
+        )));
+    }
+    Ok(certificates)
+}
+
+/// Builds the client identity from a certificate and private key.
+fn load_identity(client: &ClientCertificate) -> Result<Identity, HttpClientError> {
+    let key = match &client.private_key {
+        PrivateKeySource::PemFile { path } => read_file(path, "private key")?,
+        PrivateKeySource::PemContent { content } => content.clone().into_bytes(),
+        PrivateKeySource::DerFile { path } => {
+            der_to_pem("PRIVATE KEY", &read_file(path, "private key")?).into_bytes()
+        }
+        PrivateKeySource::Pkcs12File { path } => {
+            return Err(HttpClientError::TlsError(format!(
+                "PKCS#12 client certificates are not supported; convert {} to PEM with \
+                 `openssl pkcs12 -in <file> -out client.pem -nodes`",
+                path.display()
+            )));
+        }
+    };
+    if String::from_utf8_lossy(&key).contains("ENCRYPTED") {
+        return Err(HttpClientError::TlsError(
+            "Encrypted private keys are not supported; decrypt the key with \
+             `openssl pkey -in <key> -out key.pem`"
+                .to_string(),
+        ));
+    }
+
+    let certificate = match &client.certificate {
+        CertificateSource::PemFile { path } => read_file(path, "client certificate")?,
+        CertificateSource::PemContent { content } => content.clone().into_bytes(),
+        CertificateSource::DerFile { path } => {
+            der_to_pem("CERTIFICATE", &read_file(path, "client certificate")?).into_bytes()
+        }
+        CertificateSource::System => {
+            return Err(HttpClientError::TlsError(
+                "Client certificates cannot be loaded from the system store".to_string(),
+            ));
+        }
+    };
+
+    let mut pem = key;
+    pem.push(b'\n');
+    pem.extend(certificate);
+    Identity::from_pem(&pem).map_err(|e| tls_error("Invalid client certificate or key", &e))
+}
+
+fn read_file(path: &Path, what: &str) -> Result<Vec<u8>, HttpClientError> {
+    fs::read(path).map_err(|e| {
+        HttpClientError::TlsError(format!("Failed to read {what} {}: {e}", path.display()))
+    })
+}
+
+fn der_to_pem(label: &str, der: &[u8]) -> String {
+    let encoded = STANDARD.encode(der);
+    let mut pem = format!("-----BEGIN {label}-----\n");
+    for line in encoded.as_bytes().chunks(64) {
+        pem.push_str(&String::from_utf8_lossy(line));
+        pem.push('\n');
+    }
+    pem.push_str("-----END ");
+    pem.push_str(label);
+    pem.push_str("-----\n");
+    pem
+}
+
+fn tls_error(context: &str, error: &reqwest::Error) -> HttpClientError {
+    HttpClientError::TlsError(format!("{context}: {error}"))
+}
+
+const fn to_reqwest_version(version: TlsVersion) -> Version {
+    match version {
+        TlsVersion::Tls10 => Version::TLS_1_0,
+        TlsVersion::Tls11 => Version::TLS_1_1,
+        TlsVersion::Tls12 => Version::TLS_1_2,
+        TlsVersion::Tls13 => Version::TLS_1_3,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/tls")
+            .join(name)
+    }
+
+    #[test]
+    fn test_load_ca_certificates() {
+        let pem = fs::read_to_string(fixture("ca.pem")).unwrap();
+        let sources = vec![
+            CertificateSource::PemFile {
+                path: fixture("ca.pem"),
+            },
+            CertificateSource::DerFile {
+                path: fixture("ca.der"),
+            },
+            CertificateSource::PemContent { content: pem },
+            CertificateSource::System,
+        ];
+        assert_eq!(load_ca_certificates(&sources).unwrap().len(), 3);
+
+        let missing = CertificateSource::PemFile {
+            path: fixture("missing.pem"),
+        };
+        assert!(matches!(
+            load_ca_certificates(&[missing]),
+            Err(HttpClientError::TlsError(message)) if message.contains("missing.pem")
+        ));
+
+        let empty = CertificateSource::PemContent {
+            content: "not a certificate".to_string(),
+        };
+        assert!(matches!(
+            load_ca_certificates(&[empty]),
+            Err(HttpClientError::TlsError(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_identity() {
+        let client =
+            ClientCertificate::from_pem_files(fixture("client.pem"), fixture("client.key"));
+        assert!(load_identity(&client).is_ok());
+
+        let pkcs12 = ClientCertificate::from_pkcs12(fixture("client.p12"), None);
+        assert!(matches!(
+            load_identity(&pkcs12),
+            Err(HttpClientError::TlsError(message)) if message.contains("PKCS#12")
+        ));
+    }
+
+    #[test]
+    fn test_apply_tls_rejects_unsupported_options() {
+        let mut hostnames = TlsConfig::new();
+        hostnames.danger_accept_invalid_hostnames = true;
+        assert!(matches!(
+            apply_tls(reqwest::Client::builder(), &hostnames),
+            Err(HttpClientError::TlsError(_))
+        ));
+
+        let pinned = TlsConfig::new()
+            .with_ca_pem_file(fixture("ca.pem"))
+            .with_min_tls_version(TlsVersion::Tls13)
+            .with_max_tls_version(TlsVersion::Tls13);
+        assert!(apply_tls(reqwest::Client::builder(), &pinned).is_ok());
+    }
+}
@@ -1,10 +1,13 @@
 //! Import Module
 //!
 //! This module provides functionality to import data from various external formats
+//! (Postman collections and environments, `OpenAPI` and Swagger specifications)
 //! into Vortex native format.
 
+pub mod openapi;
 pub mod postman;
 
+pub use openapi::OpenApiImporter;
 pub use postman::{
     ImportConfig, ImportError, ImportFormat, ImportPreview, ImportResult, ImportWarning,
     PostmanCollection, PostmanEnvironment, PostmanImporter, ValidationResult, WarningSeverity,
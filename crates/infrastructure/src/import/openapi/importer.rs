@@ -0,0 +1,422 @@
+//! `OpenAPI` Importer - Main Import Logic
+//!
+//! This module provides validation, preview and import of `OpenAPI` 3.x and
+//! Swagger 2.0 specifications, written in JSON or YAML.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde_json::Value;
+use vortex_application::ports::slugify;
+use vortex_domain::persistence::SavedRequest;
+
+use super::mapper::{MappedSpec, map_spec};
+use crate::import::{
+    ImportConfig, ImportError, ImportFormat, ImportPreview, ImportResult, ValidationResult,
+    WarningStats,
+};
+use crate::serialization::to_json_stable;
+
+/// Main `OpenAPI` and Swagger importer
+pub struct OpenApiImporter {
+    config: ImportConfig,
+}
+
+impl OpenApiImporter {
+    /// Create a new importer with default config
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: ImportConfig::default(),
+        }
+    }
+
+    /// Create a new importer with custom config
+    #[must_use]
+    pub const fn with_config(config: ImportConfig) -> Self {
+        Self { config }
+    }
+
+    /// Check whether content is an `OpenAPI` or Swagger specification
+    #[must_use]
+    pub fn is_spec(content: &str) -> bool {
+        Self::parse(content).is_ok_and(|spec| Self::detect_format(&spec) != ImportFormat::Unknown)
+    }
+
+    /// Validate a file before importing
+    #[must_use]
+    pub fn validate_file(&self, content: &str) -> ValidationResult {
+        let invalid = |format, issue: String| ValidationResult {
+            is_valid: false,
+            format,
+            issues: vec![issue],
+        };
+
+        if content.len() > self.config.max_file_size {
+            return invalid(
+                ImportFormat::Unknown,
+                format!(
+                    "File size ({} bytes) exceeds maximum ({} bytes)",
+                    content.len(),
+                    self.config.max_file_size
+                ),
+            );
+        }
+
+        let spec = match Self::parse(content) {
+            Ok(spec) => spec,
+            Err(e) => return invalid(ImportFormat::Unknown, e.to_string()),
+        };
+
+        let format = Self::detect_format(&spec);
+        if format == ImportFormat::Unknown {
+            return invalid(
+                format,
+                "Unknown format: File is not an OpenAPI 3.x or Swagger 2.0 specification"
+                    .to_string(),
+            );
+        }
+
+        let mapped = map_spec(&spec, format);
+        let (requests, folders) = Self::count_mapped_items(&mapped);
+        if requests + folders > self.config.max_items {
+            return invalid(
+                format,
+                format!(
+                    "Too many items: {} exceeds maximum of {}",
+                    requests + folders,
+                    self.config.max_items
+                ),
+            );
+        }
+
+        ValidationResult {
+            is_valid: true,
+            format,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Preview what will be imported without actually importing
+    #[allow(clippy::missing_errors_doc)]
+    pub fn preview(&self, content: &str) -> Result<ImportPreview, ImportError> {
+        let (mapped, format) = Self::map(content)?;
+        let (request_count, folder_count) = Self::count_mapped_items(&mapped);
+
+        Ok(ImportPreview {
+            format: match format {
+                ImportFormat::Swagger2 => "Swagger 2.0",
+                _ => "OpenAPI 3",
+            }
+            .to_string(),
+            collection_name: Some(mapped.collection.name),
+            environment_name: None,
+            request_count,
+            folder_count,
+            variable_count: mapped.collection.variables.len(),
+            warnings: mapped.warnings,
+        })
+    }
+
+    /// Import a specification as a collection
+    ///
+    /// Each tag becomes a folder, each operation a request.
+    #[allow(clippy::missing_errors_doc)]
+    pub fn import_collection(
+        &self,
+        content: &str,
+        workspace_path: &Path,
+    ) -> Result<ImportResult, ImportError> {
+        let (mapped, _) = Self::map(content)?;
+
+        // Check for errors if not skipping
+        if !self.config.skip_on_error && WarningStats::from_warnings(&mapped.warnings).has_errors()
+        {
+            return Err(ImportError::Aborted);
+        }
+
+        let mut safe_name = slugify(&mapped.collection.name);
+        if safe_name.is_empty() {
+            safe_name = "imported-api".to_string();
+        }
+        let collection_dir = workspace_path.join("collections").join(safe_name);
+        let requests_dir = collection_dir.join("requests");
+        std::fs::create_dir_all(&requests_dir)?;
+        Self::write_json(&collection_dir.join("collection.json"), &mapped.collection)?;
+
+        let mut requests_imported = Self::write_requests(&mapped.requests, &requests_dir)?.len();
+
+        let mut folder_names = HashSet::new();
+        for tag_folder in &mapped.folders {
+            let folder_dir =
+                requests_dir.join(unique_name(&mut folder_names, &tag_folder.folder.name));
+            std::fs::create_dir_all(&folder_dir)?;
+
+            let mut folder = tag_folder.folder.clone();
+            folder.order = Self::write_requests(&tag_folder.requests, &folder_dir)?;
+            requests_imported += folder.order.len();
+            Self::write_json(&folder_dir.join("folder.json"), &folder)?;
+        }
+
+        Ok(ImportResult {
+            name: mapped.collection.name,
+            requests_imported,
+            folders_imported: mapped.folders.len(),
+            variables_imported: mapped.collection.variables.len(),
+            warnings: mapped.warnings,
+        })
+    }
+
+    /// Parse and map a specification
+    fn map(content: &str) -> Result<(MappedSpec, ImportFormat), ImportError> {
+        let spec = Self::parse(content)?;
+        let format = Self::detect_format(&spec);
+        if format == ImportFormat::Unknown {
+            return Err(ImportError::InvalidSpec(
+                "Not an OpenAPI 3.x or Swagger 2.0 specification".to_string(),
+            ));
+        }
+        Ok((map_spec(&spec, format), format))
+    }
+
+    /// Parse a specification written in JSON or YAML
+    fn parse(content: &str) -> Result<Value, ImportError> {
+        if content.trim_start().starts_with('{') {
+            return serde_json::from_str(content)
+                .map_err(|e| ImportError::InvalidJson(e.to_string()));
+        }
+        serde_yaml::from_str::<serde_yaml::Value>(content)
+            .map(yaml_to_json)
+            .map_err(|e| ImportError::InvalidYaml(e.to_string()))
+    }
+
+    /// Detect the specification version
+    fn detect_format(spec: &Value) -> ImportFormat {
+        let version = |field: &str| spec.get(field).map(super::schema::example_text);
+        if version("openapi").is_some_and(|version| version.starts_with("3.")) {
+            ImportFormat::OpenApi3
+        } else if version("swagger").is_some_and(|version| version.starts_with("2.")) {
+            ImportFormat::Swagger2
+        } else {
+            ImportFormat::Unknown
+        }
+    }
+
+    /// Count mapped requests and folders
+    fn count_mapped_items(mapped: &MappedSpec) -> (usize, usize) {
+        let requests = mapped.requests.len()
+            + mapped
+                .folders
+                .iter()
+                .map(|folder| folder.requests.len())
+                .sum::<usize>();
+        (requests, mapped.folders.len())
+    }
+
+    /// Write requests to a directory, returning their file names in order
+    fn write_requests(requests: &[SavedRequest], dir: &Path) -> Result<Vec<String>, ImportError> {
+        let mut names = HashSet::new();
+        let mut files = Vec::with_capacity(requests.len());
+        for request in requests {
+            let file_name = format!("{}.json", unique_name(&mut names, &request.name));
+            Self::write_json(&dir.join(&file_name), request)?;
+            files.push(file_name);
+        }
+        Ok(files)
+    }
+
+    fn write_json<T: serde::Serialize>(path: &Path, value: &T) -> Result<(), ImportError> {
+        let json = to_json_stable(value).map_err(|e| std::io::Error::other(e.to_string()))?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+impl Default for OpenApiImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Slugify a name, adding a number if it is already taken
+fn unique_name(taken: &mut HashSet<String>, name: &str) -> String {
+    let mut base = slugify(name);
+    if base.is_empty() {
+        base = "request".to_string();
+    }
+    let mut candidate = base.clone();
+    let mut number = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{base}-{number}");
+        number += 1;
+    }
+    candidate
+}
+
+/// Convert YAML to JSON, turning keys such as response codes into strings
+fn yaml_to_json(yaml: serde_yaml::Value) -> Value {
+    match yaml {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(value) => Value::Bool(value),
+        serde_yaml::Value::Number(number) => number
+            .as_i64()
+            .map(Value::from)
+            .or_else(|| number.as_u64().map(Value::from))
+            .or_else(|| number.as_f64().map(Value::from))
+            .unwrap_or_default(),
+        serde_yaml::Value::String(text) => Value::String(text),
+        serde_yaml::Value::Sequence(items) => items.into_iter().map(yaml_to_json).collect(),
+        serde_yaml::Value::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match yaml_to_json(key) {
+                        Value::String(key) => key,
+                        other => other.to_string(),
+                    };
+                    (key, yaml_to_json(value))
+                })
+                .collect(),
+        ),
+        serde_yaml::Value::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::serialization::from_json;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+    use vortex_domain::persistence::{PersistenceCollection, PersistenceFolder};
+
+    const PETSTORE_YAML: &str = r"
+openapi: 3.0.0
+info:
+  title: Pet Store
+  version: 1.0.0
+servers:
+  - url: https://petstore.example.com/v1
+tags:
+  - name: pets
+paths:
+  /pets:
+    get:
+      tags: [pets]
+      summary: List pets
+      responses:
+        200:
+          description: A list of pets
+    post:
+      tags: [pets]
+      summary: Create pet
+      requestBody:
+        content:
+          application/json:
+            example: {name: Rex}
+      responses:
+        201:
+          description: Created
+  /pets/{petId}:
+    get:
+      tags: [pets]
+      summary: List pets
+      parameters:
+        - {name: petId, in: path, required: true, schema: {type: integer}}
+  /health:
+    get:
+      summary: Health
+";
+
+    #[test]
+    fn test_detect_formats() {
+        assert!(OpenApiImporter::is_spec(PETSTORE_YAML));
+        assert!(OpenApiImporter::is_spec(
+            r#"{"swagger": "2.0", "info": {"title": "Legacy"}, "paths": {}}"#
+        ));
+        assert!(!OpenApiImporter::is_spec(
+            r#"{"info": {"name": "Test", "schema": "v2.1"}, "item": []}"#
+        ));
+        assert!(!OpenApiImporter::is_spec("not: [valid"));
+    }
+
+    #[test]
+    fn test_yaml_keys_become_strings() {
+        let spec = OpenApiImporter::parse("responses:\n  200: ok\n  true: yes\n").unwrap();
+        assert_eq!(
+            spec,
+            serde_json::json!({"responses": {"200": "ok", "true": "yes"}})
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_other_files() {
+        let importer = OpenApiImporter::new();
+
+        let result = importer.validate_file(r#"{"name": "Env", "values": []}"#);
+        assert!(!result.is_valid);
+        assert_eq!(result.format, ImportFormat::Unknown);
+
+        let result = importer.validate_file(PETSTORE_YAML);
+        assert!(result.is_valid);
+        assert_eq!(result.format, ImportFormat::OpenApi3);
+    }
+
+    #[test]
+    fn test_preview_spec() {
+        let preview = OpenApiImporter::new().preview(PETSTORE_YAML).unwrap();
+
+        assert_eq!(preview.format, "OpenAPI 3");
+        assert_eq!(preview.collection_name, Some("Pet Store".to_string()));
+        assert_eq!(preview.request_count, 4);
+        assert_eq!(preview.folder_count, 1);
+        assert_eq!(preview.variable_count, 2);
+    }
+
+    #[test]
+    fn test_import_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = OpenApiImporter::new()
+            .import_collection(PETSTORE_YAML, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(result.name, "Pet Store");
+        assert_eq!(result.requests_imported, 4);
+        assert_eq!(result.folders_imported, 1);
+
+        let collection_dir = temp_dir.path().join("collections").join("pet-store");
+        let collection: PersistenceCollection =
+            from_json(&std::fs::read_to_string(collection_dir.join("collection.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            collection.variables["baseUrl"],
+            "https://petstore.example.com/v1"
+        );
+
+        let requests_dir = collection_dir.join("requests");
+        assert!(requests_dir.join("health.json").exists());
+
+        // Requests with the same name get numbered files
+        let folder: PersistenceFolder = from_json(
+            &std::fs::read_to_string(requests_dir.join("pets").join("folder.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            folder.order,
+            vec!["list-pets.json", "create-pet.json", "list-pets-2.json"]
+        );
+
+        let create: SavedRequest = from_json(
+            &std::fs::read_to_string(requests_dir.join("pets").join("create-pet.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(create.url, "{{baseUrl}}/pets");
+        assert_eq!(
+            create.body,
+            Some(vortex_domain::persistence::PersistenceRequestBody::json(
+                serde_json::json!({"name": "Rex"})
+            ))
+        );
+    }
+}
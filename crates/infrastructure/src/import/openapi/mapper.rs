@@ -0,0 +1,1101 @@
+//! `OpenAPI` to Vortex Mapping Logic
+//!
+//! This module converts `OpenAPI` 3.x and Swagger 2.0 specifications to a
+//! Vortex collection with one folder per tag.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{Map, Value};
+use vortex_domain::persistence::{
+    ApiKeyLocation, FormDataField, PersistenceAuth, PersistenceCollection, PersistenceFolder,
+    PersistenceRequestBody, SavedRequest,
+};
+use vortex_domain::request::HttpMethod;
+
+use super::schema::{Spec, declared_example, example_text, schema_type};
+use crate::import::{ImportFormat, ImportWarning};
+
+/// Collection variable holding the server URL
+pub const BASE_URL_VARIABLE: &str = "baseUrl";
+
+/// Path item fields holding operations
+const OPERATION_FIELDS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// Headers that `OpenAPI` defines elsewhere, so parameters with these names
+/// are ignored
+const IGNORED_HEADERS: [&str; 3] = ["accept", "content-type", "authorization"];
+
+/// Result of mapping a specification
+#[derive(Debug)]
+pub struct MappedSpec {
+    /// Collection metadata, with `baseUrl`, parameter variables and the
+    /// default auth
+    pub collection: PersistenceCollection,
+    /// One folder per tag, in the order the specification lists its tags
+    pub folders: Vec<TagFolder>,
+    /// Requests for operations without tags
+    pub requests: Vec<SavedRequest>,
+    /// Constructs that were skipped or need attention
+    pub warnings: Vec<ImportWarning>,
+}
+
+/// A folder for the operations of one tag
+#[derive(Debug)]
+pub struct TagFolder {
+    /// Folder metadata
+    pub folder: PersistenceFolder,
+    /// Requests for the operations whose first tag is this one
+    pub requests: Vec<SavedRequest>,
+}
+
+/// Map a parsed specification to a collection
+#[must_use]
+pub fn map_spec(root: &Value, format: ImportFormat) -> MappedSpec {
+    let mut mapper = Mapper {
+        spec: Spec::new(root),
+        format,
+        variables: BTreeMap::new(),
+        schemes: HashMap::new(),
+        warnings: Vec::new(),
+    };
+
+    let info = root.get("info");
+    let name = info
+        .and_then(|info| info.get("title"))
+        .and_then(Value::as_str)
+        .filter(|title| !title.trim().is_empty())
+        .unwrap_or("Imported API");
+    let mut collection = PersistenceCollection::new(uuid::Uuid::now_v7().to_string(), name);
+    collection.description = info
+        .and_then(|info| info.get("description"))
+        .and_then(Value::as_str)
+        .map(ToString::to_string);
+
+    let base_url = mapper.base_url();
+    mapper
+        .variables
+        .insert(BASE_URL_VARIABLE.to_string(), base_url);
+
+    // Auth required unless an operation says otherwise
+    collection.auth = root
+        .get("security")
+        .and_then(|security| mapper.security_auth(security, "security"));
+
+    let mut folders = TagFolder::declared(root);
+    let mut requests = Vec::new();
+
+    for (path, item) in root
+        .get("paths")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+    {
+        let Some(item) = mapper.spec.resolve(item) else {
+            continue;
+        };
+        let shared_parameters = item.get("parameters");
+        for (field, operation) in item.as_object().into_iter().flatten() {
+            let Some(method) = operation_method(field, operation) else {
+                continue;
+            };
+            let request = mapper.map_operation(
+                path,
+                method,
+                operation,
+                shared_parameters,
+                collection.auth.as_ref(),
+            );
+
+            let tag = operation
+                .get("tags")
+                .and_then(Value::as_array)
+                .and_then(|tags| tags.first())
+                .and_then(Value::as_str);
+            match tag {
+                Some(tag) => {
+                    let index = folders
+                        .iter()
+                        .position(|folder| folder.folder.name == tag)
+                        .unwrap_or_else(|| {
+                            folders.push(TagFolder::new(tag));
+                            folders.len() - 1
+                        });
+                    folders[index].requests.push(request);
+                }
+                None => requests.push(request),
+            }
+        }
+    }
+    folders.retain(|folder| !folder.requests.is_empty());
+
+    mapper.report_skipped();
+    collection.variables = mapper.variables;
+    MappedSpec {
+        collection,
+        folders,
+        requests,
+        warnings: mapper.warnings,
+    }
+}
+
+impl TagFolder {
+    fn new(name: &str) -> Self {
+        Self {
+            folder: PersistenceFolder::new(uuid::Uuid::now_v7().to_string(), name),
+            requests: Vec::new(),
+        }
+    }
+
+    /// Folders for the tags declared by a specification, in order
+    fn declared(root: &Value) -> Vec<Self> {
+        root.get("tags")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|tag| {
+                let name = tag.get("name").and_then(Value::as_str)?;
+                let mut folder = Self::new(name);
+                folder.folder.description = tag
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(ToString::to_string);
+                Some(folder)
+            })
+            .collect()
+    }
+}
+
+impl Mapper<'_> {
+    /// Warn about skipped webhooks and unresolved references
+    fn report_skipped(&mut self) {
+        // OpenAPI 3.1 webhooks are requests the API sends, not ones it receives
+        if let Some(webhooks) = self
+            .spec
+            .root
+            .get("webhooks")
+            .and_then(Value::as_object)
+            .filter(|webhooks| !webhooks.is_empty())
+        {
+            self.warnings.push(ImportWarning::info(
+                "webhooks",
+                format!(
+                    "{} webhooks are not supported and were skipped",
+                    webhooks.len()
+                ),
+            ));
+        }
+
+        for reference in &self.spec.unresolved_refs {
+            self.warnings.push(ImportWarning::warning(
+            reference,
+            "Reference could not be resolved; only references within the document are supported",
+        ));
+        }
+    }
+}
+
+/// Get the method of a path item field, if it holds an operation
+///
+/// Besides the standard fields, `x-<method>` extensions with responses are
+/// read as operations, which is how other methods are exported.
+fn operation_method(field: &str, operation: &Value) -> Option<HttpMethod> {
+    if OPERATION_FIELDS.contains(&field) {
+        return field.parse().ok();
+    }
+    field
+        .strip_prefix("x-")
+        .filter(|_| operation.get("responses").is_some())
+        .and_then(|method| method.to_uppercase().parse().ok())
+}
+
+/// Mapping state for one specification
+struct Mapper<'a> {
+    spec: Spec<'a>,
+    format: ImportFormat,
+    /// Collection variables: `baseUrl` and one per parameter
+    variables: BTreeMap<String, String>,
+    /// Security schemes by name, `None` if not supported
+    schemes: HashMap<String, Option<PersistenceAuth>>,
+    warnings: Vec<ImportWarning>,
+}
+
+impl<'a> Mapper<'a> {
+    /// Get the server URL for the `baseUrl` variable
+    fn base_url(&mut self) -> String {
+        let root = self.spec.root;
+        let url = if self.format == ImportFormat::Swagger2 {
+            let base_path = root.get("basePath").and_then(Value::as_str).unwrap_or("");
+            let scheme = root
+                .get("schemes")
+                .and_then(Value::as_array)
+                .and_then(|schemes| schemes.first())
+                .and_then(Value::as_str)
+                .unwrap_or("https");
+            if let Some(host) = root.get("host").and_then(Value::as_str) {
+                format!("{scheme}://{host}{base_path}")
+            } else {
+                self.warnings.push(ImportWarning::warning(
+                    "host",
+                    "No host is defined, set the baseUrl variable before sending requests",
+                ));
+                base_path.to_string()
+            }
+        } else {
+            let servers = root
+                .get("servers")
+                .and_then(Value::as_array)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let Some(server) = servers.first() else {
+                self.warnings.push(ImportWarning::warning(
+                    "servers",
+                    "No server is defined, set the baseUrl variable before sending requests",
+                ));
+                return String::new();
+            };
+            if servers.len() > 1 {
+                self.warnings.push(ImportWarning::info(
+                    "servers",
+                    format!(
+                        "Only the first of {} servers is used for baseUrl",
+                        servers.len()
+                    ),
+                ));
+            }
+
+            // Fill in server variables with their defaults
+            let mut url = server
+                .get("url")
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string();
+            for (name, variable) in server
+                .get("variables")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+            {
+                let value = variable
+                    .get("default")
+                    .map(example_text)
+                    .unwrap_or_default();
+                url = url.replace(&format!("{{{name}}}"), &value);
+            }
+            url
+        };
+
+        if !url.contains("://") {
+            self.warnings.push(ImportWarning::warning(
+                if self.format == ImportFormat::Swagger2 {
+                    "basePath"
+                } else {
+                    "servers"
+                },
+                format!("Server URL '{url}' is relative, set baseUrl to an absolute URL"),
+            ));
+        }
+        url.trim_end_matches('/').to_string()
+    }
+
+    /// Map a security requirement list to auth
+    ///
+    /// The first requirement with a supported scheme is used. Returns `None`
+    /// if none is supported or the list is empty, which means no auth.
+    fn security_auth(&mut self, requirements: &Value, path: &str) -> Option<PersistenceAuth> {
+        for requirement in requirements.as_array().into_iter().flatten() {
+            let Some(schemes) = requirement.as_object() else {
+                continue;
+            };
+            if schemes.len() > 1 {
+                let names: Vec<&str> = schemes.keys().map(String::as_str).collect();
+                self.warnings.push(ImportWarning::info(
+                    path,
+                    format!(
+                        "Requirement combines schemes {}; only one is used",
+                        names.join(", ")
+                    ),
+                ));
+            }
+            for (name, scopes) in schemes {
+                if let Some(auth) = self.scheme_auth(name) {
+                    return Some(with_scopes(auth, scopes));
+                }
+            }
+        }
+        None
+    }
+
+    /// Map a security scheme to auth, reporting unsupported schemes once
+    fn scheme_auth(&mut self, name: &str) -> Option<PersistenceAuth> {
+        if let Some(auth) = self.schemes.get(name) {
+            return auth.clone();
+        }
+
+        let definitions = if self.format == ImportFormat::Swagger2 {
+            self.spec.root.get("securityDefinitions")
+        } else {
+            self.spec
+                .root
+                .get("components")
+                .and_then(|components| components.get("securitySchemes"))
+        };
+        let path = format!("securitySchemes/{name}");
+        let auth = if let Some(scheme) = definitions
+            .and_then(|definitions| definitions.get(name))
+            .and_then(|scheme| self.spec.resolve(scheme))
+        {
+            self.map_scheme(scheme, &path)
+        } else {
+            self.warnings.push(ImportWarning::warning(
+                &path,
+                "Security scheme is not defined",
+            ));
+            None
+        };
+        self.schemes.insert(name.to_string(), auth.clone());
+        auth
+    }
+
+    fn map_scheme(&mut self, scheme: &Value, path: &str) -> Option<PersistenceAuth> {
+        let field = |name: &str| scheme.get(name).and_then(Value::as_str).unwrap_or("");
+        let (auth, variables) = match field("type") {
+            "basic" => (basic_auth(), "{{username}} and {{password}}"),
+            "http" => match field("scheme").to_lowercase().as_str() {
+                "basic" => (basic_auth(), "{{username}} and {{password}}"),
+                "digest" => (
+                    PersistenceAuth::Digest {
+                        username: variable("username"),
+                        password: variable("password"),
+                    },
+                    "{{username}} and {{password}}",
+                ),
+                "bearer" => (PersistenceAuth::bearer(variable("token")), "{{token}}"),
+                other => return self.unsupported_scheme(path, &format!("HTTP '{other}' auth")),
+            },
+            "apiKey" => {
+                let location = match field("in") {
+                    "header" => ApiKeyLocation::Header,
+                    "query" => ApiKeyLocation::Query,
+                    other => {
+                        return self.unsupported_scheme(path, &format!("API keys in '{other}'"));
+                    }
+                };
+                (
+                    PersistenceAuth::ApiKey {
+                        key: field("name").to_string(),
+                        value: variable("apiKey"),
+                        location,
+                    },
+                    "{{apiKey}}",
+                )
+            }
+            "oauth2" => match self.oauth2_flows(scheme) {
+                Some(auth) => (auth, "{{clientId}} and {{clientSecret}}"),
+                None => return self.unsupported_scheme(path, "This OAuth 2.0 flow"),
+            },
+            other => return self.unsupported_scheme(path, &format!("'{other}' security")),
+        };
+        self.warnings.push(ImportWarning::info(
+            path,
+            format!("Set {variables} in an environment to authenticate"),
+        ));
+        Some(auth)
+    }
+
+    /// Map the first supported OAuth 2.0 flow of a scheme
+    fn oauth2_flows(&self, scheme: &Value) -> Option<PersistenceAuth> {
+        // Swagger 2.0 has one flow, with the URLs on the scheme itself
+        let flows: Vec<(&str, &Value)> = if self.format == ImportFormat::Swagger2 {
+            let flow = match scheme.get("flow").and_then(Value::as_str)? {
+                "application" => "clientCredentials",
+                "accessCode" => "authorizationCode",
+                other => other,
+            };
+            vec![(flow, scheme)]
+        } else {
+            scheme
+                .get("flows")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(flow, settings)| (flow.as_str(), settings))
+                .collect()
+        };
+
+        let url = |settings: &Value, name: &str| {
+            settings
+                .get(name)
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string()
+        };
+        ["authorizationCode", "clientCredentials", "password"]
+            .iter()
+            .find_map(|wanted| flows.iter().find(|(flow, _)| flow == wanted))
+            .map(|(flow, settings)| match *flow {
+                "authorizationCode" => PersistenceAuth::Oauth2AuthCode {
+                    auth_url: url(settings, "authorizationUrl"),
+                    token_url: url(settings, "tokenUrl"),
+                    client_id: variable("clientId"),
+                    client_secret: variable("clientSecret"),
+                    redirect_uri: variable("redirectUri"),
+                    scope: None,
+                },
+                "clientCredentials" => PersistenceAuth::Oauth2ClientCredentials {
+                    token_url: url(settings, "tokenUrl"),
+                    client_id: variable("clientId"),
+                    client_secret: variable("clientSecret"),
+                    scope: None,
+                },
+                _ => PersistenceAuth::Oauth2Password {
+                    token_url: url(settings, "tokenUrl"),
+                    client_id: variable("clientId"),
+                    client_secret: variable("clientSecret"),
+                    username: variable("username"),
+                    password: variable("password"),
+                    scope: None,
+                },
+            })
+    }
+
+    fn unsupported_scheme(&mut self, path: &str, what: &str) -> Option<PersistenceAuth> {
+        self.warnings.push(ImportWarning::warning(
+            path,
+            format!("{what} is not supported, requests using it have no auth"),
+        ));
+        None
+    }
+
+    /// Resolve the parameters of an operation
+    ///
+    /// Operation parameters override path parameters with the same name.
+    fn parameters(
+        &mut self,
+        operation: &'a Value,
+        shared_parameters: Option<&'a Value>,
+    ) -> Vec<&'a Value> {
+        let mut parameters: Vec<&'a Value> = Vec::new();
+        for parameter in [shared_parameters, operation.get("parameters")]
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_array)
+            .flatten()
+        {
+            let Some(parameter) = self.spec.resolve(parameter) else {
+                continue;
+            };
+            let key = |p: &Value| (p.get("name").cloned(), p.get("in").cloned());
+            match parameters.iter().position(|p| key(p) == key(parameter)) {
+                Some(index) => parameters[index] = parameter,
+                None => parameters.push(parameter),
+            }
+        }
+        parameters
+    }
+
+    /// Map an operation to a request
+    fn map_operation(
+        &mut self,
+        path: &str,
+        method: HttpMethod,
+        operation: &'a Value,
+        shared_parameters: Option<&'a Value>,
+        default_auth: Option<&PersistenceAuth>,
+    ) -> SavedRequest {
+        let location = format!("{method} {path}");
+        let name = ["summary", "operationId"]
+            .iter()
+            .find_map(|field| operation.get(field).and_then(Value::as_str))
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or(&location)
+            .to_string();
+        let url = format!("{}{}", variable(BASE_URL_VARIABLE), path_template(path));
+        let mut request =
+            SavedRequest::new(uuid::Uuid::now_v7().to_string(), name, method.into(), url);
+
+        let mut body_parameter = None;
+        let mut form_parameters = Vec::new();
+        for parameter in self.parameters(operation, shared_parameters) {
+            let name = parameter.get("name").and_then(Value::as_str).unwrap_or("");
+            match parameter.get("in").and_then(Value::as_str).unwrap_or("") {
+                "path" => self.define_variable(name, parameter),
+                "query" => {
+                    request
+                        .query_params
+                        .insert(name.to_string(), variable(name));
+                    self.define_variable(name, parameter);
+                }
+                "header" if !IGNORED_HEADERS.contains(&name.to_lowercase().as_str()) => {
+                    request.headers.insert(name.to_string(), variable(name));
+                    self.define_variable(name, parameter);
+                }
+                "cookie" => self.warnings.push(ImportWarning::warning(
+                    &location,
+                    format!("Cookie parameter '{name}' is not supported and was skipped"),
+                )),
+                "body" => body_parameter = Some(parameter),
+                "formData" => form_parameters.push(parameter),
+                _ => {}
+            }
+        }
+
+        // Body
+        if let Some(request_body) = operation
+            .get("requestBody")
+            .and_then(|body| self.spec.resolve(body))
+            && let Some(content) = request_body.get("content").and_then(Value::as_object)
+        {
+            self.content_body(&mut request, content, &location);
+        } else if let Some(parameter) = body_parameter {
+            let media_type = self.consumes(operation);
+            let schema = parameter.get("schema");
+            let example = schema
+                .map(|schema| self.spec.example(schema))
+                .unwrap_or_default();
+            self.set_body(&mut request, &media_type, schema, example, &location);
+        } else if !form_parameters.is_empty() {
+            let multipart = self.consumes(operation) == "multipart/form-data"
+                || form_parameters
+                    .iter()
+                    .any(|p| p.get("type").and_then(Value::as_str) == Some("file"));
+            let fields = form_parameters.iter().map(|parameter| {
+                let name = parameter.get("name").and_then(Value::as_str).unwrap_or("");
+                let is_file = parameter.get("type").and_then(Value::as_str) == Some("file");
+                (name.to_string(), is_file, self.parameter_example(parameter))
+            });
+            let fields: Vec<_> = fields.collect();
+            request.body = Some(self.form_body(fields, multipart, &location));
+        }
+
+        // Auth, when it differs from the collection's
+        if let Some(security) = operation.get("security") {
+            let auth = self.security_auth(security, &location);
+            if auth.as_ref() != default_auth {
+                match auth {
+                    Some(auth) => request.auth = Some(auth),
+                    None => self.warnings.push(ImportWarning::info(
+                        &location,
+                        "Operation needs no auth but inherits the collection auth",
+                    )),
+                }
+            }
+        }
+
+        if operation
+            .get("callbacks")
+            .and_then(Value::as_object)
+            .is_some_and(|callbacks| !callbacks.is_empty())
+        {
+            self.warnings.push(ImportWarning::info(
+                &location,
+                "Callbacks are not supported and were skipped",
+            ));
+        }
+        if operation
+            .get("servers")
+            .and_then(Value::as_array)
+            .is_some_and(|servers| !servers.is_empty())
+        {
+            self.warnings.push(ImportWarning::warning(
+                &location,
+                "Operation servers are not supported, the request uses baseUrl",
+            ));
+        }
+
+        request
+    }
+
+    /// Set the body from the media types of an `OpenAPI` 3 request body
+    ///
+    /// JSON is preferred, then forms, then text.
+    fn content_body(
+        &mut self,
+        request: &mut SavedRequest,
+        content: &'a Map<String, Value>,
+        location: &str,
+    ) {
+        let Some((media_type, media)) = content
+            .iter()
+            .min_by_key(|(media_type, _)| media_preference(media_type))
+        else {
+            return;
+        };
+        let example = self.media_example(media);
+        self.set_body(request, media_type, media.get("schema"), example, location);
+    }
+
+    /// Set a body of the given media type from an example
+    fn set_body(
+        &mut self,
+        request: &mut SavedRequest,
+        media_type: &str,
+        schema: Option<&'a Value>,
+        example: Value,
+        location: &str,
+    ) {
+        let body = match media_preference(media_type) {
+            0 => {
+                if media_type != "application/json" {
+                    request
+                        .headers
+                        .insert("Content-Type".to_string(), media_type.to_string());
+                }
+                PersistenceRequestBody::json(example)
+            }
+            form @ (1 | 2) => {
+                let properties = schema
+                    .and_then(|schema| self.spec.resolve(schema))
+                    .and_then(|schema| schema.get("properties"))
+                    .and_then(Value::as_object);
+                let fields = example
+                    .as_object()
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, value)| {
+                        let is_file = properties
+                            .and_then(|properties| properties.get(name))
+                            .and_then(|property| property.get("format"))
+                            .and_then(Value::as_str)
+                            == Some("binary");
+                        (name.clone(), is_file, example_text(value))
+                    })
+                    .collect();
+                self.form_body(fields, form == 2, location)
+            }
+            _ if schema.is_some_and(|schema| {
+                schema.get("format").and_then(Value::as_str) == Some("binary")
+            }) || media_type == "application/octet-stream" =>
+            {
+                request
+                    .headers
+                    .insert("Content-Type".to_string(), media_type.to_string());
+                self.warnings.push(ImportWarning::info(
+                    location,
+                    "Choose a file for the binary body before sending",
+                ));
+                PersistenceRequestBody::binary("")
+            }
+            _ => {
+                if media_type != "text/plain" {
+                    request
+                        .headers
+                        .insert("Content-Type".to_string(), media_type.to_string());
+                }
+                if !example.is_string() && !example.is_null() {
+                    self.warnings.push(ImportWarning::info(
+                        location,
+                        format!("No {media_type} example was given, the body is left empty"),
+                    ));
+                    PersistenceRequestBody::text("")
+                } else {
+                    PersistenceRequestBody::text(example_text(&example))
+                }
+            }
+        };
+        request.body = Some(body);
+    }
+
+    /// Build a form body from `(name, is_file, value)` fields
+    fn form_body(
+        &mut self,
+        fields: Vec<(String, bool, String)>,
+        multipart: bool,
+        location: &str,
+    ) -> PersistenceRequestBody {
+        if !multipart {
+            return PersistenceRequestBody::form_urlencoded(
+                fields
+                    .into_iter()
+                    .map(|(name, _, value)| (name, value))
+                    .collect(),
+            );
+        }
+        if fields.iter().any(|(_, is_file, _)| *is_file) {
+            self.warnings.push(ImportWarning::info(
+                location,
+                "Choose files for the file fields before sending",
+            ));
+        }
+        PersistenceRequestBody::form_data(
+            fields
+                .into_iter()
+                .map(|(name, is_file, value)| {
+                    if is_file {
+                        FormDataField::File {
+                            name,
+                            path: String::new(),
+                        }
+                    } else {
+                        FormDataField::text(name, value)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Get the example of a media type, or build one from its schema
+    fn media_example(&mut self, media: &'a Value) -> Value {
+        if let Some(example) = media.get("example") {
+            return example.clone();
+        }
+        if let Some(example) = media
+            .get("examples")
+            .and_then(Value::as_object)
+            .and_then(|examples| examples.values().next())
+            .and_then(|example| self.spec.resolve(example))
+            .and_then(|example| example.get("value"))
+        {
+            return example.clone();
+        }
+        media
+            .get("schema")
+            .map(|schema| self.spec.example(schema))
+            .unwrap_or_default()
+    }
+
+    /// Get the first content type a Swagger 2.0 operation consumes
+    fn consumes(&self, operation: &Value) -> String {
+        [operation, self.spec.root]
+            .iter()
+            .find_map(|value| {
+                value
+                    .get("consumes")
+                    .and_then(Value::as_array)
+                    .and_then(|types| types.first())
+                    .and_then(Value::as_str)
+            })
+            .unwrap_or("application/json")
+            .to_string()
+    }
+
+    /// Add a collection variable for a parameter, keeping the first
+    /// example seen for a name
+    fn define_variable(&mut self, name: &str, parameter: &'a Value) {
+        if !self.variables.contains_key(name) {
+            let example = self.parameter_example(parameter);
+            self.variables.insert(name.to_string(), example);
+        }
+    }
+
+    /// Get the example value of a parameter, or an empty string
+    fn parameter_example(&mut self, parameter: &'a Value) -> String {
+        let example = declared_example(parameter)
+            .or_else(|| parameter.get("x-example"))
+            .or_else(|| {
+                parameter
+                    .get("examples")
+                    .and_then(Value::as_object)
+                    .and_then(|examples| examples.values().next())
+                    .and_then(|example| self.spec.resolve(example))
+                    .and_then(|example| example.get("value"))
+            })
+            .or_else(|| {
+                parameter
+                    .get("schema")
+                    .and_then(|schema| self.spec.resolve(schema))
+                    .and_then(declared_example)
+            });
+        match example {
+            Some(example) => example_text(example),
+            // Parameters of array type in Swagger 2.0 describe their items
+            None if schema_type(parameter) == Some("array") => parameter
+                .get("items")
+                .and_then(declared_example)
+                .map(example_text)
+                .unwrap_or_default(),
+            None => String::new(),
+        }
+    }
+}
+
+/// Rank a media type for choosing the request body: JSON, then forms, then
+/// text
+fn media_preference(media_type: &str) -> u8 {
+    if media_type.contains("json") {
+        0
+    } else if media_type == "application/x-www-form-urlencoded" {
+        1
+    } else if media_type == "multipart/form-data" {
+        2
+    } else if media_type.starts_with("text/") {
+        3
+    } else {
+        4
+    }
+}
+
+/// Set the scopes a requirement asks for on `OAuth` 2.0 auth
+fn with_scopes(mut auth: PersistenceAuth, scopes: &Value) -> PersistenceAuth {
+    let scopes: Vec<&str> = scopes
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    if !scopes.is_empty() {
+        match &mut auth {
+            PersistenceAuth::Oauth2ClientCredentials { scope, .. }
+            | PersistenceAuth::Oauth2AuthCode { scope, .. }
+            | PersistenceAuth::Oauth2Password { scope, .. } => *scope = Some(scopes.join(" ")),
+            _ => {}
+        }
+    }
+    auth
+}
+
+fn basic_auth() -> PersistenceAuth {
+    PersistenceAuth::basic(variable("username"), variable("password"))
+}
+
+/// Reference a variable, e.g. `{{name}}`
+fn variable(name: &str) -> String {
+    format!("{{{{{name}}}}}")
+}
+
+/// Turn path templates such as `/users/{id}` into `/users/{{id}}`
+fn path_template(path: &str) -> String {
+    let mut url = String::new();
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        let Some(length) = rest[start..].find('}') else {
+            break;
+        };
+        url.push_str(&rest[..start]);
+        url.push_str(&variable(&rest[start + 1..start + length]));
+        rest = &rest[start + length + 1..];
+    }
+    url.push_str(rest);
+    url
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use vortex_domain::persistence::PersistenceHttpMethod;
+
+    fn find<'a>(mapped: &'a MappedSpec, name: &str) -> &'a SavedRequest {
+        mapped
+            .folders
+            .iter()
+            .flat_map(|folder| &folder.requests)
+            .chain(&mapped.requests)
+            .find(|request| request.name == name)
+            .unwrap_or_else(|| panic!("no request named {name}"))
+    }
+
+    #[test]
+    fn test_path_template() {
+        assert_eq!(path_template("/users/{id}"), "/users/{{id}}");
+        assert_eq!(path_template("/a/{x}/b/{y}.json"), "/a/{{x}}/b/{{y}}.json");
+        assert_eq!(path_template("/plain"), "/plain");
+    }
+
+    #[test]
+    fn test_map_openapi3() {
+        let spec = json!({
+            "openapi": "3.0.3",
+            "info": {"title": "Pet Store", "description": "Pets"},
+            "servers": [{"url": "https://{region}.example.com/v1/", "variables": {"region": {"default": "eu"}}}],
+            "tags": [{"name": "pets", "description": "Pet operations"}, {"name": "unused"}],
+            "security": [{"bearer": []}],
+            "components": {
+                "securitySchemes": {"bearer": {"type": "http", "scheme": "bearer"}},
+                "parameters": {"PetId": {"name": "petId", "in": "path", "required": true, "example": 7}},
+                "schemas": {"Pet": {"type": "object", "properties": {"name": {"type": "string"}}}}
+            },
+            "paths": {
+                "/pets/{petId}": {
+                    "parameters": [{"$ref": "#/components/parameters/PetId"}],
+                    "get": {
+                        "tags": ["pets"],
+                        "summary": "Get pet",
+                        "parameters": [
+                            {"name": "fields", "in": "query", "schema": {"type": "string", "default": "all"}},
+                            {"name": "X-Trace", "in": "header"},
+                            {"name": "Accept", "in": "header"},
+                            {"name": "session", "in": "cookie"}
+                        ]
+                    },
+                    "put": {
+                        "tags": ["pets"],
+                        "operationId": "updatePet",
+                        "security": [],
+                        "requestBody": {"content": {
+                            "application/xml": {},
+                            "application/json": {"schema": {"$ref": "#/components/schemas/Pet"}}
+                        }}
+                    }
+                },
+                "/health": {"get": {"responses": {}}},
+                "/cache": {"x-purge": {"responses": {"200": {"description": "Purged"}}}}
+            }
+        });
+
+        let mapped = map_spec(&spec, ImportFormat::OpenApi3);
+
+        assert_eq!(mapped.collection.name, "Pet Store");
+        assert_eq!(mapped.collection.description.as_deref(), Some("Pets"));
+        assert_eq!(
+            mapped.collection.auth,
+            Some(PersistenceAuth::bearer("{{token}}"))
+        );
+        assert_eq!(
+            mapped.collection.variables,
+            BTreeMap::from([
+                (
+                    "baseUrl".to_string(),
+                    "https://eu.example.com/v1".to_string()
+                ),
+                ("X-Trace".to_string(), String::new()),
+                ("fields".to_string(), "all".to_string()),
+                ("petId".to_string(), "7".to_string()),
+            ])
+        );
+
+        assert_eq!(mapped.folders.len(), 1);
+        assert_eq!(mapped.folders[0].folder.name, "pets");
+        assert_eq!(
+            mapped.folders[0].folder.description.as_deref(),
+            Some("Pet operations")
+        );
+
+        let get = find(&mapped, "Get pet");
+        assert_eq!(get.method, PersistenceHttpMethod::Get);
+        assert_eq!(get.url, "{{baseUrl}}/pets/{{petId}}");
+        assert_eq!(get.query_params["fields"], "{{fields}}");
+        assert_eq!(
+            get.headers,
+            BTreeMap::from([("X-Trace".to_string(), "{{X-Trace}}".to_string())])
+        );
+
+        let put = find(&mapped, "updatePet");
+        assert_eq!(
+            put.body,
+            Some(PersistenceRequestBody::json(json!({"name": "string"})))
+        );
+        assert!(put.auth.is_none());
+
+        let purge = find(&mapped, "PURGE /cache");
+        assert_eq!(
+            purge.method,
+            PersistenceHttpMethod::Custom("PURGE".to_string())
+        );
+        assert_eq!(mapped.requests.len(), 2);
+
+        let messages: Vec<&str> = mapped.warnings.iter().map(|w| w.message.as_str()).collect();
+        assert!(messages.contains(&"Cookie parameter 'session' is not supported and was skipped"));
+        assert!(messages.contains(&"Operation needs no auth but inherits the collection auth"));
+    }
+
+    #[test]
+    fn test_map_swagger2() {
+        let spec = json!({
+            "swagger": "2.0",
+            "info": {"title": "Legacy"},
+            "host": "api.example.com",
+            "basePath": "/v2",
+            "schemes": ["http"],
+            "consumes": ["application/json"],
+            "securityDefinitions": {
+                "key": {"type": "apiKey", "name": "X-Api-Key", "in": "header"},
+                "oauth": {"type": "oauth2", "flow": "application", "tokenUrl": "https://auth.example.com/token"},
+                "implicit": {"type": "oauth2", "flow": "implicit", "authorizationUrl": "https://auth.example.com"}
+            },
+            "definitions": {"User": {"type": "object", "properties": {"age": {"type": "integer"}}}},
+            "paths": {
+                "/users": {
+                    "post": {
+                        "summary": "Create user",
+                        "security": [{"oauth": ["write", "read"]}],
+                        "parameters": [{"name": "user", "in": "body", "schema": {"$ref": "#/definitions/User"}}]
+                    },
+                    "put": {
+                        "summary": "Upload avatar",
+                        "security": [{"implicit": []}, {"key": []}],
+                        "parameters": [
+                            {"name": "avatar", "in": "formData", "type": "file"},
+                            {"name": "caption", "in": "formData", "type": "string", "x-example": "Me"}
+                        ]
+                    },
+                    "get": {
+                        "summary": "List users",
+                        "parameters": [{"name": "ids", "in": "query", "type": "array", "items": {"type": "integer", "default": 1}}]
+                    }
+                }
+            }
+        });
+
+        let mapped = map_spec(&spec, ImportFormat::Swagger2);
+
+        assert_eq!(
+            mapped.collection.variables["baseUrl"],
+            "http://api.example.com/v2"
+        );
+        assert_eq!(mapped.collection.variables["ids"], "1");
+
+        let create = find(&mapped, "Create user");
+        assert_eq!(create.url, "{{baseUrl}}/users");
+        assert_eq!(
+            create.body,
+            Some(PersistenceRequestBody::json(json!({"age": 0})))
+        );
+        assert_eq!(
+            create.auth,
+            Some(PersistenceAuth::Oauth2ClientCredentials {
+                token_url: "https://auth.example.com/token".to_string(),
+                client_id: "{{clientId}}".to_string(),
+                client_secret: "{{clientSecret}}".to_string(),
+                scope: Some("write read".to_string()),
+            })
+        );
+
+        let upload = find(&mapped, "Upload avatar");
+        assert_eq!(
+            upload.body,
+            Some(PersistenceRequestBody::form_data(vec![
+                FormDataField::File {
+                    name: "avatar".to_string(),
+                    path: String::new(),
+                },
+                FormDataField::text("caption", "Me"),
+            ]))
+        );
+        assert_eq!(
+            upload.auth,
+            Some(PersistenceAuth::api_key_header("X-Api-Key", "{{apiKey}}"))
+        );
+        assert!(
+            mapped
+                .warnings
+                .iter()
+                .any(|w| w.path == "securitySchemes/implicit"
+                    && w.severity == crate::import::WarningSeverity::Warning)
+        );
+    }
+
+    #[test]
+    fn test_map_reports_missing_servers_and_external_refs() {
+        let spec = json!({
+            "openapi": "3.1.0",
+            "info": {"title": "Refs"},
+            "paths": {
+                "/items": {"post": {"requestBody": {"$ref": "shared.yaml#/components/requestBodies/Item"}}}
+            },
+            "webhooks": {"newItem": {"post": {}}}
+        });
+
+        let mapped = map_spec(&spec, ImportFormat::OpenApi3);
+
+        assert_eq!(mapped.collection.variables["baseUrl"], "");
+        assert!(find(&mapped, "POST /items").body.is_none());
+        let paths: Vec<&str> = mapped.warnings.iter().map(|w| w.path.as_str()).collect();
+        assert!(paths.contains(&"servers"));
+        assert!(paths.contains(&"webhooks"));
+        assert!(paths.contains(&"shared.yaml#/components/requestBodies/Item"));
+    }
+}
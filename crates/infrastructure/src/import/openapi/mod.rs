@@ -0,0 +1,10 @@
+//! `OpenAPI` Import Module
+//!
+//! This module provides functionality to import `OpenAPI` 3.x and Swagger 2.0
+//! specifications, in JSON or YAML, as Vortex collections.
+
+pub mod importer;
+pub mod mapper;
+pub mod schema;
+
+pub use importer::OpenApiImporter;
@@ -0,0 +1,288 @@
+//! Reference Resolution and Example Generation
+//!
+//! This module resolves local `$ref`s within a specification and builds
+//! example values from schemas.
+
+use serde_json::{Map, Value, json};
+use std::collections::BTreeSet;
+
+/// Maximum number of chained `$ref`s followed before giving up
+const MAX_REF_CHAIN: usize = 16;
+
+/// Maximum schema nesting depth for generated examples
+const MAX_EXAMPLE_DEPTH: usize = 8;
+
+/// A parsed specification with the `$ref`s it could not resolve
+pub struct Spec<'a> {
+    /// The whole specification document
+    pub root: &'a Value,
+    /// References to other documents or missing definitions
+    pub unresolved_refs: BTreeSet<String>,
+}
+
+impl<'a> Spec<'a> {
+    /// Wrap a parsed specification
+    #[must_use]
+    pub const fn new(root: &'a Value) -> Self {
+        Self {
+            root,
+            unresolved_refs: BTreeSet::new(),
+        }
+    }
+
+    /// Follow `$ref`s until reaching a value without one
+    ///
+    /// Only references within the document (`#/...`) are followed. Returns
+    /// `None` and records the reference if it cannot be resolved.
+    pub fn resolve(&mut self, value: &'a Value) -> Option<&'a Value> {
+        let mut current = value;
+        for _ in 0..MAX_REF_CHAIN {
+            let Some(reference) = ref_of(current) else {
+                return Some(current);
+            };
+            let Some(target) = reference
+                .strip_prefix('#')
+                .and_then(|pointer| self.root.pointer(pointer))
+            else {
+                self.unresolved_refs.insert(reference.to_string());
+                return None;
+            };
+            current = target;
+        }
+        None
+    }
+
+    /// Build an example value for a schema
+    ///
+    /// Uses the schema's own `example`, `default` or first `enum` value
+    /// where present, and otherwise a placeholder for its type. Recursive
+    /// schemas stop at the first repeated reference.
+    pub fn example(&mut self, schema: &'a Value) -> Value {
+        self.example_at(schema, &mut Vec::new())
+    }
+
+    fn example_at(&mut self, schema: &'a Value, refs: &mut Vec<&'a str>) -> Value {
+        if refs.len() > MAX_EXAMPLE_DEPTH {
+            return Value::Null;
+        }
+
+        // Follow a reference, unless it is already being expanded
+        if let Some(reference) = ref_of(schema) {
+            if refs.contains(&reference) {
+                return Value::Null;
+            }
+            let Some(target) = self.resolve(schema) else {
+                return Value::Null;
+            };
+            refs.push(reference);
+            let example = self.example_at(target, refs);
+            refs.pop();
+            return example;
+        }
+
+        if let Some(example) = declared_example(schema) {
+            return example.clone();
+        }
+
+        if let Some(all_of) = schema.get("allOf").and_then(Value::as_array) {
+            let mut merged = Map::new();
+            for part in all_of {
+                match self.example_at(part, refs) {
+                    Value::Object(fields) => merged.extend(fields),
+                    Value::Null => {}
+                    other => return other,
+                }
+            }
+            return Value::Object(merged);
+        }
+
+        if let Some(first) = ["oneOf", "anyOf"]
+            .iter()
+            .find_map(|key| schema.get(key).and_then(Value::as_array))
+            .and_then(|choices| choices.first())
+        {
+            return self.example_at(first, refs);
+        }
+
+        match schema_type(schema) {
+            Some("object") => self.object_example(schema, refs),
+            Some("array") => schema
+                .get("items")
+                .map_or_else(|| json!([]), |items| json!([self.example_at(items, refs)])),
+            Some("string") => string_example(schema),
+            Some("integer" | "number") => json!(0),
+            Some("boolean") => json!(false),
+            _ if schema.get("properties").is_some() => self.object_example(schema, refs),
+            _ => Value::Null,
+        }
+    }
+
+    fn object_example(&mut self, schema: &'a Value, refs: &mut Vec<&'a str>) -> Value {
+        let mut fields = Map::new();
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                // Read-only properties are set by the server
+                if property.get("readOnly").and_then(Value::as_bool) == Some(true) {
+                    continue;
+                }
+                fields.insert(name.clone(), self.example_at(property, refs));
+            }
+        }
+        Value::Object(fields)
+    }
+}
+
+/// Get the `$ref` of a value, if it has one
+fn ref_of(value: &Value) -> Option<&str> {
+    value.get("$ref").and_then(Value::as_str)
+}
+
+/// Get the value a schema or parameter declares as its example
+///
+/// Checks `example`, the first of `examples` (an array in `OpenAPI` 3.1
+/// schemas), `default`, the first `enum` value and `const`.
+#[must_use]
+pub fn declared_example(value: &Value) -> Option<&Value> {
+    value
+        .get("example")
+        .or_else(|| {
+            value
+                .get("examples")
+                .and_then(Value::as_array)
+                .and_then(|examples| examples.first())
+        })
+        .or_else(|| value.get("default"))
+        .or_else(|| {
+            value
+                .get("enum")
+                .and_then(Value::as_array)
+                .and_then(|values| values.first())
+        })
+        .or_else(|| value.get("const"))
+}
+
+/// Get the type of a schema
+///
+/// `OpenAPI` 3.1 allows a list of types, in which case the first type other
+/// than `null` is used.
+#[must_use]
+pub fn schema_type(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(name) => Some(name),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|name| *name != "null"),
+        _ => None,
+    }
+}
+
+/// Build a placeholder string matching the schema's format
+fn string_example(schema: &Value) -> Value {
+    let example = match schema.get("format").and_then(Value::as_str) {
+        Some("date-time") => "2024-01-01T00:00:00Z",
+        Some("date") => "2024-01-01",
+        Some("time") => "00:00:00",
+        Some("email") => "user@example.com",
+        Some("uuid") => "00000000-0000-0000-0000-000000000000",
+        Some("uri" | "url") => "https://example.com",
+        Some("hostname") => "example.com",
+        Some("ipv4") => "127.0.0.1",
+        Some("ipv6") => "::1",
+        Some("binary" | "byte") => "",
+        _ => "string",
+    };
+    json!(example)
+}
+
+/// Render an example as text, e.g. for a variable or form field
+#[must_use]
+pub fn example_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_resolve_local_and_external_refs() {
+        let root = json!({
+            "components": {"schemas": {
+                "Id": {"type": "integer"},
+                "Alias": {"$ref": "#/components/schemas/Id"}
+            }}
+        });
+        let mut spec = Spec::new(&root);
+
+        let alias = json!({"$ref": "#/components/schemas/Alias"});
+        assert_eq!(spec.resolve(&alias), Some(&json!({"type": "integer"})));
+
+        let external = json!({"$ref": "common.yaml#/Id"});
+        assert_eq!(spec.resolve(&external), None);
+        assert!(spec.unresolved_refs.contains("common.yaml#/Id"));
+    }
+
+    #[test]
+    fn test_example_from_schema() {
+        let root = json!({
+            "components": {"schemas": {
+                "Pet": {
+                    "type": "object",
+                    "properties": {
+                        "id": {"type": "integer", "readOnly": true},
+                        "name": {"type": "string", "example": "Rex"},
+                        "status": {"type": "string", "enum": ["available", "sold"]},
+                        "born": {"type": "string", "format": "date"},
+                        "tags": {"type": "array", "items": {"type": "string"}},
+                        "owner": {"$ref": "#/components/schemas/Owner"}
+                    }
+                },
+                "Owner": {
+                    "allOf": [
+                        {"type": "object", "properties": {"email": {"type": "string", "format": "email"}}},
+                        {"type": "object", "properties": {"vip": {"type": ["boolean", "null"]}}}
+                    ]
+                }
+            }}
+        });
+        let mut spec = Spec::new(&root);
+
+        let pet = json!({"$ref": "#/components/schemas/Pet"});
+        assert_eq!(
+            spec.example(&pet),
+            json!({
+                "name": "Rex",
+                "status": "available",
+                "born": "2024-01-01",
+                "tags": ["string"],
+                "owner": {"email": "user@example.com", "vip": false}
+            })
+        );
+    }
+
+    #[test]
+    fn test_example_of_recursive_schema() {
+        let root = json!({
+            "components": {"schemas": {
+                "Node": {
+                    "type": "object",
+                    "properties": {
+                        "value": {"type": "integer"},
+                        "next": {"$ref": "#/components/schemas/Node"}
+                    }
+                }
+            }}
+        });
+        let mut spec = Spec::new(&root);
+
+        let node = json!({"$ref": "#/components/schemas/Node"});
+        assert_eq!(spec.example(&node), json!({"value": 0, "next": null}));
+    }
+}
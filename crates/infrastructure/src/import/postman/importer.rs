@@ -58,6 +58,12 @@ pub enum ImportError {
     /// File is not a valid Postman format
     #[error("Invalid Postman format: {0}")]
     InvalidFormat(String),
+    /// YAML parsing failed
+    #[error("Invalid YAML: {0}")]
+    InvalidYaml(String),
+    /// File is not a valid `OpenAPI` or Swagger specification
+    #[error("Invalid OpenAPI specification: {0}")]
+    InvalidSpec(String),
     /// IO operation failed
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
@@ -92,6 +98,10 @@ pub enum ImportFormat {
     PostmanCollectionV21,
     /// Postman Environment format
     PostmanEnvironment,
+    /// `OpenAPI` 3.x specification
+    OpenApi3,
+    /// Swagger 2.0 specification
+    Swagger2,
     /// Unknown or unsupported format
     Unknown,
 }
@@ -186,7 +196,7 @@ impl PostmanImporter {
         let format = Self::detect_format(&json);
 
         match format {
-            ImportFormat::Unknown => {
+            ImportFormat::Unknown | ImportFormat::OpenApi3 | ImportFormat::Swagger2 => {
                 issues.push(
                     "Unknown format: File is not a valid Postman Collection or Environment"
                         .to_string(),
@@ -288,9 +298,11 @@ impl PostmanImporter {
                     warnings: mapped.warnings,
                 })
             }
-            ImportFormat::Unknown => Err(ImportError::InvalidFormat(
-                "Unknown format: Not a valid Postman Collection or Environment".to_string(),
-            )),
+            ImportFormat::Unknown | ImportFormat::OpenApi3 | ImportFormat::Swagger2 => {
+                Err(ImportError::InvalidFormat(
+                    "Unknown format: Not a valid Postman Collection or Environment".to_string(),
+                ))
+            }
         }
     }
 
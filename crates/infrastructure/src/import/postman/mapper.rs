@@ -12,6 +12,7 @@ use super::types::{
 use super::warning::{ImportWarning, WarningSeverity};
 use serde_json::{Map, Value, json};
 use std::collections::BTreeMap;
+use vortex_domain::request::HttpMethod;
 
 /// Result of mapping a collection
 #[derive(Debug)]
@@ -97,6 +98,10 @@ pub enum MappedAuth {
         value: String,
         in_header: bool,
     },
+    Digest {
+        username: String,
+        password: String,
+    },
     OAuth2 {
         access_token: Option<String>,
         token_url: Option<String>,
@@ -121,9 +126,16 @@ pub struct MappedEnvironment {
 }
 
 /// Map HTTP method to Vortex format
+///
+/// Custom methods such as `PURGE` are kept. Returns `None` if the name is
+/// not a valid HTTP method.
 #[must_use]
-pub fn map_http_method(method: &str) -> String {
-    method.to_uppercase()
+pub fn map_http_method(method: &str) -> Option<String> {
+    method
+        .to_uppercase()
+        .parse::<HttpMethod>()
+        .ok()
+        .map(String::from)
 }
 
 /// Map headers from Postman format
@@ -300,6 +312,12 @@ pub fn map_auth(auth: &Option<PostmanAuth>) -> (Option<MappedAuth>, Vec<ImportWa
                 in_header: in_location == "header",
             })
         }
+        "digest" => {
+            // Realm, nonce and the rest come from the server's challenge
+            let username = a.get_param(&a.digest, "username").unwrap_or_default();
+            let password = a.get_param(&a.digest, "password").unwrap_or_default();
+            Some(MappedAuth::Digest { username, password })
+        }
         "oauth2" => {
             let access_token = a.get_param(&a.oauth2, "accessToken");
             let token_url = a.get_param(&a.oauth2, "accessTokenUrl");
@@ -316,7 +334,7 @@ pub fn map_auth(auth: &Option<PostmanAuth>) -> (Option<MappedAuth>, Vec<ImportWa
                 token_url,
             })
         }
-        "digest" | "hawk" | "ntlm" | "awsv4" => {
+        "hawk" | "ntlm" | "awsv4" => {
             warnings.push(ImportWarning::new(
                 "auth",
                 format!(
@@ -414,6 +432,18 @@ pub fn map_postman_item(
 
     // It's a request
     if let Some(ref request) = item.request {
+        let Some(method) = map_http_method(&request.method) else {
+            warnings.push(ImportWarning::new(
+                &current_path,
+                format!(
+                    "Invalid HTTP method '{}', request was skipped",
+                    request.method
+                ),
+                WarningSeverity::Error,
+            ));
+            return (None, warnings);
+        };
+
         // Check for scripts
         if !item.event.is_empty() {
             warnings.push(ImportWarning::new(
@@ -444,7 +474,7 @@ pub fn map_postman_item(
                     .description
                     .clone()
                     .or_else(|| request.description.clone()),
-                method: map_http_method(&request.method),
+                method,
                 url: request.url.raw(),
                 headers,
                 query_params: params,
@@ -618,6 +648,11 @@ pub fn auth_to_vortex_json(auth: &MappedAuth) -> Value {
             "value": value,
             "in": if *in_header { "header" } else { "query" }
         }),
+        MappedAuth::Digest { username, password } => json!({
+            "type": "digest",
+            "username": username,
+            "password": password
+        }),
         MappedAuth::OAuth2 {
             access_token,
             token_url,
@@ -636,9 +671,13 @@ mod tests {
 
     #[test]
     fn test_map_http_method() {
-        assert_eq!(map_http_method("get"), "GET");
-        assert_eq!(map_http_method("POST"), "POST");
-        assert_eq!(map_http_method("pAtCh"), "PATCH");
+        assert_eq!(map_http_method("get").unwrap(), "GET");
+        assert_eq!(map_http_method("POST").unwrap(), "POST");
+        assert_eq!(map_http_method("pAtCh").unwrap(), "PATCH");
+        assert_eq!(map_http_method("TRACE").unwrap(), "TRACE");
+        assert_eq!(map_http_method("purge").unwrap(), "PURGE");
+        assert_eq!(map_http_method("PROPFIND").unwrap(), "PROPFIND");
+        assert!(map_http_method("NOT VALID").is_none());
     }
 
     #[test]
@@ -711,4 +750,26 @@ mod tests {
             _ => panic!("Expected Bearer auth"),
         }
     }
+
+    #[test]
+    fn test_map_auth_digest() {
+        let auth: PostmanAuth = serde_json::from_value(json!({
+            "type": "digest",
+            "digest": [
+                { "key": "username", "value": "admin" },
+                { "key": "password", "value": "{{password}}" },
+                { "key": "realm", "value": "appliance" },
+                { "key": "algorithm", "value": "MD5" }
+            ]
+        }))
+        .unwrap();
+
+        let (mapped, warnings) = map_auth(&Some(auth));
+        assert!(warnings.is_empty());
+        let mapped = mapped.unwrap();
+        assert_eq!(
+            auth_to_vortex_json(&mapped),
+            json!({ "type": "digest", "username": "admin", "password": "{{password}}" })
+        );
+    }
 }
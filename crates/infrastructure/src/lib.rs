@@ -10,27 +10,41 @@ pub mod export;
 pub mod http;
 pub mod import;
 pub mod persistence;
+pub mod report;
 pub mod scripting;
 pub mod serialization;
 pub mod testing;
 
-pub use adapters::ReqwestHttpClient;
-pub use auth::OAuth2Provider;
+pub use adapters::{
+    ReqwestHttpClient, SharedCookieJar, ShellSecretCommandRunner, TonicGrpcClient,
+    TungsteniteWebSocketClient,
+};
+pub use auth::{BrowserOpener, OAuth2Provider};
 pub use codegen::{CodeGenerator, generate_code};
 pub use export::{ExportError, HarExporter, OpenApiExporter, export_request, export_requests};
-pub use http::{BodyBuildError, BuiltBody, build_body};
+pub use http::{
+    BodyBuildError, BuiltBody, ProxyEnvironment, ProxyResolver, apply_tls, build_body, build_proxy,
+};
 pub use import::{
     ImportConfig, ImportError, ImportFormat, ImportPreview, ImportResult, ImportWarning,
-    PostmanCollection, PostmanEnvironment, PostmanImporter, ValidationResult, WarningSeverity,
-    WarningStats,
+    OpenApiImporter, PostmanCollection, PostmanEnvironment, PostmanImporter, ValidationResult,
+    WarningSeverity, WarningStats,
 };
 pub use persistence::{
-    FileEnvironmentRepository, FileSecretsRepository, FileSystemCollectionRepository,
-    FileSystemWorkspaceRepository, HistoryError, HistoryRepository, SettingsError,
-    SettingsRepository, TokioFileSystem,
+    EncryptionError, FileCookieRepository, FileEnvironmentRepository, FileGraphqlSchemaRepository,
+    FileSecretsRepository, FileSystemCollectionRepository, FileSystemWorkspaceRepository,
+    HistoryError, HistoryRepository, SettingsError, SettingsRepository, TokenCacheError,
+    TokenCacheRepository, TokioFileSystem,
+};
+pub use report::{
+    JSON_REPORT_SCHEMA_VERSION, JsonReportWriter, JunitReportWriter, ReportError, TapReportWriter,
+    save_report, write_report,
 };
 pub use scripting::{ParseError, ScriptExecutor, parse_script};
 pub use serialization::{
     SerializationError, from_json, from_json_bytes, to_json_stable, to_json_stable_bytes,
 };
-pub use testing::TestRunner;
+pub use testing::{
+    CollectionRunner, DataFileError, DataFileFormat, DataRow, PlannedRequest, RunnerConfig,
+    TestRunner, load_data_file, parse_csv, parse_json, plan_requests,
+};
@@ -0,0 +1,180 @@
+//! File-based cookie repository implementation.
+//!
+//! The workspace jar is stored in `.vortex/cookies.json`; per-environment jars
+//! live in `.vortex/cookies/<environment>.json`. Like secrets, these files
+//! should be added to `.gitignore`.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use vortex_application::ports::{
+    CookieError, CookieRepository, FileSystem, FileSystemError, slugify,
+};
+use vortex_domain::cookie::CookieJar;
+
+use crate::serialization::{from_json_bytes, to_json_stable_bytes};
+
+/// Converts `FileSystemError` to `std::io::Error` for `CookieError`.
+fn to_io_error(e: FileSystemError) -> std::io::Error {
+    match e {
+        FileSystemError::Io(io_err) => io_err,
+        FileSystemError::NotFound(path) => {
+            std::io::Error::new(std::io::ErrorKind::NotFound, path.display().to_string())
+        }
+        FileSystemError::PermissionDenied(path) => std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            path.display().to_string(),
+        ),
+        FileSystemError::AlreadyExists(path) => std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            path.display().to_string(),
+        ),
+        _ => std::io::Error::other(e.to_string()),
+    }
+}
+
+/// File-based cookie repository.
+///
+/// Stores cookie jars as:
+/// ```text
+/// workspace/
+///   .vortex/
+///     cookies.json
+///     cookies/
+///       development.json
+///       production.json
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileCookieRepository<F> {
+    fs: F,
+}
+
+impl<F: FileSystem> FileCookieRepository<F> {
+    /// Creates a new file-based cookie repository.
+    pub const fn new(fs: F) -> Self {
+        Self { fs }
+    }
+
+    /// Returns the cookie jar file path for a workspace and optional environment.
+    fn cookies_path(workspace: &Path, environment: Option<&str>) -> PathBuf {
+        let vortex_dir = workspace.join(".vortex");
+        environment.map_or_else(
+            || vortex_dir.join("cookies.json"),
+            |name| {
+                vortex_dir
+                    .join("cookies")
+                    .join(format!("{}.json", slugify(name)))
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl<F: FileSystem + Sync> CookieRepository for FileCookieRepository<F> {
+    async fn load(
+        &self,
+        workspace: &Path,
+        environment: Option<&str>,
+    ) -> Result<CookieJar, CookieError> {
+        let path = Self::cookies_path(workspace, environment);
+
+        if !self.fs.exists(&path).await {
+            return Ok(CookieJar::new());
+        }
+
+        let content = self
+            .fs
+            .read_file(&path)
+            .await
+            .map_err(|e| CookieError::Io(to_io_error(e)))?;
+
+        let mut jar: CookieJar =
+            from_json_bytes(&content).map_err(|e| CookieError::Serialization(e.to_string()))?;
+        jar.cleanup_expired();
+
+        Ok(jar)
+    }
+
+    async fn save(
+        &self,
+        workspace: &Path,
+        environment: Option<&str>,
+        jar: &CookieJar,
+    ) -> Result<(), CookieError> {
+        let path = Self::cookies_path(workspace, environment);
+
+        if let Some(parent) = path.parent() {
+            self.fs
+                .create_dir_all(parent)
+                .await
+                .map_err(|e| CookieError::Io(to_io_error(e)))?;
+        }
+
+        let content =
+            to_json_stable_bytes(jar).map_err(|e| CookieError::Serialization(e.to_string()))?;
+
+        self.fs
+            .write_file(&path, &content)
+            .await
+            .map_err(|e| CookieError::Io(to_io_error(e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use vortex_domain::cookie::Cookie;
+
+    use super::*;
+    use crate::TokioFileSystem;
+
+    #[test]
+    fn test_cookies_path() {
+        let workspace = PathBuf::from("/test/workspace");
+        assert_eq!(
+            FileCookieRepository::<TokioFileSystem>::cookies_path(&workspace, None),
+            PathBuf::from("/test/workspace/.vortex/cookies.json")
+        );
+        assert_eq!(
+            FileCookieRepository::<TokioFileSystem>::cookies_path(&workspace, Some("My Env")),
+            PathBuf::from("/test/workspace/.vortex/cookies/my-env.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = FileCookieRepository::new(TokioFileSystem);
+
+        assert!(repo.load(dir.path(), None).await.unwrap().is_empty());
+
+        let mut jar = CookieJar::new();
+        jar.add(Cookie::new("session", "abc", "example.com").with_http_only(true));
+        jar.add(
+            Cookie::new("stale", "old", "example.com")
+                .with_expires(Utc::now() + Duration::milliseconds(50)),
+        );
+        repo.save(dir.path(), None, &jar).await.unwrap();
+
+        let mut staging = CookieJar::new();
+        staging.add(Cookie::new("token", "xyz", "staging.example.com"));
+        repo.save(dir.path(), Some("Staging"), &staging)
+            .await
+            .unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let loaded = repo.load(dir.path(), None).await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        let cookie = loaded.all()[0];
+        assert_eq!(cookie.name, "session");
+        assert!(cookie.http_only);
+
+        let loaded = repo.load(dir.path(), Some("Staging")).await.unwrap();
+        assert_eq!(loaded.all()[0].name, "token");
+        assert!(dir.path().join(".vortex/cookies/staging.json").exists());
+    }
+}
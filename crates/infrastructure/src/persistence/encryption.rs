@@ -0,0 +1,240 @@
+//! Passphrase-based encryption for files at rest.
+//!
+//! Contents are encrypted with AES-256-GCM under a key derived from the
+//! passphrase with PBKDF2-HMAC-SHA256. The file keeps a versioned header with
+//! the key derivation parameters next to the nonce and ciphertext:
+//!
+//! ```json
+//! {
+//!   "encryption": {
+//!     "version": 1,
+//!     "cipher": "aes-256-gcm",
+//!     "kdf": "pbkdf2-sha256",
+//!     "iterations": 600000,
+//!     "salt": "..."
+//!   },
+//!   "nonce": "...",
+//!   "ciphertext": "..."
+//! }
+//! ```
+
+use std::num::NonZeroU32;
+use std::ops::RangeInclusive;
+
+use aws_lc_rs::aead::{AES_256_GCM, Aad, Nonce, RandomizedNonceKey};
+use aws_lc_rs::{pbkdf2, rand};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+
+/// Current encryption format version.
+const VERSION: u32 = 1;
+
+const CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "pbkdf2-sha256";
+const SALT_LEN: usize = 16;
+
+/// PBKDF2 iterations for newly encrypted files (OWASP recommendation).
+pub const DEFAULT_ITERATIONS: NonZeroU32 = NonZeroU32::new(600_000).unwrap();
+
+/// PBKDF2 iterations accepted when writing or reading a file.
+pub const ITERATION_RANGE: RangeInclusive<u32> = 1_000..=10_000_000;
+
+/// Error type for encryption operations.
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    /// The file uses a format this version cannot read.
+    #[error("Unsupported encryption format: version {version}, {cipher}, {kdf}")]
+    UnsupportedFormat {
+        /// Format version.
+        version: u32,
+        /// Cipher name.
+        cipher: String,
+        /// Key derivation function name.
+        kdf: String,
+    },
+
+    /// The passphrase is wrong or the file was modified.
+    #[error("Wrong passphrase or corrupted file")]
+    Decryption,
+
+    /// The contents could not be encrypted.
+    #[error("Encryption failed")]
+    Encryption,
+
+    /// The file asks for a PBKDF2 iteration count outside [`ITERATION_RANGE`].
+    #[error("Unsupported PBKDF2 iteration count: {0}")]
+    InvalidIterations(u32),
+}
+
+/// Key derivation parameters stored in front of the ciphertext.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptionHeader {
+    version: u32,
+    cipher: String,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+}
+
+/// An encrypted file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncryptedFile {
+    encryption: EncryptionHeader,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedFile {
+    /// Encrypts `plaintext` with a fresh salt and nonce.
+    ///
+    /// `purpose` is authenticated with the ciphertext, so a file encrypted
+    /// for one purpose cannot be passed off as another.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EncryptionError::InvalidIterations` if `iterations` is out of
+    /// range, and `EncryptionError::Encryption` if encryption fails.
+    pub fn seal(
+        plaintext: &[u8],
+        passphrase: &str,
+        iterations: NonZeroU32,
+        purpose: &str,
+    ) -> Result<Self, EncryptionError> {
+        let iterations = checked_iterations(iterations.get())?;
+        let mut salt = [0; SALT_LEN];
+        rand::fill(&mut salt).map_err(|_| EncryptionError::Encryption)?;
+        let key = derive_key(passphrase, iterations, &salt)?;
+
+        let mut in_out = plaintext.to_vec();
+        let nonce = key
+            .seal_in_place_append_tag(Aad::from(aad(purpose)), &mut in_out)
+            .map_err(|_| EncryptionError::Encryption)?;
+
+        Ok(Self {
+            encryption: EncryptionHeader {
+                version: VERSION,
+                cipher: CIPHER.to_string(),
+                kdf: KDF.to_string(),
+                iterations: iterations.get(),
+                salt: STANDARD.encode(salt),
+            },
+            nonce: STANDARD.encode(nonce.as_ref()),
+            ciphertext: STANDARD.encode(in_out),
+        })
+    }
+
+    /// Decrypts the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EncryptionError::Decryption` if the passphrase or purpose is
+    /// wrong or the file was modified, `EncryptionError::UnsupportedFormat`
+    /// for files written by a newer version, and
+    /// `EncryptionError::InvalidIterations` if the iteration count is out of
+    /// range.
+    pub fn open(&self, passphrase: &str, purpose: &str) -> Result<Vec<u8>, EncryptionError> {
+        let header = &self.encryption;
+        if header.version != VERSION || header.cipher != CIPHER || header.kdf != KDF {
+            return Err(EncryptionError::UnsupportedFormat {
+                version: header.version,
+                cipher: header.cipher.clone(),
+                kdf: header.kdf.clone(),
+            });
+        }
+
+        let iterations = checked_iterations(header.iterations)?;
+        let key = derive_key(passphrase, iterations, &decode(&header.salt)?)?;
+        let nonce = Nonce::try_assume_unique_for_key(&decode(&self.nonce)?)
+            .map_err(|_| EncryptionError::Decryption)?;
+
+        let mut in_out = decode(&self.ciphertext)?;
+        let plaintext = key
+            .open_in_place(nonce, Aad::from(aad(purpose)), &mut in_out)
+            .map_err(|_| EncryptionError::Decryption)?;
+        Ok(plaintext.to_vec())
+    }
+
+    /// Returns true if the JSON document is an encrypted file.
+    #[must_use]
+    pub fn is_encrypted(json: &[u8]) -> bool {
+        serde_json::from_slice::<serde_json::Value>(json).is_ok_and(|value| {
+            value
+                .get("encryption")
+                .is_some_and(serde_json::Value::is_object)
+        })
+    }
+}
+
+/// Checks an iteration count against [`ITERATION_RANGE`].
+///
+/// A tampered file could otherwise make key derivation run for hours.
+fn checked_iterations(iterations: u32) -> Result<NonZeroU32, EncryptionError> {
+    NonZeroU32::new(iterations)
+        .filter(|_| ITERATION_RANGE.contains(&iterations))
+        .ok_or(EncryptionError::InvalidIterations(iterations))
+}
+
+fn derive_key(
+    passphrase: &str,
+    iterations: NonZeroU32,
+    salt: &[u8],
+) -> Result<RandomizedNonceKey, EncryptionError> {
+    let mut key = [0; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    RandomizedNonceKey::new(&AES_256_GCM, &key).map_err(|_| EncryptionError::Encryption)
+}
+
+fn aad(purpose: &str) -> String {
+    format!("vortex:{purpose}:v{VERSION}")
+}
+
+fn decode(value: &str) -> Result<Vec<u8>, EncryptionError> {
+    STANDARD
+        .decode(value)
+        .map_err(|_| EncryptionError::Decryption)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: NonZeroU32 = NonZeroU32::new(1_000).unwrap();
+
+    #[test]
+    fn test_seal_and_open() {
+        let file = EncryptedFile::seal(b"top secret", "pass", ITERATIONS, "test").unwrap();
+        let json = serde_json::to_vec(&file).unwrap();
+        assert!(EncryptedFile::is_encrypted(&json));
+        assert!(!EncryptedFile::is_encrypted(br#"{"secrets":{}}"#));
+        assert!(!String::from_utf8_lossy(&json).contains("top secret"));
+
+        let file: EncryptedFile = serde_json::from_slice(&json).unwrap();
+        assert_eq!(file.open("pass", "test").unwrap(), b"top secret");
+        assert!(matches!(
+            file.open("wrong", "test"),
+            Err(EncryptionError::Decryption)
+        ));
+        assert!(matches!(
+            file.open("pass", "other"),
+            Err(EncryptionError::Decryption)
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_format() {
+        let mut file = EncryptedFile::seal(b"data", "pass", ITERATIONS, "test").unwrap();
+        file.encryption.version = 2;
+        assert!(matches!(
+            file.open("pass", "test"),
+            Err(EncryptionError::UnsupportedFormat { version: 2, .. })
+        ));
+    }
+}
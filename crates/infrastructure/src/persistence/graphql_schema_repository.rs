@@ -0,0 +1,178 @@
+//! File-based GraphQL schema cache implementation.
+//!
+//! Introspection results are stored in `.vortex/graphql/<endpoint>.json`,
+//! one file per endpoint, as received from the server.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use serde_json::Value as JsonValue;
+use vortex_application::ports::{
+    FileSystem, FileSystemError, GraphqlSchemaError, GraphqlSchemaRepository, slugify,
+};
+use vortex_domain::graphql::GraphqlSchema;
+
+use crate::serialization::{from_json_bytes, to_json_stable_bytes};
+
+/// Converts `FileSystemError` to `std::io::Error` for `GraphqlSchemaError`.
+fn to_io_error(e: FileSystemError) -> std::io::Error {
+    match e {
+        FileSystemError::Io(io_err) => io_err,
+        FileSystemError::NotFound(path) => {
+            std::io::Error::new(std::io::ErrorKind::NotFound, path.display().to_string())
+        }
+        FileSystemError::PermissionDenied(path) => std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            path.display().to_string(),
+        ),
+        FileSystemError::AlreadyExists(path) => std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            path.display().to_string(),
+        ),
+        _ => std::io::Error::other(e.to_string()),
+    }
+}
+
+/// File-based GraphQL schema cache.
+///
+/// Stores introspection results as:
+/// ```text
+/// workspace/
+///   .vortex/
+///     graphql/
+///       https-api-example-com-graphql.json
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileGraphqlSchemaRepository<F> {
+    fs: F,
+}
+
+impl<F: FileSystem> FileGraphqlSchemaRepository<F> {
+    /// Creates a new file-based GraphQL schema cache.
+    pub const fn new(fs: F) -> Self {
+        Self { fs }
+    }
+
+    /// Returns the cache file path for an endpoint.
+    ///
+    /// The query string and fragment are not part of the endpoint.
+    fn schema_path(workspace: &Path, endpoint: &str) -> PathBuf {
+        let endpoint = endpoint.split(['?', '#']).next().unwrap_or_default();
+        workspace
+            .join(".vortex")
+            .join("graphql")
+            .join(format!("{}.json", slugify(endpoint)))
+    }
+}
+
+#[async_trait]
+impl<F: FileSystem + Sync> GraphqlSchemaRepository for FileGraphqlSchemaRepository<F> {
+    async fn load(
+        &self,
+        workspace: &Path,
+        endpoint: &str,
+    ) -> Result<Option<GraphqlSchema>, GraphqlSchemaError> {
+        let path = Self::schema_path(workspace, endpoint);
+
+        if !self.fs.exists(&path).await {
+            return Ok(None);
+        }
+
+        let content = self
+            .fs
+            .read_file(&path)
+            .await
+            .map_err(|e| GraphqlSchemaError::Io(to_io_error(e)))?;
+
+        let introspection: JsonValue = from_json_bytes(&content)
+            .map_err(|e| GraphqlSchemaError::Serialization(e.to_string()))?;
+        let schema = GraphqlSchema::from_introspection(&introspection)
+            .map_err(|e| GraphqlSchemaError::Serialization(e.to_string()))?;
+
+        Ok(Some(schema))
+    }
+
+    async fn save(
+        &self,
+        workspace: &Path,
+        endpoint: &str,
+        introspection: &str,
+    ) -> Result<(), GraphqlSchemaError> {
+        let path = Self::schema_path(workspace, endpoint);
+
+        let introspection: JsonValue = serde_json::from_str(introspection)
+            .map_err(|e| GraphqlSchemaError::Serialization(e.to_string()))?;
+        let content = to_json_stable_bytes(&introspection)
+            .map_err(|e| GraphqlSchemaError::Serialization(e.to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            self.fs
+                .create_dir_all(parent)
+                .await
+                .map_err(|e| GraphqlSchemaError::Io(to_io_error(e)))?;
+        }
+
+        self.fs
+            .write_file(&path, &content)
+            .await
+            .map_err(|e| GraphqlSchemaError::Io(to_io_error(e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::TokioFileSystem;
+
+    const INTROSPECTION: &str = r#"{"data": {"__schema": {
+        "queryType": {"name": "Query"},
+        "types": [
+            {"kind": "OBJECT", "name": "Query", "fields": [
+                {"name": "me", "args": [], "type": {"kind": "SCALAR", "name": "String"}}
+            ]},
+            {"kind": "SCALAR", "name": "String"}
+        ]
+    }}}"#;
+
+    #[test]
+    fn test_schema_path() {
+        let workspace = PathBuf::from("/test/workspace");
+        assert_eq!(
+            FileGraphqlSchemaRepository::<TokioFileSystem>::schema_path(
+                &workspace,
+                "https://api.example.com/graphql?debug=1"
+            ),
+            PathBuf::from("/test/workspace/.vortex/graphql/https-api-example-com-graphql.json")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = FileGraphqlSchemaRepository::new(TokioFileSystem);
+        let endpoint = "https://api.example.com/graphql";
+
+        assert!(repo.load(dir.path(), endpoint).await.unwrap().is_none());
+
+        repo.save(dir.path(), endpoint, INTROSPECTION)
+            .await
+            .unwrap();
+        let schema = repo.load(dir.path(), endpoint).await.unwrap().unwrap();
+        assert_eq!(schema.type_count(), 2);
+        assert!(schema.get_type("Query").unwrap().fields.contains_key("me"));
+
+        assert!(
+            repo.load(dir.path(), "https://other.example.com/graphql")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(matches!(
+            repo.save(dir.path(), endpoint, "<html>").await,
+            Err(GraphqlSchemaError::Serialization(_))
+        ));
+    }
+}
@@ -1,17 +1,25 @@
 //! Persistence implementations for file-based storage.
 
 mod collection_repository;
+mod cookie_repository;
+mod encryption;
 mod environment_repository;
 mod file_system;
+mod graphql_schema_repository;
 mod history_repository;
 mod secrets_repository;
 mod settings_repository;
+mod token_cache_repository;
 mod workspace_repository;
 
 pub use collection_repository::*;
+pub use cookie_repository::*;
+pub use encryption::EncryptionError;
 pub use environment_repository::*;
 pub use file_system::*;
+pub use graphql_schema_repository::*;
 pub use history_repository::*;
 pub use secrets_repository::*;
 pub use settings_repository::*;
+pub use token_cache_repository::*;
 pub use workspace_repository::*;
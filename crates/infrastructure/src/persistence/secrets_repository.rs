@@ -2,15 +2,25 @@
 //!
 //! Secrets are stored in `.vortex/secrets.json` within the workspace.
 //! This file should be added to `.gitignore` to prevent accidental commits.
+//!
+//! With a passphrase, the file is encrypted at rest (see [`EncryptedFile`]).
+//! Plaintext files are still read, and are encrypted on the next save or by
+//! [`FileSecretsRepository::migrate`].
 
+use std::fmt;
+use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use vortex_application::ports::{FileSystem, FileSystemError, SecretsError, SecretsRepository};
 use vortex_domain::environment::SecretsStore;
 
+use super::encryption::{DEFAULT_ITERATIONS, EncryptedFile, EncryptionError};
 use crate::serialization::{from_json_bytes, to_json_stable_bytes};
 
+/// Authenticated with the ciphertext to tell secrets files from other files.
+const PURPOSE: &str = "secrets";
+
 /// Converts `FileSystemError` to `std::io::Error` for `SecretsError`.
 fn to_io_error(e: FileSystemError) -> std::io::Error {
     match e {
@@ -47,38 +57,129 @@ fn to_io_error(e: FileSystemError) -> std::io::Error {
 ///   }
 /// }
 /// ```
-#[derive(Debug, Clone)]
+///
+/// With a passphrase, the same document is stored encrypted.
+#[derive(Clone)]
 pub struct FileSecretsRepository<F> {
     fs: F,
+    passphrase: Option<String>,
+    iterations: NonZeroU32,
+}
+
+impl<F: fmt::Debug> fmt::Debug for FileSecretsRepository<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileSecretsRepository")
+            .field("fs", &self.fs)
+            .field("encrypted", &self.passphrase.is_some())
+            .finish_non_exhaustive()
+    }
 }
 
 impl<F: FileSystem> FileSecretsRepository<F> {
     /// Creates a new file-based secrets repository.
     pub const fn new(fs: F) -> Self {
-        Self { fs }
+        Self {
+            fs,
+            passphrase: None,
+            iterations: DEFAULT_ITERATIONS,
+        }
+    }
+
+    /// Encrypts the secrets file with a key derived from this passphrase.
+    #[must_use]
+    pub fn with_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+        self.passphrase = Some(passphrase.into());
+        self
+    }
+
+    /// Sets the PBKDF2 iterations used when saving.
+    ///
+    /// Existing files are always read with the iterations they were written with.
+    #[must_use]
+    pub const fn with_iterations(mut self, iterations: NonZeroU32) -> Self {
+        self.iterations = iterations;
+        self
     }
 
     /// Returns the secrets file path for a workspace.
     fn secrets_path(workspace: &Path) -> PathBuf {
         workspace.join(".vortex").join("secrets.json")
     }
+
+    /// Returns true if the workspace has an encrypted secrets file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read.
+    pub async fn is_encrypted(&self, workspace: &Path) -> Result<bool, SecretsError> {
+        Ok(self
+            .read(workspace)
+            .await?
+            .is_some_and(|content| EncryptedFile::is_encrypted(&content)))
+    }
+
+    /// Encrypts a plaintext secrets file with the passphrase.
+    ///
+    /// Returns false if there is no secrets file or it is already encrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SecretsError::PassphraseRequired` without a passphrase, or an
+    /// error if the file cannot be read or written.
+    pub async fn migrate(&self, workspace: &Path) -> Result<bool, SecretsError> {
+        if self.passphrase.is_none() {
+            return Err(SecretsError::PassphraseRequired);
+        }
+        match self.read(workspace).await? {
+            Some(content) if !EncryptedFile::is_encrypted(&content) => {
+                let store = from_json_bytes(&content)
+                    .map_err(|e| SecretsError::Serialization(e.to_string()))?;
+                self.save(workspace, &store).await?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Reads the raw secrets file, if it exists.
+    async fn read(&self, workspace: &Path) -> Result<Option<Vec<u8>>, SecretsError> {
+        let path = Self::secrets_path(workspace);
+        if !self.fs.exists(&path).await {
+            return Ok(None);
+        }
+        self.fs
+            .read_file(&path)
+            .await
+            .map(Some)
+            .map_err(|e| SecretsError::Io(to_io_error(e)))
+    }
+}
+
+/// Converts encryption failures, telling a wrong passphrase apart.
+fn to_secrets_error(error: EncryptionError) -> SecretsError {
+    match error {
+        EncryptionError::Decryption => SecretsError::InvalidPassphrase,
+        other => SecretsError::Encryption(other.to_string()),
+    }
 }
 
 #[async_trait]
 impl<F: FileSystem + Sync> SecretsRepository for FileSecretsRepository<F> {
     async fn load(&self, workspace: &Path) -> Result<SecretsStore, SecretsError> {
-        let path = Self::secrets_path(workspace);
-
-        if !self.fs.exists(&path).await {
+        let Some(mut content) = self.read(workspace).await? else {
             // Return empty store if file doesn't exist
             return Ok(SecretsStore::new());
-        }
+        };
 
-        let content = self
-            .fs
-            .read_file(&path)
-            .await
-            .map_err(|e| SecretsError::Io(to_io_error(e)))?;
+        if EncryptedFile::is_encrypted(&content) {
+            let passphrase = self
+                .passphrase
+                .as_deref()
+                .ok_or(SecretsError::PassphraseRequired)?;
+            let file: EncryptedFile = from_json_bytes(&content)
+                .map_err(|e| SecretsError::Serialization(e.to_string()))?;
+            content = file.open(passphrase, PURPOSE).map_err(to_secrets_error)?;
+        }
 
         let store: SecretsStore =
             from_json_bytes(&content).map_err(|e| SecretsError::Serialization(e.to_string()))?;
@@ -90,15 +191,25 @@ impl<F: FileSystem + Sync> SecretsRepository for FileSecretsRepository<F> {
         let vortex_dir = workspace.join(".vortex");
         let path = Self::secrets_path(workspace);
 
+        let mut content = to_json_stable_bytes(secrets)
+            .map_err(|e| SecretsError::Serialization(e.to_string()))?;
+
+        if let Some(passphrase) = &self.passphrase {
+            let file = EncryptedFile::seal(&content, passphrase, self.iterations, PURPOSE)
+                .map_err(to_secrets_error)?;
+            content = to_json_stable_bytes(&file)
+                .map_err(|e| SecretsError::Serialization(e.to_string()))?;
+        } else if self.is_encrypted(workspace).await? {
+            // Never silently replace an encrypted file with plaintext
+            return Err(SecretsError::PassphraseRequired);
+        }
+
         // Ensure .vortex directory exists
         self.fs
             .create_dir_all(&vortex_dir)
             .await
             .map_err(|e| SecretsError::Io(to_io_error(e)))?;
 
-        let content = to_json_stable_bytes(secrets)
-            .map_err(|e| SecretsError::Serialization(e.to_string()))?;
-
         self.fs
             .write_file(&path, &content)
             .await
@@ -109,6 +220,7 @@ impl<F: FileSystem + Sync> SecretsRepository for FileSecretsRepository<F> {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
     use crate::TokioFileSystem;
@@ -119,4 +231,112 @@ mod tests {
         let path = FileSecretsRepository::<TokioFileSystem>::secrets_path(&workspace);
         assert_eq!(path, PathBuf::from("/test/workspace/.vortex/secrets.json"));
     }
+
+    fn repository() -> FileSecretsRepository<TokioFileSystem> {
+        FileSecretsRepository::new(TokioFileSystem::new())
+            .with_iterations(NonZeroU32::new(1_000).unwrap())
+    }
+
+    fn store() -> SecretsStore {
+        let mut store = SecretsStore::new();
+        store.set_secret("production", "api_key", "sk-prod-456");
+        store
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let encrypted = repository().with_passphrase("hunter2");
+        encrypted.save(dir.path(), &store()).await.unwrap();
+
+        let content = std::fs::read_to_string(dir.path().join(".vortex/secrets.json")).unwrap();
+        assert!(content.contains("\"encryption\""));
+        assert!(!content.contains("sk-prod-456"));
+        assert!(encrypted.is_encrypted(dir.path()).await.unwrap());
+
+        let loaded = encrypted.load(dir.path()).await.unwrap();
+        assert_eq!(
+            loaded.get_secret("production", "api_key"),
+            Some("sk-prod-456")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wrong_or_missing_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        repository()
+            .with_passphrase("hunter2")
+            .save(dir.path(), &store())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            repository().with_passphrase("wrong").load(dir.path()).await,
+            Err(SecretsError::InvalidPassphrase)
+        ));
+        assert!(matches!(
+            repository().load(dir.path()).await,
+            Err(SecretsError::PassphraseRequired)
+        ));
+        // Saving without the passphrase must not downgrade the file
+        assert!(matches!(
+            repository().save(dir.path(), &SecretsStore::new()).await,
+            Err(SecretsError::PassphraseRequired)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_out_of_range_iterations() {
+        let dir = tempfile::tempdir().unwrap();
+        let encrypted = repository().with_passphrase("hunter2");
+        encrypted.save(dir.path(), &store()).await.unwrap();
+
+        let path = dir.path().join(".vortex/secrets.json");
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(
+            &path,
+            content.replace("\"iterations\": 1000", "\"iterations\": 4294967295"),
+        )
+        .unwrap();
+        assert!(matches!(
+            encrypted.load(dir.path()).await,
+            Err(SecretsError::Encryption(_))
+        ));
+
+        // Files that could not be read back are never written
+        let too_few = repository()
+            .with_iterations(NonZeroU32::new(1).unwrap())
+            .with_passphrase("hunter2");
+        assert!(matches!(
+            too_few.save(dir.path(), &store()).await,
+            Err(SecretsError::Encryption(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_migrate_plaintext_file() {
+        let dir = tempfile::tempdir().unwrap();
+        repository().save(dir.path(), &store()).await.unwrap();
+
+        let encrypted = repository().with_passphrase("hunter2");
+        assert!(!encrypted.is_encrypted(dir.path()).await.unwrap());
+        // Plaintext files stay readable before the migration
+        assert!(encrypted.load(dir.path()).await.is_ok());
+
+        assert!(matches!(
+            repository().migrate(dir.path()).await,
+            Err(SecretsError::PassphraseRequired)
+        ));
+        assert!(encrypted.migrate(dir.path()).await.unwrap());
+        assert!(!encrypted.migrate(dir.path()).await.unwrap());
+        assert!(encrypted.is_encrypted(dir.path()).await.unwrap());
+        assert_eq!(
+            encrypted
+                .load(dir.path())
+                .await
+                .unwrap()
+                .get_secret("production", "api_key"),
+            Some("sk-prod-456")
+        );
+    }
 }
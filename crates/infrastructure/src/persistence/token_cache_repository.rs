@@ -0,0 +1,341 @@
+//! Encrypted `OAuth2` token cache.
+//!
+//! Persists tokens across restarts in the platform-specific config directory:
+//! - Linux/macOS: ~/.config/vortex/tokens.json
+//! - Windows: %APPDATA%/vortex/tokens.json
+//!
+//! The tokens are encrypted with a key derived from a user passphrase; see
+//! [`EncryptedFile`] for the format.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
+
+use tokio::fs;
+use vortex_application::TokenStore;
+use vortex_domain::OAuth2Token;
+
+use super::encryption::{DEFAULT_ITERATIONS, EncryptedFile, EncryptionError};
+use crate::serialization::{SerializationError, from_json_bytes, to_json_stable_bytes};
+
+/// Authenticated with the ciphertext to tell token caches from other files.
+const PURPOSE: &str = "token-cache";
+
+/// Error type for token cache operations.
+#[derive(Debug, thiserror::Error)]
+pub enum TokenCacheError {
+    /// IO error during file operations.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Serialization/deserialization error.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] SerializationError),
+
+    /// Could not determine config directory.
+    #[error("Could not determine config directory")]
+    NoConfigDir,
+
+    /// The cache could not be encrypted or decrypted.
+    #[error("Could not unlock the token cache: {0}")]
+    Encryption(#[from] EncryptionError),
+}
+
+/// Repository for the encrypted token cache.
+///
+/// Tokens are keyed by [`AuthConfig::cache_key`](vortex_domain::AuthConfig::cache_key),
+/// like in the [`TokenStore`] they are loaded into.
+#[derive(Clone)]
+pub struct TokenCacheRepository {
+    path: PathBuf,
+    passphrase: String,
+    iterations: NonZeroU32,
+}
+
+impl fmt::Debug for TokenCacheRepository {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenCacheRepository")
+            .field("path", &self.path)
+            .field("iterations", &self.iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenCacheRepository {
+    /// Creates a token cache in the Vortex config directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TokenCacheError::NoConfigDir` if the config directory cannot
+    /// be determined.
+    pub fn new(passphrase: impl Into<String>) -> Result<Self, TokenCacheError> {
+        let path = Self::default_path().ok_or(TokenCacheError::NoConfigDir)?;
+        Ok(Self::at(path, passphrase))
+    }
+
+    /// Creates a token cache stored at the given path.
+    #[must_use]
+    pub fn at(path: impl Into<PathBuf>, passphrase: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            passphrase: passphrase.into(),
+            iterations: DEFAULT_ITERATIONS,
+        }
+    }
+
+    /// Sets the PBKDF2 iterations used when saving.
+    ///
+    /// Existing caches are always read with the iterations they were written with.
+    #[must_use]
+    pub const fn with_iterations(mut self, iterations: NonZeroU32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Returns the default path of the token cache, if available.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("vortex").join("tokens.json"))
+    }
+
+    /// Returns the path of this token cache.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the cached tokens.
+    ///
+    /// Returns no tokens if the file doesn't exist. Tokens that have expired
+    /// and cannot be refreshed are dropped.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn load(&self) -> Result<BTreeMap<String, OAuth2Token>, TokenCacheError> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let file: EncryptedFile = from_json_bytes(&fs::read(&self.path).await?)?;
+        let plaintext = file.open(&self.passphrase, PURPOSE)?;
+        let mut tokens: BTreeMap<String, OAuth2Token> = from_json_bytes(&plaintext)?;
+        tokens.retain(|_, token| is_usable(token));
+        Ok(tokens)
+    }
+
+    /// Encrypts and saves the tokens, replacing the cache.
+    ///
+    /// Tokens that have expired and cannot be refreshed are not written.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn save(
+        &self,
+        tokens: &BTreeMap<String, OAuth2Token>,
+    ) -> Result<(), TokenCacheError> {
+        let usable: BTreeMap<_, _> = tokens
+            .iter()
+            .filter(|(_, token)| is_usable(token))
+            .collect();
+
+        let file = EncryptedFile::seal(
+            &to_json_stable_bytes(&usable)?,
+            &self.passphrase,
+            self.iterations,
+            PURPOSE,
+        )?;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&self.path, to_json_stable_bytes(&file)?).await?;
+        restrict_permissions(&self.path).await?;
+        Ok(())
+    }
+
+    /// Loads the cached tokens into a token store, returning how many were loaded.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn load_into(&self, store: &TokenStore) -> Result<usize, TokenCacheError> {
+        let tokens = self.load().await?;
+        let count = tokens.len();
+        for (key, token) in tokens {
+            store.store(key, token).await;
+        }
+        Ok(count)
+    }
+
+    /// Saves all tokens of a token store, replacing the cache.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn save_from(&self, store: &TokenStore) -> Result<(), TokenCacheError> {
+        self.save(&store.snapshot().await.into_iter().collect())
+            .await
+    }
+
+    /// Removes a single token from the cache, returning it if it was cached.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn revoke(&self, key: &str) -> Result<Option<OAuth2Token>, TokenCacheError> {
+        let mut tokens = self.load().await?;
+        let removed = tokens.remove(key);
+        if removed.is_some() {
+            self.save(&tokens).await?;
+        }
+        Ok(removed)
+    }
+
+    /// Deletes the cache file. This does not need the passphrase.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn clear(&self) -> Result<(), TokenCacheError> {
+        match fs::remove_file(&self.path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns true if the token is still valid or can be refreshed.
+fn is_usable(token: &OAuth2Token) -> bool {
+    !token.is_expired_or_expiring(0) || token.can_refresh()
+}
+
+/// Makes the cache readable by the current user only.
+#[cfg(unix)]
+async fn restrict_permissions(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).await
+}
+
+#[cfg(not(unix))]
+#[allow(clippy::unused_async)]
+async fn restrict_permissions(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::*;
+
+    fn cache(dir: &Path, passphrase: &str) -> TokenCacheRepository {
+        TokenCacheRepository::at(dir.join("tokens.json"), passphrase)
+            .with_iterations(NonZeroU32::new(1_000).unwrap())
+    }
+
+    fn token(
+        access_token: &str,
+        expires_in: Option<u64>,
+        refresh_token: Option<&str>,
+    ) -> OAuth2Token {
+        OAuth2Token::new(
+            access_token.to_string(),
+            "Bearer".to_string(),
+            expires_in,
+            refresh_token.map(str::to_string),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn default_path_is_valid() {
+        if let Some(p) = TokenCacheRepository::default_path() {
+            assert!(p.ends_with("vortex/tokens.json"));
+        }
+    }
+
+    #[tokio::test]
+    async fn save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = cache(dir.path(), "correct horse");
+        assert!(repo.load().await.unwrap().is_empty());
+
+        let store = TokenStore::new();
+        store
+            .store("cc:a".to_string(), token("secret-access", Some(3600), None))
+            .await;
+        repo.save_from(&store).await.unwrap();
+
+        let content = std::fs::read_to_string(repo.path()).unwrap();
+        assert!(!content.contains("secret-access"));
+        assert!(content.contains("\"iterations\": 1000"));
+
+        let restored = TokenStore::new();
+        assert_eq!(repo.load_into(&restored).await.unwrap(), 1);
+        assert_eq!(
+            restored.get_valid("cc:a").await.unwrap().access_token,
+            "secret-access"
+        );
+
+        // The default iterations apply to writing only
+        let reader = TokenCacheRepository::at(repo.path(), "correct horse");
+        assert_eq!(reader.load().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn wrong_passphrase_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut tokens = BTreeMap::new();
+        tokens.insert("cc:a".to_string(), token("a", None, None));
+        cache(dir.path(), "right").save(&tokens).await.unwrap();
+
+        assert!(matches!(
+            cache(dir.path(), "wrong").load().await,
+            Err(TokenCacheError::Encryption(EncryptionError::Decryption))
+        ));
+    }
+
+    #[tokio::test]
+    async fn out_of_range_iterations_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = cache(dir.path(), "pass");
+        let mut tokens = BTreeMap::new();
+        tokens.insert("cc:a".to_string(), token("a", None, None));
+        repo.save(&tokens).await.unwrap();
+
+        let content = std::fs::read_to_string(repo.path()).unwrap();
+        let tampered = content.replace("\"iterations\": 1000", "\"iterations\": 4294967295");
+        std::fs::write(repo.path(), tampered).unwrap();
+
+        assert!(matches!(
+            repo.load().await,
+            Err(TokenCacheError::Encryption(
+                EncryptionError::InvalidIterations(u32::MAX)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn expired_tokens_without_refresh_are_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = cache(dir.path(), "pass");
+
+        let mut expired = token("old", Some(60), None);
+        expired.expires_at = Some(Utc::now() - Duration::seconds(10));
+        let mut refreshable = token("stale", Some(60), Some("refresh"));
+        refreshable.expires_at = expired.expires_at;
+
+        let mut tokens = BTreeMap::new();
+        tokens.insert("expired".to_string(), expired);
+        tokens.insert("refreshable".to_string(), refreshable);
+        tokens.insert("valid".to_string(), token("new", Some(3600), None));
+        repo.save(&tokens).await.unwrap();
+
+        let loaded = repo.load().await.unwrap();
+        assert_eq!(loaded.keys().collect::<Vec<_>>(), ["refreshable", "valid"]);
+    }
+
+    #[tokio::test]
+    async fn revoke_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = cache(dir.path(), "pass");
+        let mut tokens = BTreeMap::new();
+        tokens.insert("a".to_string(), token("a", None, None));
+        tokens.insert("b".to_string(), token("b", None, None));
+        repo.save(&tokens).await.unwrap();
+
+        assert_eq!(repo.revoke("a").await.unwrap().unwrap().access_token, "a");
+        assert!(repo.revoke("a").await.unwrap().is_none());
+        assert_eq!(repo.load().await.unwrap().keys().collect::<Vec<_>>(), ["b"]);
+
+        cache(dir.path(), "").clear().await.unwrap();
+        assert!(!repo.path().exists());
+        repo.clear().await.unwrap();
+    }
+}
@@ -0,0 +1,395 @@
+//! HTML report writer.
+//!
+//! Produces a single self-contained page (inline CSS, no scripts or external
+//! assets) that can be opened offline. Request and response details use
+//! native `<details>` elements so they collapse without JavaScript.
+//!
+//! Every value that may carry a secret is passed through [`RunReport::mask`],
+//! and credential headers are always masked.
+
+use std::fmt::Write;
+
+use vortex_domain::report::{RequestRunResult, RunReport, SECRET_MASK};
+use vortex_domain::testing::AssertionResult;
+
+use super::escape;
+
+/// Bodies longer than this are truncated in the page.
+const MAX_BODY_DISPLAY: usize = 256 * 1024;
+
+/// Headers whose values are credentials and are masked regardless of secrets.
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "proxy-authorization"];
+
+const STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Roboto, sans-serif; margin: 0; padding: 24px; background: #f6f7f9; color: #1f2328; }
+section.run { max-width: 1100px; margin: 0 auto 40px; }
+h1 { margin: 0 0 4px; font-size: 24px; }
+.meta { color: #59636e; margin: 0 0 16px; font-size: 13px; }
+.cards { display: flex; flex-wrap: wrap; gap: 12px; margin-bottom: 12px; }
+.card { background: #fff; border: 1px solid #d1d9e0; border-radius: 6px; padding: 10px 16px; min-width: 120px; }
+.card .value { font-size: 22px; font-weight: 600; }
+.card .label { font-size: 12px; color: #59636e; }
+.bar { height: 8px; background: #cf222e; border-radius: 4px; overflow: hidden; margin-bottom: 20px; }
+.bar > div { height: 100%; background: #1a7f37; }
+details.request { background: #fff; border: 1px solid #d1d9e0; border-left-width: 4px; border-radius: 6px; margin-bottom: 8px; }
+details.request.passed { border-left-color: #1a7f37; }
+details.request.failed { border-left-color: #cf222e; }
+details.request.errored { border-left-color: #9a6700; }
+details.request.skipped { border-left-color: #59636e; }
+details.request > summary { cursor: pointer; padding: 8px 12px; display: flex; gap: 12px; align-items: baseline; }
+.mark { font-weight: 700; }
+.passed .mark { color: #1a7f37; }
+.failed .mark, .errored .mark { color: #cf222e; }
+.skipped .mark, p.skipped { color: #59636e; }
+.method { font-family: monospace; font-weight: 600; min-width: 60px; }
+.name { flex: 1; }
+.status, .time { font-family: monospace; color: #59636e; }
+.content { padding: 0 12px 12px; }
+.url { font-family: monospace; word-break: break-all; }
+.error { color: #9a6700; font-weight: 600; }
+table { border-collapse: collapse; width: 100%; font-size: 13px; margin: 8px 0; }
+td, th { border-bottom: 1px solid #eef1f4; padding: 4px 8px; text-align: left; vertical-align: top; }
+tr.fail td { background: #ffebe9; }
+.diff { font-family: monospace; white-space: pre-wrap; word-break: break-all; }
+.diff .expected { color: #1a7f37; }
+.diff .actual { color: #cf222e; }
+details.part { margin-top: 8px; }
+details.part > summary { cursor: pointer; color: #0969da; }
+pre { background: #f6f8fa; padding: 8px; overflow: auto; max-height: 400px; font-size: 12px; white-space: pre-wrap; word-break: break-all; }
+footer { color: #59636e; font-size: 12px; text-align: center; }
+";
+
+/// HTML report writer.
+pub struct HtmlReportWriter;
+
+impl HtmlReportWriter {
+    /// Renders the given runs as a self-contained HTML page.
+    #[must_use]
+    pub fn write(reports: &[RunReport]) -> String {
+        let title = reports
+            .iter()
+            .map(|r| r.collection_name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n");
+        html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+        let _ = writeln!(html, "<title>Vortex run report: {}</title>", escape(&title));
+        let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+
+        for report in reports {
+            Self::write_run(&mut html, report);
+        }
+
+        let _ = writeln!(
+            html,
+            "<footer>Generated by Vortex {}</footer>\n</body>\n</html>",
+            escape(env!("CARGO_PKG_VERSION"))
+        );
+        html
+    }
+
+    fn write_run(html: &mut String, report: &RunReport) {
+        let summary = &report.summary;
+        let pass_rate = report.test_results().pass_rate();
+
+        html.push_str("<section class=\"run\">\n");
+        let _ = writeln!(html, "<h1>{}</h1>", escape(&report.collection_name));
+
+        let mut meta = vec![format!(
+            "Started {}",
+            report.started_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )];
+        if let Some(environment) = &report.environment {
+            meta.insert(0, format!("Environment: {environment}"));
+        }
+        meta.push(format!("Duration {} ms", summary.duration_ms));
+        let _ = writeln!(html, "<p class=\"meta\">{}</p>", escape(&meta.join(" · ")));
+
+        html.push_str("<div class=\"cards\">\n");
+        let cards = [
+            (format!("{pass_rate:.1}%"), "Assertion pass rate"),
+            (
+                format!("{}/{}", summary.passed_requests, summary.total_requests),
+                "Requests passed",
+            ),
+            (summary.failed_requests.to_string(), "Requests failed"),
+            (summary.errored_requests.to_string(), "Requests errored"),
+            (summary.skipped_requests.to_string(), "Requests skipped"),
+            (
+                format!("{}/{}", summary.passed_assertions, summary.total_assertions),
+                "Assertions passed",
+            ),
+        ];
+        for (value, label) in cards {
+            let _ = writeln!(
+                html,
+                "<div class=\"card\"><div class=\"value\">{}</div><div class=\"label\">{label}</div></div>",
+                escape(&value)
+            );
+        }
+        html.push_str("</div>\n");
+        let _ = writeln!(
+            html,
+            "<div class=\"bar\"><div style=\"width: {pass_rate:.1}%\"></div></div>"
+        );
+
+        for result in &report.results {
+            Self::write_request(html, report, result);
+        }
+
+        html.push_str("</section>\n");
+    }
+
+    fn write_request(html: &mut String, report: &RunReport, result: &RequestRunResult) {
+        let (class, mark) = if result.has_request_error() {
+            ("errored", "✗")
+        } else if result.is_skipped() {
+            ("skipped", "−")
+        } else if result.passed() {
+            ("passed", "✓")
+        } else {
+            ("failed", "✗")
+        };
+        let open = if matches!(class, "passed" | "skipped") {
+            ""
+        } else {
+            " open"
+        };
+        let name = result.display_path();
+        let name = match result.iteration {
+            Some(index) => format!("{name} [iteration {}]", index + 1),
+            None => name,
+        };
+        let status = result
+            .response
+            .as_ref()
+            .map_or_else(String::new, |r| format!("{} {}", r.status, r.status_text));
+
+        let _ = writeln!(
+            html,
+            "<details class=\"request {class}\"{open}>\n<summary><span class=\"mark\">{mark}</span><span class=\"method\">{}</span><span class=\"name\">{}</span><span class=\"status\">{}</span><span class=\"time\">{} ms</span></summary>",
+            escape(&result.method),
+            escape(&name),
+            escape(&status),
+            result.duration_ms
+        );
+        html.push_str("<div class=\"content\">\n");
+        let _ = writeln!(
+            html,
+            "<p class=\"url\">{}</p>",
+            escape(&report.mask(&result.url))
+        );
+
+        if let Some(error) = &result.error {
+            let _ = writeln!(
+                html,
+                "<p class=\"error\">{}</p>",
+                escape(&report.mask(error))
+            );
+        }
+        if let Some(reason) = &result.skipped {
+            let _ = writeln!(
+                html,
+                "<p class=\"skipped\">Skipped: {}</p>",
+                escape(&report.mask(reason))
+            );
+        }
+
+        if !result.test_results.results.is_empty() {
+            html.push_str("<table class=\"assertions\">\n<tr><th></th><th>Assertion</th><th>Actual</th></tr>\n");
+            for assertion in &result.test_results.results {
+                Self::write_assertion(html, report, assertion);
+            }
+            html.push_str("</table>\n");
+        }
+
+        if let Some(request) = &result.request {
+            let headers: Vec<_> = request
+                .headers
+                .all()
+                .iter()
+                .filter(|h| h.enabled)
+                .map(|h| (h.name.as_str(), h.value.as_str()))
+                .collect();
+            write_headers(html, report, "Request headers", &headers);
+            if !request.body.is_empty() {
+                write_body(html, report, "Request body", &request.body.content);
+            }
+        }
+
+        if let Some(response) = &result.response {
+            let mut headers: Vec<_> = response
+                .headers_map
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect();
+            headers.sort_unstable();
+            write_headers(html, report, "Response headers", &headers);
+
+            if std::str::from_utf8(&response.body_bytes).is_ok() {
+                write_body(html, report, "Response body", &response.body);
+            } else {
+                let _ = writeln!(
+                    html,
+                    "<p>Response body: binary, {} bytes</p>",
+                    response.size
+                );
+            }
+        }
+
+        html.push_str("</div>\n</details>\n");
+    }
+
+    fn write_assertion(html: &mut String, report: &RunReport, assertion: &AssertionResult) {
+        let actual = assertion
+            .actual
+            .as_deref()
+            .map(|a| escape(&report.mask(a)))
+            .unwrap_or_default();
+
+        if assertion.passed {
+            let _ = writeln!(
+                html,
+                "<tr class=\"pass\"><td class=\"mark\">✓</td><td>{}</td><td>{actual}</td></tr>",
+                escape(&report.mask(&assertion.assertion.description()))
+            );
+            return;
+        }
+
+        let mut diff = String::new();
+        if let Some(expected) = assertion.assertion.expected() {
+            let _ = write!(
+                diff,
+                "<div class=\"expected\">- expected: {}</div>",
+                escape(&report.mask(&expected))
+            );
+        }
+        if let Some(actual) = &assertion.actual {
+            let _ = write!(
+                diff,
+                "<div class=\"actual\">+ actual:   {}</div>",
+                escape(&report.mask(actual))
+            );
+        }
+        if let Some(error) = &assertion.error {
+            let _ = write!(diff, "<div>{}</div>", escape(&report.mask(error)));
+        }
+
+        let _ = writeln!(
+            html,
+            "<tr class=\"fail\"><td class=\"mark\">✗</td><td>{}<div class=\"diff\">{diff}</div></td><td>{actual}</td></tr>",
+            escape(&report.mask(&assertion.assertion.description()))
+        );
+    }
+}
+
+fn write_headers(html: &mut String, report: &RunReport, title: &str, headers: &[(&str, &str)]) {
+    if headers.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(
+        html,
+        "<details class=\"part\"><summary>{title} ({})</summary>\n<table>",
+        headers.len()
+    );
+    for (name, value) in headers {
+        let value = if CREDENTIAL_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+            mask_credentials(value)
+        } else {
+            report.mask(value)
+        };
+        let _ = writeln!(
+            html,
+            "<tr><th>{}</th><td>{}</td></tr>",
+            escape(name),
+            escape(&value)
+        );
+    }
+    html.push_str("</table></details>\n");
+}
+
+fn write_body(html: &mut String, report: &RunReport, title: &str, body: &str) {
+    let pretty = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok());
+    let body = pretty.as_deref().unwrap_or(body);
+
+    let (shown, note) = if body.len() > MAX_BODY_DISPLAY {
+        let mut end = MAX_BODY_DISPLAY;
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        (
+            &body[..end],
+            format!(" (truncated, {} bytes total)", body.len()),
+        )
+    } else {
+        (body, String::new())
+    };
+
+    let _ = writeln!(
+        html,
+        "<details class=\"part\"><summary>{title}{note}</summary><pre>{}</pre></details>",
+        escape(&report.mask(shown))
+    );
+}
+
+/// Masks a credential header value, keeping the scheme (e.g. `Bearer`).
+fn mask_credentials(value: &str) -> String {
+    match value.split_once(' ') {
+        Some((scheme, _)) => format!("{scheme} {SECRET_MASK}"),
+        None => SECRET_MASK.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::super::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_write_summary_and_requests() {
+        let html = HtmlReportWriter::write(&[fixtures::report()]);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Vortex run report: API</title>"));
+        // 2 of 3 assertions passed.
+        assert!(html.contains("<div class=\"value\">66.7%</div>"));
+        assert!(html.contains("<details class=\"request passed\">"));
+        assert!(html.contains("<details class=\"request failed\" open>"));
+        assert!(html.contains("<details class=\"request errored\" open>"));
+        assert!(html.contains("Environment: staging"));
+        assert!(!html.contains("<script"));
+    }
+
+    #[test]
+    fn test_write_failed_assertion_diff() {
+        let html = HtmlReportWriter::write(&[fixtures::report()]);
+
+        assert!(html.contains(
+            "<div class=\"expected\">- expected: &lt;ok&gt; &amp; &quot;done&quot;</div>"
+        ));
+        assert!(html.contains("<div class=\"actual\">+ actual:   {&quot;ok&quot;:false}</div>"));
+        assert!(html.contains("<div>Text not found</div>"));
+    }
+
+    #[test]
+    fn test_write_masks_secrets() {
+        let html = HtmlReportWriter::write(&[fixtures::report()]);
+
+        assert!(!html.contains(fixtures::SECRET));
+        assert!(html.contains("<th>Authorization</th><td>Bearer ********</td>"));
+        assert!(html.contains("<th>X-Api-Key</th><td>********</td>"));
+        assert!(html.contains("&quot;token&quot;: &quot;********&quot;"));
+    }
+
+    #[test]
+    fn test_mask_credentials_keeps_scheme() {
+        assert_eq!(mask_credentials("Basic dXNlcjpwYXNz"), "Basic ********");
+        assert_eq!(mask_credentials("opaque"), "********");
+    }
+}
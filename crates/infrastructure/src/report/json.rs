@@ -0,0 +1,256 @@
+//! JSON report writer.
+//!
+//! Produces a versioned document whose shape is independent of the internal
+//! domain types, so CI tooling can rely on it across releases. Additive
+//! changes keep the schema version; anything else bumps it.
+//!
+//! URLs, errors and assertion details are passed through [`RunReport::mask`].
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+use vortex_domain::report::{RequestRunResult, RunReport, RunSummary};
+
+use super::ReportError;
+
+/// Version of the JSON report schema written by [`JsonReportWriter`].
+pub const JSON_REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// JSON report writer.
+pub struct JsonReportWriter;
+
+impl JsonReportWriter {
+    /// Renders the given runs as a JSON report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn write(reports: &[RunReport], pretty: bool) -> Result<String, ReportError> {
+        let document = JsonReport {
+            schema_version: JSON_REPORT_SCHEMA_VERSION,
+            generator: JsonGenerator {
+                name: "Vortex",
+                version: env!("CARGO_PKG_VERSION"),
+            },
+            summary: combined_summary(reports),
+            runs: reports.iter().map(JsonRun::from).collect(),
+        };
+
+        if pretty {
+            serde_json::to_string_pretty(&document)
+        } else {
+            serde_json::to_string(&document)
+        }
+        .map_err(|e| ReportError::Serialization(e.to_string()))
+    }
+}
+
+/// Sums the summaries of several runs.
+fn combined_summary(reports: &[RunReport]) -> RunSummary {
+    reports
+        .iter()
+        .map(|r| &r.summary)
+        .fold(RunSummary::default(), |total, s| RunSummary {
+            total_requests: total.total_requests + s.total_requests,
+            passed_requests: total.passed_requests + s.passed_requests,
+            failed_requests: total.failed_requests + s.failed_requests,
+            errored_requests: total.errored_requests + s.errored_requests,
+            skipped_requests: total.skipped_requests + s.skipped_requests,
+            total_assertions: total.total_assertions + s.total_assertions,
+            passed_assertions: total.passed_assertions + s.passed_assertions,
+            failed_assertions: total.failed_assertions + s.failed_assertions,
+            duration_ms: total.duration_ms + s.duration_ms,
+        })
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    generator: JsonGenerator,
+    summary: RunSummary,
+    runs: Vec<JsonRun<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonGenerator {
+    name: &'static str,
+    version: &'static str,
+}
+
+#[derive(Serialize)]
+struct JsonRun<'a> {
+    run_id: Uuid,
+    collection: &'a str,
+    environment: Option<&'a str>,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+    pass_rate: f64,
+    summary: &'a RunSummary,
+    requests: Vec<JsonRequest<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    iterations: Vec<JsonIteration<'a>>,
+}
+
+impl<'a> From<&'a RunReport> for JsonRun<'a> {
+    fn from(report: &'a RunReport) -> Self {
+        Self {
+            run_id: report.run_id,
+            collection: &report.collection_name,
+            environment: report.environment.as_deref(),
+            started_at: report.started_at,
+            finished_at: report.finished_at,
+            pass_rate: report.pass_rate(),
+            summary: &report.summary,
+            requests: report
+                .results
+                .iter()
+                .map(|result| JsonRequest::new(report, result))
+                .collect(),
+            iterations: report
+                .iterations
+                .iter()
+                .map(|iteration| JsonIteration {
+                    index: iteration.index,
+                    passed: iteration.passed(),
+                    data: &iteration.data,
+                    summary: &iteration.summary,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRequest<'a> {
+    id: &'a str,
+    name: &'a str,
+    folder_path: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iteration: Option<usize>,
+    method: &'a str,
+    url: String,
+    passed: bool,
+    status: Option<u16>,
+    response_time_ms: Option<u64>,
+    duration_ms: u64,
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    skipped: Option<String>,
+    assertions: Vec<JsonAssertion>,
+}
+
+impl<'a> JsonRequest<'a> {
+    /// Describes a request result, masking the run's secret values.
+    fn new(report: &RunReport, result: &'a RequestRunResult) -> Self {
+        let mask = |text: &Option<String>| text.as_deref().map(|text| report.mask(text));
+        Self {
+            id: &result.request_id,
+            name: &result.request_name,
+            folder_path: &result.folder_path,
+            iteration: result.iteration,
+            method: &result.method,
+            url: report.mask(&result.url),
+            passed: result.passed(),
+            status: result.response.as_ref().map(|r| r.status),
+            response_time_ms: result
+                .response
+                .as_ref()
+                .map(|r| u64::try_from(r.duration.as_millis()).unwrap_or(u64::MAX)),
+            duration_ms: result.duration_ms,
+            error: mask(&result.error),
+            skipped: mask(&result.skipped),
+            assertions: result
+                .test_results
+                .results
+                .iter()
+                .map(|assertion| JsonAssertion {
+                    description: report.mask(&assertion.assertion.description()),
+                    passed: assertion.passed,
+                    actual: mask(&assertion.actual),
+                    message: mask(&assertion.error),
+                    duration_ms: result.duration_ms,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonAssertion {
+    description: String,
+    passed: bool,
+    actual: Option<String>,
+    message: Option<String>,
+    /// Duration of the request the assertion checked.
+    duration_ms: u64,
+}
+
+#[derive(Serialize)]
+struct JsonIteration<'a> {
+    index: usize,
+    passed: bool,
+    data: &'a BTreeMap<String, String>,
+    summary: &'a RunSummary,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::super::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_write_schema() {
+        let report = fixtures::report();
+        let json: Value = serde_json::from_str(
+            &JsonReportWriter::write(&[report.clone(), report], false).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(json["schema_version"], 1);
+        assert_eq!(json["summary"]["total_requests"], 6);
+        assert_eq!(json["runs"].as_array().unwrap().len(), 2);
+
+        let run = &json["runs"][0];
+        assert_eq!(run["collection"], "API");
+        assert_eq!(run["environment"], "staging");
+        assert!(run.get("iterations").is_none());
+
+        let login = &run["requests"][1];
+        assert_eq!(login["folder_path"], json!(["Auth"]));
+        assert_eq!(login["passed"], false);
+        assert_eq!(login["status"], 200);
+        assert_eq!(login["response_time_ms"], 42);
+        assert_eq!(run["requests"][0]["status"], Value::Null);
+        assert_eq!(login["duration_ms"], 1250);
+        assert_eq!(
+            login["assertions"][1],
+            json!({
+                "description": "Body contains '<ok> & \"done\"'",
+                "passed": false,
+                "actual": "{\"ok\":false}",
+                "message": "Text not found",
+                "duration_ms": 1250
+            })
+        );
+        assert_eq!(run["requests"][2]["error"], "Connection refused");
+    }
+
+    #[test]
+    fn test_write_masks_secrets() {
+        let mut report = fixtures::report();
+        let login = &mut report.results[1];
+        login.url = format!("{}?key={}", login.url, fixtures::SECRET);
+        login.test_results.results[0].actual = Some(fixtures::SECRET.to_string());
+        report.results[2].error = Some(format!("Bad token {}", fixtures::SECRET));
+
+        let json = JsonReportWriter::write(&[report], false).unwrap();
+
+        assert!(!json.contains(fixtures::SECRET));
+        assert!(json.contains("https://api.test/login?key=********"));
+    }
+}
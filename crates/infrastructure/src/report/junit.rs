@@ -0,0 +1,232 @@
+//! `JUnit` XML report writer.
+//!
+//! Each request becomes a `<testsuite>` and each of its assertions a
+//! `<testcase>`, which is the layout GitLab and Jenkins render best. URLs,
+//! errors and assertion details are passed through [`RunReport::mask`].
+
+use std::fmt::Write;
+
+use vortex_domain::report::{RequestRunResult, RunReport};
+
+use super::{Outcome, escape, request_label, test_cases};
+
+/// `JUnit` XML report writer.
+pub struct JunitReportWriter;
+
+impl JunitReportWriter {
+    /// Renders the given runs as a `JUnit` XML document.
+    #[must_use]
+    pub fn write(reports: &[RunReport]) -> String {
+        let (mut tests, mut failures, mut errors) = (0, 0, 0);
+        for report in reports {
+            for case in report.results.iter().flat_map(|r| test_cases(report, r)) {
+                tests += 1;
+                match case.outcome {
+                    Outcome::Passed | Outcome::Skipped { .. } => {}
+                    Outcome::Failed { .. } => failures += 1,
+                    Outcome::Errored { .. } => errors += 1,
+                }
+            }
+        }
+        let duration_ms: u64 = reports.iter().map(|r| r.summary.duration_ms).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"Vortex\" tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\" time=\"{}\">",
+            seconds(duration_ms)
+        );
+        for report in reports {
+            for result in &report.results {
+                Self::write_suite(&mut xml, report, result);
+            }
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    fn write_suite(xml: &mut String, report: &RunReport, result: &RequestRunResult) {
+        let cases = test_cases(report, result);
+        let failures = cases
+            .iter()
+            .filter(|c| matches!(c.outcome, Outcome::Failed { .. }))
+            .count();
+        let errors = cases
+            .iter()
+            .filter(|c| matches!(c.outcome, Outcome::Errored { .. }))
+            .count();
+        let skipped = cases
+            .iter()
+            .filter(|c| matches!(c.outcome, Outcome::Skipped { .. }))
+            .count();
+        let name = escape(&request_label(report, result));
+
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{name}\" tests=\"{}\" failures=\"{failures}\" errors=\"{errors}\" skipped=\"{skipped}\" time=\"{}\" timestamp=\"{}\">",
+            cases.len(),
+            seconds(result.duration_ms),
+            report.started_at.format("%Y-%m-%dT%H:%M:%S")
+        );
+
+        xml.push_str("    <properties>\n");
+        let mut properties = vec![
+            ("method", result.method.clone()),
+            ("url", report.mask(&result.url)),
+        ];
+        if let Some(environment) = &report.environment {
+            properties.push(("environment", environment.clone()));
+        }
+        if let Some(response) = &result.response {
+            properties.push(("status", response.status.to_string()));
+            properties.push((
+                "response_time_ms",
+                response.duration.as_millis().to_string(),
+            ));
+        }
+        for (key, value) in properties {
+            let _ = writeln!(
+                xml,
+                "      <property name=\"{key}\" value=\"{}\"/>",
+                escape(&value)
+            );
+        }
+        xml.push_str("    </properties>\n");
+
+        for case in cases {
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{name}\" time=\"{}\"",
+                escape(&case.name),
+                seconds(case.duration_ms)
+            );
+            let actual = case
+                .actual
+                .map(|actual| format!("actual: {}", escape(&actual)));
+
+            match case.outcome {
+                Outcome::Passed => match actual {
+                    Some(actual) => {
+                        let _ = writeln!(
+                            xml,
+                            ">\n      <system-out>{actual}</system-out>\n    </testcase>"
+                        );
+                    }
+                    None => xml.push_str("/>\n"),
+                },
+                Outcome::Failed { message } => {
+                    let _ = writeln!(
+                        xml,
+                        ">\n      <failure message=\"{}\" type=\"AssertionFailure\">{}</failure>\n    </testcase>",
+                        escape(&message),
+                        actual.unwrap_or_default()
+                    );
+                }
+                Outcome::Errored { message } => {
+                    let _ = writeln!(
+                        xml,
+                        ">\n      <error message=\"{}\" type=\"RequestError\"/>\n    </testcase>",
+                        escape(&message)
+                    );
+                }
+                Outcome::Skipped { message } => {
+                    let _ = writeln!(
+                        xml,
+                        ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                        escape(&message)
+                    );
+                }
+            }
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+}
+
+/// Formats milliseconds as seconds with millisecond precision.
+fn seconds(ms: u64) -> String {
+    format!("{}.{:03}", ms / 1000, ms % 1000)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::super::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_write_counts_and_structure() {
+        let xml = JunitReportWriter::write(&[fixtures::report()]);
+
+        assert!(xml.contains(
+            "<testsuites name=\"Vortex\" tests=\"4\" failures=\"1\" errors=\"1\" time=\"4.000\">"
+        ));
+        assert!(xml.contains("<testsuite name=\"API / Auth/Login\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("timestamp=\"2026-01-02T03:04:05\""));
+        assert!(xml.contains("<property name=\"environment\" value=\"staging\"/>"));
+        assert!(xml.contains("<property name=\"status\" value=\"200\"/>"));
+        assert!(xml.contains("<error message=\"Connection refused\" type=\"RequestError\"/>"));
+        assert_eq!(xml.matches("<testsuite ").count(), 3);
+    }
+
+    #[test]
+    fn test_write_escapes_failure_details() {
+        let xml = JunitReportWriter::write(&[fixtures::report()]);
+
+        assert!(
+            xml.contains("name=\"Body contains &apos;&lt;ok&gt; &amp; &quot;done&quot;&apos;\"")
+        );
+        assert!(xml.contains(
+            "<failure message=\"Text not found\" type=\"AssertionFailure\">actual: {&quot;ok&quot;:false}</failure>"
+        ));
+        assert!(xml.contains("<system-out>actual: 200</system-out>"));
+    }
+
+    #[test]
+    fn test_write_testcase_durations() {
+        let xml = JunitReportWriter::write(&[fixtures::report()]);
+
+        assert!(xml.contains(
+            "<testcase name=\"Status code = 200\" classname=\"API / Health\" time=\"1.250\""
+        ));
+        assert!(
+            xml.contains(
+                "<testcase name=\"request\" classname=\"API / Auth/Logout\" time=\"1.250\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_skipped_request() {
+        let mut report = fixtures::report();
+        report.results[0].skipped = Some("gRPC is not supported".to_string());
+        let xml = JunitReportWriter::write(&[report]);
+
+        assert!(xml.contains(
+            "<testsuite name=\"API / Health\" tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"1\""
+        ));
+        assert!(xml.contains("<skipped message=\"gRPC is not supported\"/>"));
+    }
+
+    #[test]
+    fn test_write_masks_secrets() {
+        let mut report = fixtures::report();
+        let login = &mut report.results[1];
+        login.url = format!("{}?key={}", login.url, fixtures::SECRET);
+        login.test_results.results[1].actual = Some(fixtures::SECRET.to_string());
+        login.test_results.results[1].error = Some(format!("Got {}", fixtures::SECRET));
+        report.results[2].error = Some(format!("Bad token {}", fixtures::SECRET));
+
+        let xml = JunitReportWriter::write(&[report]);
+
+        assert!(!xml.contains(fixtures::SECRET));
+        assert!(xml.contains("value=\"https://api.test/login?key=********\""));
+        assert!(xml.contains("<error message=\"Bad token ********\""));
+    }
+
+    #[test]
+    fn test_seconds() {
+        assert_eq!(seconds(42), "0.042");
+        assert_eq!(seconds(61_005), "61.005");
+    }
+}
@@ -0,0 +1,319 @@
+//! Run report writers.
+//!
+//! This module turns collection [`RunReport`]s into files: `JUnit` XML, TAP
+//! and a versioned JSON document for CI systems, and a self-contained HTML
+//! page for people. Every writer masks the run's secret values.
+
+mod html;
+mod json;
+mod junit;
+mod tap;
+
+pub use html::HtmlReportWriter;
+pub use json::{JSON_REPORT_SCHEMA_VERSION, JsonReportWriter};
+pub use junit::JunitReportWriter;
+pub use tap::TapReportWriter;
+
+use std::path::Path;
+
+use vortex_domain::report::{ReportFormat, RequestRunResult, RunReport};
+
+/// Error type for report writing.
+#[derive(Debug, thiserror::Error)]
+pub enum ReportError {
+    /// The report could not be serialized.
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+
+    /// IO error while writing the report file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Renders the given runs in the requested format.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn write_report(reports: &[RunReport], format: ReportFormat) -> Result<String, ReportError> {
+    match format {
+        ReportFormat::Junit => Ok(JunitReportWriter::write(reports)),
+        ReportFormat::Tap => Ok(TapReportWriter::write(reports)),
+        ReportFormat::Json => JsonReportWriter::write(reports, true),
+        ReportFormat::Html => Ok(HtmlReportWriter::write(reports)),
+    }
+}
+
+/// Renders the given runs and writes them to `path`, creating parent directories.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails or the file cannot be written.
+pub async fn save_report(
+    reports: &[RunReport],
+    format: ReportFormat,
+    path: &Path,
+) -> Result<(), ReportError> {
+    let content = write_report(reports, format)?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, content).await?;
+    Ok(())
+}
+
+/// Outcome of a single reported check.
+enum Outcome {
+    /// The check passed.
+    Passed,
+    /// An assertion did not hold.
+    Failed { message: String },
+    /// The request could not be sent.
+    Errored { message: String },
+    /// The runner did not send the request.
+    Skipped { message: String },
+}
+
+/// One reported check: an assertion, or the request itself when it errored,
+/// was skipped or had no assertions.
+struct TestCase {
+    name: String,
+    outcome: Outcome,
+    actual: Option<String>,
+    /// Duration of the request the check belongs to.
+    duration_ms: u64,
+}
+
+/// Flattens a request result into the checks shown by line-based formats,
+/// masking the run's secret values.
+fn test_cases(report: &RunReport, result: &RequestRunResult) -> Vec<TestCase> {
+    if let Some(error) = &result.error {
+        return vec![TestCase {
+            name: "request".to_string(),
+            outcome: Outcome::Errored {
+                message: report.mask(error),
+            },
+            actual: None,
+            duration_ms: result.duration_ms,
+        }];
+    }
+
+    if let Some(reason) = &result.skipped {
+        return vec![TestCase {
+            name: "request".to_string(),
+            outcome: Outcome::Skipped {
+                message: report.mask(reason),
+            },
+            actual: None,
+            duration_ms: result.duration_ms,
+        }];
+    }
+
+    if result.test_results.results.is_empty() {
+        return vec![TestCase {
+            name: "request".to_string(),
+            outcome: Outcome::Passed,
+            actual: None,
+            duration_ms: result.duration_ms,
+        }];
+    }
+
+    result
+        .test_results
+        .results
+        .iter()
+        .map(|assertion| TestCase {
+            name: report.mask(&assertion.assertion.description()),
+            outcome: if assertion.passed {
+                Outcome::Passed
+            } else {
+                Outcome::Failed {
+                    message: report.mask(assertion.error.as_deref().unwrap_or("Assertion failed")),
+                }
+            },
+            actual: assertion
+                .actual
+                .as_deref()
+                .map(|actual| report.mask(actual)),
+            duration_ms: result.duration_ms,
+        })
+        .collect()
+}
+
+/// Returns the display name of a request within a run, e.g. `API / Auth/Login [2]`.
+fn request_label(report: &RunReport, result: &RequestRunResult) -> String {
+    let label = format!("{} / {}", report.collection_name, result.display_path());
+    match result.iteration {
+        Some(index) => format!("{label} [{}]", index + 1),
+        None => label,
+    }
+}
+
+/// Escapes text for use in XML and HTML attributes and content, dropping
+/// characters XML 1.0 cannot represent.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' => escaped.push_str("&#9;"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    //! Shared run reports for the writer tests.
+
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use vortex_domain::request::{RequestBody, RequestSpec};
+    use vortex_domain::response::ResponseSpec;
+    use vortex_domain::testing::{Assertion, AssertionResult, StatusExpectation, TestResults};
+
+    use super::*;
+
+    /// Secret value used by the `Login` request.
+    pub const SECRET: &str = "s3cr3t-t0ken";
+
+    fn result(
+        name: &str,
+        folder_path: &[&str],
+        assertions: Vec<AssertionResult>,
+        error: Option<&str>,
+    ) -> RequestRunResult {
+        RequestRunResult {
+            request_id: name.to_lowercase(),
+            request_name: name.to_string(),
+            folder_path: folder_path.iter().map(ToString::to_string).collect(),
+            iteration: None,
+            method: "GET".to_string(),
+            url: format!("https://api.test/{}", name.to_lowercase()),
+            request: None,
+            response: None,
+            test_results: TestResults::new(name, assertions, 1),
+            error: error.map(ToString::to_string),
+            skipped: None,
+            duration_ms: 1_250,
+        }
+    }
+
+    /// A run with one passing request, one failing request and one error.
+    ///
+    /// `Login` carries [`SECRET`] in its headers and response body.
+    pub fn report() -> RunReport {
+        let status = Assertion::StatusCode {
+            expected: StatusExpectation::exact(200),
+        };
+        let body = Assertion::BodyContains {
+            text: "<ok> & \"done\"".to_string(),
+            ignore_case: false,
+        };
+
+        let mut login = result(
+            "Login",
+            &["Auth"],
+            vec![
+                AssertionResult::pass_with_value(status.clone(), "200"),
+                AssertionResult::fail_with_value(body, "{\"ok\":false}", "Text not found"),
+            ],
+            None,
+        );
+        login.request = Some(
+            RequestSpec::post(&login.url)
+                .with_header("Authorization", format!("Bearer {SECRET}"))
+                .with_header("X-Api-Key", SECRET)
+                .with_body(RequestBody::json(r#"{"user":"alice"}"#)),
+        );
+        login.response = Some(ResponseSpec::new(
+            200,
+            HashMap::from([("Content-Type".to_string(), "application/json".to_string())]),
+            format!(r#"{{"token":"{SECRET}"}}"#).into_bytes(),
+            Duration::from_millis(42),
+        ));
+
+        let started_at = Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap();
+        RunReport::new(
+            "API",
+            Some("staging".to_string()),
+            vec![
+                result(
+                    "Health",
+                    &[],
+                    vec![AssertionResult::pass_with_value(status, "200")],
+                    None,
+                ),
+                login,
+                result("Logout", &["Auth"], Vec::new(), Some("Connection refused")),
+            ],
+            started_at,
+            started_at + chrono::Duration::milliseconds(4_000),
+        )
+        .with_secret_values(vec![SECRET.to_string()])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_test_cases_cover_errors_and_empty_requests() {
+        let report = fixtures::report();
+
+        let login = test_cases(&report, &report.results[1]);
+        assert_eq!(login.len(), 2);
+        assert!(
+            matches!(&login[1].outcome, Outcome::Failed { message } if message == "Text not found")
+        );
+        assert_eq!(login[1].actual.as_deref(), Some("{\"ok\":false}"));
+        assert_eq!(login[1].duration_ms, 1_250);
+
+        let logout = test_cases(&report, &report.results[2]);
+        assert_eq!(logout[0].name, "request");
+        assert!(matches!(logout[0].outcome, Outcome::Errored { .. }));
+    }
+
+    #[test]
+    fn test_request_label_includes_iteration() {
+        let mut report = fixtures::report();
+        report.results[1].iteration = Some(1);
+
+        assert_eq!(request_label(&report, &report.results[0]), "API / Health");
+        assert_eq!(
+            request_label(&report, &report.results[1]),
+            "API / Auth/Login [2]"
+        );
+    }
+
+    #[test]
+    fn test_escape_drops_invalid_characters() {
+        assert_eq!(escape("a\u{0}b\nc"), "ab&#10;c");
+        assert_eq!(escape("<a href='x'>"), "&lt;a href=&apos;x&apos;&gt;");
+    }
+
+    #[tokio::test]
+    async fn test_save_report_creates_directories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("reports/junit.xml");
+
+        save_report(&[fixtures::report()], ReportFormat::Junit, &path)
+            .await
+            .unwrap();
+
+        let content = std::fs::read_to_string(path).unwrap();
+        assert!(content.starts_with("<?xml"));
+    }
+}
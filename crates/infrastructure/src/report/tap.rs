@@ -0,0 +1,143 @@
+//! TAP (Test Anything Protocol) report writer.
+//!
+//! Emits TAP version 13: one test point per assertion, with a YAML
+//! diagnostic block carrying the actual value, failure message and duration.
+//! URLs, errors and assertion details are passed through [`RunReport::mask`].
+
+use std::fmt::Write;
+
+use vortex_domain::report::RunReport;
+
+use super::{Outcome, request_label, test_cases};
+
+/// TAP version 13 report writer.
+pub struct TapReportWriter;
+
+impl TapReportWriter {
+    /// Renders the given runs as a TAP stream.
+    #[must_use]
+    pub fn write(reports: &[RunReport]) -> String {
+        let total: usize = reports
+            .iter()
+            .flat_map(|report| report.results.iter().map(|r| test_cases(report, r).len()))
+            .sum();
+
+        let mut tap = format!("TAP version 13\n1..{total}\n");
+        let mut number = 0;
+
+        for report in reports {
+            match &report.environment {
+                Some(env) => {
+                    let _ = writeln!(tap, "# {} (environment: {env})", report.collection_name);
+                }
+                None => {
+                    let _ = writeln!(tap, "# {}", report.collection_name);
+                }
+            }
+
+            for result in &report.results {
+                let label = request_label(report, result);
+                for case in test_cases(report, result) {
+                    number += 1;
+                    let (status, message, directive) = match case.outcome {
+                        Outcome::Passed => ("ok", None, String::new()),
+                        Outcome::Failed { message } | Outcome::Errored { message } => {
+                            ("not ok", Some(message), String::new())
+                        }
+                        Outcome::Skipped { message } => {
+                            let directive = format!(" # SKIP {}", description(&message));
+                            ("ok", None, directive)
+                        }
+                    };
+                    let _ = writeln!(
+                        tap,
+                        "{status} {number} - {}{directive}",
+                        description(&format!("{label}: {}", case.name))
+                    );
+
+                    tap.push_str("  ---\n");
+                    if let Some(message) = message {
+                        let _ = writeln!(tap, "  message: {}", yaml_string(&message));
+                    }
+                    if let Some(actual) = case.actual {
+                        let _ = writeln!(tap, "  actual: {}", yaml_string(&actual));
+                    }
+                    let _ = writeln!(tap, "  method: {}", yaml_string(&result.method));
+                    let _ = writeln!(tap, "  url: {}", yaml_string(&report.mask(&result.url)));
+                    let _ = writeln!(tap, "  duration_ms: {}", case.duration_ms);
+                    tap.push_str("  ...\n");
+                }
+            }
+        }
+
+        tap
+    }
+}
+
+/// Makes text safe for a test point description: single line, `#` escaped.
+fn description(text: &str) -> String {
+    text.replace(['\r', '\n'], " ").replace('#', "\\#")
+}
+
+/// Quotes a value for the YAML diagnostic block.
+fn yaml_string(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::super::fixtures;
+    use super::*;
+
+    #[test]
+    fn test_write_plan_and_points() {
+        let tap = TapReportWriter::write(&[fixtures::report()]);
+        let lines: Vec<_> = tap.lines().collect();
+
+        assert_eq!(lines[0], "TAP version 13");
+        assert_eq!(lines[1], "1..4");
+        assert_eq!(lines[2], "# API (environment: staging)");
+        assert!(tap.contains("ok 1 - API / Health: Status code = 200\n"));
+        assert!(tap.contains("not ok 3 - API / Auth/Login: Body contains"));
+        assert!(tap.contains("not ok 4 - API / Auth/Logout: request\n"));
+    }
+
+    #[test]
+    fn test_write_diagnostics() {
+        let tap = TapReportWriter::write(&[fixtures::report()]);
+
+        assert!(tap.contains("  message: \"Text not found\"\n  actual: \"{\\\"ok\\\":false}\"\n"));
+        assert!(tap.contains("  message: \"Connection refused\"\n"));
+        assert!(tap.contains("  duration_ms: 1250\n  ...\n"));
+    }
+
+    #[test]
+    fn test_write_masks_secrets() {
+        let mut report = fixtures::report();
+        let login = &mut report.results[1];
+        login.url = format!("{}?key={}", login.url, fixtures::SECRET);
+        login.test_results.results[1].actual = Some(fixtures::SECRET.to_string());
+        report.results[2].error = Some(format!("Bad token {}", fixtures::SECRET));
+
+        let tap = TapReportWriter::write(&[report]);
+
+        assert!(!tap.contains(fixtures::SECRET));
+        assert!(tap.contains("  url: \"https://api.test/login?key=********\"\n"));
+        assert!(tap.contains("  message: \"Bad token ********\"\n"));
+    }
+
+    #[test]
+    fn test_write_skipped_request() {
+        let mut report = fixtures::report();
+        report.results[0].skipped = Some("gRPC is not supported".to_string());
+        let tap = TapReportWriter::write(&[report]);
+
+        assert!(tap.contains("ok 1 - API / Health: request # SKIP gRPC is not supported\n"));
+    }
+
+    #[test]
+    fn test_description_escapes_directives() {
+        assert_eq!(description("a # b\nc"), "a \\# b c");
+    }
+}
@@ -0,0 +1,715 @@
+//! Collection runner implementation.
+//!
+//! Executes every saved request of a collection in folder order, resolving
+//! variables and auth, and evaluates each request's tests with [`TestRunner`].
+//! A run can be repeated once per row of a data file for data-driven testing.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use vortex_application::ports::{CollectionTree, FolderTree, HttpClient, slugify};
+use vortex_application::{AuthProvider, ExecuteRequest, ResolveVariables};
+use vortex_domain::auth::{AuthConfig, AuthResolution};
+use vortex_domain::environment::ResolutionContext;
+use vortex_domain::grpc::is_grpc_url;
+use vortex_domain::persistence::{PersistenceAuth, SavedRequest};
+use vortex_domain::report::{RequestRunResult, RunReport};
+use vortex_domain::request::{Header, QueryParam, RequestSpec};
+use vortex_domain::testing::TestResults;
+
+use super::TestRunner;
+use super::data_file::DataRow;
+
+/// Options controlling a collection run.
+#[derive(Debug, Clone, Default)]
+pub struct RunnerConfig {
+    /// Stop the run after the first failed request.
+    pub stop_on_failure: bool,
+    /// Delay between requests in milliseconds.
+    pub delay_between_ms: u64,
+    /// Only run requests inside folders with one of these names (case-insensitive).
+    pub folders: Vec<String>,
+    /// Only run requests with one of these names (case-insensitive).
+    pub requests: Vec<String>,
+}
+
+impl RunnerConfig {
+    /// Sets the stop on failure flag.
+    #[must_use]
+    pub const fn with_stop_on_failure(mut self, stop: bool) -> Self {
+        self.stop_on_failure = stop;
+        self
+    }
+
+    /// Sets the delay between requests.
+    #[must_use]
+    pub const fn with_delay(mut self, ms: u64) -> Self {
+        self.delay_between_ms = ms;
+        self
+    }
+
+    /// Adds a folder name filter.
+    #[must_use]
+    pub fn with_folder(mut self, name: impl Into<String>) -> Self {
+        self.folders.push(name.into());
+        self
+    }
+
+    /// Adds a request name filter.
+    #[must_use]
+    pub fn with_request(mut self, name: impl Into<String>) -> Self {
+        self.requests.push(name.into());
+        self
+    }
+
+    /// Returns whether a request at the given folder path passes the filters.
+    fn matches(&self, folder_path: &[String], request_name: &str) -> bool {
+        let folder_matches = self.folders.is_empty()
+            || folder_path
+                .iter()
+                .any(|folder| self.folders.iter().any(|f| f.eq_ignore_ascii_case(folder)));
+        let request_matches = self.requests.is_empty()
+            || self
+                .requests
+                .iter()
+                .any(|r| r.eq_ignore_ascii_case(request_name));
+
+        folder_matches && request_matches
+    }
+}
+
+/// A request scheduled for execution, with its inherited context.
+#[derive(Debug, Clone)]
+pub struct PlannedRequest {
+    /// Names of the containing folders, outermost first.
+    pub folder_path: Vec<String>,
+    /// The saved request.
+    pub request: SavedRequest,
+    /// Effective auth: the request's own, else the nearest folder's, else the collection's.
+    pub auth: Option<PersistenceAuth>,
+}
+
+/// An item inside a collection or folder, used for ordering.
+enum Item<'a> {
+    Request(&'a SavedRequest),
+    Folder(&'a FolderTree),
+}
+
+impl Item<'_> {
+    /// Returns the on-disk name this item is referenced by in `folder.order`.
+    fn file_name(&self) -> String {
+        match self {
+            Self::Request(request) => format!("{}.json", slugify(&request.name)),
+            Self::Folder(folder) => folder
+                .path
+                .rsplit('/')
+                .next()
+                .unwrap_or(&folder.path)
+                .to_string(),
+        }
+    }
+
+    fn sort_key(&self) -> (u8, String) {
+        match self {
+            Self::Request(request) => (0, request.name.to_lowercase()),
+            Self::Folder(folder) => (1, folder.folder.name.to_lowercase()),
+        }
+    }
+}
+
+/// Orders items by an explicit `order` list; unlisted items follow,
+/// requests before folders, each sorted by name.
+fn ordered_items<'a>(
+    requests: &'a [SavedRequest],
+    folders: &'a [FolderTree],
+    order: &[String],
+) -> Vec<Item<'a>> {
+    let mut items: Vec<Item<'a>> = requests
+        .iter()
+        .map(Item::Request)
+        .chain(folders.iter().map(Item::Folder))
+        .collect();
+
+    items.sort_by_cached_key(|item| {
+        let name = item.file_name();
+        let position = order
+            .iter()
+            .position(|entry| entry.trim_end_matches('/') == name)
+            .unwrap_or(usize::MAX);
+        (position, item.sort_key())
+    });
+    items
+}
+
+/// Flattens a collection tree into execution order, applying the config filters.
+#[must_use]
+pub fn plan_requests(tree: &CollectionTree, config: &RunnerConfig) -> Vec<PlannedRequest> {
+    fn visit(
+        items: Vec<Item<'_>>,
+        folder_path: &[String],
+        auth: Option<&PersistenceAuth>,
+        config: &RunnerConfig,
+        planned: &mut Vec<PlannedRequest>,
+    ) {
+        for item in items {
+            match item {
+                Item::Request(request) => {
+                    if config.matches(folder_path, &request.name) {
+                        planned.push(PlannedRequest {
+                            folder_path: folder_path.to_vec(),
+                            request: request.clone(),
+                            auth: request.auth.as_ref().or(auth).cloned(),
+                        });
+                    }
+                }
+                Item::Folder(folder) => {
+                    let mut path = folder_path.to_vec();
+                    path.push(folder.folder.name.clone());
+                    let folder_auth = folder.folder.auth.as_ref().or(auth);
+                    let children =
+                        ordered_items(&folder.requests, &folder.subfolders, &folder.folder.order);
+                    visit(children, &path, folder_auth, config, planned);
+                }
+            }
+        }
+    }
+
+    let mut planned = Vec::new();
+    visit(
+        ordered_items(&tree.requests, &tree.folders, &[]),
+        &[],
+        tree.collection.auth.as_ref(),
+        config,
+        &mut planned,
+    );
+    planned
+}
+
+/// Runs saved collections request by request.
+pub struct CollectionRunner<C: HttpClient, A: AuthProvider> {
+    execute_request: ExecuteRequest<C>,
+    auth_provider: A,
+    test_runner: TestRunner,
+    config: RunnerConfig,
+}
+
+impl<C: HttpClient, A: AuthProvider> CollectionRunner<C, A> {
+    /// Creates a new collection runner.
+    pub const fn new(client: Arc<C>, auth_provider: A, config: RunnerConfig) -> Self {
+        Self {
+            execute_request: ExecuteRequest::new(client),
+            auth_provider,
+            test_runner: TestRunner::new(),
+            config,
+        }
+    }
+
+    /// Returns the runner configuration.
+    #[must_use]
+    pub const fn config(&self) -> &RunnerConfig {
+        &self.config
+    }
+
+    /// Runs all matching requests of a collection and returns the report.
+    ///
+    /// `on_result` is called after each request completes, in execution order.
+    pub async fn run<F>(
+        &self,
+        tree: &CollectionTree,
+        context: ResolutionContext,
+        mut on_result: F,
+    ) -> RunReport
+    where
+        F: FnMut(&RequestRunResult) + Send,
+    {
+        let started_at = Utc::now();
+        let environment = environment_name(&context);
+        let secret_values = context.secret_values();
+        let mut results = Vec::new();
+
+        self.run_sequence(tree, context, None, &mut on_result, &mut results)
+            .await;
+
+        RunReport::new(
+            &tree.collection.name,
+            environment,
+            results,
+            started_at,
+            Utc::now(),
+        )
+        .with_secret_values(secret_values)
+    }
+
+    /// Runs all matching requests once per data row and returns the report.
+    ///
+    /// Each row is injected as the data layer of the context, so its values
+    /// override environment variables but not secrets. The report holds the
+    /// results of every iteration plus a per-iteration aggregate.
+    pub async fn run_iterations<F>(
+        &self,
+        tree: &CollectionTree,
+        context: ResolutionContext,
+        rows: &[DataRow],
+        mut on_result: F,
+    ) -> RunReport
+    where
+        F: FnMut(&RequestRunResult) + Send,
+    {
+        let started_at = Utc::now();
+        let environment = environment_name(&context);
+        let secret_values = context.secret_values();
+        let mut results = Vec::new();
+
+        for (index, row) in rows.iter().enumerate() {
+            if index > 0 && self.config.delay_between_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(self.config.delay_between_ms)).await;
+            }
+
+            let context = context.clone().with_data(row);
+            let completed = self
+                .run_sequence(tree, context, Some(index), &mut on_result, &mut results)
+                .await;
+            if !completed {
+                break;
+            }
+        }
+
+        RunReport::new(
+            &tree.collection.name,
+            environment,
+            results,
+            started_at,
+            Utc::now(),
+        )
+        .with_iterations(rows)
+        .with_secret_values(secret_values)
+    }
+
+    /// Runs the planned requests in order, appending to `results`.
+    ///
+    /// Returns `false` if the sequence was cut short by `stop_on_failure`.
+    async fn run_sequence<F>(
+        &self,
+        tree: &CollectionTree,
+        context: ResolutionContext,
+        iteration: Option<usize>,
+        on_result: &mut F,
+        results: &mut Vec<RequestRunResult>,
+    ) -> bool
+    where
+        F: FnMut(&RequestRunResult) + Send,
+    {
+        let mut resolver = ResolveVariables::new(context);
+
+        for (index, planned) in plan_requests(tree, &self.config).iter().enumerate() {
+            if index > 0 && self.config.delay_between_ms > 0 {
+                tokio::time::sleep(Duration::from_millis(self.config.delay_between_ms)).await;
+            }
+
+            let mut result = self.run_request(planned, &mut resolver).await;
+            result.iteration = iteration;
+            on_result(&result);
+            let failed = !result.passed();
+            results.push(result);
+
+            if failed && self.config.stop_on_failure {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Resolves, sends and tests a single planned request.
+    pub async fn run_request(
+        &self,
+        planned: &PlannedRequest,
+        resolver: &mut ResolveVariables,
+    ) -> RequestRunResult {
+        let start = Instant::now();
+        let request = &planned.request;
+        let mut result = RequestRunResult {
+            request_id: request.id.clone(),
+            request_name: request.name.clone(),
+            folder_path: planned.folder_path.clone(),
+            iteration: None,
+            method: request.method.to_string(),
+            url: request.url.clone(),
+            request: None,
+            response: None,
+            test_results: TestResults::new(&request.name, Vec::new(), 0),
+            error: None,
+            skipped: None,
+            duration_ms: 0,
+        };
+
+        if request.grpc.is_some() || is_grpc_url(&request.url) {
+            result.skipped =
+                Some("gRPC requests are not supported by the collection runner".to_string());
+            return result;
+        }
+
+        match self.prepare(planned, resolver).await {
+            Ok(spec) => {
+                result.url = spec.full_url();
+                result.request = Some(RequestSpec {
+                    auth: AuthConfig::None,
+                    ..spec.clone()
+                });
+                match self.execute_request.execute(&spec).await {
+                    Ok(response) => {
+                        result.test_results =
+                            self.test_runner.run(&request.test_suite(), &response);
+                        result.response = Some(response);
+                    }
+                    Err(e) => result.error = Some(e.to_string()),
+                }
+            }
+            Err(message) => result.error = Some(message),
+        }
+
+        result.duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        result
+    }
+
+    /// Builds the executable request: converts, resolves variables and applies auth.
+    async fn prepare(
+        &self,
+        planned: &PlannedRequest,
+        resolver: &mut ResolveVariables,
+    ) -> Result<RequestSpec, String> {
+        let mut spec = RequestSpec::try_from(&planned.request).map_err(|e| e.to_string())?;
+        spec.auth = planned
+            .auth
+            .as_ref()
+            .map(AuthConfig::from)
+            .unwrap_or_default();
+
+        let output = resolver.execute(&spec);
+        if !output.is_complete {
+            return Err(format!(
+                "Unresolved variables: {}",
+                output.all_unresolved.join(", ")
+            ));
+        }
+
+        let mut spec = output.resolved_request;
+        match self.auth_provider.resolve(&spec.auth).await {
+            AuthResolution::None => {}
+            AuthResolution::Header { name, value } => spec.headers.add(Header::new(name, value)),
+            AuthResolution::QueryParam { name, value } => {
+                spec.query_params.add(QueryParam::new(name, value));
+            }
+            AuthResolution::Pending { message } => {
+                return Err(format!("Authentication requires interaction: {message}"));
+            }
+            AuthResolution::Failed { error } => {
+                return Err(format!("Authentication failed: {error}"));
+            }
+        }
+
+        Ok(spec)
+    }
+}
+
+/// Returns the active environment name, if any.
+fn environment_name(context: &ResolutionContext) -> Option<String> {
+    Some(context.environment_name.clone()).filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Mutex;
+
+    use vortex_application::HttpClientError;
+    use vortex_domain::auth::{AuthError, OAuth2Token};
+    use vortex_domain::environment::Variable;
+    use vortex_domain::persistence::{
+        PersistenceCollection, PersistenceFolder, PersistenceGrpc, PersistenceHttpMethod,
+        TestAssertion,
+    };
+    use vortex_domain::response::ResponseSpec;
+
+    use super::*;
+    use crate::auth::OAuth2Provider;
+
+    /// HTTP client that answers with a fixed status and records sent requests.
+    struct MockHttpClient {
+        status: u16,
+        sent: Mutex<Vec<RequestSpec>>,
+    }
+
+    impl MockHttpClient {
+        fn new(status: u16) -> Self {
+            Self {
+                status,
+                sent: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn execute(
+            &self,
+            request: &RequestSpec,
+        ) -> Pin<Box<dyn Future<Output = Result<ResponseSpec, HttpClientError>> + Send + '_>>
+        {
+            self.sent.lock().unwrap().push(request.clone());
+            let status = self.status;
+            Box::pin(async move {
+                Ok(ResponseSpec::new(
+                    status,
+                    HashMap::new(),
+                    b"{}".to_vec(),
+                    Duration::from_millis(5),
+                ))
+            })
+        }
+    }
+
+    /// Auth provider that never resolves anything.
+    struct NoAuth;
+
+    impl AuthProvider for NoAuth {
+        fn resolve<'a>(
+            &'a self,
+            _config: &'a AuthConfig,
+        ) -> Pin<Box<dyn Future<Output = AuthResolution> + Send + 'a>> {
+            Box::pin(async { AuthResolution::None })
+        }
+
+        fn refresh_token<'a>(
+            &'a self,
+            _config: &'a AuthConfig,
+            _refresh_token: &'a str,
+        ) -> Pin<Box<dyn Future<Output = Result<OAuth2Token, AuthError>> + Send + 'a>> {
+            Box::pin(async { Err(AuthError::UserCancelled) })
+        }
+
+        fn revoke_token<'a>(
+            &'a self,
+            _config: &'a AuthConfig,
+            _token: &'a str,
+        ) -> Pin<Box<dyn Future<Output = Result<(), AuthError>> + Send + 'a>> {
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get_cached_token(&self, _config: &AuthConfig) -> Option<OAuth2Token> {
+            None
+        }
+
+        fn clear_cached_token(&self, _config: &AuthConfig) {}
+    }
+
+    fn request(name: &str) -> SavedRequest {
+        SavedRequest::new(
+            name.to_string(),
+            name,
+            PersistenceHttpMethod::Get,
+            "{{base_url}}/items",
+        )
+        .with_test(TestAssertion::status("ok", 200))
+    }
+
+    fn folder(name: &str, order: &[&str], requests: Vec<SavedRequest>) -> FolderTree {
+        FolderTree {
+            folder: PersistenceFolder::new(name.to_string(), name)
+                .with_order(order.iter().map(ToString::to_string).collect()),
+            requests,
+            subfolders: Vec::new(),
+            path: slugify(name),
+        }
+    }
+
+    fn tree() -> CollectionTree {
+        CollectionTree {
+            collection: PersistenceCollection::new("c".to_string(), "API")
+                .with_auth(PersistenceAuth::bearer("{{token}}")),
+            requests: vec![request("Health")],
+            folders: vec![folder(
+                "Auth",
+                &["login.json", "refresh.json", "logout.json"],
+                vec![request("Logout"), request("Login"), request("Refresh")],
+            )],
+        }
+    }
+
+    fn context() -> ResolutionContext {
+        let mut context = ResolutionContext::new();
+        context
+            .environment
+            .insert("base_url".to_string(), Variable::new("https://api.test"));
+        context
+            .environment
+            .insert("token".to_string(), Variable::new("abc"));
+        context
+    }
+
+    #[test]
+    fn test_plan_respects_folder_order() {
+        let planned = plan_requests(&tree(), &RunnerConfig::default());
+        let names: Vec<_> = planned.iter().map(|p| p.request.name.as_str()).collect();
+
+        assert_eq!(names, ["Health", "Login", "Refresh", "Logout"]);
+        assert_eq!(planned[1].folder_path, ["Auth"]);
+        assert_eq!(planned[1].auth, Some(PersistenceAuth::bearer("{{token}}")));
+    }
+
+    #[test]
+    fn test_plan_filters() {
+        let config = RunnerConfig::default().with_folder("auth");
+        let planned = plan_requests(&tree(), &config);
+        assert_eq!(planned.len(), 3);
+
+        let config = RunnerConfig::default().with_request("LOGIN");
+        let planned = plan_requests(&tree(), &config);
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].request.name, "Login");
+    }
+
+    #[tokio::test]
+    async fn test_run_resolves_variables_and_auth() {
+        let client = Arc::new(MockHttpClient::new(200));
+        let runner = CollectionRunner::new(
+            client.clone(),
+            OAuth2Provider::new(),
+            RunnerConfig::default(),
+        );
+
+        let mut context = context();
+        context
+            .secrets
+            .insert("password".to_string(), "hunter2".to_string());
+
+        let mut seen = 0;
+        let report = runner.run(&tree(), context, |_| seen += 1).await;
+
+        assert_eq!(seen, 4);
+        assert!(report.all_passed());
+        assert_eq!(report.summary.passed_assertions, 4);
+        assert_eq!(report.secret_values, ["hunter2"]);
+
+        let captured = report.results[0].request.as_ref().unwrap();
+        assert_eq!(captured.auth, AuthConfig::None);
+        assert_eq!(captured.headers.len(), 1);
+
+        let sent = client.sent.lock().unwrap().clone();
+        assert_eq!(sent[0].url, "https://api.test/items");
+        assert!(
+            sent[0]
+                .headers
+                .all()
+                .iter()
+                .any(|h| h.name == "Authorization" && h.value == "Bearer abc")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_stops_on_failure() {
+        let client = Arc::new(MockHttpClient::new(500));
+        let config = RunnerConfig::default().with_stop_on_failure(true);
+        let runner = CollectionRunner::new(client, NoAuth, config);
+
+        let report = runner.run(&tree(), context(), |_| {}).await;
+
+        assert_eq!(report.results.len(), 1);
+        assert!(!report.all_passed());
+        assert_eq!(report.summary.failed_requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_grpc_requests_are_skipped() {
+        let client = Arc::new(MockHttpClient::new(200));
+        let runner = CollectionRunner::new(client.clone(), NoAuth, RunnerConfig::default());
+        let mut tree = tree();
+        tree.requests = vec![
+            SavedRequest::new(
+                "greet".to_string(),
+                "Greet",
+                PersistenceHttpMethod::Post,
+                "grpc://localhost:50051",
+            )
+            .with_grpc(PersistenceGrpc::new("helloworld.Greeter", "SayHello")),
+        ];
+        tree.folders.clear();
+
+        let report = runner.run(&tree, context(), |_| {}).await;
+
+        assert!(client.sent.lock().unwrap().is_empty());
+        assert!(report.all_passed());
+        assert_eq!(report.summary.skipped_requests, 1);
+        assert_eq!(
+            report.results[0].skipped.as_deref(),
+            Some("gRPC requests are not supported by the collection runner")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unresolved_variables_are_reported() {
+        let runner = CollectionRunner::new(
+            Arc::new(MockHttpClient::new(200)),
+            NoAuth,
+            RunnerConfig::default(),
+        );
+
+        let report = runner.run(&tree(), ResolutionContext::new(), |_| {}).await;
+
+        assert_eq!(report.summary.errored_requests, 4);
+        assert!(
+            report.results[0]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("base_url")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_iterations_injects_data_rows() {
+        let client = Arc::new(MockHttpClient::new(200));
+        let runner = CollectionRunner::new(client.clone(), NoAuth, RunnerConfig::default());
+        let rows = vec![
+            DataRow::from([("base_url".to_string(), "https://one.test".to_string())]),
+            DataRow::from([("base_url".to_string(), "https://two.test".to_string())]),
+        ];
+
+        let report = runner
+            .run_iterations(&tree(), context(), &rows, |_| {})
+            .await;
+
+        assert_eq!(report.results.len(), 8);
+        assert_eq!(report.iterations.len(), 2);
+        assert!(
+            report
+                .iterations
+                .iter()
+                .all(|i| i.summary.total_requests == 4)
+        );
+        assert_eq!(report.results[4].iteration, Some(1));
+
+        let sent = client.sent.lock().unwrap().clone();
+        assert_eq!(sent[0].url, "https://one.test/items");
+        assert_eq!(sent[4].url, "https://two.test/items");
+    }
+
+    #[tokio::test]
+    async fn test_run_iterations_stops_on_failure() {
+        let client = Arc::new(MockHttpClient::new(500));
+        let config = RunnerConfig::default().with_stop_on_failure(true);
+        let runner = CollectionRunner::new(client, NoAuth, config);
+        let rows = vec![DataRow::new(), DataRow::new()];
+
+        let report = runner
+            .run_iterations(&tree(), context(), &rows, |_| {})
+            .await;
+
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.iterations.len(), 1);
+        assert!(!report.iterations[0].passed());
+    }
+}
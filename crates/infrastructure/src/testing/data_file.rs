@@ -0,0 +1,190 @@
+//! Data files for data-driven runs.
+//!
+//! A data file provides one row of variables per iteration. Two formats are
+//! supported:
+//! - CSV with a header row naming the variables
+//! - a JSON array of flat objects
+//!
+//! Every value is converted to a string, since variables are always strings.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+/// One row of a data file: variable name to value.
+pub type DataRow = BTreeMap<String, String>;
+
+/// Error type for data file loading.
+#[derive(Debug, thiserror::Error)]
+pub enum DataFileError {
+    /// IO error while reading the file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The CSV content could not be parsed.
+    #[error("Invalid CSV: {0}")]
+    Csv(#[from] csv::Error),
+
+    /// The JSON content could not be parsed.
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// The content does not have the expected shape.
+    #[error("Invalid data file: {0}")]
+    InvalidFormat(String),
+}
+
+/// Supported data file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFileFormat {
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A JSON array of objects.
+    Json,
+}
+
+impl DataFileFormat {
+    /// Detects the format from the file extension, defaulting to CSV.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// Loads the rows of a data file, detecting the format from its extension.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed.
+pub async fn load_data_file(path: &Path) -> Result<Vec<DataRow>, DataFileError> {
+    let content = tokio::fs::read_to_string(path).await?;
+    match DataFileFormat::from_path(path) {
+        DataFileFormat::Csv => parse_csv(&content),
+        DataFileFormat::Json => parse_json(&content),
+    }
+}
+
+/// Parses CSV content with a header row into data rows.
+///
+/// # Errors
+///
+/// Returns an error if the CSV is malformed or a row has the wrong number of fields.
+pub fn parse_csv(content: &str) -> Result<Vec<DataRow>, DataFileError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(content.as_bytes());
+    let headers = reader.headers()?.clone();
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record?;
+            Ok(headers
+                .iter()
+                .zip(record.iter())
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect())
+        })
+        .collect()
+}
+
+/// Parses a JSON array of objects into data rows.
+///
+/// Strings are used as-is, `null` becomes an empty string and any other value
+/// is kept as its JSON text.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed or is not an array of objects.
+pub fn parse_json(content: &str) -> Result<Vec<DataRow>, DataFileError> {
+    let Value::Array(items) = serde_json::from_str(content)? else {
+        return Err(DataFileError::InvalidFormat(
+            "expected a JSON array of objects".to_string(),
+        ));
+    };
+
+    items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| match item {
+            Value::Object(fields) => Ok(fields
+                .into_iter()
+                .map(|(name, value)| (name, value_to_string(value)))
+                .collect()),
+            _ => Err(DataFileError::InvalidFormat(format!(
+                "item {index} is not an object"
+            ))),
+        })
+        .collect()
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::String(s) => s,
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("user, password\nalice,secret\nbob,\"a,b\"\n").unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0]["user"], "alice");
+        assert_eq!(rows[0]["password"], "secret");
+        assert_eq!(rows[1]["password"], "a,b");
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_ragged_rows() {
+        assert!(matches!(
+            parse_csv("a,b\n1,2,3\n"),
+            Err(DataFileError::Csv(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_json_stringifies_values() {
+        let rows =
+            parse_json(r#"[{"id": 1, "name": "alice", "admin": true, "note": null}]"#).unwrap();
+
+        assert_eq!(rows[0]["id"], "1");
+        assert_eq!(rows[0]["name"], "alice");
+        assert_eq!(rows[0]["admin"], "true");
+        assert_eq!(rows[0]["note"], "");
+    }
+
+    #[test]
+    fn test_parse_json_requires_array_of_objects() {
+        assert!(matches!(
+            parse_json(r#"{"id": 1}"#),
+            Err(DataFileError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse_json("[1, 2]"),
+            Err(DataFileError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            DataFileFormat::from_path(Path::new("rows.JSON")),
+            DataFileFormat::Json
+        );
+        assert_eq!(
+            DataFileFormat::from_path(Path::new("rows.csv")),
+            DataFileFormat::Csv
+        );
+    }
+}
@@ -1,7 +1,15 @@
 //! Response testing infrastructure.
 //!
-//! This module provides the test runner that executes assertions against HTTP responses.
+//! This module provides the test runner that executes assertions against HTTP responses,
+//! the collection runner that executes saved requests and their tests in sequence,
+//! and the data file loader used for data-driven runs.
 
+mod collection_runner;
+mod data_file;
 mod runner;
 
+pub use collection_runner::{CollectionRunner, PlannedRequest, RunnerConfig, plan_requests};
+pub use data_file::{
+    DataFileError, DataFileFormat, DataRow, load_data_file, parse_csv, parse_json,
+};
 pub use runner::TestRunner;
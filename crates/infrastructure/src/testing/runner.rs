@@ -5,11 +5,13 @@
 use std::time::Instant;
 
 use regex::Regex;
-use vortex_domain::response::ResponseSpec;
+use vortex_domain::response::{ResponseSpec, TimingPhase};
 use vortex_domain::testing::{
     Assertion, AssertionResult, ComparisonOperator, StatusExpectation, TestResults, TestSuite,
 };
 
+use crate::http::UNMEASURED_PHASES;
+
 /// Test runner that executes assertions against responses.
 #[derive(Debug, Default)]
 pub struct TestRunner {
@@ -68,6 +70,9 @@ impl TestRunner {
             Assertion::ResponseTime { max_ms } => {
                 self.check_response_time(assertion, response, *max_ms)
             }
+            Assertion::PhaseTime { phase, max_ms } => {
+                self.check_phase_time(assertion, response, *phase, *max_ms)
+            }
             Assertion::HeaderExists { name, value } => {
                 self.check_header_exists(assertion, response, name, value.as_deref())
             }
@@ -99,6 +104,18 @@ impl TestRunner {
             Assertion::BodyLength { operator, length } => {
                 self.check_body_length(assertion, response, *operator, *length)
             }
+            Assertion::EventCount { operator, count } => {
+                self.check_event_count(assertion, response, *operator, *count)
+            }
+            Assertion::EventContains { event, text } => {
+                self.check_event_contains(assertion, response, event.as_deref(), text)
+            }
+            Assertion::RedirectedTo { location } => {
+                self.check_redirected_to(assertion, response, location)
+            }
+            Assertion::RedirectCount { operator, count } => {
+                self.check_redirect_count(assertion, response, *operator, *count)
+            }
         }
     }
 
@@ -138,6 +155,40 @@ impl TestRunner {
         }
     }
 
+    fn check_phase_time(
+        &self,
+        assertion: &Assertion,
+        response: &ResponseSpec,
+        phase: TimingPhase,
+        max_ms: u64,
+    ) -> AssertionResult {
+        let Some(actual) = response.timing.get(phase) else {
+            // A phase the client never measures cannot be checked, while
+            // one that did not happen, e.g. connecting on a reused
+            // connection, took no time
+            if UNMEASURED_PHASES.contains(&phase) {
+                return AssertionResult::fail(
+                    assertion.clone(),
+                    format!("{} is not measured separately", phase.label()),
+                );
+            }
+            return AssertionResult::pass_with_value(assertion.clone(), "-".to_string());
+        };
+        let actual_ms = actual.as_millis() as u64;
+        if actual_ms <= max_ms {
+            AssertionResult::pass_with_value(assertion.clone(), format!("{actual_ms}ms"))
+        } else {
+            AssertionResult::fail_with_value(
+                assertion.clone(),
+                format!("{actual_ms}ms"),
+                format!(
+                    "{} took {actual_ms}ms, expected <= {max_ms}ms",
+                    phase.label()
+                ),
+            )
+        }
+    }
+
     fn check_header_exists(
         &self,
         assertion: &Assertion,
@@ -432,17 +483,7 @@ impl TestRunner {
         expected_length: usize,
     ) -> AssertionResult {
         let actual_length = response.body.len();
-        let matches = match operator {
-            ComparisonOperator::Equals => actual_length == expected_length,
-            ComparisonOperator::NotEquals => actual_length != expected_length,
-            ComparisonOperator::GreaterThan => actual_length > expected_length,
-            ComparisonOperator::GreaterThanOrEqual => actual_length >= expected_length,
-            ComparisonOperator::LessThan => actual_length < expected_length,
-            ComparisonOperator::LessThanOrEqual => actual_length <= expected_length,
-            ComparisonOperator::Contains | ComparisonOperator::Matches => false,
-        };
-
-        if matches {
+        if compare_count(actual_length, operator, expected_length) {
             AssertionResult::pass_with_value(assertion.clone(), actual_length.to_string())
         } else {
             AssertionResult::fail_with_value(
@@ -457,6 +498,117 @@ impl TestRunner {
             )
         }
     }
+
+    fn check_event_count(
+        &self,
+        assertion: &Assertion,
+        response: &ResponseSpec,
+        operator: ComparisonOperator,
+        expected_count: usize,
+    ) -> AssertionResult {
+        let actual_count = response.events.len();
+        if compare_count(actual_count, operator, expected_count) {
+            AssertionResult::pass_with_value(assertion.clone(), actual_count.to_string())
+        } else {
+            AssertionResult::fail_with_value(
+                assertion.clone(),
+                actual_count.to_string(),
+                format!(
+                    "Event count {} does not {} {}",
+                    actual_count,
+                    operator.symbol(),
+                    expected_count
+                ),
+            )
+        }
+    }
+
+    fn check_event_contains(
+        &self,
+        assertion: &Assertion,
+        response: &ResponseSpec,
+        event_type: Option<&str>,
+        text: &str,
+    ) -> AssertionResult {
+        let mut events = response
+            .events
+            .iter()
+            .filter(|event| event_type.is_none_or(|t| event.event_type() == t))
+            .peekable();
+        if events.peek().is_none() {
+            let message = event_type.map_or_else(
+                || "Response has no events".to_string(),
+                |t| format!("No '{t}' events received"),
+            );
+            return AssertionResult::fail(assertion.clone(), message);
+        }
+
+        match events.find(|event| event.data.contains(text)) {
+            Some(event) => AssertionResult::pass_with_value(assertion.clone(), event.data.clone()),
+            None => AssertionResult::fail(
+                assertion.clone(),
+                format!("No event data contains '{text}'"),
+            ),
+        }
+    }
+
+    fn check_redirected_to(
+        &self,
+        assertion: &Assertion,
+        response: &ResponseSpec,
+        location: &str,
+    ) -> AssertionResult {
+        let Some(last) = response.redirects.last() else {
+            return AssertionResult::fail(assertion.clone(), "Response was not redirected");
+        };
+        if last.points_to(location) {
+            AssertionResult::pass_with_value(assertion.clone(), last.location.clone())
+        } else {
+            AssertionResult::fail_with_value(
+                assertion.clone(),
+                last.location.clone(),
+                format!("Redirected to '{}', expected '{location}'", last.location),
+            )
+        }
+    }
+
+    fn check_redirect_count(
+        &self,
+        assertion: &Assertion,
+        response: &ResponseSpec,
+        operator: ComparisonOperator,
+        expected_count: usize,
+    ) -> AssertionResult {
+        let actual_count = response.redirects.len();
+        if compare_count(actual_count, operator, expected_count) {
+            AssertionResult::pass_with_value(assertion.clone(), actual_count.to_string())
+        } else {
+            AssertionResult::fail_with_value(
+                assertion.clone(),
+                actual_count.to_string(),
+                format!(
+                    "Redirect count {} does not {} {}",
+                    actual_count,
+                    operator.symbol(),
+                    expected_count
+                ),
+            )
+        }
+    }
+}
+
+/// Compares a count or length with an operator. `Contains` and `Matches`
+/// never match.
+const fn compare_count(actual: usize, operator: ComparisonOperator, expected: usize) -> bool {
+    match operator {
+        ComparisonOperator::Equals => actual == expected,
+        ComparisonOperator::NotEquals => actual != expected,
+        ComparisonOperator::GreaterThan => actual > expected,
+        ComparisonOperator::GreaterThanOrEqual => actual >= expected,
+        ComparisonOperator::LessThan => actual < expected,
+        ComparisonOperator::LessThanOrEqual => actual <= expected,
+        ComparisonOperator::Contains | ComparisonOperator::Matches => false,
+    }
 }
 
 /// Query a JSON value using a simple JSONPath-like syntax.
@@ -601,6 +753,7 @@ mod tests {
     use super::*;
     use std::collections::HashMap;
     use std::time::Duration;
+    use vortex_domain::response::{RedirectHop, ResponseTiming, StreamFormat, StreamParser};
 
     fn create_response(status: u16, body: &str, headers: HashMap<String, String>) -> ResponseSpec {
         ResponseSpec::new(
@@ -661,6 +814,46 @@ mod tests {
         assert!(!result.passed);
     }
 
+    #[test]
+    fn test_phase_time() {
+        let runner = TestRunner::new();
+        let mut response = create_response(200, "", HashMap::new());
+        response.timing.wait = Some(Duration::from_millis(80));
+
+        let assertion = Assertion::PhaseTime {
+            phase: TimingPhase::Wait,
+            max_ms: 100,
+        };
+        assert!(runner.run_assertion(&assertion, &response).passed);
+
+        let assertion = Assertion::PhaseTime {
+            phase: TimingPhase::Wait,
+            max_ms: 50,
+        };
+        let result = runner.run_assertion(&assertion, &response);
+        assert!(!result.passed);
+        assert_eq!(result.actual.as_deref(), Some("80ms"));
+
+        // A phase that did not happen passes
+        let assertion = Assertion::PhaseTime {
+            phase: TimingPhase::Dns,
+            max_ms: 0,
+        };
+        assert!(runner.run_assertion(&assertion, &response).passed);
+
+        // A phase the client does not measure fails
+        let assertion = Assertion::PhaseTime {
+            phase: TimingPhase::Tls,
+            max_ms: 1_000,
+        };
+        let result = runner.run_assertion(&assertion, &response);
+        assert!(!result.passed);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("TLS Handshake is not measured separately")
+        );
+    }
+
     #[test]
     fn test_header_exists() {
         let runner = TestRunner::new();
@@ -871,6 +1064,84 @@ mod tests {
         assert!(result.passed);
     }
 
+    #[test]
+    fn test_stream_events() {
+        let runner = TestRunner::new();
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "text/event-stream".to_string());
+        let body = "event: tick\ndata: 1\n\nevent: done\ndata: {\"ok\": true}\n\n";
+        let mut response = create_response(200, body, headers);
+        response.events = StreamParser::parse_all(
+            StreamFormat::ServerSentEvents,
+            body.as_bytes(),
+            chrono::Utc::now(),
+        );
+
+        let assertion = Assertion::EventCount {
+            operator: ComparisonOperator::Equals,
+            count: 2,
+        };
+        assert!(runner.run_assertion(&assertion, &response).passed);
+
+        let assertion = Assertion::EventContains {
+            event: Some("done".to_string()),
+            text: "\"ok\": true".to_string(),
+        };
+        assert!(runner.run_assertion(&assertion, &response).passed);
+
+        let assertion = Assertion::EventContains {
+            event: Some("tick".to_string()),
+            text: "ok".to_string(),
+        };
+        let result = runner.run_assertion(&assertion, &response);
+        assert!(!result.passed);
+        assert_eq!(result.error.as_deref(), Some("No event data contains 'ok'"));
+
+        let assertion = Assertion::EventContains {
+            event: Some("error".to_string()),
+            text: String::new(),
+        };
+        assert!(!runner.run_assertion(&assertion, &response).passed);
+    }
+
+    #[test]
+    fn test_redirects() {
+        let runner = TestRunner::new();
+        let mut response = create_response(200, "", HashMap::new());
+        let assertion = Assertion::RedirectedTo {
+            location: "/login".to_string(),
+        };
+        let result = runner.run_assertion(&assertion, &response);
+        assert_eq!(result.error.as_deref(), Some("Response was not redirected"));
+
+        response.redirects = ["https://example.com/account", "https://example.com/login"]
+            .iter()
+            .map(|location| RedirectHop {
+                status: 302,
+                url: "https://example.com/".to_string(),
+                location: (*location).to_string(),
+                headers: HashMap::new(),
+                timing: ResponseTiming::default(),
+            })
+            .collect();
+        assert!(runner.run_assertion(&assertion, &response).passed);
+
+        let assertion = Assertion::RedirectCount {
+            operator: ComparisonOperator::LessThanOrEqual,
+            count: 2,
+        };
+        assert!(runner.run_assertion(&assertion, &response).passed);
+        let assertion = Assertion::RedirectCount {
+            operator: ComparisonOperator::LessThanOrEqual,
+            count: 1,
+        };
+        let result = runner.run_assertion(&assertion, &response);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("Redirect count 2 does not <= 1")
+        );
+    }
+
     #[test]
     fn test_run_suite() {
         let runner = TestRunner::new();
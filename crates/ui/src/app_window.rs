@@ -3,44 +3,68 @@
 //! This module provides the main application window with all business logic bindings.
 
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 use slint::{ComponentHandle, ModelRc, SharedString, VecModel};
 use tokio::sync::mpsc;
 use vortex_application::{
-    CancellationToken, CreateWorkspace, CreateWorkspaceInput, EnvironmentRepository,
-    ExecuteRequest, ExecuteResultExt, VariableResolver, ports::WorkspaceRepository,
+    AuthEvent, AuthProvider, CancellationToken, CookieRepository, CreateWorkspace,
+    CreateWorkspaceInput, EnvironmentRepository, ExecuteRequest, ExecuteResult, ExecuteResultExt,
+    FetchGraphqlSchema, GraphqlSchemaRepository, GraphqlSubscription, GraphqlSubscriptionEvent,
+    GraphqlSubscriptionInput, GrpcClient, GrpcEvent, ResolveSecretCommands, ResolveVariables,
+    ResponseStreamEvent, VariableResolver, WebSocketClient, WebSocketEvent, WebSocketSender,
+    ports::WorkspaceRepository,
 };
 use vortex_domain::{
-    FontScale, HistoryAuth, HistoryEntry, HistoryHeader, HistoryParam, RequestHistory, ThemeMode,
-    UserSettings,
-};
-use vortex_domain::{
-    RequestState,
+    AuthConfig, AuthResolution, RequestErrorKind, RequestState,
+    cookie::{Cookie, CookieJar},
     environment::{Environment, ResolutionContext, Variable, VariableMap},
+    graphql::{self, GraphqlSchema},
+    grpc::{GrpcCall, GrpcError, GrpcService, GrpcStatus, is_grpc_url, split_messages},
     persistence::{
-        ApiKeyLocation, PersistenceAuth, PersistenceHttpMethod, PersistenceRequestBody,
-        SavedRequest,
+        ApiKeyLocation, PersistenceAuth, PersistenceGrpc, PersistenceHttpMethod,
+        PersistenceRequestBody, RequestSettings, SavedRequest, WorkspaceManifest,
     },
+    report::mask_secrets,
     request::{HttpMethod, RequestBody, RequestSpec},
+    response::{DownloadOptions, ResponseSpec, ResponseTiming, StreamEvent, format_bytes},
+    tls::TlsConfig,
+    websocket::{
+        ConnectionInfo, ConnectionState, MessageType, WebSocketConfig, WebSocketError,
+        WebSocketMessage,
+    },
+};
+use vortex_domain::{
+    FontScale, GlobalProxySettings, HistoryAuth, HistoryEntry, HistoryHeader, HistoryParam,
+    ProxyConfig, RequestHistory, ThemeMode, UserSettings,
 };
 use vortex_infrastructure::{
-    FileEnvironmentRepository, FileSystemWorkspaceRepository, HistoryRepository, PostmanImporter,
-    ReqwestHttpClient, SettingsRepository, TokioFileSystem, from_json, to_json_stable,
+    FileCookieRepository, FileEnvironmentRepository, FileGraphqlSchemaRepository,
+    FileSystemWorkspaceRepository, HistoryRepository, OAuth2Provider, OpenApiImporter,
+    PostmanImporter, ProxyResolver, ReqwestHttpClient, SettingsRepository, SharedCookieJar,
+    ShellSecretCommandRunner, TokioFileSystem, TonicGrpcClient, TungsteniteWebSocketClient,
+    from_json, to_json_stable,
 };
 
+use crate::CookieRow;
 use crate::EnvironmentInfo;
 use crate::HeaderRow;
 use crate::HistoryItem;
 use crate::MainWindow;
+use crate::ProxySettings;
 use crate::QueryParam;
+use crate::RedirectHop;
 use crate::ResponseHeader;
+use crate::TimingPhase;
 use crate::TreeItem;
 use crate::VariableRow;
 use crate::VortexPalette;
 use crate::VortexTypography;
+use crate::WebSocketLogEntry;
 // Sprint 06: Tab and Search types
 use crate::RequestTab;
 use crate::SearchResult;
@@ -49,8 +73,10 @@ use crate::ImportPreviewData;
 use crate::ImportState;
 use crate::ImportWarningItem;
 use crate::bridge::{
-    AuthData, EnvironmentData, HeaderData, HistoryItemData, ImportWarningData, QueryParamData,
-    SearchResultData, TabData, TabState, TreeItemData, UiCommand, UiUpdate, VariableData,
+    AuthData, CookieData, EnvironmentData, HeaderData, HistoryItemData, ImportWarningData,
+    LiveResponseData, ProxySettingsData, QueryParamData, RedirectHopData, RequestResult,
+    ResponseHeaderData, SearchResultData, TabData, TabState, TimingPhaseData, TreeItemData,
+    UiCommand, UiUpdate, VariableData, WebSocketMessageData, method_at,
 };
 
 /// Application window wrapper with business logic bindings.
@@ -75,6 +101,7 @@ impl AppWindow {
         // Clone command senders for each callback
         let cmd_tx_send = cmd_tx.clone();
         let cmd_tx_cancel = cmd_tx.clone();
+        let cmd_tx_download = cmd_tx.clone();
         let cmd_tx_create_ws = cmd_tx.clone();
         let cmd_tx_open_ws = cmd_tx.clone();
         let cmd_tx_close_ws = cmd_tx.clone();
@@ -95,12 +122,14 @@ impl AppWindow {
         let cmd_tx_del_var = cmd_tx.clone();
         let cmd_tx_var_changed = cmd_tx.clone();
         let cmd_tx_url_changed = cmd_tx.clone();
+        let cmd_tx_custom_method = cmd_tx.clone();
 
         // Settings command senders
         let cmd_tx_toggle_theme = cmd_tx.clone();
         let cmd_tx_open_settings = cmd_tx.clone();
         let cmd_tx_theme_mode = cmd_tx.clone();
         let cmd_tx_font_scale = cmd_tx.clone();
+        let cmd_tx_proxy_settings = cmd_tx.clone();
 
         // History command senders
         let cmd_tx_history_click = cmd_tx.clone();
@@ -156,15 +185,100 @@ impl AppWindow {
         let cmd_tx_format_req = cmd_tx.clone();
         let cmd_tx_copy_formatted = cmd_tx.clone();
 
+        // WebSocket command senders
+        let cmd_tx_ws_connect = cmd_tx.clone();
+        let cmd_tx_ws_disconnect = cmd_tx.clone();
+        let cmd_tx_ws_send = cmd_tx.clone();
+        let cmd_tx_ws_clear = cmd_tx.clone();
+
+        // GraphQL command senders
+        let cmd_tx_graphql_schema = cmd_tx.clone();
+
+        // gRPC command senders
+        let cmd_tx_grpc_methods = cmd_tx.clone();
+        let cmd_tx_grpc_method_selected = cmd_tx.clone();
+
+        // Cookie manager command senders
+        let cmd_tx_open_cookies = cmd_tx.clone();
+        let cmd_tx_delete_cookie = cmd_tx.clone();
+        let cmd_tx_save_cookie = cmd_tx.clone();
+        let cmd_tx_clear_cookies = cmd_tx.clone();
+        let cmd_tx_cookie_scope = cmd_tx.clone();
+
         // Set up UI callbacks
+        let ui_weak_send = ui_weak.clone();
         window.on_send_request(move || {
+            // ws:// and wss:// URLs connect instead of sending an HTTP request
+            if let Some(ui) = ui_weak_send.upgrade()
+                && ui.get_websocket_mode()
+            {
+                if ui.get_graphql_mode() {
+                    let _ = cmd_tx_send.send(graphql_subscribe_command(&ui));
+                    return;
+                }
+                let _ = cmd_tx_send.send(UiCommand::WebSocketConnect {
+                    subprotocols: ui.get_ws_subprotocols().to_string(),
+                    auto_reconnect: ui.get_ws_auto_reconnect(),
+                });
+                return;
+            }
+            // grpc:// and grpcs:// URLs call the selected gRPC method
+            if let Some(ui) = ui_weak_send.upgrade()
+                && ui.get_grpc_mode()
+            {
+                let _ = cmd_tx_send.send(UiCommand::InvokeGrpc {
+                    message: ui.get_request_body().to_string(),
+                    proto_files: ui.get_grpc_proto_files().to_string(),
+                });
+                return;
+            }
             let _ = cmd_tx_send.send(UiCommand::SendRequest);
         });
 
+        let ui_weak_grpc = ui_weak.clone();
+        window.on_load_grpc_methods(move || {
+            if let Some(ui) = ui_weak_grpc.upgrade() {
+                let _ = cmd_tx_grpc_methods.send(UiCommand::LoadGrpcMethods {
+                    proto_files: ui.get_grpc_proto_files().to_string(),
+                });
+            }
+        });
+
+        window.on_grpc_method_selected(move |index| {
+            let _ = cmd_tx_grpc_method_selected.send(UiCommand::GrpcMethodSelected { index });
+        });
+
+        let ui_weak_graphql = ui_weak.clone();
+        window.on_fetch_graphql_schema(move || {
+            if let Some(ui) = ui_weak_graphql.upgrade() {
+                let _ = cmd_tx_graphql_schema.send(UiCommand::FetchGraphqlSchema {
+                    url: ui.get_url().to_string(),
+                });
+            }
+        });
+
         window.on_cancel_request(move || {
             let _ = cmd_tx_cancel.send(UiCommand::CancelRequest);
         });
 
+        let ui_weak_download = ui_weak.clone();
+        window.on_send_and_download(move || {
+            let file_name = ui_weak_download
+                .upgrade()
+                .map(|ui| download_file_name(&ui.get_url()))
+                .unwrap_or_default();
+            let tx = cmd_tx_download.clone();
+            std::thread::spawn(move || {
+                if let Some(path) = rfd::FileDialog::new()
+                    .set_title("Save Response As")
+                    .set_file_name(file_name)
+                    .save_file()
+                {
+                    let _ = tx.send(UiCommand::SendRequestToFile { path });
+                }
+            });
+        });
+
         window.on_copy_response_body(move || {
             // TODO: Implement clipboard copy
             eprintln!("Copy to clipboard not yet implemented");
@@ -288,6 +402,12 @@ impl AppWindow {
             });
         });
 
+        window.on_custom_method_entered(move |name| {
+            let _ = cmd_tx_custom_method.send(UiCommand::AddCustomMethod {
+                name: name.trim().to_string(),
+            });
+        });
+
         // Settings callbacks (Sprint 04)
         window.on_toggle_theme(move || {
             let _ = cmd_tx_toggle_theme.send(UiCommand::ToggleTheme);
@@ -305,6 +425,17 @@ impl AppWindow {
             let _ = cmd_tx_font_scale.send(UiCommand::SetFontScale { index });
         });
 
+        window.on_proxy_settings_changed(move |proxy: ProxySettings| {
+            let _ = cmd_tx_proxy_settings.send(UiCommand::SetProxySettings(ProxySettingsData {
+                enabled: proxy.enabled,
+                url: proxy.url.to_string(),
+                username: proxy.username.to_string(),
+                password: proxy.password.to_string(),
+                bypass: proxy.bypass.to_string(),
+                use_system: proxy.use_system,
+            }));
+        });
+
         // History callbacks (Sprint 04)
         window.on_history_item_clicked(move |item: HistoryItem| {
             let _ = cmd_tx_history_click.send(UiCommand::LoadHistoryItem {
@@ -493,6 +624,69 @@ impl AppWindow {
             }
         });
 
+        // WebSocket callbacks
+        let ui_weak_ws_connect = ui_weak.clone();
+        window.on_ws_connect(move || {
+            if let Some(ui) = ui_weak_ws_connect.upgrade() {
+                if ui.get_graphql_mode() {
+                    let _ = cmd_tx_ws_connect.send(graphql_subscribe_command(&ui));
+                    return;
+                }
+                let _ = cmd_tx_ws_connect.send(UiCommand::WebSocketConnect {
+                    subprotocols: ui.get_ws_subprotocols().to_string(),
+                    auto_reconnect: ui.get_ws_auto_reconnect(),
+                });
+            }
+        });
+
+        window.on_ws_disconnect(move || {
+            let _ = cmd_tx_ws_disconnect.send(UiCommand::WebSocketDisconnect);
+        });
+
+        let ui_weak_ws_send = ui_weak.clone();
+        window.on_ws_send(move || {
+            if let Some(ui) = ui_weak_ws_send.upgrade() {
+                let _ = cmd_tx_ws_send.send(UiCommand::WebSocketSend {
+                    content: ui.get_ws_message_text().to_string(),
+                    binary: ui.get_ws_send_binary(),
+                });
+            }
+        });
+
+        window.on_ws_clear(move || {
+            let _ = cmd_tx_ws_clear.send(UiCommand::WebSocketClearLog);
+        });
+
+        // Cookie manager callbacks
+        window.on_open_cookie_manager(move || {
+            let _ = cmd_tx_open_cookies.send(UiCommand::OpenCookieManager);
+        });
+
+        window.on_delete_cookie(move |index| {
+            let _ = cmd_tx_delete_cookie.send(UiCommand::DeleteCookie { index });
+        });
+
+        window.on_save_cookie(move |index, cookie: CookieRow| {
+            let _ = cmd_tx_save_cookie.send(UiCommand::SaveCookie {
+                index,
+                cookie: CookieData {
+                    domain: cookie.domain.trim().to_string(),
+                    name: cookie.name.trim().to_string(),
+                    value: cookie.value.to_string(),
+                    path: cookie.path.trim().to_string(),
+                    ..CookieData::default()
+                },
+            });
+        });
+
+        window.on_clear_cookies(move || {
+            let _ = cmd_tx_clear_cookies.send(UiCommand::ClearCookies);
+        });
+
+        window.on_cookies_per_environment_changed(move |enabled| {
+            let _ = cmd_tx_cookie_scope.send(UiCommand::SetCookiesPerEnvironment(enabled));
+        });
+
         // Spawn the async runtime in a separate thread
         let ui_weak_async = ui_weak.clone();
         let cmd_tx_async = cmd_tx;
@@ -561,6 +755,8 @@ struct AppState {
     dark_mode: bool,
     theme_mode: ThemeMode,
     font_scale: FontScale,
+    proxy_settings: GlobalProxySettings,
+    workspace_proxy: Option<ProxyConfig>, // Overrides the user's proxy while a workspace is open
     // History state (Sprint 04)
     history: RequestHistory,
     history_visible: bool,
@@ -586,6 +782,28 @@ struct AppState {
     // Sprint 04: Import state
     import_file_path: Option<String>,
     import_preview_done: bool,
+    // WebSocket state
+    websocket_session: u64, // Incremented per connection; events from older ones are ignored
+    websocket_state: ConnectionState,
+    websocket_sender: Option<WebSocketSender>,
+    websocket_log: Vec<WebSocketMessageData>,
+    // Cookie jar state
+    cookie_jar: SharedCookieJar, // Shared with the HTTP client
+    cookie_location: Option<CookieLocation>, // Where the active jar is saved
+    cookies_saved_revision: u64,
+    cookies_per_environment: bool,
+    cookie_list: Vec<Cookie>, // Cookies as listed in the manager
+    // GraphQL state
+    graphql_schemas: HashMap<String, GraphqlSchema>, // Fetched or cached schemas by endpoint
+    // gRPC state
+    grpc_services: Vec<GrpcService>, // Services listed in the method picker
+    grpc_target: PersistenceGrpc,    // Selected method and .proto files
+    // Methods in the method selector; custom methods are appended as used
+    methods: Vec<HttpMethod>,
+    // Output of command variables by command line, run once per session
+    secret_command_values: HashMap<String, String>,
+    // Signs and fetches tokens for saved auth the editor cannot show
+    auth_provider: OAuth2Provider,
 }
 
 impl AppState {
@@ -602,6 +820,8 @@ impl AppState {
             dark_mode: settings.theme.is_dark(),
             theme_mode: settings.theme,
             font_scale: settings.font_scale,
+            proxy_settings: settings.proxy,
+            workspace_proxy: None,
             history,
             history_visible: settings.history_visible,
             // Sprint 05
@@ -619,12 +839,50 @@ impl AppState {
             updating_url_from_params: false,
             import_file_path: None,
             import_preview_done: false,
+            websocket_session: 0,
+            websocket_state: ConnectionState::Disconnected,
+            websocket_sender: None,
+            websocket_log: Vec::new(),
+            cookie_jar: SharedCookieJar::default(),
+            cookie_location: None,
+            cookies_saved_revision: 0,
+            cookies_per_environment: false,
+            cookie_list: Vec::new(),
+            graphql_schemas: HashMap::new(),
+            grpc_services: Vec::new(),
+            grpc_target: PersistenceGrpc::default(),
+            methods: HttpMethod::all().to_vec(),
+            secret_command_values: HashMap::new(),
+            auth_provider: OAuth2Provider::new(),
         }
     }
 
+    /// Returns the index of `method` in the method selector, adding it to
+    /// the selector if it is a custom method that is not listed yet.
+    fn method_index(
+        &mut self,
+        method: &HttpMethod,
+        update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    ) -> i32 {
+        let index = self
+            .methods
+            .iter()
+            .position(|m| m == method)
+            .unwrap_or_else(|| {
+                self.methods.push(method.clone());
+                let names = self.methods.iter().map(ToString::to_string).collect();
+                let _ = update_tx.send(UiUpdate::Methods(names));
+                self.methods.len() - 1
+            });
+        i32::try_from(index).unwrap_or(0)
+    }
+
     /// Gets tabs as UI data.
     fn tabs_to_ui(&self) -> Vec<TabData> {
-        self.tabs.iter().map(TabState::to_tab_data).collect()
+        self.tabs
+            .iter()
+            .map(|tab| tab.to_tab_data(&self.methods))
+            .collect()
     }
 
     /// Saves current UI state to the active tab.
@@ -638,6 +896,7 @@ impl AppState {
             tab.headers = self.request_headers.clone();
             tab.query_params = self.query_params.clone();
             tab.auth = self.auth_data.clone();
+            tab.grpc = is_grpc_url(url).then(|| self.grpc_target.clone());
         }
     }
 
@@ -651,6 +910,7 @@ impl AppState {
             theme: self.theme_mode,
             font_scale: self.font_scale,
             history_visible: self.history_visible,
+            proxy: self.proxy_settings.clone(),
             ..UserSettings::default()
         }
     }
@@ -687,16 +947,46 @@ impl AppState {
             .map(|env| env.name.clone())
             .unwrap_or_default();
 
-        ResolutionContext {
+        let mut context = ResolutionContext {
             globals: VariableMap::new(),
             collection: VariableMap::new(),
             environment: environment_vars,
             environment_name,
+            data: HashMap::new(),
             secrets: HashMap::new(),
+        };
+
+        // Command variables resolve once their command has run this session
+        for (name, command) in context.secret_commands() {
+            if let Some(value) = self.secret_command_values.get(&command) {
+                context.secrets.insert(name, value.clone());
+            }
         }
+        context
     }
 }
 
+/// Creates the auth provider, showing device codes the user must approve
+/// under the loading spinner.
+fn auth_provider(update_tx: mpsc::UnboundedSender<UiUpdate>) -> OAuth2Provider {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            if let AuthEvent::DeviceCodeIssued {
+                user_code,
+                verification_uri,
+                ..
+            } = event
+            {
+                let _ = update_tx.send(UiUpdate::ElapsedTime(format!(
+                    "Enter code {user_code} at {verification_uri}"
+                )));
+            }
+        }
+    });
+    OAuth2Provider::new().with_event_sender(tx)
+}
+
 /// Runs the async runtime for handling HTTP requests and workspace operations.
 fn run_async_runtime(
     ui_weak: slint::Weak<MainWindow>,
@@ -712,8 +1002,8 @@ fn run_async_runtime(
 
     rt.block_on(async move {
         // Initialize infrastructure
-        let http_client = ReqwestHttpClient::new().expect("Failed to create HTTP client");
-        let execute_request = ExecuteRequest::new(Arc::new(http_client));
+        let websocket_client: Arc<dyn WebSocketClient> = Arc::new(TungsteniteWebSocketClient::new());
+        let grpc_client: Arc<dyn GrpcClient> = Arc::new(TonicGrpcClient::new());
         let fs = TokioFileSystem;
         let workspace_repo = FileSystemWorkspaceRepository::new(fs);
         let settings_repo = SettingsRepository::new();
@@ -725,6 +1015,16 @@ fn run_async_runtime(
 
         // Application state (initialized from settings)
         let mut state = AppState::from_settings(settings.clone(), history);
+        state.auth_provider = auth_provider(update_tx.clone());
+
+        // The HTTP client sends and stores cookies through the state's jar
+        let http_client = Arc::new(
+            ReqwestHttpClient::with_cookie_jar(state.cookie_jar.clone())
+                .expect("Failed to create HTTP client")
+                .with_proxy_resolver(proxy_resolver(&state)),
+        );
+        let execute_request = Arc::new(ExecuteRequest::new(Arc::clone(&http_client)));
+        let secret_commands = ResolveSecretCommands::new(ShellSecretCommandRunner::new());
         let mut current_cancel: Option<CancellationToken> = None;
 
         // Send initial settings to UI
@@ -733,6 +1033,7 @@ fn run_async_runtime(
             font_scale_index: settings.font_scale.to_index(),
             dark_mode: settings.theme.is_dark(),
             font_scale_factor: settings.font_scale.factor(),
+            proxy: ProxySettingsData::from_settings(&settings.proxy),
         });
 
         // Send initial history to UI
@@ -742,109 +1043,172 @@ fn run_async_runtime(
         while let Some(cmd) = cmd_rx.recv().await {
             match cmd {
                 UiCommand::SendRequest => {
-                    if let Some(result) = handle_send_request(
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    handle_send_request(
                         &ui_weak,
                         &execute_request,
                         &update_tx,
+                        &cmd_tx,
                         &mut current_cancel,
-                        &state,
+                        &mut state,
+                        None,
                     )
-                    .await
-                    {
-                        // Save response to current tab
-                        if let Some(ref active_id) = state.active_tab_id
-                            && let Some(tab) = state.tabs.iter_mut().find(|t| &t.id == active_id) {
-                                tab.response_state = result.response_state;
-                                tab.response_body = result.response_body.clone();
-                                tab.status_code = result.status_code.map_or(0, i32::from);
-                                tab.status_text = result.status_text.clone();
-                                tab.duration = result.duration_display.clone();
-                                tab.size = result.size_display.clone();
-                                tab.response_headers = result.response_headers.clone();
-                                tab.error_title = result.error_title.clone();
-                                tab.error_message = result.error_message.clone();
-                            }
+                    .await;
+                }
 
-                        // Also save to state for formatting
-                        state.response_body = result.response_body.clone();
+                UiCommand::SendRequestToFile { path } => {
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    handle_send_request(
+                        &ui_weak,
+                        &execute_request,
+                        &update_tx,
+                        &cmd_tx,
+                        &mut current_cancel,
+                        &mut state,
+                        Some(DownloadOptions::new(path)),
+                    )
+                    .await;
+                }
 
-                        // Add to history
-                        let body_for_history = if result.request_body.is_empty() {
-                            None
-                        } else {
-                            Some(result.request_body.clone())
-                        };
+                UiCommand::RequestFinished(result) => {
+                    // Save response to the tab it was sent from
+                    if let Some(ref tab_id) = result.tab_id
+                        && let Some(tab) = state.tabs.iter_mut().find(|t| &t.id == tab_id) {
+                            tab.response_state = result.response_state;
+                            tab.response_body = result.response_body.clone();
+                            tab.status_code = result.status_code.map_or(0, i32::from);
+                            tab.status_text = result.status_text.clone();
+                            tab.duration = result.duration_display.clone();
+                            tab.size = result.size_display.clone();
+                            tab.response_headers = result.response_headers.clone();
+                            tab.response_timing = timing_phase_data(&result.timing);
+                            tab.response_redirects = result.redirects.clone();
+                            tab.error_title = result.error_title.clone();
+                            tab.error_message = result.error_message.clone();
+                        }
 
-                        // Convert headers for history
-                        let headers_for_history: Vec<HistoryHeader> = state.request_headers
-                            .iter()
-                            .map(|h| HistoryHeader {
-                                key: h.key.clone(),
-                                value: h.value.clone(),
-                                enabled: h.enabled,
-                            })
-                            .collect();
+                    // Also save to state for formatting
+                    state.response_body = result.response_body.clone();
 
-                        // Convert params for history
-                        let params_for_history: Vec<HistoryParam> = state.query_params
-                            .iter()
-                            .map(|p| HistoryParam {
-                                key: p.key.clone(),
-                                value: p.value.clone(),
-                                enabled: p.enabled,
-                            })
-                            .collect();
+                    // Add to history
+                    let body_for_history = if result.request_body.is_empty() {
+                        None
+                    } else {
+                        Some(result.request_body.clone())
+                    };
 
-                        // Convert auth for history
-                        let auth_for_history = if state.auth_data.auth_type > 0 {
-                            Some(HistoryAuth {
-                                auth_type: state.auth_data.auth_type,
-                                bearer_token: state.auth_data.bearer_token.clone(),
-                                basic_username: state.auth_data.basic_username.clone(),
-                                basic_password: state.auth_data.basic_password.clone(),
-                                api_key_name: state.auth_data.api_key_name.clone(),
-                                api_key_value: state.auth_data.api_key_value.clone(),
-                                api_key_location: state.auth_data.api_key_location,
-                            })
-                        } else {
-                            None
-                        };
+                    // Convert headers for history
+                    let headers_for_history: Vec<HistoryHeader> = state.request_headers
+                        .iter()
+                        .map(|h| HistoryHeader {
+                            key: h.key.clone(),
+                            value: h.value.clone(),
+                            enabled: h.enabled,
+                        })
+                        .collect();
 
-                        let entry = if let (Some(status), Some(duration)) = (result.status_code, result.duration_ms) {
-                            HistoryEntry::new(
-                                result.method,
-                                result.url,
-                                status,
-                                duration,
-                                None,
-                                body_for_history,
-                                headers_for_history,
-                                params_for_history,
-                                auth_for_history,
-                            )
-                        } else {
-                            HistoryEntry::failed(
-                                result.method,
-                                result.url,
-                                None,
-                                body_for_history,
-                                headers_for_history,
-                                params_for_history,
-                                auth_for_history,
-                            )
-                        };
+                    // Convert params for history
+                    let params_for_history: Vec<HistoryParam> = state.query_params
+                        .iter()
+                        .map(|p| HistoryParam {
+                            key: p.key.clone(),
+                            value: p.value.clone(),
+                            enabled: p.enabled,
+                        })
+                        .collect();
 
-                        state.history.add(entry);
+                    // Convert auth for history
+                    let auth_for_history = if state.auth_data.auth_type > 0 {
+                        Some(HistoryAuth {
+                            auth_type: state.auth_data.auth_type,
+                            bearer_token: state.auth_data.bearer_token.clone(),
+                            basic_username: state.auth_data.basic_username.clone(),
+                            basic_password: state.auth_data.basic_password.clone(),
+                            api_key_name: state.auth_data.api_key_name.clone(),
+                            api_key_value: state.auth_data.api_key_value.clone(),
+                            api_key_location: state.auth_data.api_key_location,
+                        })
+                    } else {
+                        None
+                    };
 
-                        // Update UI with new history
-                        let _ = update_tx.send(UiUpdate::HistoryItems(state.history_to_ui_items()));
+                    let mut entry = if let (Some(status), Some(duration)) = (result.status_code, result.duration_ms) {
+                        HistoryEntry::new(
+                            result.method,
+                            result.url,
+                            status,
+                            duration,
+                            None,
+                            body_for_history,
+                            headers_for_history,
+                            params_for_history,
+                            auth_for_history,
+                        )
+                    } else {
+                        HistoryEntry::failed(
+                            result.method,
+                            result.url,
+                            None,
+                            body_for_history,
+                            headers_for_history,
+                            params_for_history,
+                            auth_for_history,
+                        )
+                    };
 
-                        // Save history to disk
-                        let history_repo = HistoryRepository::new();
-                        if let Err(e) = history_repo.save(&state.history).await {
-                            eprintln!("Failed to save history: {e}");
-                        }
+                    // Downloads are referenced, not embedded
+                    if let Some(path) = result.response_file.clone() {
+                        entry = entry.with_response_file(path);
                     }
+                    entry = entry.with_timing(result.timing);
+
+                    state.history.add(entry);
+
+                    // Update UI with new history
+                    let _ = update_tx.send(UiUpdate::HistoryItems(state.history_to_ui_items()));
+
+                    // Save history to disk
+                    let history_repo = HistoryRepository::new();
+                    if let Err(e) = history_repo.save(&state.history).await {
+                        eprintln!("Failed to save history: {e}");
+                    }
+
+                    // Persist cookies set by the response and its redirects
+                    save_cookie_jar(&mut state).await;
+                }
+
+                UiCommand::FetchGraphqlSchema { url } => {
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    fetch_graphql_schema(&url, &http_client, &mut state, &update_tx).await;
+                }
+
+                UiCommand::LoadGrpcMethods { proto_files } => {
+                    state.grpc_target.proto_files = parse_proto_files(&proto_files);
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    load_grpc_methods(grpc_client.as_ref(), &mut state, &update_tx).await;
+                }
+
+                UiCommand::GrpcMethodSelected { index } => {
+                    select_grpc_method(&mut state, &update_tx, index);
+                }
+
+                UiCommand::InvokeGrpc {
+                    message,
+                    proto_files,
+                } => {
+                    state.grpc_target.proto_files = parse_proto_files(&proto_files);
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    current_cancel = invoke_grpc(&grpc_client, &state, &update_tx, &message);
                 }
 
                 UiCommand::CancelRequest => {
@@ -863,8 +1227,13 @@ fn run_async_runtime(
                         })
                         .await
                     {
-                        Ok(_manifest) => {
+                        Ok(manifest) => {
                             state.workspace_path = Some(path.clone());
+                            state.graphql_schemas.clear();
+                            state.cookies_per_environment = manifest.cookies_per_environment;
+                            state.workspace_proxy = workspace_proxy(&manifest);
+                            http_client.set_proxy_resolver(proxy_resolver(&state));
+                            apply_workspace_tls(&http_client, &manifest, &update_tx);
                             state.environments.clear();
                             state.current_environment_index = None;
 
@@ -876,6 +1245,7 @@ fn run_async_runtime(
 
                             // Load environments
                             load_environments(&path, &mut state, &update_tx).await;
+                            sync_cookie_jar(&mut state).await;
                         }
                         Err(e) => {
                             let _ = update_tx.send(UiUpdate::Error {
@@ -888,8 +1258,13 @@ fn run_async_runtime(
 
                 UiCommand::OpenWorkspace { path } => {
                     match workspace_repo.load(&path).await {
-                        Ok(_manifest) => {
+                        Ok(manifest) => {
                             state.workspace_path = Some(path.clone());
+                            state.graphql_schemas.clear();
+                            state.cookies_per_environment = manifest.cookies_per_environment;
+                            state.workspace_proxy = workspace_proxy(&manifest);
+                            http_client.set_proxy_resolver(proxy_resolver(&state));
+                            apply_workspace_tls(&http_client, &manifest, &update_tx);
                             let _ = update_tx
                                 .send(UiUpdate::WorkspacePath(path.display().to_string()));
 
@@ -903,6 +1278,7 @@ fn run_async_runtime(
 
                             // Load environments
                             load_environments(&path, &mut state, &update_tx).await;
+                            sync_cookie_jar(&mut state).await;
                         }
                         Err(e) => {
                             let _ = update_tx.send(UiUpdate::Error {
@@ -920,6 +1296,13 @@ fn run_async_runtime(
                     state.current_environment_index = None;
                     state.editing_environment = None;
                     state.editing_environment_index = None;
+                    state.cookies_per_environment = false;
+                    state.graphql_schemas.clear();
+                    state.workspace_proxy = None;
+                    http_client.set_proxy_resolver(proxy_resolver(&state));
+                    http_client.set_tls_config(TlsConfig::default());
+                    let _ = update_tx.send(UiUpdate::TlsWarnings(Vec::new()));
+                    sync_cookie_jar(&mut state).await;
 
                     let _ = update_tx.send(UiUpdate::WorkspacePath(String::new()));
                     let _ = update_tx.send(UiUpdate::CollectionItems(vec![]));
@@ -965,6 +1348,7 @@ fn run_async_runtime(
                                 state.query_params = tab.query_params.clone();
                                 state.request_headers = tab.headers.clone();
                                 state.auth_data = tab.auth.clone();
+                                restore_grpc_target(&mut state, &update_tx, tab.grpc.as_ref());
 
                                 let _ = update_tx.send(UiUpdate::LoadFullRequest {
                                     url: tab.url.clone(),
@@ -981,6 +1365,7 @@ fn run_async_runtime(
                             // Create a new tab for this request
                             // Try to parse as SavedRequest first, fall back to raw JSON for imported files
                             let parsed_request = from_json::<vortex_domain::persistence::SavedRequest>(&content);
+                            let grpc = parsed_request.as_ref().ok().and_then(|req| req.grpc.clone());
 
                             // If parsing fails, try to extract data directly from JSON (for old imports)
                             let (request_name, method_str, url, body, headers_map, query_params_map) = if let Ok(req) = &parsed_request {
@@ -994,7 +1379,7 @@ fn run_async_runtime(
 
                                 (
                                     req.name.clone(),
-                                    format!("{:?}", req.method).to_uppercase(),
+                                    req.method.to_string(),
                                     req.url.clone(),
                                     body_str,
                                     req.headers.clone(),
@@ -1027,16 +1412,8 @@ fn run_async_runtime(
                             };
 
                             {
-                                let method_index = match method_str.to_uppercase().as_str() {
-                                    "GET" => 0,
-                                    "POST" => 1,
-                                    "PUT" => 2,
-                                    "PATCH" => 3,
-                                    "DELETE" => 4,
-                                    "HEAD" => 5,
-                                    "OPTIONS" => 6,
-                                    _ => 0,
-                                };
+                                let method = method_str.parse().unwrap_or_default();
+                                let method_index = state.method_index(&method, &update_tx);
 
                                 let headers: Vec<HeaderData> = headers_map.iter()
                                     .map(|(k, v)| HeaderData { key: k.clone(), value: v.clone(), description: String::new(), enabled: true })
@@ -1057,6 +1434,7 @@ fn run_async_runtime(
                                     auth: AuthData::default(),
                                     has_unsaved_changes: false,
                                     file_path: Some(path_str),
+                                    grpc: grpc.clone(),
                                     // Response defaults
                                     response_state: 0,
                                     response_body: String::new(),
@@ -1065,6 +1443,8 @@ fn run_async_runtime(
                                     duration: String::new(),
                                     size: String::new(),
                                     response_headers: Vec::new(),
+                                    response_timing: Vec::new(),
+                                    response_redirects: Vec::new(),
                                     error_title: String::new(),
                                     error_message: String::new(),
                                 };
@@ -1077,6 +1457,7 @@ fn run_async_runtime(
                                 state.base_url = url.split('?').next().unwrap_or("").to_string();
                                 state.query_params = query_params.clone();
                                 state.request_headers = headers.clone();
+                                restore_grpc_target(&mut state, &update_tx, grpc.as_ref());
 
                                 let _ = update_tx.send(UiUpdate::LoadFullRequest {
                                     url,
@@ -1155,7 +1536,7 @@ fn run_async_runtime(
                     for tab in &mut state.tabs {
                         if tab.has_unsaved_changes
                             && let Some(ref file_path) = tab.file_path {
-                                let mut saved_request = build_saved_request_from_tab(tab);
+                                let mut saved_request = build_saved_request_from_tab(tab, &state.methods);
 
                                 // Try to preserve the original ID
                                 if let Ok(existing_content) = std::fs::read_to_string(file_path)
@@ -1195,6 +1576,7 @@ fn run_async_runtime(
                         state.current_environment_index = None;
                     }
                     let _ = update_tx.send(UiUpdate::CurrentEnvironmentIndex(index));
+                    sync_cookie_jar(&mut state).await;
 
                     // Update resolved URL preview
                     resolve_and_update_url(&state, &update_tx);
@@ -1271,6 +1653,7 @@ fn run_async_runtime(
                                                 (current_idx - 1) as i32,
                                             ));
                                         }
+                                    sync_cookie_jar(&mut state).await;
 
                                     // Update environment names
                                     let names: Vec<String> =
@@ -1390,6 +1773,7 @@ fn run_async_runtime(
                                 value: String::new(),
                                 enabled: true,
                                 secret: false,
+                                command: None,
                             },
                         );
 
@@ -1456,6 +1840,12 @@ fn run_async_runtime(
                     if let Some(ref mut editing_env) = state.editing_environment {
                         // Get the old key at this index using our tracked order
                         if let Some(old_key) = state.editing_variable_keys.get(index as usize).cloned() {
+                            // The editor doesn't show commands, keep the existing one
+                            let command = editing_env
+                                .variables
+                                .get(&old_key)
+                                .and_then(|var| var.command.clone());
+
                             // Remove old entry if name changed
                             if old_key != name {
                                 editing_env.variables.remove(&old_key);
@@ -1472,13 +1862,30 @@ fn run_async_runtime(
                                     value,
                                     enabled,
                                     secret: is_secret,
+                                    command,
                                 },
                             );
                         }
                     }
                 }
 
+                UiCommand::AddCustomMethod { name } => match name.parse::<HttpMethod>() {
+                    Ok(method) => {
+                        let index = state.method_index(&method, &update_tx);
+                        let _ = update_tx.send(UiUpdate::MethodIndex(index));
+                    }
+                    Err(e) => {
+                        let _ = update_tx.send(UiUpdate::Error {
+                            title: "Invalid method".to_string(),
+                            message: e.to_string(),
+                        });
+                    }
+                },
+
                 UiCommand::UrlChanged { url } => {
+                    let _ = update_tx.send(UiUpdate::WebSocketMode(is_websocket_url(&url)));
+                    let _ = update_tx.send(UiUpdate::GrpcMode(is_grpc_url(&url)));
+
                     // Skip re-parsing if we're updating from params (to avoid circular update)
                     if state.updating_url_from_params {
                         state.updating_url_from_params = false;
@@ -1572,19 +1979,23 @@ fn run_async_runtime(
                     let _ = update_tx.send(UiUpdate::ShowSettings(false));
                 }
 
+                UiCommand::SetProxySettings(proxy) => {
+                    state.proxy_settings = proxy.to_settings();
+                    http_client.set_proxy_resolver(proxy_resolver(&state));
+
+                    // Save settings
+                    let settings = state.to_settings();
+                    let settings_repo = SettingsRepository::new();
+                    if let Err(e) = settings_repo.save(&settings).await {
+                        eprintln!("Failed to save settings: {e}");
+                    }
+                }
+
                 // History commands (Sprint 04)
                 UiCommand::LoadHistoryItem { id } => {
                     if let Some(entry) = state.history.get(&id).cloned() {
                         // Load the request into the editor
-                        let method_index = match entry.method {
-                            vortex_domain::request::HttpMethod::Get => 0,
-                            vortex_domain::request::HttpMethod::Post => 1,
-                            vortex_domain::request::HttpMethod::Put => 2,
-                            vortex_domain::request::HttpMethod::Patch => 3,
-                            vortex_domain::request::HttpMethod::Delete => 4,
-                            vortex_domain::request::HttpMethod::Head => 5,
-                            vortex_domain::request::HttpMethod::Options => 6,
-                        };
+                        let method_index = state.method_index(&entry.method, &update_tx);
 
                         // Convert history headers to HeaderData
                         let headers: Vec<HeaderData> = entry.headers
@@ -1617,6 +2028,7 @@ fn run_async_runtime(
                             api_key_name: a.api_key_name.clone(),
                             api_key_value: a.api_key_value.clone(),
                             api_key_location: a.api_key_location,
+                            preserved: None,
                         }).unwrap_or_default();
 
                         // Update state
@@ -1624,6 +2036,7 @@ fn run_async_runtime(
                         state.request_headers = headers.clone();
                         state.query_params = query_params.clone();
                         state.auth_data = auth.clone();
+                        restore_grpc_target(&mut state, &update_tx, None);
 
                         // Send full request data to UI
                         let _ = update_tx.send(UiUpdate::LoadFullRequest {
@@ -1724,6 +2137,8 @@ fn run_async_runtime(
                 // Sprint 05: Authentication commands
                 UiCommand::AuthTypeChanged { auth_type } => {
                     state.auth_data.auth_type = auth_type;
+                    // Choosing an auth type replaces auth the editor cannot show
+                    state.auth_data.preserved = None;
                 }
 
                 UiCommand::BearerTokenChanged { token } => {
@@ -1747,7 +2162,7 @@ fn run_async_runtime(
                         && let Some(tab) = state.tabs.iter_mut().find(|t| &t.id == active_id) {
                             if let Some(ref file_path) = tab.file_path.clone() {
                                 // Build and save the request
-                                let mut saved_request = build_saved_request_from_tab(tab);
+                                let mut saved_request = build_saved_request_from_tab(tab, &state.methods);
 
                                 // Try to preserve the original ID if we can read the existing file
                                 if let Ok(existing_content) = std::fs::read_to_string(file_path)
@@ -1900,6 +2315,7 @@ fn run_async_runtime(
                         state.query_params = tab.query_params.clone();
                         state.request_headers = tab.headers.clone();
                         state.auth_data = tab.auth.clone();
+                        restore_grpc_target(&mut state, &update_tx, tab.grpc.as_ref());
 
                         let _ = update_tx.send(UiUpdate::LoadFullRequest {
                             url: tab.url.clone(),
@@ -1919,6 +2335,8 @@ fn run_async_runtime(
                             duration: tab.duration.clone(),
                             size: tab.size.clone(),
                             headers: tab.response_headers.clone(),
+                            timing: tab.response_timing.clone(),
+                            redirects: tab.response_redirects.clone(),
                             error_title: tab.error_title.clone(),
                             error_message: tab.error_message.clone(),
                         });
@@ -1944,6 +2362,7 @@ fn run_async_runtime(
                                 state.query_params = tab.query_params.clone();
                                 state.request_headers = tab.headers.clone();
                                 state.auth_data = tab.auth.clone();
+                                restore_grpc_target(&mut state, &update_tx, tab.grpc.as_ref());
 
                                 let _ = update_tx.send(UiUpdate::LoadFullRequest {
                                     url: tab.url.clone(),
@@ -1963,6 +2382,8 @@ fn run_async_runtime(
                                     duration: tab.duration.clone(),
                                     size: tab.size.clone(),
                                     headers: tab.response_headers.clone(),
+                                    timing: tab.response_timing.clone(),
+                                    redirects: tab.response_redirects.clone(),
                                     error_title: tab.error_title.clone(),
                                     error_message: tab.error_message.clone(),
                                 });
@@ -1986,6 +2407,7 @@ fn run_async_runtime(
                     state.query_params.clear();
                     state.request_headers.clear();
                     state.auth_data = AuthData::default();
+                    restore_grpc_target(&mut state, &update_tx, None);
 
                     let _ = update_tx.send(UiUpdate::LoadFullRequest {
                         url: String::new(),
@@ -2037,16 +2459,8 @@ fn run_async_runtime(
                         && let Ok(content) = tokio::fs::read_to_string(&path).await
                             && let Ok(request) = from_json::<vortex_domain::persistence::SavedRequest>(&content) {
                                 // Create new tab with this request
-                                let method_index = match request.method {
-                                    PersistenceHttpMethod::Get => 0,
-                                    PersistenceHttpMethod::Post => 1,
-                                    PersistenceHttpMethod::Put => 2,
-                                    PersistenceHttpMethod::Patch => 3,
-                                    PersistenceHttpMethod::Delete => 4,
-                                    PersistenceHttpMethod::Head => 5,
-                                    PersistenceHttpMethod::Options => 6,
-                                    PersistenceHttpMethod::Trace => 0,
-                                };
+                                let method_index =
+                                    state.method_index(&request.method.clone().into(), &update_tx);
 
                                 let body = request.body.as_ref().map(|b| match b {
                                     vortex_domain::persistence::PersistenceRequestBody::Json { content } => content.to_string(),
@@ -2073,6 +2487,7 @@ fn run_async_runtime(
                                     auth: persistence_auth_to_ui(request.auth.as_ref()),
                                     has_unsaved_changes: false,
                                     file_path: Some(path.display().to_string()),
+                                    grpc: request.grpc.clone(),
                                     // Response defaults
                                     response_state: 0,
                                     response_body: String::new(),
@@ -2081,6 +2496,8 @@ fn run_async_runtime(
                                     duration: String::new(),
                                     size: String::new(),
                                     response_headers: Vec::new(),
+                                    response_timing: Vec::new(),
+                                    response_redirects: Vec::new(),
                                     error_title: String::new(),
                                     error_message: String::new(),
                                 };
@@ -2093,6 +2510,7 @@ fn run_async_runtime(
                                 state.query_params = query_params.clone();
                                 state.request_headers = headers.clone();
                                 state.auth_data = persistence_auth_to_ui(request.auth.as_ref());
+                                restore_grpc_target(&mut state, &update_tx, request.grpc.as_ref());
 
                                 let _ = update_tx.send(UiUpdate::LoadFullRequest {
                                     url: request.url,
@@ -2210,6 +2628,166 @@ fn run_async_runtime(
                     }
                 }
 
+                // --- WebSocket Commands ---
+                UiCommand::WebSocketConnect { subprotocols, auto_reconnect } => {
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    connect_websocket(
+                        &websocket_client,
+                        &mut state,
+                        &update_tx,
+                        &cmd_tx,
+                        &subprotocols,
+                        auto_reconnect,
+                    );
+                }
+
+                UiCommand::GraphqlSubscribe {
+                    query,
+                    variables,
+                    operation_name,
+                    connection_params,
+                } => {
+                    let input = GraphqlSubscriptionInput {
+                        query,
+                        variables,
+                        operation_name: Some(operation_name).filter(|name| !name.trim().is_empty()),
+                        connection_params,
+                        ..GraphqlSubscriptionInput::default()
+                    };
+                    if !run_secret_commands(&secret_commands, &mut state, &update_tx).await {
+                        continue;
+                    }
+                    start_graphql_subscription(&websocket_client, &mut state, &update_tx, &cmd_tx, input);
+                }
+
+                UiCommand::GraphqlSubscriptionEvent { session, event } => {
+                    if session == state.websocket_session {
+                        handle_graphql_subscription_event(&mut state, &update_tx, event);
+                    }
+                }
+
+                UiCommand::WebSocketOpened { session, sender } => {
+                    if session == state.websocket_session {
+                        state.websocket_sender = Some(sender);
+                    } else {
+                        // Disconnected while the handshake was in flight
+                        let _ = sender.close();
+                    }
+                }
+
+                UiCommand::WebSocketEvent { session, event } => {
+                    if session == state.websocket_session {
+                        handle_websocket_event(&mut state, &update_tx, event);
+                    }
+                }
+
+                // Cookie manager commands
+                UiCommand::OpenCookieManager => {
+                    send_cookie_list(&mut state, &update_tx);
+                    let _ = update_tx.send(UiUpdate::ShowCookieManager(true));
+                }
+
+                UiCommand::DeleteCookie { index } => {
+                    if let Some(cookie) = usize::try_from(index)
+                        .ok()
+                        .and_then(|i| state.cookie_list.get(i))
+                        .cloned()
+                    {
+                        state.cookie_jar.update(|jar| jar.remove_cookie(&cookie));
+                        save_cookie_jar(&mut state).await;
+                    }
+                    send_cookie_list(&mut state, &update_tx);
+                }
+
+                UiCommand::SaveCookie { index, cookie } => {
+                    let domain = cookie.domain.trim_start_matches('.').to_lowercase();
+                    if !cookie.name.is_empty() && !domain.is_empty() {
+                        let existing = usize::try_from(index)
+                            .ok()
+                            .and_then(|i| state.cookie_list.get(i))
+                            .cloned();
+                        let path = if cookie.path.is_empty() {
+                            "/".to_string()
+                        } else {
+                            cookie.path
+                        };
+                        // Editing keeps the expiry and flags of the original cookie
+                        let updated = match &existing {
+                            Some(old) => Cookie {
+                                name: cookie.name,
+                                value: cookie.value,
+                                domain,
+                                path,
+                                ..old.clone()
+                            },
+                            None => Cookie::new(cookie.name, cookie.value, domain).with_path(path),
+                        };
+
+                        state.cookie_jar.update(|jar| {
+                            if let Some(old) = &existing {
+                                jar.remove_cookie(old);
+                            }
+                            jar.add(updated);
+                        });
+                        save_cookie_jar(&mut state).await;
+                    }
+                    send_cookie_list(&mut state, &update_tx);
+                }
+
+                UiCommand::ClearCookies => {
+                    state.cookie_jar.update(CookieJar::clear);
+                    save_cookie_jar(&mut state).await;
+                    send_cookie_list(&mut state, &update_tx);
+                }
+
+                UiCommand::SetCookiesPerEnvironment(enabled) => {
+                    if let Some(ws_path) = state.workspace_path.clone() {
+                        let result = match workspace_repo.load(&ws_path).await {
+                            Ok(mut manifest) => {
+                                manifest.cookies_per_environment = enabled;
+                                workspace_repo.save(&ws_path, &manifest).await
+                            }
+                            Err(e) => Err(e),
+                        };
+
+                        match result {
+                            Ok(()) => {
+                                state.cookies_per_environment = enabled;
+                                sync_cookie_jar(&mut state).await;
+                            }
+                            Err(e) => {
+                                let _ = update_tx.send(UiUpdate::Error {
+                                    title: "Failed to update cookie settings".to_string(),
+                                    message: e.to_string(),
+                                });
+                            }
+                        }
+                    }
+                    send_cookie_list(&mut state, &update_tx);
+                }
+
+                UiCommand::WebSocketDisconnect => {
+                    if let Some(sender) = state.websocket_sender.take() {
+                        let _ = sender.close();
+                    } else if state.websocket_state.is_connecting() {
+                        // Abandon the pending handshake
+                        state.websocket_session += 1;
+                        let info = ConnectionInfo::new(&state.current_url);
+                        update_websocket_status(&mut state, &update_tx, &info);
+                    }
+                }
+
+                UiCommand::WebSocketSend { content, binary } => {
+                    send_websocket_message(&mut state, &update_tx, &content, binary);
+                }
+
+                UiCommand::WebSocketClearLog => {
+                    state.websocket_log.clear();
+                    let _ = update_tx.send(UiUpdate::WebSocketMessages(Vec::new()));
+                }
+
                 UiCommand::RefreshTree => {
                     // Refresh the collection tree
                     if let Some(ref ws_path) = state.workspace_path {
@@ -2262,8 +2840,8 @@ fn run_async_runtime(
                     let tx = update_tx.clone();
                     std::thread::spawn(move || {
                         if let Some(path) = rfd::FileDialog::new()
-                            .set_title("Select Postman File to Import")
-                            .add_filter("JSON files", &["json"])
+                            .set_title("Select File to Import")
+                            .add_filter("Collections and specifications", &["json", "yaml", "yml"])
                             .pick_file()
                         {
                             let _ = tx.send(UiUpdate::ImportFileSelected {
@@ -2304,12 +2882,16 @@ fn run_async_runtime(
                                     }
                                 };
 
-                                let importer = PostmanImporter::new();
-
                                 // Send initial progress
                                 let _ = tx.send(UiUpdate::ImportProgress(0.1));
 
-                                match importer.import_collection(&content, &ws) {
+                                let result = if OpenApiImporter::is_spec(&content) {
+                                    OpenApiImporter::new().import_collection(&content, &ws)
+                                } else {
+                                    PostmanImporter::new().import_collection(&content, &ws)
+                                };
+
+                                match result {
                                     Ok(result) => {
                                         let _ = tx.send(UiUpdate::ImportProgress(1.0));
                                         let _ = tx.send(UiUpdate::ImportDialogComplete {
@@ -2354,11 +2936,15 @@ fn run_async_runtime(
                                 }
                             };
 
-                            // Create importer
-                            let importer = PostmanImporter::new();
+                            // Validate and preview with the importer matching the file
+                            let (validation, preview) = if OpenApiImporter::is_spec(&content) {
+                                let importer = OpenApiImporter::new();
+                                (importer.validate_file(&content), importer.preview(&content))
+                            } else {
+                                let importer = PostmanImporter::new();
+                                (importer.validate_file(&content), importer.preview(&content))
+                            };
 
-                            // Validate
-                            let validation = importer.validate_file(&content);
                             if !validation.is_valid {
                                 let _ = tx.send(UiUpdate::ImportError {
                                     message: validation.issues.join(", "),
@@ -2366,8 +2952,7 @@ fn run_async_runtime(
                                 return;
                             }
 
-                            // Preview
-                            match importer.preview(&content) {
+                            match preview {
                                 Ok(preview) => {
                                     let warnings: Vec<ImportWarningData> = preview.warnings.iter()
                                         .map(|w| ImportWarningData {
@@ -2405,6 +2990,37 @@ fn run_async_runtime(
     });
 }
 
+/// Runs the command variables of the current environment that have not run
+/// yet this session, so that resolution can use their values.
+///
+/// Returns false after reporting a failing command as a request error.
+async fn run_secret_commands(
+    secret_commands: &ResolveSecretCommands<ShellSecretCommandRunner>,
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+) -> bool {
+    let mut context = state.build_resolution_context();
+    let pending = context.secret_commands();
+    if pending.is_empty() {
+        return true;
+    }
+
+    if let Err(e) = secret_commands.execute(&mut context).await {
+        let _ = update_tx.send(UiUpdate::State(RequestState::error(
+            RequestErrorKind::Unknown,
+            e.to_string(),
+        )));
+        return false;
+    }
+    for (name, command) in pending {
+        if let Some(value) = context.secrets.remove(&name) {
+            state.secret_command_values.insert(command, value);
+        }
+    }
+    resolve_and_update_url(state, update_tx);
+    true
+}
+
 /// Resolves variables in the current URL and sends the update.
 fn resolve_and_update_url(state: &AppState, update_tx: &mpsc::UnboundedSender<UiUpdate>) {
     if state.current_url.is_empty() {
@@ -2511,41 +3127,33 @@ async fn load_environments(
     }
 }
 
-/// Handles the `SendRequest` command.
-/// Result of a request execution for history tracking and tab state saving.
-struct RequestResult {
-    method: HttpMethod,
-    url: String,
-    status_code: Option<u16>,
-    duration_ms: Option<u64>,
-    // Response data for tab state
-    response_state: i32, // 2=Success, 3=Error
-    response_body: String,
-    status_text: String,
-    duration_display: String,
-    size_display: String,
-    response_headers: Vec<crate::bridge::ResponseHeaderData>,
-    error_title: String,
-    error_message: String,
-    // Request body for history
-    request_body: String,
-}
-
+/// Handles the `SendRequest` and `SendRequestToFile` commands.
+///
+/// The request runs in the background; its outcome comes back as
+/// `UiCommand::RequestFinished`. With `download` the body is saved to a file.
 async fn handle_send_request(
     ui_weak: &slint::Weak<MainWindow>,
-    execute_request: &ExecuteRequest<ReqwestHttpClient>,
+    execute_request: &Arc<ExecuteRequest<ReqwestHttpClient>>,
     update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    cmd_tx: &mpsc::UnboundedSender<UiCommand>,
     current_cancel: &mut Option<CancellationToken>,
-    state: &AppState,
-) -> Option<RequestResult> {
+    state: &mut AppState,
+    download: Option<DownloadOptions>,
+) {
     // Get current request data from UI
-    let (data_tx, mut data_rx) = tokio::sync::oneshot::channel::<(String, i32, String)>();
+    let (data_tx, mut data_rx) =
+        tokio::sync::oneshot::channel::<(String, i32, String, Option<GraphqlEditorBody>)>();
 
     let _ = ui_weak.upgrade_in_event_loop(move |ui| {
         let url = ui.get_url().to_string();
         let method_index = ui.get_method_index();
         let body = ui.get_request_body().to_string();
-        let _ = data_tx.send((url, method_index, body));
+        let graphql = ui.get_graphql_mode().then(|| GraphqlEditorBody {
+            query: ui.get_graphql_query().to_string(),
+            variables: ui.get_graphql_variables().to_string(),
+            operation_name: ui.get_graphql_operation_name().to_string(),
+        });
+        let _ = data_tx.send((url, method_index, body, graphql));
     });
 
     // Wait a bit for the UI to respond
@@ -2554,199 +3162,1305 @@ async fn handle_send_request(
         .ok()
         .and_then(std::result::Result::ok);
 
-    if let Some((url, method_index, body)) = request_data {
+    if let Some((url, method_index, body, graphql)) = request_data {
         // Resolve variables in URL and body
         let context = state.build_resolution_context();
         let mut resolver = VariableResolver::new(context);
+        // Command output is a secret, so it never reaches the history
+        let command_values: Vec<String> = state.secret_command_values.values().cloned().collect();
 
         let resolved_url = resolver.resolve(&url).resolved;
         let resolved_body = resolver.resolve(&body).resolved;
 
         // Create request spec
-        let method = match method_index {
-            0 => HttpMethod::Get,
-            1 => HttpMethod::Post,
-            2 => HttpMethod::Put,
-            3 => HttpMethod::Patch,
-            4 => HttpMethod::Delete,
-            5 => HttpMethod::Head,
-            6 => HttpMethod::Options,
-            _ => HttpMethod::Get,
-        };
+        let method = method_at(&state.methods, method_index);
 
         // Save body for history before it's moved
-        let request_body_for_history = resolved_body.clone();
-        let request_body = if method.has_body() && !resolved_body.is_empty() {
-            RequestBody::json(resolved_body)
-        } else {
-            RequestBody::none()
+        let mut request_body_for_history = resolved_body.clone();
+        let request_body = match graphql {
+            Some(graphql) if method.has_body() => {
+                let query = resolver.resolve(&graphql.query).resolved;
+                let variables = resolver.resolve(&graphql.variables).resolved;
+                let operation_name =
+                    Some(graphql.operation_name).filter(|name| !name.trim().is_empty());
+
+                // Check the query against the cached schema before sending
+                if let Some(schema) = graphql_schema(state, &resolved_url).await {
+                    let errors = graphql::validate(&query, operation_name.as_deref(), schema);
+                    if !errors.is_empty() {
+                        let details = errors
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        let _ = update_tx.send(UiUpdate::State(RequestState::error_with_details(
+                            RequestErrorKind::InvalidBody,
+                            "The GraphQL query does not match the schema",
+                            details,
+                        )));
+                        return;
+                    }
+                }
+
+                let body = RequestBody::graphql(query, variables, operation_name);
+                request_body_for_history = body.encoded().unwrap_or_default();
+                body
+            }
+            _ if method.has_body() && !resolved_body.is_empty() => RequestBody::json(resolved_body),
+            _ => RequestBody::none(),
         };
 
         let mut request = RequestSpec::new("UI Request");
-        request.method = method;
+        request.method = method.clone();
         request.url = resolved_url.clone();
         request.body = request_body;
+        request.download = download;
+
+        // Update UI to loading state, which also covers fetching OAuth2 tokens
+        let _ = update_tx.send(UiUpdate::State(RequestState::loading()));
+
+        if let Err(message) = add_headers_and_auth(&mut request, state, &mut resolver).await {
+            let _ = update_tx.send(UiUpdate::State(RequestState::error(
+                RequestErrorKind::Unknown,
+                message,
+            )));
+            return;
+        }
 
-        // Sprint 05: Add custom headers
-        for header in &state.request_headers {
-            if header.enabled && !header.key.is_empty() {
-                let resolved_value = resolver.resolve(&header.value).resolved;
+        // Create cancellation token
+        let (cancel_token, cancel_receiver) = CancellationToken::new();
+        *current_cancel = Some(cancel_token);
+
+        // Execute in the background, so that streams can be cancelled
+        let execute_request = Arc::clone(execute_request);
+        let update_tx = update_tx.clone();
+        let cmd_tx = cmd_tx.clone();
+        let tab_id = state.active_tab_id.clone();
+        tokio::spawn(async move {
+            let (stream_tx, stream_rx) = mpsc::unbounded_channel();
+            let progress = tokio::spawn(show_response_progress(stream_rx, update_tx.clone()));
+            let result = execute_request
+                .execute_streaming(&request, cancel_receiver, stream_tx)
+                .await;
+            let _ = progress.await;
+
+            let result = request_result(
+                result,
+                &update_tx,
+                method,
+                mask_secrets(&resolved_url, &command_values),
+                mask_secrets(&request_body_for_history, &command_values),
+            );
+            let _ = cmd_tx.send(UiCommand::RequestFinished(Box::new(RequestResult {
+                tab_id,
+                ..result
+            })));
+        });
+    }
+}
+
+/// Shows a response in the response panel while it arrives.
+///
+/// Server-Sent Events and NDJSON lines are listed with their arrival time;
+/// other bodies grow as chunks arrive.
+async fn show_response_progress(
+    mut updates: mpsc::UnboundedReceiver<ResponseStreamEvent>,
+    update_tx: mpsc::UnboundedSender<UiUpdate>,
+) {
+    let started = std::time::Instant::now();
+    let mut response = ResponseSpec::default();
+    while let Some(update) = updates.recv().await {
+        match update {
+            ResponseStreamEvent::Started { status, headers } => {
+                response = ResponseSpec::new(status, headers, Vec::new(), started.elapsed());
+                let _ = update_tx.send(UiUpdate::ResponseHeaders(response_header_data(&response)));
+            }
+            ResponseStreamEvent::Event(event) => {
+                response.size += event.data.len();
+                push_stream_event(&mut response.body, &event);
+            }
+            ResponseStreamEvent::Chunk(text) => {
+                response.size += text.len();
+                response.body.push_str(&text);
+            }
+            ResponseStreamEvent::Progress(progress) => {
+                response.size = usize::try_from(progress.received).unwrap_or(usize::MAX);
+                response.body = format!("Downloading... {}", progress.display());
+            }
+        }
+        response.duration = started.elapsed();
+        let _ = update_tx.send(UiUpdate::LiveResponse(LiveResponseData {
+            status_code: i32::from(response.status),
+            status_text: response.status_text.clone(),
+            body: response.body.clone(),
+            duration: response.duration_display(),
+            size: response.size_display(),
+            streaming: true,
+        }));
+    }
+}
+
+/// Shows the outcome of a request and collects it for history and tab state.
+fn request_result(
+    result: ExecuteResult,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    method: HttpMethod,
+    url: String,
+    request_body: String,
+) -> RequestResult {
+    // Extract response data for history and tab state
+    let (
+        status_code,
+        duration_ms,
+        response_state,
+        response_body,
+        status_text,
+        duration_display,
+        size_display,
+        response_headers,
+        error_title,
+        error_message,
+    ) = match &result {
+        Ok(response) => (
+            Some(response.status),
+            Some(response.duration.as_millis() as u64),
+            2, // Success
+            response_body_text(response),
+            response.status_text.clone(),
+            response.duration_display(),
+            response.size_display(),
+            response_header_data(response),
+            String::new(),
+            String::new(),
+        ),
+        Err(e) => {
+            let (title, message) = ("Request Failed".to_string(), e.to_string());
+            (
+                None,
+                None,
+                3, // Error
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                Vec::new(),
+                title,
+                message,
+            )
+        }
+    };
+
+    let response_file = result
+        .as_ref()
+        .ok()
+        .and_then(|response| response.saved_to.clone());
+    let timing = result
+        .as_ref()
+        .map(|response| response.timing)
+        .unwrap_or_default();
+    let redirects = result.as_ref().map(redirect_hop_data).unwrap_or_default();
+
+    // Send response headers, timing and redirects to UI
+    let _ = update_tx.send(UiUpdate::ResponseHeaders(response_headers.clone()));
+    let _ = update_tx.send(UiUpdate::ResponseTiming(timing_phase_data(&timing)));
+    let _ = update_tx.send(UiUpdate::ResponseRedirects(redirects.clone()));
+
+    // Update UI with result
+    let _ = update_tx.send(UiUpdate::State(result.to_request_state()));
+
+    RequestResult {
+        tab_id: None,
+        method,
+        url,
+        status_code,
+        duration_ms,
+        response_state,
+        response_body,
+        status_text,
+        duration_display,
+        size_display,
+        response_headers,
+        error_title,
+        error_message,
+        request_body,
+        response_file,
+        timing,
+        redirects,
+    }
+}
+
+/// Returns the redirect chain of a response for the redirects panel.
+fn redirect_hop_data(response: &ResponseSpec) -> Vec<RedirectHopData> {
+    response
+        .redirects
+        .iter()
+        .map(|hop| RedirectHopData {
+            status: i32::from(hop.status),
+            url: hop.url.clone(),
+            location: hop.location.clone(),
+            duration: format!("{} ms", hop.timing.total().as_millis()),
+        })
+        .collect()
+}
+
+/// Returns the timing waterfall of a response for the timing panel.
+#[allow(clippy::cast_possible_truncation)]
+fn timing_phase_data(timing: &ResponseTiming) -> Vec<TimingPhaseData> {
+    let total = timing.total().as_secs_f64();
+    let fraction = |duration: Duration| {
+        if total > 0.0 {
+            (duration.as_secs_f64() / total) as f32
+        } else {
+            0.0
+        }
+    };
+    timing
+        .waterfall()
+        .into_iter()
+        .map(|(phase, start, duration)| TimingPhaseData {
+            label: phase.label().to_string(),
+            duration: format!("{:.1} ms", duration.as_secs_f64() * 1000.0),
+            offset: fraction(start),
+            width: fraction(duration),
+        })
+        .collect()
+}
+
+/// Returns the response headers for the headers panel.
+fn response_header_data(response: &ResponseSpec) -> Vec<ResponseHeaderData> {
+    response
+        .headers_map
+        .iter()
+        .map(|(name, value)| ResponseHeaderData {
+            name: name.clone(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+/// Returns the body to show for a response: its events with their arrival
+/// time for streams, the body text otherwise.
+///
+/// For downloads a note on where the body was saved follows the preview.
+fn response_body_text(response: &ResponseSpec) -> String {
+    let mut text = if response.events.is_empty() {
+        response.body_as_string_lossy()
+    } else {
+        format_stream_events(&response.events)
+    };
+    if let Some(path) = &response.saved_to {
+        if !text.is_empty() {
+            text.push_str("\n\n");
+        }
+        if response.body_truncated {
+            let _ = write!(
+                text,
+                "// Body truncated: showing {} of {}",
+                format_bytes(response.body_bytes.len() as u64),
+                response.size_display()
+            );
+            text.push('\n');
+        }
+        let _ = write!(text, "// Saved to {}", path.display());
+    }
+    text
+}
+
+/// Returns a file name for saving the response of a URL: its last path
+/// segment, or `response` if there is none.
+fn download_file_name(url: &str) -> String {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.split("://").last())
+        .and_then(|path| path.split_once('/'))
+        .and_then(|(_, path)| path.rsplit('/').find(|segment| !segment.is_empty()))
+        .unwrap_or("response")
+        .to_string()
+}
+
+/// Formats stream events as a log.
+fn format_stream_events(events: &[StreamEvent]) -> String {
+    let mut log = String::new();
+    for event in events {
+        push_stream_event(&mut log, event);
+    }
+    log
+}
+
+/// Appends an event to a stream log, after a comment line giving its
+/// arrival time and, for Server-Sent Events, its type and ID.
+fn push_stream_event(log: &mut String, event: &StreamEvent) {
+    if !log.is_empty() {
+        log.push_str("\n\n");
+    }
+    let time = event.received_at.with_timezone(&chrono::Local);
+    let _ = write!(log, "// {}", time.format("%H:%M:%S%.3f"));
+    if let Some(name) = &event.event {
+        let _ = write!(log, " {name}");
+    }
+    if let Some(id) = &event.id {
+        let _ = write!(log, " #{id}");
+    }
+    log.push('\n');
+    log.push_str(&event.data);
+}
+
+/// Adds the editor headers and authentication to a request.
+///
+/// # Errors
+///
+/// Returns a message if the saved auth cannot be sent from the desktop app.
+async fn add_headers_and_auth(
+    request: &mut RequestSpec,
+    state: &AppState,
+    resolver: &mut VariableResolver,
+) -> Result<(), String> {
+    // Sprint 05: Add custom headers
+    for header in &state.request_headers {
+        if header.enabled && !header.key.is_empty() {
+            let resolved_value = resolver.resolve(&header.value).resolved;
+            request.headers.add(vortex_domain::request::Header::new(
+                header.key.clone(),
+                resolved_value,
+            ));
+        }
+    }
+
+    // Sprint 05: Add authentication headers
+    match state.auth_data.auth_type {
+        0 => {
+            // Saved auth the editor cannot show
+            if let Some(auth) = &state.auth_data.preserved {
+                apply_saved_auth(request, auth, resolver, &state.auth_provider).await?;
+            }
+        }
+        1 => {
+            // Bearer token
+            if !state.auth_data.bearer_token.is_empty() {
+                let resolved_token = resolver.resolve(&state.auth_data.bearer_token).resolved;
                 request.headers.add(vortex_domain::request::Header::new(
-                    header.key.clone(),
-                    resolved_value,
+                    "Authorization",
+                    format!("Bearer {resolved_token}"),
                 ));
             }
         }
-
-        // Sprint 05: Add authentication headers
-        match state.auth_data.auth_type {
-            1 => {
-                // Bearer token
-                if !state.auth_data.bearer_token.is_empty() {
-                    let resolved_token = resolver.resolve(&state.auth_data.bearer_token).resolved;
+        2 => {
+            // Basic auth
+            if !state.auth_data.basic_username.is_empty() {
+                let resolved_username = resolver.resolve(&state.auth_data.basic_username).resolved;
+                let resolved_password = resolver.resolve(&state.auth_data.basic_password).resolved;
+                let credentials = format!("{resolved_username}:{resolved_password}");
+                use base64::Engine;
+                let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
+                request.headers.add(vortex_domain::request::Header::new(
+                    "Authorization",
+                    format!("Basic {encoded}"),
+                ));
+            }
+        }
+        3 => {
+            // API Key
+            if !state.auth_data.api_key_name.is_empty() && !state.auth_data.api_key_value.is_empty()
+            {
+                let resolved_value = resolver.resolve(&state.auth_data.api_key_value).resolved;
+                if state.auth_data.api_key_location == 0 {
+                    // Header
                     request.headers.add(vortex_domain::request::Header::new(
-                        "Authorization",
-                        format!("Bearer {resolved_token}"),
+                        state.auth_data.api_key_name.clone(),
+                        resolved_value,
                     ));
+                } else {
+                    // Query param - append to URL
+                    let separator = if request.url.contains('?') { "&" } else { "?" };
+                    request.url = format!(
+                        "{}{}{}={}",
+                        request.url, separator, state.auth_data.api_key_name, resolved_value
+                    );
                 }
             }
-            2 => {
-                // Basic auth
-                if !state.auth_data.basic_username.is_empty() {
-                    let resolved_username =
-                        resolver.resolve(&state.auth_data.basic_username).resolved;
-                    let resolved_password =
-                        resolver.resolve(&state.auth_data.basic_password).resolved;
-                    let credentials = format!("{resolved_username}:{resolved_password}");
-                    use base64::Engine;
-                    let encoded = base64::engine::general_purpose::STANDARD.encode(credentials);
-                    request.headers.add(vortex_domain::request::Header::new(
-                        "Authorization",
-                        format!("Basic {encoded}"),
-                    ));
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Applies saved auth the editor cannot show, with variables resolved.
+///
+/// # Errors
+///
+/// Returns a message if signing failed or no `OAuth2` token could be obtained.
+async fn apply_saved_auth(
+    request: &mut RequestSpec,
+    auth: &PersistenceAuth,
+    resolver: &VariableResolver,
+    auth_provider: &OAuth2Provider,
+) -> Result<(), String> {
+    let mut unresolved = Vec::new();
+    let config = ResolveVariables::new(resolver.context().clone())
+        .resolve_auth(&AuthConfig::from(auth), &mut unresolved);
+
+    // AWS and Digest requests are signed by the HTTP client once they are final
+    if config.signs_request() {
+        request.auth = config;
+        return Ok(());
+    }
+    match auth_provider.resolve(&config).await {
+        AuthResolution::None => {}
+        AuthResolution::Header { name, value } => {
+            request
+                .headers
+                .add(vortex_domain::request::Header::new(name, value));
+        }
+        AuthResolution::QueryParam { name, value } => {
+            request
+                .query_params
+                .add(vortex_domain::request::QueryParam::new(name, value));
+        }
+        AuthResolution::Pending { message } => {
+            return Err(format!("Authentication requires interaction: {message}"));
+        }
+        AuthResolution::Failed { error } => {
+            return Err(format!("Authentication failed: {error}"));
+        }
+    }
+    Ok(())
+}
+
+/// GraphQL body fields read from the request editor.
+struct GraphqlEditorBody {
+    query: String,
+    variables: String,
+    operation_name: String,
+}
+
+/// Returns the GraphQL endpoint of a URL, which keys the schema cache.
+fn graphql_endpoint(url: &str) -> &str {
+    url.split(['?', '#']).next().unwrap_or_default()
+}
+
+/// Returns the schema of a GraphQL endpoint, loading it from the workspace
+/// cache if it was not fetched in this session.
+async fn graphql_schema<'a>(state: &'a mut AppState, url: &str) -> Option<&'a GraphqlSchema> {
+    let endpoint = graphql_endpoint(url);
+    if !state.graphql_schemas.contains_key(endpoint) {
+        let workspace = state.workspace_path.as_ref()?;
+        let schema_repo = FileGraphqlSchemaRepository::new(TokioFileSystem);
+        match schema_repo.load(workspace, endpoint).await {
+            Ok(Some(schema)) => {
+                state.graphql_schemas.insert(endpoint.to_string(), schema);
+            }
+            Ok(None) => return None,
+            Err(e) => {
+                eprintln!("Failed to load GraphQL schema: {e}");
+                return None;
+            }
+        }
+    }
+    state.graphql_schemas.get(endpoint)
+}
+
+/// Downloads the schema of a GraphQL endpoint and caches it in the workspace.
+async fn fetch_graphql_schema(
+    url: &str,
+    http_client: &Arc<ReqwestHttpClient>,
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+) {
+    let mut resolver = VariableResolver::new(state.build_resolution_context());
+    let mut request = RequestSpec::new("GraphQL Schema");
+    request.url = resolver.resolve(url).resolved;
+    if let Err(message) = add_headers_and_auth(&mut request, state, &mut resolver).await {
+        let _ = update_tx.send(UiUpdate::GraphqlSchemaStatus(format!(
+            "Schema fetch failed: {message}"
+        )));
+        return;
+    }
+
+    let _ = update_tx.send(UiUpdate::GraphqlSchemaStatus(
+        "Fetching schema...".to_string(),
+    ));
+    let output = match FetchGraphqlSchema::new(Arc::clone(http_client))
+        .execute(&request)
+        .await
+    {
+        Ok(output) => output,
+        Err(e) => {
+            let _ = update_tx.send(UiUpdate::GraphqlSchemaStatus(format!(
+                "Schema fetch failed: {e}"
+            )));
+            return;
+        }
+    };
+
+    let endpoint = graphql_endpoint(&request.url).to_string();
+    let mut status = format!("Schema: {} types", output.schema.type_count());
+    if let Some(workspace) = &state.workspace_path {
+        let schema_repo = FileGraphqlSchemaRepository::new(TokioFileSystem);
+        match schema_repo
+            .save(workspace, &endpoint, &output.introspection)
+            .await
+        {
+            Ok(()) => status.push_str(" (cached)"),
+            Err(e) => eprintln!("Failed to cache GraphQL schema: {e}"),
+        }
+    }
+    state.graphql_schemas.insert(endpoint, output.schema);
+    let _ = update_tx.send(UiUpdate::GraphqlSchemaStatus(status));
+}
+
+/// Splits the comma-separated `.proto` files field into paths.
+fn parse_proto_files(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Returns the enabled request headers as gRPC metadata, with variables resolved.
+fn grpc_metadata(state: &AppState, resolver: &mut VariableResolver) -> Vec<(String, String)> {
+    state
+        .request_headers
+        .iter()
+        .filter(|h| h.enabled && !h.key.is_empty())
+        .map(|h| (h.key.clone(), resolver.resolve(&h.value).resolved))
+        .collect()
+}
+
+/// Returns the method picker label of a method.
+fn grpc_method_label(service: &str, method: &str) -> String {
+    format!("{service}/{method}")
+}
+
+/// Shows the gRPC settings of a loaded request.
+///
+/// The method list is reset to the saved method until methods are loaded again.
+fn restore_grpc_target(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    grpc: Option<&PersistenceGrpc>,
+) {
+    state.grpc_target = grpc.cloned().unwrap_or_default();
+    state.grpc_services.clear();
+
+    let target = &state.grpc_target;
+    let (methods, selected) = if target.method.is_empty() {
+        (Vec::new(), -1)
+    } else {
+        (vec![grpc_method_label(&target.service, &target.method)], 0)
+    };
+    let _ = update_tx.send(UiUpdate::GrpcProtoFiles(target.proto_files.join(", ")));
+    let _ = update_tx.send(UiUpdate::GrpcMethods {
+        methods,
+        selected,
+        status: String::new(),
+    });
+}
+
+/// Lists the methods of the current endpoint from its `.proto` files or server reflection.
+async fn load_grpc_methods(
+    client: &dyn GrpcClient,
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+) {
+    let mut resolver = VariableResolver::new(state.build_resolution_context());
+    let endpoint = resolver.resolve(state.current_url.trim()).resolved;
+    let metadata = grpc_metadata(state, &mut resolver);
+    let workspace = state.workspace_path.clone().unwrap_or_default();
+    let source = state.grpc_target.source(&workspace);
+
+    let _ = update_tx.send(UiUpdate::GrpcStatus("Loading methods...".to_string()));
+    let services = match client.list_services(&endpoint, &source, &metadata).await {
+        Ok(services) => services,
+        Err(error) => {
+            let _ = update_tx.send(UiUpdate::GrpcStatus(error.to_string()));
+            return;
+        }
+    };
+
+    let target = &state.grpc_target;
+    let mut methods = Vec::new();
+    let mut selected = -1;
+    for service in &services {
+        for method in &service.methods {
+            if service.name == target.service && method.name == target.method {
+                selected = i32::try_from(methods.len()).unwrap_or(-1);
+            }
+            methods.push(grpc_method_label(&service.name, &method.name));
+        }
+    }
+    let status = format!("{} methods in {} services", methods.len(), services.len());
+    state.grpc_services = services;
+
+    let _ = update_tx.send(UiUpdate::GrpcMethods {
+        methods,
+        selected,
+        status,
+    });
+}
+
+/// Selects a method from the loaded list and offers its message template.
+fn select_grpc_method(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    index: i32,
+) {
+    let Some(index) = usize::try_from(index).ok() else {
+        return;
+    };
+    let Some((service, method)) = state
+        .grpc_services
+        .iter()
+        .flat_map(|service| service.methods.iter().map(move |method| (service, method)))
+        .nth(index)
+    else {
+        return;
+    };
+
+    state.grpc_target.service.clone_from(&service.name);
+    state.grpc_target.method.clone_from(&method.name);
+    let _ = update_tx.send(UiUpdate::GrpcMessageTemplate(method.input_template.clone()));
+}
+
+/// Converts a gRPC error to the request error shown in the response panel.
+fn grpc_error_state(error: &GrpcError) -> RequestState {
+    let kind = match error {
+        GrpcError::InvalidUrl(_) => RequestErrorKind::InvalidUrl,
+        GrpcError::ConnectionFailed(_) => RequestErrorKind::ConnectionFailed,
+        GrpcError::UnknownService(_)
+        | GrpcError::UnknownMethod(_)
+        | GrpcError::InvalidMessage(_) => RequestErrorKind::InvalidBody,
+        GrpcError::Schema(_) => RequestErrorKind::Unknown,
+    };
+    RequestState::error(kind, error.to_string())
+}
+
+/// Calls the selected gRPC method on the current URL.
+///
+/// The message text is one JSON object, or an array of objects for a client
+/// stream. Enabled request headers are sent as metadata. The call runs in the
+/// background and updates the response panel as messages arrive; the returned
+/// token cancels it.
+fn invoke_grpc(
+    client: &Arc<dyn GrpcClient>,
+    state: &AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    message: &str,
+) -> Option<CancellationToken> {
+    let target = &state.grpc_target;
+    if target.method.is_empty() {
+        let _ = update_tx.send(UiUpdate::State(RequestState::error(
+            RequestErrorKind::InvalidBody,
+            "Select a gRPC method first",
+        )));
+        return None;
+    }
+
+    let mut resolver = VariableResolver::new(state.build_resolution_context());
+    let messages = match split_messages(&resolver.resolve(message).resolved) {
+        Ok(messages) => messages,
+        Err(error) => {
+            let _ = update_tx.send(UiUpdate::State(grpc_error_state(&error)));
+            return None;
+        }
+    };
+    let workspace = state.workspace_path.clone().unwrap_or_default();
+    let call = GrpcCall {
+        endpoint: resolver.resolve(state.current_url.trim()).resolved,
+        source: target.source(&workspace),
+        service: target.service.clone(),
+        method: target.method.clone(),
+        metadata: grpc_metadata(state, &mut resolver),
+        messages,
+    };
+    let streaming = state
+        .grpc_services
+        .iter()
+        .find(|service| service.name == call.service)
+        .and_then(|service| service.method(&call.method))
+        .is_some_and(|method| method.kind.is_server_streaming());
+
+    let _ = update_tx.send(UiUpdate::State(RequestState::loading()));
+    let (cancel_token, mut cancel_receiver) = CancellationToken::new();
+
+    let client = Arc::clone(client);
+    let update_tx = update_tx.clone();
+    tokio::spawn(async move {
+        let started = std::time::Instant::now();
+        let mut session = match client.invoke(&call).await {
+            Ok(session) => session,
+            Err(error) => {
+                let _ = update_tx.send(UiUpdate::State(grpc_error_state(&error)));
+                return;
+            }
+        };
+        session.close_send();
+
+        let mut response = ResponseSpec::default();
+        let mut headers = Vec::new();
+        loop {
+            let event = tokio::select! {
+                event = session.next_event() => event,
+                () = cancel_receiver.cancelled() => {
+                    let _ = update_tx.send(UiUpdate::State(RequestState::error(
+                        RequestErrorKind::Cancelled,
+                        "Request cancelled",
+                    )));
+                    return;
                 }
+            };
+            let Some(event) = event else {
+                break;
+            };
+
+            let status = match event {
+                GrpcEvent::Headers(metadata) => {
+                    headers.extend(metadata);
+                    continue;
+                }
+                GrpcEvent::Message {
+                    content,
+                    received_at,
+                } => {
+                    response.size += content.len();
+                    if !response.body.is_empty() {
+                        response.body.push_str("\n\n");
+                    }
+                    if streaming {
+                        let time = received_at.with_timezone(&chrono::Local);
+                        let _ = writeln!(response.body, "// {}", time.format("%H:%M:%S%.3f"));
+                    }
+                    response.body.push_str(&content);
+                    None
+                }
+                GrpcEvent::Finished { status, trailers } => {
+                    headers.extend(trailers);
+                    Some(status)
+                }
+            };
+
+            response.duration = started.elapsed();
+            let finished = status.is_some();
+            let (status_code, status_text) = status.map_or_else(
+                || (-1, "Streaming...".to_string()),
+                |status| (status.code.as_i32(), grpc_status_text(&status)),
+            );
+            let _ = update_tx.send(UiUpdate::LiveResponse(LiveResponseData {
+                status_code,
+                status_text,
+                body: response.body.clone(),
+                duration: response.duration_display(),
+                size: response.size_display(),
+                streaming: !finished,
+            }));
+            if finished {
+                break;
             }
-            3 => {
-                // API Key
-                if !state.auth_data.api_key_name.is_empty()
-                    && !state.auth_data.api_key_value.is_empty()
-                {
-                    let resolved_value = resolver.resolve(&state.auth_data.api_key_value).resolved;
-                    if state.auth_data.api_key_location == 0 {
-                        // Header
-                        request.headers.add(vortex_domain::request::Header::new(
-                            state.auth_data.api_key_name.clone(),
-                            resolved_value,
-                        ));
-                    } else {
-                        // Query param - append to URL
-                        let separator = if resolved_url.contains('?') { "&" } else { "?" };
-                        request.url = format!(
-                            "{}{}{}={}",
-                            request.url, separator, state.auth_data.api_key_name, resolved_value
-                        );
+        }
+
+        let headers = headers
+            .into_iter()
+            .map(|(name, value)| crate::bridge::ResponseHeaderData { name, value })
+            .collect();
+        let _ = update_tx.send(UiUpdate::ResponseHeaders(headers));
+        let _ = update_tx.send(UiUpdate::ResponseTiming(Vec::new()));
+        let _ = update_tx.send(UiUpdate::ResponseRedirects(Vec::new()));
+    });
+
+    Some(cancel_token)
+}
+
+/// Formats a final gRPC status for the status badge.
+fn grpc_status_text(status: &GrpcStatus) -> String {
+    if status.message.is_empty() {
+        status.code.as_str().to_string()
+    } else {
+        format!("{}: {}", status.code.as_str(), status.message)
+    }
+}
+
+/// Maximum number of entries kept in the WebSocket message log.
+const WEBSOCKET_LOG_LIMIT: usize = 500;
+
+/// Returns true if the URL should open a WebSocket instead of an HTTP request.
+fn is_websocket_url(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
+
+/// Opens a WebSocket connection to the current URL with the enabled request headers.
+///
+/// The handshake runs in the background; its events come back as
+/// `UiCommand::WebSocketEvent` tagged with the session number.
+fn connect_websocket(
+    client: &Arc<dyn WebSocketClient>,
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    cmd_tx: &mpsc::UnboundedSender<UiCommand>,
+    subprotocols: &str,
+    auto_reconnect: bool,
+) {
+    if state.websocket_state.is_connected()
+        || state.websocket_state.is_connecting()
+        || state.websocket_state == ConnectionState::Closing
+    {
+        return;
+    }
+
+    let mut resolver = VariableResolver::new(state.build_resolution_context());
+    let headers = state
+        .request_headers
+        .iter()
+        .filter(|h| h.enabled && !h.key.is_empty())
+        .map(|h| (h.key.clone(), resolver.resolve(&h.value).resolved))
+        .collect();
+    let config = WebSocketConfig {
+        url: resolver.resolve(state.current_url.trim()).resolved,
+        headers,
+        auto_reconnect,
+        subprotocols: subprotocols
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(ToString::to_string)
+            .collect(),
+        ..WebSocketConfig::default()
+    };
+
+    state.websocket_session += 1;
+    state.websocket_sender = None;
+    let session = state.websocket_session;
+
+    let mut info = ConnectionInfo::new(&config.url);
+    info.state = ConnectionState::Connecting;
+    update_websocket_status(state, update_tx, &info);
+
+    let client = Arc::clone(client);
+    let cmd_tx = cmd_tx.clone();
+    tokio::spawn(async move {
+        match client.connect(&config).await {
+            Ok(websocket) => {
+                let (sender, mut events) = websocket.into_parts();
+                let _ = cmd_tx.send(UiCommand::WebSocketOpened { session, sender });
+                while let Some(event) = events.recv().await {
+                    if cmd_tx
+                        .send(UiCommand::WebSocketEvent { session, event })
+                        .is_err()
+                    {
+                        break;
                     }
                 }
             }
-            _ => {}
+            Err(error) => {
+                let mut info = ConnectionInfo::new(&config.url);
+                info.state = ConnectionState::Failed;
+                let _ = cmd_tx.send(UiCommand::WebSocketEvent {
+                    session,
+                    event: WebSocketEvent::Error(error),
+                });
+                let _ = cmd_tx.send(UiCommand::WebSocketEvent {
+                    session,
+                    event: WebSocketEvent::StateChanged(info),
+                });
+            }
         }
+    });
+}
 
-        // Update UI to loading state
-        let _ = update_tx.send(UiUpdate::State(RequestState::loading()));
+/// Applies an event from the current WebSocket session.
+fn handle_websocket_event(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    event: WebSocketEvent,
+) {
+    match event {
+        WebSocketEvent::StateChanged(info) => {
+            if matches!(
+                info.state,
+                ConnectionState::Disconnected | ConnectionState::Failed
+            ) {
+                state.websocket_sender = None;
+            }
+            update_websocket_status(state, update_tx, &info);
+        }
+        WebSocketEvent::Message(message) => {
+            push_websocket_log(
+                state,
+                update_tx,
+                WebSocketMessageData::from_message(&message),
+            );
+        }
+        WebSocketEvent::Error(error) => {
+            push_websocket_log(
+                state,
+                update_tx,
+                WebSocketMessageData::error(error.to_string()),
+            );
+        }
+    }
+}
 
-        // Create cancellation token
-        let (cancel_token, cancel_receiver) = CancellationToken::new();
-        *current_cancel = Some(cancel_token);
+/// Builds the command that starts a GraphQL subscription from the editor.
+fn graphql_subscribe_command(ui: &MainWindow) -> UiCommand {
+    UiCommand::GraphqlSubscribe {
+        query: ui.get_graphql_query().to_string(),
+        variables: ui.get_graphql_variables().to_string(),
+        operation_name: ui.get_graphql_operation_name().to_string(),
+        connection_params: ui.get_graphql_connection_params().to_string(),
+    }
+}
 
-        // Execute request with cancellation support
-        let result = execute_request
-            .execute_with_cancellation(&request, cancel_receiver)
-            .await;
+/// Starts a GraphQL subscription on the current URL with the enabled request headers.
+///
+/// Like a plain WebSocket connection, the subscription runs in the background
+/// and reports back as `UiCommand::GraphqlSubscriptionEvent` tagged with the
+/// session number. Variables are resolved in the query, variables and
+/// connection params.
+fn start_graphql_subscription(
+    client: &Arc<dyn WebSocketClient>,
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    cmd_tx: &mpsc::UnboundedSender<UiCommand>,
+    mut input: GraphqlSubscriptionInput,
+) {
+    if state.websocket_state.is_connected()
+        || state.websocket_state.is_connecting()
+        || state.websocket_state == ConnectionState::Closing
+    {
+        return;
+    }
 
-        // Extract response data for history and tab state
-        let (
-            status_code,
-            duration_ms,
-            response_state,
-            response_body,
-            status_text,
-            duration_display,
-            size_display,
-            response_headers,
-            error_title,
-            error_message,
-        ) = match &result {
-            Ok(response) => {
-                let headers: Vec<crate::bridge::ResponseHeaderData> = response
-                    .headers_map
-                    .iter()
-                    .map(|(name, value)| crate::bridge::ResponseHeaderData {
-                        name: name.clone(),
-                        value: value.clone(),
-                    })
-                    .collect();
-
-                (
-                    Some(response.status),
-                    Some(response.duration.as_millis() as u64),
-                    2, // Success
-                    response.body_as_string_lossy(),
-                    response.status_text.clone(),
-                    response.duration_display(),
-                    response.size_display(),
-                    headers,
-                    String::new(),
-                    String::new(),
-                )
+    let mut resolver = VariableResolver::new(state.build_resolution_context());
+    input.config = WebSocketConfig {
+        url: resolver.resolve(state.current_url.trim()).resolved,
+        headers: state
+            .request_headers
+            .iter()
+            .filter(|h| h.enabled && !h.key.is_empty())
+            .map(|h| (h.key.clone(), resolver.resolve(&h.value).resolved))
+            .collect(),
+        ..WebSocketConfig::default()
+    };
+    input.query = resolver.resolve(&input.query).resolved;
+    input.variables = resolver.resolve(&input.variables).resolved;
+    input.connection_params = resolver.resolve(&input.connection_params).resolved;
+
+    state.websocket_session += 1;
+    state.websocket_sender = None;
+    let session = state.websocket_session;
+
+    let mut info = ConnectionInfo::new(&input.config.url);
+    info.state = ConnectionState::Connecting;
+    update_websocket_status(state, update_tx, &info);
+
+    let use_case = GraphqlSubscription::new(Arc::clone(client));
+    let cmd_tx = cmd_tx.clone();
+    tokio::spawn(async move {
+        let url = input.config.url.clone();
+        match use_case.execute(input).await {
+            Ok(mut subscription) => {
+                let sender = subscription.sender().clone();
+                let _ = cmd_tx.send(UiCommand::WebSocketOpened { session, sender });
+                while let Some(event) = subscription.next_event().await {
+                    if cmd_tx
+                        .send(UiCommand::GraphqlSubscriptionEvent { session, event })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
             }
-            Err(e) => {
-                let (title, message) = ("Request Failed".to_string(), e.to_string());
-                (
-                    None,
-                    None,
-                    3, // Error
-                    String::new(),
-                    String::new(),
-                    String::new(),
-                    String::new(),
-                    Vec::new(),
-                    title,
+            Err(error) => {
+                let mut info = ConnectionInfo::new(url);
+                info.state = ConnectionState::Failed;
+                for event in [
+                    GraphqlSubscriptionEvent::Warning(error.to_string()),
+                    GraphqlSubscriptionEvent::StateChanged(info),
+                ] {
+                    let _ = cmd_tx.send(UiCommand::GraphqlSubscriptionEvent { session, event });
+                }
+            }
+        }
+    });
+}
+
+/// Applies an event from the current GraphQL subscription.
+///
+/// Each result is logged as a received message with its arrival time.
+fn handle_graphql_subscription_event(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    event: GraphqlSubscriptionEvent,
+) {
+    let entry = match event {
+        GraphqlSubscriptionEvent::StateChanged(info) => {
+            handle_websocket_event(state, update_tx, WebSocketEvent::StateChanged(info));
+            return;
+        }
+        GraphqlSubscriptionEvent::Next {
+            payload,
+            received_at,
+        } => {
+            let content = serde_json::to_string_pretty(&payload).unwrap_or_default();
+            let mut message = WebSocketMessage::received_text(content);
+            message.timestamp = received_at;
+            WebSocketMessageData::from_message(&message)
+        }
+        GraphqlSubscriptionEvent::Error(errors) => {
+            WebSocketMessageData::error(format!("Subscription failed: {}", errors.join("; ")))
+        }
+        GraphqlSubscriptionEvent::Complete => WebSocketMessageData::from_message(
+            &WebSocketMessage::system("Subscription complete", MessageType::Text),
+        ),
+        GraphqlSubscriptionEvent::Warning(warning) => WebSocketMessageData::error(warning),
+    };
+    push_websocket_log(state, update_tx, entry);
+}
+
+/// Sends the composed message over the open WebSocket connection.
+fn send_websocket_message(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    content: &str,
+    binary: bool,
+) {
+    let message = if binary {
+        match parse_hex(content) {
+            Ok(bytes) => WebSocketMessage::binary(bytes),
+            Err(message) => {
+                let _ = update_tx.send(UiUpdate::Error {
+                    title: "Invalid Binary Message".to_string(),
                     message,
-                )
+                });
+                return;
             }
-        };
+        }
+    } else {
+        WebSocketMessage::text(content)
+    };
 
-        // Send response headers to UI
-        let _ = update_tx.send(UiUpdate::ResponseHeaders(response_headers.clone()));
+    let result = match &state.websocket_sender {
+        Some(sender) if state.websocket_state.is_connected() => sender.send(message),
+        _ => Err(WebSocketError::NotConnected),
+    };
+    match result {
+        Ok(()) => {
+            let _ = update_tx.send(UiUpdate::WebSocketMessageSent);
+        }
+        Err(error) => {
+            push_websocket_log(
+                state,
+                update_tx,
+                WebSocketMessageData::error(error.to_string()),
+            );
+        }
+    }
+}
 
-        // Convert result to RequestState
-        let request_state = result.to_request_state();
+/// Sends the connection state and statistics to the UI.
+fn update_websocket_status(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    info: &ConnectionInfo,
+) {
+    state.websocket_state = info.state;
+
+    let status_text = match &info.subprotocol {
+        Some(protocol) if info.state.is_connected() => {
+            format!("{} ({protocol})", info.state.as_str())
+        }
+        _ => info.state.as_str().to_string(),
+    };
+    let counters = if info.state == ConnectionState::Reconnecting {
+        format!("Attempt {}", info.reconnect_attempts)
+    } else if info.messages_sent + info.messages_received > 0 {
+        format!(
+            "{} sent ({} B) \u{00b7} {} received ({} B)",
+            info.messages_sent, info.bytes_sent, info.messages_received, info.bytes_received
+        )
+    } else {
+        String::new()
+    };
 
-        // Update UI with result
-        let _ = update_tx.send(UiUpdate::State(request_state));
+    let _ = update_tx.send(UiUpdate::WebSocketStatus {
+        state: match info.state {
+            ConnectionState::Disconnected => 0,
+            ConnectionState::Connecting => 1,
+            ConnectionState::Connected => 2,
+            ConnectionState::Reconnecting => 3,
+            ConnectionState::Failed => 4,
+            ConnectionState::Closing => 5,
+        },
+        status_text,
+        stats: counters,
+    });
+}
 
-        // Clear cancellation token
-        *current_cancel = None;
+/// Appends an entry to the WebSocket message log and refreshes the UI.
+fn push_websocket_log(
+    state: &mut AppState,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+    entry: WebSocketMessageData,
+) {
+    state.websocket_log.push(entry);
+    if state.websocket_log.len() > WEBSOCKET_LOG_LIMIT {
+        let excess = state.websocket_log.len() - WEBSOCKET_LOG_LIMIT;
+        state.websocket_log.drain(..excess);
+    }
+    let _ = update_tx.send(UiUpdate::WebSocketMessages(
+        state.websocket_log.iter().rev().cloned().collect(),
+    ));
+}
 
-        // Return result for history tracking and tab state saving
-        return Some(RequestResult {
-            method,
-            url: resolved_url,
-            status_code,
-            duration_ms,
-            response_state,
-            response_body,
-            status_text,
-            duration_display,
-            size_display,
-            response_headers,
-            error_title,
-            error_message,
-            request_body: request_body_for_history,
-        });
+/// Parses whitespace-separated hex bytes, e.g. `48 65 6c 6c 6f`.
+fn parse_hex(text: &str) -> Result<Vec<u8>, String> {
+    let digits: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err("Hex input must have an even number of digits".to_string());
     }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| {
+            digits
+                .get(i..i + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| format!("Invalid hex byte at position {}", i / 2 + 1))
+        })
+        .collect()
+}
+
+/// Where a cookie jar is persisted: the workspace and, when jars are kept
+/// per environment, the environment name.
+type CookieLocation = (PathBuf, Option<String>);
+
+/// Returns the location of the cookie jar for the current workspace and environment.
+fn cookie_location(state: &AppState) -> Option<CookieLocation> {
+    let workspace = state.workspace_path.clone()?;
+    let environment = if state.cookies_per_environment {
+        state.current_environment().map(|env| env.name.clone())
+    } else {
+        None
+    };
+    Some((workspace, environment))
+}
+
+/// Saves the active cookie jar if it changed since it was loaded or last saved.
+///
+/// Without an open workspace the jar only lives in memory.
+async fn save_cookie_jar(state: &mut AppState) {
+    let revision = state.cookie_jar.revision();
+    if revision == state.cookies_saved_revision {
+        return;
+    }
+
+    if let Some((workspace, environment)) = &state.cookie_location {
+        let cookie_repo = FileCookieRepository::new(TokioFileSystem);
+        let jar = state.cookie_jar.snapshot();
+        if let Err(e) = cookie_repo
+            .save(workspace, environment.as_deref(), &jar)
+            .await
+        {
+            eprintln!("Failed to save cookies: {e}");
+            return;
+        }
+    }
+    state.cookies_saved_revision = revision;
+}
+
+/// Switches the HTTP client to the cookie jar of the current workspace and
+/// environment, saving the previous jar first.
+async fn sync_cookie_jar(state: &mut AppState) {
+    let location = cookie_location(state);
+    if location == state.cookie_location {
+        return;
+    }
+
+    save_cookie_jar(state).await;
+
+    let jar = match &location {
+        Some((workspace, environment)) => FileCookieRepository::new(TokioFileSystem)
+            .load(workspace, environment.as_deref())
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Failed to load cookies: {e}");
+                CookieJar::new()
+            }),
+        None => CookieJar::new(),
+    };
+    state.cookie_jar.replace(jar);
+    state.cookies_saved_revision = state.cookie_jar.revision();
+    state.cookie_location = location;
+}
+
+/// Returns the proxy configured in a workspace manifest, if any.
+fn workspace_proxy(manifest: &WorkspaceManifest) -> Option<ProxyConfig> {
+    manifest
+        .settings
+        .as_ref()
+        .and_then(|settings| settings.proxy.clone())
+}
+
+/// Applies the TLS configuration of a workspace manifest to the client
+/// and shows its security warnings.
+fn apply_workspace_tls(
+    http_client: &ReqwestHttpClient,
+    manifest: &WorkspaceManifest,
+    update_tx: &mpsc::UnboundedSender<UiUpdate>,
+) {
+    let tls = manifest
+        .settings
+        .as_ref()
+        .and_then(RequestSettings::tls_config)
+        .unwrap_or_default();
+    let warnings = tls
+        .security_warnings()
+        .iter()
+        .map(|warning| warning.message().to_string())
+        .collect();
+    http_client.set_tls_config(tls);
+    let _ = update_tx.send(UiUpdate::TlsWarnings(warnings));
+}
+
+/// Builds the proxy resolver for the current state.
+///
+/// A workspace proxy replaces the user's global proxy while the workspace
+/// is open; per-request proxies apply on top of either.
+fn proxy_resolver(state: &AppState) -> ProxyResolver {
+    let settings = state.workspace_proxy.clone().map_or_else(
+        || state.proxy_settings.clone(),
+        |proxy| GlobalProxySettings {
+            http_proxy: proxy,
+            ..GlobalProxySettings::default()
+        },
+    );
+    ProxyResolver::new(settings)
+}
+
+/// Sends the active cookie jar to the cookie manager.
+fn send_cookie_list(state: &mut AppState, update_tx: &mpsc::UnboundedSender<UiUpdate>) {
+    state.cookie_list = state
+        .cookie_jar
+        .snapshot()
+        .sorted()
+        .into_iter()
+        .cloned()
+        .collect();
+
+    let scope = match &state.cookie_location {
+        None => "Not saved (no workspace open)".to_string(),
+        Some((_, None)) => "Workspace jar".to_string(),
+        Some((_, Some(environment))) => format!("Environment jar: {environment}"),
+    };
 
-    None
+    let _ = update_tx.send(UiUpdate::CookieList {
+        cookies: state
+            .cookie_list
+            .iter()
+            .map(CookieData::from_cookie)
+            .collect(),
+        scope,
+        per_environment: state.cookies_per_environment,
+    });
 }
 
 /// Loads the workspace tree from disk.
@@ -2921,6 +4635,7 @@ fn load_folder_items<'a>(
 fn apply_update(ui: &MainWindow, update: UiUpdate) {
     match update {
         UiUpdate::State(state) => {
+            ui.set_response_streaming(false);
             match state {
                 RequestState::Idle => {
                     ui.set_response_state(0);
@@ -2934,7 +4649,7 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
                     #[allow(clippy::cast_possible_wrap)]
                     ui.set_status_code(i32::from(response.status));
                     ui.set_status_text(response.status_text.clone().into());
-                    ui.set_response_body(response.body_as_string_lossy().into());
+                    ui.set_response_body(response_body_text(&response).into());
                     ui.set_duration(response.duration_display().into());
                     ui.set_size(response.size_display().into());
                 }
@@ -2964,6 +4679,17 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             ui.set_workspace_path(path.into());
         }
 
+        UiUpdate::TlsWarnings(warnings) => {
+            let model: ModelRc<SharedString> = Rc::new(VecModel::from(
+                warnings
+                    .into_iter()
+                    .map(SharedString::from)
+                    .collect::<Vec<_>>(),
+            ))
+            .into();
+            ui.set_tls_warnings(model);
+        }
+
         UiUpdate::CollectionItems(items) => {
             let slint_items: Vec<TreeItem> = items
                 .into_iter()
@@ -2991,7 +4717,18 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             ui.set_is_saving(is_saving);
         }
 
+        UiUpdate::Methods(methods) => {
+            let model: Vec<SharedString> = methods.into_iter().map(SharedString::from).collect();
+            ui.set_methods(Rc::new(VecModel::from(model)).into());
+        }
+
+        UiUpdate::MethodIndex(index) => {
+            ui.set_method_index(index);
+        }
+
         UiUpdate::LoadRequest { url, method, body } => {
+            ui.set_websocket_mode(is_websocket_url(&url));
+            ui.set_grpc_mode(is_grpc_url(&url));
             ui.set_url(url.into());
             ui.set_method_index(method);
             ui.set_request_body(body.into());
@@ -3088,9 +4825,18 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             font_scale_index,
             dark_mode,
             font_scale_factor,
+            proxy,
         } => {
             ui.set_theme_mode_index(theme_index);
             ui.set_font_scale_index(font_scale_index);
+            ui.set_proxy_settings(ProxySettings {
+                enabled: proxy.enabled,
+                url: proxy.url.into(),
+                username: proxy.username.into(),
+                password: proxy.password.into(),
+                bypass: proxy.bypass.into(),
+                use_system: proxy.use_system,
+            });
             ui.global::<VortexPalette>().set_dark_mode(dark_mode);
             ui.global::<VortexTypography>()
                 .set_scale_factor(font_scale_factor);
@@ -3120,6 +4866,8 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
 
         // Sprint 05: URL update (from params sync)
         UiUpdate::UpdateUrl(url) => {
+            ui.set_websocket_mode(is_websocket_url(&url));
+            ui.set_grpc_mode(is_grpc_url(&url));
             ui.set_url(url.into());
         }
 
@@ -3169,6 +4917,9 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             ui.set_response_headers(model);
         }
 
+        UiUpdate::ResponseTiming(timing) => set_response_timing(ui, timing),
+        UiUpdate::ResponseRedirects(redirects) => set_response_redirects(ui, redirects),
+
         // Sprint 05: Auth data updates
         UiUpdate::AuthData(auth) => {
             ui.set_auth_type(auth.auth_type);
@@ -3207,6 +4958,8 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             query_params,
             auth,
         } => {
+            ui.set_websocket_mode(is_websocket_url(&url));
+            ui.set_grpc_mode(is_grpc_url(&url));
             ui.set_url(url.into());
             ui.set_method_index(method);
             ui.set_request_body(body.into());
@@ -3426,6 +5179,118 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
         }
 
         // Restore response state when switching tabs
+        // WebSocket updates
+        UiUpdate::GrpcMode(enabled) => {
+            ui.set_grpc_mode(enabled);
+        }
+
+        UiUpdate::GrpcMethods {
+            methods,
+            selected,
+            status,
+        } => {
+            let model: ModelRc<SharedString> = Rc::new(VecModel::from(
+                methods
+                    .into_iter()
+                    .map(SharedString::from)
+                    .collect::<Vec<_>>(),
+            ))
+            .into();
+            ui.set_grpc_methods(model);
+            ui.set_grpc_method_index(selected);
+            ui.set_grpc_status(status.into());
+        }
+
+        UiUpdate::GrpcStatus(status) => {
+            ui.set_grpc_status(status.into());
+        }
+
+        UiUpdate::GrpcProtoFiles(files) => {
+            ui.set_grpc_proto_files(files.into());
+        }
+
+        UiUpdate::GrpcMessageTemplate(template) => {
+            if ui.get_request_body().trim().is_empty() {
+                ui.set_request_body(template.into());
+            }
+        }
+
+        UiUpdate::LiveResponse(response) => {
+            ui.set_response_state(2);
+            ui.set_status_code(response.status_code);
+            ui.set_status_text(response.status_text.into());
+            ui.set_response_body(response.body.into());
+            ui.set_duration(response.duration.into());
+            ui.set_size(response.size.into());
+            ui.set_response_streaming(response.streaming);
+        }
+
+        UiUpdate::WebSocketMode(enabled) => {
+            ui.set_websocket_mode(enabled);
+        }
+
+        UiUpdate::GraphqlSchemaStatus(status) => {
+            ui.set_graphql_schema_status(status.into());
+        }
+
+        UiUpdate::WebSocketStatus {
+            state,
+            status_text,
+            stats,
+        } => {
+            ui.set_ws_state(state);
+            ui.set_ws_status_text(status_text.into());
+            ui.set_ws_stats(stats.into());
+        }
+
+        UiUpdate::WebSocketMessages(messages) => {
+            let entries: Vec<WebSocketLogEntry> = messages
+                .into_iter()
+                .map(|m| WebSocketLogEntry {
+                    direction: m.direction.into(),
+                    kind: m.kind.into(),
+                    content: m.content.into(),
+                    timestamp: m.timestamp.into(),
+                    size: m.size.into(),
+                })
+                .collect();
+
+            let model: ModelRc<WebSocketLogEntry> = Rc::new(VecModel::from(entries)).into();
+            ui.set_ws_messages(model);
+        }
+
+        UiUpdate::WebSocketMessageSent => {
+            ui.set_ws_message_text(SharedString::new());
+        }
+
+        // Cookie manager updates
+        UiUpdate::ShowCookieManager(show) => {
+            ui.set_show_cookie_manager(show);
+        }
+
+        UiUpdate::CookieList {
+            cookies,
+            scope,
+            per_environment,
+        } => {
+            let rows: Vec<CookieRow> = cookies
+                .into_iter()
+                .map(|cookie| CookieRow {
+                    domain: cookie.domain.into(),
+                    name: cookie.name.into(),
+                    value: cookie.value.into(),
+                    path: cookie.path.into(),
+                    expires: cookie.expires.into(),
+                    flags: cookie.flags.into(),
+                })
+                .collect();
+
+            let model: ModelRc<CookieRow> = Rc::new(VecModel::from(rows)).into();
+            ui.set_cookie_rows(model);
+            ui.set_cookie_scope_text(scope.into());
+            ui.set_cookies_per_environment(per_environment);
+        }
+
         UiUpdate::RestoreResponseState {
             state,
             body,
@@ -3434,6 +5299,8 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
             duration,
             size,
             headers,
+            timing,
+            redirects,
             error_title,
             error_message,
         } => {
@@ -3456,10 +5323,42 @@ fn apply_update(ui: &MainWindow, update: UiUpdate) {
                 .collect();
             let model: ModelRc<ResponseHeader> = Rc::new(VecModel::from(slint_headers)).into();
             ui.set_response_headers(model);
+            set_response_timing(ui, timing);
+            set_response_redirects(ui, redirects);
         }
     }
 }
 
+/// Shows a response timing waterfall in the timing panel.
+fn set_response_timing(ui: &MainWindow, timing: Vec<TimingPhaseData>) {
+    let phases: Vec<TimingPhase> = timing
+        .into_iter()
+        .map(|phase| TimingPhase {
+            label: phase.label.into(),
+            duration: phase.duration.into(),
+            offset: phase.offset,
+            width: phase.width,
+        })
+        .collect();
+    let model: ModelRc<TimingPhase> = Rc::new(VecModel::from(phases)).into();
+    ui.set_response_timing(model);
+}
+
+/// Shows the redirect chain of a response in the redirects panel.
+fn set_response_redirects(ui: &MainWindow, redirects: Vec<RedirectHopData>) {
+    let hops: Vec<RedirectHop> = redirects
+        .into_iter()
+        .map(|hop| RedirectHop {
+            status: hop.status,
+            url: hop.url.into(),
+            location: hop.location.into(),
+            duration: hop.duration.into(),
+        })
+        .collect();
+    let model: ModelRc<RedirectHop> = Rc::new(VecModel::from(hops)).into();
+    ui.set_response_redirects(model);
+}
+
 // --- Sprint 06: Import/Export Helper Functions ---
 
 /// Import a Postman collection v2.1 format.
@@ -4059,18 +5958,27 @@ fn persistence_auth_to_ui(auth: Option<&PersistenceAuth>) -> AuthData {
             },
             ..AuthData::default()
         },
-        // OAuth2 types default to None for now (not supported in UI yet)
+        // OAuth2, AWS, Digest and JWT are not editable in the UI yet, so keep them as saved
         Some(
-            PersistenceAuth::Oauth2ClientCredentials { .. }
-            | PersistenceAuth::Oauth2AuthCode { .. },
-        ) => AuthData::default(),
+            auth @ (PersistenceAuth::Oauth2ClientCredentials { .. }
+            | PersistenceAuth::Oauth2AuthCode { .. }
+            | PersistenceAuth::Oauth2Password { .. }
+            | PersistenceAuth::Oauth2DeviceCode { .. }
+            | PersistenceAuth::Oauth2JwtBearer { .. }
+            | PersistenceAuth::AwsSigV4 { .. }
+            | PersistenceAuth::Digest { .. }
+            | PersistenceAuth::Jwt { .. }),
+        ) => AuthData {
+            preserved: Some(auth.clone()),
+            ..AuthData::default()
+        },
     }
 }
 
 /// Converts UI `AuthData` back to persistence format for saving.
 fn ui_auth_to_persistence(auth: &AuthData) -> Option<PersistenceAuth> {
     match auth.auth_type {
-        0 => None, // No auth
+        0 => auth.preserved.clone(), // No auth, unless the editor cannot show it
         1 => Some(PersistenceAuth::bearer(&auth.bearer_token)),
         2 => Some(PersistenceAuth::basic(
             &auth.basic_username,
@@ -4094,22 +6002,14 @@ fn ui_auth_to_persistence(auth: &AuthData) -> Option<PersistenceAuth> {
 }
 
 /// Builds a `SavedRequest` from `TabState` for persistence.
-fn build_saved_request_from_tab(tab: &TabState) -> SavedRequest {
-    let method = match tab.method {
-        0 => PersistenceHttpMethod::Get,
-        1 => PersistenceHttpMethod::Post,
-        2 => PersistenceHttpMethod::Put,
-        3 => PersistenceHttpMethod::Patch,
-        4 => PersistenceHttpMethod::Delete,
-        5 => PersistenceHttpMethod::Head,
-        6 => PersistenceHttpMethod::Options,
-        _ => PersistenceHttpMethod::Get,
-    };
-
+///
+/// `methods` are the methods in the method selector, which `tab.method`
+/// indexes.
+fn build_saved_request_from_tab(tab: &TabState, methods: &[HttpMethod]) -> SavedRequest {
     let mut request = SavedRequest::new(
         uuid::Uuid::now_v7().to_string(),
         &tab.name,
-        method,
+        method_at(methods, tab.method).into(),
         &tab.url,
     );
 
@@ -4144,5 +6044,135 @@ fn build_saved_request_from_tab(tab: &TabState) -> SavedRequest {
     // Add auth if present
     request.auth = ui_auth_to_persistence(&tab.auth);
 
+    if is_grpc_url(&tab.url) {
+        request.grpc.clone_from(&tab.grpc);
+    }
+
     request
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use vortex_domain::OAuth2Token;
+
+    use super::*;
+
+    /// App state with `auth` loaded into the editor as if from a saved request.
+    fn state_with_saved_auth(auth: &PersistenceAuth) -> AppState {
+        let mut state = AppState::from_settings(UserSettings::default(), RequestHistory::new(10));
+        state.auth_data = persistence_auth_to_ui(Some(auth));
+        state
+    }
+
+    fn resolver() -> VariableResolver {
+        VariableResolver::new(ResolutionContext {
+            secrets: HashMap::from([("access_key".to_string(), "AKID".to_string())]),
+            ..ResolutionContext::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_saved_sigv4_auth_is_sent() {
+        let state = state_with_saved_auth(&PersistenceAuth::AwsSigV4 {
+            access_key: "{{access_key}}".to_string(),
+            secret_key: "secret".to_string(),
+            session_token: None,
+            region: "eu-west-1".to_string(),
+            service: "s3".to_string(),
+        });
+        let mut request = RequestSpec::get("https://bucket.s3.amazonaws.com/key");
+
+        add_headers_and_auth(&mut request, &state, &mut resolver())
+            .await
+            .unwrap();
+
+        let AuthConfig::AwsSigV4 {
+            access_key, region, ..
+        } = &request.auth
+        else {
+            panic!("expected AWS SigV4 auth, got {:?}", request.auth);
+        };
+        assert_eq!(access_key, "AKID");
+        assert_eq!(region, "eu-west-1");
+    }
+
+    #[tokio::test]
+    async fn test_saved_digest_auth_is_sent() {
+        let state = state_with_saved_auth(&PersistenceAuth::Digest {
+            username: "alice".to_string(),
+            password: "{{access_key}}".to_string(),
+        });
+        let mut request = RequestSpec::get("https://api.test/protected");
+
+        add_headers_and_auth(&mut request, &state, &mut resolver())
+            .await
+            .unwrap();
+
+        assert_eq!(request.auth, AuthConfig::digest("alice", "AKID"));
+    }
+
+    #[tokio::test]
+    async fn test_saved_jwt_auth_is_signed() {
+        let state = state_with_saved_auth(&PersistenceAuth::Jwt {
+            algorithm: vortex_domain::JwtAlgorithm::Hs256,
+            secret: "{{access_key}}".to_string(),
+            claims: r#"{"sub":"alice"}"#.to_string(),
+            key_id: None,
+            location: ApiKeyLocation::Header,
+            query_param: "token".to_string(),
+        });
+        let mut request = RequestSpec::get("https://api.test/protected");
+
+        add_headers_and_auth(&mut request, &state, &mut resolver())
+            .await
+            .unwrap();
+
+        let [header] = request.headers.all() else {
+            panic!("expected one header, got {:?}", request.headers);
+        };
+        assert_eq!(header.name, "Authorization");
+        let token = header.value.strip_prefix("Bearer ").unwrap();
+        assert_eq!(token.split('.').count(), 3);
+        assert_eq!(request.auth, AuthConfig::None);
+    }
+
+    #[tokio::test]
+    async fn test_saved_oauth2_auth_sends_token() {
+        let auth = PersistenceAuth::Oauth2Password {
+            token_url: "https://auth.test/token".to_string(),
+            client_id: "vortex".to_string(),
+            client_secret: String::new(),
+            username: "alice".to_string(),
+            password: "{{access_key}}".to_string(),
+            scope: None,
+        };
+        let state = state_with_saved_auth(&auth);
+        let mut config = AuthConfig::from(&auth);
+        if let AuthConfig::OAuth2Password { password, .. } = &mut config {
+            *password = "AKID".to_string();
+        }
+        let token = OAuth2Token::new(
+            "token-1".to_string(),
+            "Bearer".to_string(),
+            Some(3600),
+            None,
+            Vec::new(),
+        );
+        state
+            .auth_provider
+            .token_store()
+            .store(config.cache_key().unwrap(), token)
+            .await;
+        let mut request = RequestSpec::get("https://api.test/protected");
+
+        add_headers_and_auth(&mut request, &state, &mut resolver())
+            .await
+            .unwrap();
+
+        let [header] = request.headers.all() else {
+            panic!("expected one header, got {:?}", request.headers);
+        };
+        assert_eq!(header.value, "Bearer token-1");
+    }
+}
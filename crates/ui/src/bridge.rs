@@ -3,9 +3,19 @@
 //! Defines the communication protocol between the Slint UI thread
 //! and the async Tokio runtime.
 
+use std::fmt::Write;
 use std::path::PathBuf;
 
+use vortex_application::GraphqlSubscriptionEvent;
+use vortex_application::WebSocketEvent;
+use vortex_application::WebSocketSender;
 use vortex_domain::RequestState;
+use vortex_domain::cookie::{Cookie, SameSite};
+use vortex_domain::persistence::{PersistenceAuth, PersistenceGrpc};
+use vortex_domain::proxy::{GlobalProxySettings, ProxyConfig};
+use vortex_domain::request::HttpMethod;
+use vortex_domain::response::ResponseTiming;
+use vortex_domain::websocket::{MessageDirection, MessageType, WebSocketMessage};
 
 /// Commands sent from UI to the async runtime.
 #[derive(Debug, Clone)]
@@ -13,9 +23,15 @@ pub enum UiCommand {
     /// User clicked Send button or pressed Enter.
     SendRequest,
 
+    /// User chose to send the request and save the response body to a file.
+    SendRequestToFile { path: PathBuf },
+
     /// User clicked Cancel button.
     CancelRequest,
 
+    /// A request sent with `SendRequest` finished, failed or was cancelled.
+    RequestFinished(Box<RequestResult>),
+
     /// User wants to create a new workspace.
     CreateWorkspace { path: PathBuf, name: String },
 
@@ -80,6 +96,9 @@ pub enum UiCommand {
     /// User changed the URL (for variable preview).
     UrlChanged { url: String },
 
+    /// User entered a method that is not in the method list, e.g. `PURGE`.
+    AddCustomMethod { name: String },
+
     // --- Settings Commands (Sprint 04) ---
     /// User toggled the theme (light/dark).
     ToggleTheme,
@@ -96,6 +115,9 @@ pub enum UiCommand {
     /// User wants to close settings dialog.
     CloseSettings,
 
+    /// User applied new global proxy settings.
+    SetProxySettings(ProxySettingsData),
+
     // --- History Commands (Sprint 04) ---
     /// User clicked a history item to reload it.
     LoadHistoryItem { id: String },
@@ -232,6 +254,78 @@ pub enum UiCommand {
 
     /// Import a Postman environment.
     ImportEnvironment,
+
+    // --- GraphQL Commands ---
+    /// Download the schema of the GraphQL endpoint at `url`.
+    FetchGraphqlSchema { url: String },
+
+    /// Start a GraphQL subscription on the current WebSocket URL.
+    GraphqlSubscribe {
+        query: String,
+        variables: String,
+        operation_name: String,
+        connection_params: String,
+    },
+
+    /// An event from the GraphQL subscription on connection `session`.
+    GraphqlSubscriptionEvent {
+        session: u64,
+        event: GraphqlSubscriptionEvent,
+    },
+
+    // --- gRPC Commands ---
+    /// List the methods of the current gRPC endpoint.
+    LoadGrpcMethods { proto_files: String },
+
+    /// Pick the method at `index` in the method list.
+    GrpcMethodSelected { index: i32 },
+
+    /// Call the selected method with the message editor text.
+    InvokeGrpc {
+        message: String,
+        proto_files: String,
+    },
+
+    // --- WebSocket Commands ---
+    /// Open a WebSocket connection to the current URL.
+    WebSocketConnect {
+        subprotocols: String,
+        auto_reconnect: bool,
+    },
+
+    /// Close the WebSocket connection.
+    WebSocketDisconnect,
+
+    /// Send a message over the WebSocket connection.
+    WebSocketSend { content: String, binary: bool },
+
+    /// Clear the WebSocket message log.
+    WebSocketClearLog,
+
+    /// The handshake of connection `session` completed.
+    WebSocketOpened {
+        session: u64,
+        sender: WebSocketSender,
+    },
+
+    /// An event from WebSocket connection `session`.
+    WebSocketEvent { session: u64, event: WebSocketEvent },
+
+    // --- Cookie Commands ---
+    /// Open the cookie manager.
+    OpenCookieManager,
+
+    /// Delete the cookie at `index` in the manager list.
+    DeleteCookie { index: i32 },
+
+    /// Update the cookie at `index`, or add a new one when `index` is -1.
+    SaveCookie { index: i32, cookie: CookieData },
+
+    /// Delete all cookies in the active jar.
+    ClearCookies,
+
+    /// Switch between the workspace jar and one jar per environment.
+    SetCookiesPerEnvironment(bool),
 }
 
 /// A tree item for UI display.
@@ -299,6 +393,67 @@ pub struct ResponseHeaderData {
     pub value: String,
 }
 
+/// Phase of the response timing waterfall for UI.
+#[derive(Debug, Clone)]
+pub struct TimingPhaseData {
+    pub label: String,
+    pub duration: String,
+    /// Start of the phase as a fraction of the total time.
+    pub offset: f32,
+    /// Length of the phase as a fraction of the total time.
+    pub width: f32,
+}
+
+/// Redirect followed to reach a response, for UI.
+#[derive(Debug, Clone)]
+pub struct RedirectHopData {
+    pub status: i32,
+    pub url: String,
+    pub location: String,
+    pub duration: String,
+}
+
+/// Response shown while it arrives: a streamed HTTP response or a gRPC call.
+#[derive(Debug, Clone)]
+pub struct LiveResponseData {
+    /// HTTP or gRPC status code, or -1 while a gRPC call is running.
+    pub status_code: i32,
+    pub status_text: String,
+    /// Body so far, with a timestamp line before each event or stream message.
+    pub body: String,
+    pub duration: String,
+    pub size: String,
+    /// True until the response is complete.
+    pub streaming: bool,
+}
+
+/// Result of a request execution for history tracking and tab state saving.
+#[derive(Debug, Clone)]
+pub struct RequestResult {
+    /// Tab the request was sent from.
+    pub tab_id: Option<String>,
+    pub method: HttpMethod,
+    pub url: String,
+    pub status_code: Option<u16>,
+    pub duration_ms: Option<u64>,
+    // Response data for tab state
+    pub response_state: i32, // 2=Success, 3=Error
+    pub response_body: String,
+    pub status_text: String,
+    pub duration_display: String,
+    pub size_display: String,
+    pub response_headers: Vec<ResponseHeaderData>,
+    pub error_title: String,
+    pub error_message: String,
+    // Request body for history
+    pub request_body: String,
+    /// File the response body was saved to.
+    pub response_file: Option<PathBuf>,
+    /// Per-phase timing of the response.
+    pub timing: ResponseTiming,
+    pub redirects: Vec<RedirectHopData>,
+}
+
 /// Authentication data for UI (Sprint 05).
 #[derive(Debug, Clone, Default)]
 pub struct AuthData {
@@ -309,6 +464,8 @@ pub struct AuthData {
     pub api_key_name: String,
     pub api_key_value: String,
     pub api_key_location: i32,
+    /// Saved auth the editor cannot show, written back unchanged on save.
+    pub preserved: Option<PersistenceAuth>,
 }
 
 /// Tab data for UI (Sprint 06).
@@ -333,6 +490,7 @@ pub struct TabState {
     pub auth: AuthData,
     pub has_unsaved_changes: bool,
     pub file_path: Option<String>,
+    pub grpc: Option<PersistenceGrpc>, // Set for gRPC requests
     // Response state
     pub response_state: i32, // 0=Idle, 1=Loading, 2=Success, 3=Error
     pub response_body: String,
@@ -341,6 +499,8 @@ pub struct TabState {
     pub duration: String,
     pub size: String,
     pub response_headers: Vec<ResponseHeaderData>,
+    pub response_timing: Vec<TimingPhaseData>,
+    pub response_redirects: Vec<RedirectHopData>,
     pub error_title: String,
     pub error_message: String,
 }
@@ -360,6 +520,7 @@ impl TabState {
             auth: AuthData::default(),
             has_unsaved_changes: false,
             file_path: None,
+            grpc: None,
             // Response defaults
             response_state: 0,
             response_body: String::new(),
@@ -368,33 +529,221 @@ impl TabState {
             duration: String::new(),
             size: String::new(),
             response_headers: Vec::new(),
+            response_timing: Vec::new(),
+            response_redirects: Vec::new(),
             error_title: String::new(),
             error_message: String::new(),
         }
     }
 
     /// Converts to `TabData` for UI display.
+    ///
+    /// `methods` are the methods in the method selector, which `method`
+    /// indexes.
     #[must_use]
-    pub fn to_tab_data(&self) -> TabData {
-        let method_str = match self.method {
-            0 => "GET",
-            1 => "POST",
-            2 => "PUT",
-            3 => "PATCH",
-            4 => "DELETE",
-            5 => "HEAD",
-            6 => "OPTIONS",
-            _ => "GET",
-        };
+    pub fn to_tab_data(&self, methods: &[HttpMethod]) -> TabData {
         TabData {
             id: self.id.clone(),
             name: self.name.clone(),
-            method: method_str.to_string(),
+            method: method_at(methods, self.method).to_string(),
             has_unsaved_changes: self.has_unsaved_changes,
         }
     }
 }
 
+/// Returns the method at `index` in the method selector, or GET if the
+/// index is out of range.
+#[must_use]
+pub fn method_at(methods: &[HttpMethod], index: i32) -> HttpMethod {
+    usize::try_from(index)
+        .ok()
+        .and_then(|index| methods.get(index))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// WebSocket message log entry for UI.
+#[derive(Debug, Clone)]
+pub struct WebSocketMessageData {
+    pub direction: String,
+    pub kind: String,
+    pub content: String,
+    pub timestamp: String,
+    pub size: String,
+}
+
+impl WebSocketMessageData {
+    /// Maximum number of bytes shown for a binary message.
+    const BINARY_PREVIEW_BYTES: usize = 64;
+
+    /// Creates a log entry for a domain message.
+    #[must_use]
+    pub fn from_message(message: &WebSocketMessage) -> Self {
+        let direction = match message.direction {
+            MessageDirection::Sent => "sent",
+            MessageDirection::Received => "received",
+            MessageDirection::System => "system",
+        };
+        let kind = match message.message_type {
+            MessageType::Text => "text",
+            MessageType::Binary => "binary",
+            MessageType::Ping => "ping",
+            MessageType::Pong => "pong",
+            MessageType::Close => "close",
+        };
+        let content = if message.message_type == MessageType::Binary {
+            let mut hex = String::new();
+            for byte in message.binary.iter().take(Self::BINARY_PREVIEW_BYTES) {
+                let _ = write!(hex, "{byte:02x} ");
+            }
+            if message.binary.len() > Self::BINARY_PREVIEW_BYTES {
+                hex.push('\u{2026}');
+            }
+            hex.trim_end().to_string()
+        } else {
+            message.content.clone()
+        };
+
+        Self {
+            direction: direction.to_string(),
+            kind: kind.to_string(),
+            content,
+            timestamp: message
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S%.3f")
+                .to_string(),
+            size: message.size_display(),
+        }
+    }
+
+    /// Creates a system entry for an error.
+    #[must_use]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self {
+            direction: "system".to_string(),
+            kind: "error".to_string(),
+            content: message.into(),
+            timestamp: chrono::Local::now().format("%H:%M:%S%.3f").to_string(),
+            size: String::new(),
+        }
+    }
+}
+
+/// Cookie entry for the cookie manager.
+#[derive(Debug, Clone, Default)]
+pub struct CookieData {
+    pub domain: String,
+    pub name: String,
+    pub value: String,
+    pub path: String,
+    pub expires: String,
+    pub flags: String,
+}
+
+impl CookieData {
+    /// Creates a manager entry for a domain cookie.
+    #[must_use]
+    pub fn from_cookie(cookie: &Cookie) -> Self {
+        let mut flags = Vec::new();
+        if cookie.http_only {
+            flags.push("HttpOnly");
+        }
+        if cookie.secure {
+            flags.push("Secure");
+        }
+        if cookie.same_site != SameSite::None {
+            flags.push(cookie.same_site.display_name());
+        }
+
+        Self {
+            domain: cookie.domain.clone(),
+            name: cookie.name.clone(),
+            value: cookie.value.clone(),
+            path: cookie.path.clone(),
+            expires: cookie.expires.map_or_else(
+                || "Session".to_string(),
+                |expires| {
+                    expires
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                },
+            ),
+            flags: flags.join(", "),
+        }
+    }
+}
+
+/// Global proxy settings as edited in the settings dialog.
+///
+/// The dialog edits a single proxy used for both HTTP and HTTPS.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettingsData {
+    pub enabled: bool,
+    pub url: String,
+    pub username: String,
+    pub password: String,
+    pub bypass: String, // Comma-separated hosts
+    pub use_system: bool,
+}
+
+impl ProxySettingsData {
+    /// Creates dialog data from the global proxy settings.
+    #[must_use]
+    pub fn from_settings(settings: &GlobalProxySettings) -> Self {
+        let proxy = &settings.http_proxy;
+        Self {
+            enabled: proxy.enabled && !proxy.url.is_empty(),
+            url: proxy.url.clone(),
+            username: proxy.username.clone().unwrap_or_default(),
+            password: proxy.password.clone().unwrap_or_default(),
+            bypass: proxy
+                .bypass_hosts
+                .iter()
+                .chain(&settings.global_bypass)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", "),
+            use_system: proxy.use_system_proxy,
+        }
+    }
+
+    /// Converts the dialog data back into global proxy settings.
+    ///
+    /// An explicit proxy URL wins over the environment variables; without
+    /// one, the environment is used if enabled.
+    #[must_use]
+    pub fn to_settings(&self) -> GlobalProxySettings {
+        let url = if self.enabled {
+            self.url.trim().to_string()
+        } else {
+            String::new()
+        };
+        let mut proxy = ProxyConfig {
+            enabled: !url.is_empty() || self.use_system,
+            url,
+            use_system_proxy: self.use_system,
+            ..ProxyConfig::default()
+        };
+        if !self.username.is_empty() {
+            proxy = proxy.with_auth(self.username.trim(), self.password.as_str());
+        }
+
+        GlobalProxySettings {
+            http_proxy: proxy.with_bypass(
+                self.bypass
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|host| !host.is_empty())
+                    .map(String::from)
+                    .collect(),
+            ),
+            ..GlobalProxySettings::default()
+        }
+    }
+}
+
 /// Search result data for UI (Sprint 06).
 #[derive(Debug, Clone)]
 pub struct SearchResultData {
@@ -426,6 +775,9 @@ pub enum UiUpdate {
     /// Update the workspace path.
     WorkspacePath(String),
 
+    /// Show the ways the workspace TLS configuration weakens verification.
+    TlsWarnings(Vec<String>),
+
     /// Update the collection tree items.
     CollectionItems(Vec<TreeItemData>),
 
@@ -442,6 +794,12 @@ pub enum UiUpdate {
         body: String,
     },
 
+    /// Update the methods listed in the method selector.
+    Methods(Vec<String>),
+
+    /// Select a method in the method selector.
+    MethodIndex(i32),
+
     // --- Environment Updates (Sprint 03) ---
     /// Update the list of environment names.
     EnvironmentNames(Vec<String>),
@@ -485,6 +843,7 @@ pub enum UiUpdate {
         font_scale_index: i32,
         dark_mode: bool,
         font_scale_factor: f32,
+        proxy: ProxySettingsData,
     },
 
     // --- History Updates (Sprint 04) ---
@@ -509,6 +868,10 @@ pub enum UiUpdate {
     // --- Sprint 05: Response Headers Updates ---
     /// Update response headers list.
     ResponseHeaders(Vec<ResponseHeaderData>),
+    /// Update the response timing waterfall.
+    ResponseTiming(Vec<TimingPhaseData>),
+    /// Update the redirect chain of the response.
+    ResponseRedirects(Vec<RedirectHopData>),
 
     // --- Sprint 05: Authentication Updates ---
     /// Update authentication data.
@@ -599,6 +962,61 @@ pub enum UiUpdate {
     /// Update request body with formatted JSON.
     FormattedRequestBody(String),
 
+    // --- GraphQL Updates ---
+    /// Show the state of the schema of the current GraphQL endpoint.
+    GraphqlSchemaStatus(String),
+
+    // --- gRPC Updates ---
+    /// Switch the body editor to the gRPC message editor.
+    GrpcMode(bool),
+
+    /// Update the gRPC method list.
+    GrpcMethods {
+        methods: Vec<String>,
+        selected: i32,
+        status: String,
+    },
+
+    /// Show the state of the gRPC method list.
+    GrpcStatus(String),
+
+    /// Update the `.proto` file list.
+    GrpcProtoFiles(String),
+
+    /// Offer a message for the selected method. Replaces an empty message only.
+    GrpcMessageTemplate(String),
+
+    /// Show a streamed response or gRPC call while it arrives.
+    LiveResponse(LiveResponseData),
+
+    // --- WebSocket Updates ---
+    /// Switch the response area to the WebSocket message log.
+    WebSocketMode(bool),
+
+    /// Update the WebSocket connection status.
+    WebSocketStatus {
+        state: i32, // 0=Disconnected, 1=Connecting, 2=Connected, 3=Reconnecting, 4=Failed, 5=Closing
+        status_text: String,
+        stats: String,
+    },
+
+    /// Update the WebSocket message log (newest first).
+    WebSocketMessages(Vec<WebSocketMessageData>),
+
+    /// Clear the WebSocket message composer after a send.
+    WebSocketMessageSent,
+
+    // --- Cookie Updates ---
+    /// Toggle the cookie manager dialog.
+    ShowCookieManager(bool),
+
+    /// Update the cookie manager list.
+    CookieList {
+        cookies: Vec<CookieData>,
+        scope: String,
+        per_environment: bool,
+    },
+
     /// Restore response state when switching tabs.
     RestoreResponseState {
         state: i32, // 0=Idle, 1=Loading, 2=Success, 3=Error
@@ -608,6 +1026,8 @@ pub enum UiUpdate {
         duration: String,
         size: String,
         headers: Vec<ResponseHeaderData>,
+        timing: Vec<TimingPhaseData>,
+        redirects: Vec<RedirectHopData>,
         error_title: String,
         error_message: String,
     },